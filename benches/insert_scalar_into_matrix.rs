@@ -15,7 +15,7 @@ use graphblas_sparse_linear_algebra::util::{ElementIndex, ElementIndexSelector};
 
 use graphblas_sparse_linear_algebra::context::Mode;
 
-use graphblas_sparse_linear_algebra::operators::binary_operator::First;
+use graphblas_sparse_linear_algebra::operators::binary_operator::{First, NoAccumulator};
 use graphblas_sparse_linear_algebra::operators::insert::{
     InsertScalarIntoMatrix, InsertScalarIntoMatrixTrait,
 };
@@ -74,7 +74,8 @@ fn bench_test(context: Arc<Context>) {
     let columns_to_insert: Vec<ElementIndex> = (0..6).collect();
     let columns_to_insert = ElementIndexSelector::Index(&columns_to_insert);
 
-    let insert_operator = InsertScalarIntoMatrix::new(&OperatorOptions::new_default(), None);
+    let insert_operator =
+        InsertScalarIntoMatrix::new(&OperatorOptions::new_default(), &NoAccumulator);
 
     for scalar_to_insert in 1..10 as u8 {
         insert_operator
@@ -100,7 +101,7 @@ fn bench_test(context: Arc<Context>) {
                 &rows_to_insert,
                 &columns_to_insert,
                 &scalar_to_insert,
-                &mask.clone().into(),
+                &MatrixMask::new(&mask),
             )
             .unwrap();
     }
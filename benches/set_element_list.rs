@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use graphblas_sparse_linear_algebra::context::{Context, Mode};
+use graphblas_sparse_linear_algebra::operators::binary_operator::{First, Second};
+use graphblas_sparse_linear_algebra::value_types::sparse_matrix::{
+    FromMatrixElementList, MatrixElement, MatrixElementList, SetMatrixElement,
+    SetMatrixElementList, Size, SparseMatrix,
+};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_set_element_list(c: &mut Criterion) {
+    let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+    c.bench_function("test_set_element_per_element", |b| {
+        b.iter(|| set_elements_one_by_one(context.clone()))
+    });
+    c.bench_function("test_set_element_list", |b| {
+        b.iter(|| set_element_list(context.clone()))
+    });
+}
+
+criterion_group!(benches, bench_set_element_list);
+criterion_main!(benches);
+
+const MATRIX_SIZE: usize = 1000;
+const NUMBER_OF_ELEMENTS: usize = 10000;
+
+fn elements_to_set() -> Vec<MatrixElement<u8>> {
+    (0..NUMBER_OF_ELEMENTS)
+        .map(|i| (i % MATRIX_SIZE, (i * 7) % MATRIX_SIZE, (i % 255) as u8).into())
+        .collect()
+}
+
+fn initial_matrix(context: &Arc<Context>) -> SparseMatrix<u8> {
+    let element_list = MatrixElementList::<u8>::from_element_vector(vec![
+        (1, 1, 1).into(),
+        (2, 2, 2).into(),
+        (2, 4, 10).into(),
+    ]);
+    let matrix_size: Size = (MATRIX_SIZE, MATRIX_SIZE).into();
+    SparseMatrix::<u8>::from_element_list(
+        context,
+        &matrix_size,
+        &element_list,
+        &First::<u8, u8, u8>::new(),
+    )
+    .unwrap()
+}
+
+fn set_elements_one_by_one(context: Arc<Context>) {
+    let mut matrix = initial_matrix(&context);
+    for element in elements_to_set() {
+        matrix.set_element(element).unwrap();
+    }
+}
+
+fn set_element_list(context: Arc<Context>) {
+    let mut matrix = initial_matrix(&context);
+    let element_list = MatrixElementList::<u8>::from_element_vector(elements_to_set());
+    matrix
+        .set_element_list(
+            &element_list,
+            &Second::<u8, u8, u8>::new(),
+            &Second::<u8, u8, u8>::new(),
+        )
+        .unwrap();
+}
@@ -0,0 +1,343 @@
+use crate::algorithms::validation::{check_vertices, number_of_vertices};
+use crate::error::{LogicError, LogicErrorType, SparseLinearAlgebraError};
+use crate::operators::apply::BinaryOperatorApplier;
+use crate::operators::binary_operator::{Divide, First, NoAccumulator, Plus, Times};
+use crate::operators::element_wise_addition::{
+    ElementWiseMatrixAdditionBinaryOperator, ElementWiseVectorAdditionBinaryOperator,
+};
+use crate::operators::element_wise_multiplication::ElementWiseMatrixMultiplicationBinaryOperator;
+use crate::operators::mask::MatrixMask;
+use crate::operators::monoid::Plus as PlusMonoid;
+use crate::operators::multiplication::MatrixMultiplicationOperator;
+use crate::operators::options::OperatorOptions;
+use crate::operators::reduce::MonoidReducer;
+use crate::operators::semiring::PlusFirst;
+use crate::util::ElementIndex;
+use crate::value_types::sparse_matrix::{FromMatrixElementList, MatrixElementList, SparseMatrix};
+use crate::value_types::sparse_vector::SparseVector;
+use crate::value_types::value_type::{BuiltInValueType, ValueType};
+
+/// Betweenness centrality by Brandes' algorithm, which searches breadth-first from a batch of
+/// sources at once, and then accumulates the dependencies of the sources on every vertex backwards.
+///
+/// The adjacency matrix stores A(i, j) for an edge from vertex i to vertex j, its values are ignored.
+/// The centrality of a vertex sums the fraction of the shortest paths from a source to another
+/// vertex that pass through it. For undirected graphs, every path is counted in both directions.
+/// Vertices on no such path are not stored in the result.
+#[derive(Debug, Clone)]
+pub struct BetweennessCentrality {
+    batch_size: usize,
+}
+
+impl BetweennessCentrality {
+    /// The batch size is the number of sources searched from at once,
+    /// the memory use grows with batch size * number of vertices.
+    pub fn new(batch_size: usize) -> Self {
+        Self { batch_size }
+    }
+
+    /// Uses every vertex as a source
+    pub fn exact_centrality<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+    ) -> Result<SparseVector<f64>, SparseLinearAlgebraError> {
+        let sources: Vec<ElementIndex> = (0..number_of_vertices(adjacency)?).collect();
+        self.centrality(adjacency, &sources)
+    }
+
+    /// Sums the dependencies of the given sources only
+    pub fn centrality<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+        sources: &[ElementIndex],
+    ) -> Result<SparseVector<f64>, SparseLinearAlgebraError> {
+        let number_of_vertices = number_of_vertices(adjacency)?;
+        check_vertices("source", sources, number_of_vertices)?;
+        if self.batch_size == 0 {
+            return Err(LogicError::new(
+                LogicErrorType::Other,
+                String::from("The batch size must be positive"),
+                None,
+            )
+            .into());
+        }
+
+        let plus = ElementWiseVectorAdditionBinaryOperator::<f64, f64, f64>::new(
+            &Plus::<f64, f64, f64>::new(),
+            &OperatorOptions::new_default(),
+            &NoAccumulator,
+        );
+        let mut centrality =
+            SparseVector::<f64>::new(adjacency.context_ref(), &number_of_vertices)?;
+        for batch in sources.chunks(self.batch_size) {
+            centrality = plus.compute(&centrality, &dependencies(adjacency, batch)?)?;
+        }
+        Ok(centrality)
+    }
+
+    /// Estimates the exact centrality from a sample of the sources,
+    /// by scaling their dependencies with number of vertices / number of sampled sources
+    pub fn approximate_centrality<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+        sampled_sources: &[ElementIndex],
+    ) -> Result<SparseVector<f64>, SparseLinearAlgebraError> {
+        if sampled_sources.is_empty() {
+            return Err(LogicError::new(
+                LogicErrorType::Other,
+                String::from("Approximating the centrality requires at least one sampled source"),
+                None,
+            )
+            .into());
+        }
+        let scale = number_of_vertices(adjacency)? as f64 / sampled_sources.len() as f64;
+        BinaryOperatorApplier::new(
+            &Times::<f64, f64, f64>::new(),
+            &OperatorOptions::new_default(),
+            &NoAccumulator,
+        )
+        .compute_with_vector_as_first_argument(
+            &self.centrality(adjacency, sampled_sources)?,
+            &scale,
+        )
+    }
+}
+
+// The dependencies of each source on every vertex, summed over the sources.
+// Row k of the matrices belongs to sources[k], the level matrices store the number of
+// shortest paths from the source to each vertex at that distance.
+fn dependencies<T: ValueType + BuiltInValueType<T>>(
+    adjacency: &SparseMatrix<T>,
+    sources: &[ElementIndex],
+) -> Result<SparseVector<f64>, SparseLinearAlgebraError> {
+    let number_of_vertices = number_of_vertices(adjacency)?;
+    let batch_size = sources.len();
+    let options = OperatorOptions::new_default();
+    let plus = ElementWiseMatrixAdditionBinaryOperator::<f64, f64, f64>::new(
+        &Plus::<f64, f64, f64>::new(),
+        &options,
+        &NoAccumulator,
+    );
+    let times = ElementWiseMatrixMultiplicationBinaryOperator::<f64, f64, f64>::new(
+        &Times::<f64, f64, f64>::new(),
+        &options,
+        &NoAccumulator,
+    );
+    let divide = ElementWiseMatrixMultiplicationBinaryOperator::<f64, f64, f64>::new(
+        &Divide::<f64, f64, f64>::new(),
+        &options,
+        &NoAccumulator,
+    );
+    let reciprocal =
+        BinaryOperatorApplier::new(&Divide::<f64, f64, f64>::new(), &options, &NoAccumulator);
+
+    let source_paths = SparseMatrix::<f64>::from_element_list(
+        adjacency.context_ref(),
+        &(batch_size, number_of_vertices).into(),
+        &MatrixElementList::from_vectors(
+            (0..batch_size).collect(),
+            sources.to_vec(),
+            vec![1.0; batch_size],
+        )?,
+        &First::<f64, f64, f64>::new(),
+    )?;
+
+    // Forward: the paths to the next level sum the paths to their predecessors on this level
+    let push = MatrixMultiplicationOperator::<f64, T, f64>::new(
+        &PlusFirst::<f64, T, f64>::new(),
+        &options,
+        &NoAccumulator,
+    );
+    let mut paths = source_paths.clone();
+    let mut levels: Vec<SparseMatrix<f64>> = Vec::new();
+    loop {
+        let frontier = push.compute_with_mask(
+            &MatrixMask::new(&paths).structure().complement(),
+            levels.last().unwrap_or(&source_paths),
+            adjacency,
+        )?;
+        if frontier.number_of_stored_elements()? == 0 {
+            break;
+        }
+        paths = plus.compute(&paths, &frontier)?;
+        levels.push(frontier);
+    }
+
+    // Backward: dependency(v) = sum over successors w of paths(v) / paths(w) * (1 + dependency(w)),
+    // a source does not depend on itself, so the first level is the last one that is updated
+    let pull = MatrixMultiplicationOperator::<f64, T, f64>::new(
+        &PlusFirst::<f64, T, f64>::new(),
+        &OperatorOptions::new(false, false, true),
+        &NoAccumulator,
+    );
+    let mut dependencies = SparseMatrix::<f64>::new(
+        adjacency.context_ref(),
+        &(batch_size, number_of_vertices).into(),
+    )?;
+    for level in (1..levels.len()).rev() {
+        let (successors, predecessors) = (&levels[level], &levels[level - 1]);
+        let successor_weights = plus.compute(
+            &reciprocal.compute_with_matrix_as_second_argument(&1.0, successors)?,
+            &divide.compute(&dependencies, successors)?,
+        )?;
+        let predecessor_weights = pull.compute_with_mask(
+            &MatrixMask::new(predecessors).structure(),
+            &successor_weights,
+            adjacency,
+        )?;
+        dependencies = plus.compute(
+            &dependencies,
+            &times.compute(&predecessor_weights, predecessors)?,
+        )?;
+    }
+
+    MonoidReducer::new(
+        &PlusMonoid::<f64>::new(),
+        &OperatorOptions::new(false, true, false),
+        &NoAccumulator,
+    )
+    .compute_vector(&dependencies)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::VecDeque;
+
+    use crate::algorithms::test_graphs::{adjacency, pseudo_random_edges};
+    use crate::context::{Context, Mode};
+    use crate::value_types::sparse_vector::ToDenseVector;
+
+    fn reference_centrality(
+        number_of_vertices: usize,
+        edges: &[(usize, usize)],
+        sources: &[usize],
+    ) -> Vec<f64> {
+        let mut neighbours = vec![Vec::new(); number_of_vertices];
+        for (from, to) in edges.iter() {
+            if !neighbours[*from].contains(to) {
+                neighbours[*from].push(*to);
+            }
+        }
+
+        let mut centrality = vec![0.0; number_of_vertices];
+        for source in sources.iter() {
+            let mut levels = vec![-1; number_of_vertices];
+            let mut paths = vec![0.0; number_of_vertices];
+            let mut order = Vec::new();
+            let mut queue = VecDeque::new();
+            levels[*source] = 0;
+            paths[*source] = 1.0;
+            queue.push_back(*source);
+            while let Some(vertex) = queue.pop_front() {
+                order.push(vertex);
+                for neighbour in neighbours[vertex].iter() {
+                    if levels[*neighbour] == -1 {
+                        levels[*neighbour] = levels[vertex] + 1;
+                        queue.push_back(*neighbour);
+                    }
+                    if levels[*neighbour] == levels[vertex] + 1 {
+                        paths[*neighbour] += paths[vertex];
+                    }
+                }
+            }
+
+            let mut dependencies = vec![0.0; number_of_vertices];
+            for vertex in order.iter().rev() {
+                for neighbour in neighbours[*vertex].iter() {
+                    if levels[*neighbour] == levels[*vertex] + 1 {
+                        dependencies[*vertex] +=
+                            paths[*vertex] / paths[*neighbour] * (1.0 + dependencies[*neighbour]);
+                    }
+                }
+                if vertex != source {
+                    centrality[*vertex] += dependencies[*vertex];
+                }
+            }
+        }
+        centrality
+    }
+
+    fn assert_close(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len());
+        for (actual, expected) in actual.iter().zip(expected.iter()) {
+            assert!(
+                (actual - expected).abs() <= 1e-9 * expected.abs().max(1.0),
+                "{} != {}",
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_exact_centrality_matches_reference() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let number_of_vertices = 80;
+        let edges = pseudo_random_edges(number_of_vertices, 240);
+        let adjacency = adjacency(&context, number_of_vertices, &edges, 3);
+        let all_vertices: Vec<usize> = (0..number_of_vertices).collect();
+        let expected_centrality = reference_centrality(number_of_vertices, &edges, &all_vertices);
+
+        for batch_size in [1, 7, number_of_vertices].iter() {
+            let centrality = BetweennessCentrality::new(*batch_size)
+                .exact_centrality(&adjacency)
+                .unwrap();
+            assert_close(&centrality.to_dense(0.0).unwrap(), &expected_centrality);
+        }
+    }
+
+    #[test]
+    fn test_approximate_centrality_scales_sampled_sources() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let number_of_vertices = 80;
+        let edges = pseudo_random_edges(number_of_vertices, 240);
+        let adjacency = adjacency(&context, number_of_vertices, &edges, 3);
+        let sampled_sources = [3, 41, 17, 3, 60];
+        let expected_centrality: Vec<f64> =
+            reference_centrality(number_of_vertices, &edges, &sampled_sources)
+                .iter()
+                .map(|centrality| centrality * number_of_vertices as f64 / 5.0)
+                .collect();
+
+        let centrality = BetweennessCentrality::new(2)
+            .approximate_centrality(&adjacency, &sampled_sources)
+            .unwrap();
+        assert_close(&centrality.to_dense(0.0).unwrap(), &expected_centrality);
+
+        assert!(BetweennessCentrality::new(2)
+            .approximate_centrality(&adjacency, &[])
+            .is_err());
+        assert!(BetweennessCentrality::new(0)
+            .centrality(&adjacency, &[0])
+            .is_err());
+    }
+
+    #[test]
+    fn test_centrality_of_an_undirected_path() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        // 0 - 1 - 2 - 3, with two shortest paths from 4 to 2, via 1 and via 5
+        let edges = [(0, 1), (1, 2), (2, 3), (4, 1), (4, 5), (5, 2)];
+        let symmetric_edges: Vec<(usize, usize)> = edges
+            .iter()
+            .flat_map(|(from, to)| vec![(*from, *to), (*to, *from)])
+            .collect();
+        let adjacency = adjacency(&context, 6, &symmetric_edges, 3);
+
+        let centrality = BetweennessCentrality::new(4)
+            .exact_centrality(&adjacency)
+            .unwrap()
+            .to_dense(0.0)
+            .unwrap();
+        assert_close(
+            &centrality,
+            &reference_centrality(6, &symmetric_edges, &[0, 1, 2, 3, 4, 5]),
+        );
+        assert_eq!(centrality[0], 0.0);
+        assert_eq!(centrality[3], 0.0);
+    }
+}
@@ -0,0 +1,275 @@
+use crate::algorithms::validation::{check_vertices, number_of_vertices};
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::{UnaryOperatorApplier, UnaryOperatorApplierTrait};
+use crate::operators::binary_operator::{First, NoAccumulator, Second};
+use crate::operators::insert::{InsertScalarIntoVector, InsertScalarIntoVectorTrait};
+use crate::operators::mask::VectorMask;
+use crate::operators::multiplication::{
+    MatrixVectorMultiplicationOperator, VectorMatrixMultiplicationOperator,
+};
+use crate::operators::options::OperatorOptions;
+use crate::operators::semiring::AnySecondIndex;
+use crate::operators::unary_operator::Identity;
+use crate::util::{ElementIndex, ElementIndexSelector};
+use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::sparse_vector::{FromVectorElementList, SparseVector, VectorElementList};
+use crate::value_types::value_type::{BuiltInValueType, ValueType};
+
+// DirectionOptimizing pulls when the frontier holds more than 1/PULL_FRACTION of the unvisited vertices
+const PULL_FRACTION: ElementIndex = 4;
+
+/// How each step of a breadth-first search expands the frontier
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TraversalDirection {
+    /// Multiplies the frontier with the adjacency matrix: frontier * A
+    Push,
+    /// Multiplies the transposed adjacency matrix with the frontier: A' * frontier
+    Pull,
+    /// Pushes small frontiers and pulls large ones
+    DirectionOptimizing,
+}
+
+/// Breadth-first search from one or more source vertices.
+///
+/// The adjacency matrix stores A(i, j) for an edge from vertex i to vertex j, its values are ignored.
+/// The level of a vertex is the number of edges on a shortest path from the nearest source,
+/// its parent is its predecessor on such a path. Sources have level 0 and are their own parent.
+/// Vertices that cannot be reached are not stored in the results.
+#[derive(Debug, Clone)]
+pub struct BreadthFirstSearch {
+    direction: TraversalDirection,
+}
+
+impl BreadthFirstSearch {
+    pub fn new(direction: TraversalDirection) -> Self {
+        Self { direction }
+    }
+
+    pub fn levels<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+        sources: &[ElementIndex],
+    ) -> Result<SparseVector<i64>, SparseLinearAlgebraError> {
+        self.search(adjacency, sources, None)
+    }
+
+    pub fn parents<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+        sources: &[ElementIndex],
+    ) -> Result<SparseVector<i64>, SparseLinearAlgebraError> {
+        let (_levels, parents) = self.levels_and_parents(adjacency, sources)?;
+        Ok(parents)
+    }
+
+    /// Returns (levels, parents)
+    pub fn levels_and_parents<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+        sources: &[ElementIndex],
+    ) -> Result<(SparseVector<i64>, SparseVector<i64>), SparseLinearAlgebraError> {
+        let mut parents =
+            SparseVector::<i64>::new(adjacency.context_ref(), &number_of_vertices(adjacency)?)?;
+        let levels = self.search(adjacency, sources, Some(&mut parents))?;
+        Ok((levels, parents))
+    }
+
+    // The frontier stores the parent of each of its vertices,
+    // the levels store the vertices that have been visited.
+    fn search<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+        sources: &[ElementIndex],
+        mut parents: Option<&mut SparseVector<i64>>,
+    ) -> Result<SparseVector<i64>, SparseLinearAlgebraError> {
+        let number_of_vertices = number_of_vertices(adjacency)?;
+        check_vertices("source", sources, number_of_vertices)?;
+        let context = adjacency.context_ref();
+
+        let source_elements = VectorElementList::<i64>::from_element_vector(
+            sources
+                .iter()
+                .map(|source| (*source, *source as i64).into())
+                .collect(),
+        );
+        let mut frontier = SparseVector::<i64>::from_element_list(
+            context,
+            &number_of_vertices,
+            &source_elements,
+            &First::<i64, i64, i64>::new(),
+        )?;
+        let mut levels = SparseVector::<i64>::new(context, &number_of_vertices)?;
+
+        let options = OperatorOptions::new_default();
+        let level_setter = InsertScalarIntoVector::<i64, i64>::new(&options, &NoAccumulator);
+        let parent_setter = UnaryOperatorApplier::<i64>::new(
+            &Identity::<i64>::new(),
+            &options,
+            &Second::<i64, i64, i64>::new(),
+        );
+        let push = VectorMatrixMultiplicationOperator::<i64, T, i64>::new(
+            &AnySecondIndex::<i64, T, i64>::new(),
+            &options,
+            &NoAccumulator,
+        );
+        let pull = MatrixVectorMultiplicationOperator::<T, i64, i64>::new(
+            &AnySecondIndex::<T, i64, i64>::new(),
+            &OperatorOptions::new(false, true, false),
+            &NoAccumulator,
+        );
+
+        let mut level: i64 = 0;
+        let mut number_of_visited_vertices = 0;
+        loop {
+            let frontier_size = frontier.number_of_stored_elements()?;
+            if frontier_size == 0 {
+                break;
+            }
+
+            level_setter.apply_with_mask(
+                &mut levels,
+                &ElementIndexSelector::All,
+                &level,
+                &VectorMask::new(&frontier).structure(),
+            )?;
+            if let Some(parents) = parents.as_deref_mut() {
+                parent_setter.apply_to_vector(&frontier, parents)?;
+            }
+
+            number_of_visited_vertices += frontier_size;
+            let number_of_unvisited_vertices = number_of_vertices - number_of_visited_vertices;
+            if number_of_unvisited_vertices == 0 {
+                break;
+            }
+
+            let use_pull = match self.direction {
+                TraversalDirection::Push => false,
+                TraversalDirection::Pull => true,
+                TraversalDirection::DirectionOptimizing => {
+                    frontier_size * PULL_FRACTION > number_of_unvisited_vertices
+                }
+            };
+            let unvisited = VectorMask::new(&levels).structure().complement();
+            frontier = if use_pull {
+                pull.compute_with_mask(&unvisited, adjacency, &frontier)?
+            } else {
+                push.compute_with_mask(&unvisited, &frontier, adjacency)?
+            };
+            level += 1;
+        }
+
+        Ok(levels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::VecDeque;
+
+    use crate::algorithms::test_graphs::{adjacency, pseudo_random_edges};
+    use crate::context::{Context, Mode};
+    use crate::value_types::sparse_vector::ToDenseVector;
+
+    const DIRECTIONS: [TraversalDirection; 3] = [
+        TraversalDirection::Push,
+        TraversalDirection::Pull,
+        TraversalDirection::DirectionOptimizing,
+    ];
+
+    fn reference_levels(
+        number_of_vertices: usize,
+        edges: &[(usize, usize)],
+        sources: &[usize],
+    ) -> Vec<i64> {
+        let mut neighbours = vec![Vec::new(); number_of_vertices];
+        for (from, to) in edges.iter() {
+            neighbours[*from].push(*to);
+        }
+        let mut levels = vec![-1; number_of_vertices];
+        let mut queue = VecDeque::new();
+        for source in sources.iter() {
+            levels[*source] = 0;
+            queue.push_back(*source);
+        }
+        while let Some(vertex) = queue.pop_front() {
+            for neighbour in neighbours[vertex].iter() {
+                if levels[*neighbour] == -1 {
+                    levels[*neighbour] = levels[vertex] + 1;
+                    queue.push_back(*neighbour);
+                }
+            }
+        }
+        levels
+    }
+
+    #[test]
+    fn test_levels_match_reference() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let number_of_vertices = 200;
+        let edges = pseudo_random_edges(number_of_vertices, 400);
+        let adjacency = adjacency(&context, number_of_vertices, &edges, 1.5f32);
+
+        for sources in [vec![0], vec![3, 17, 3]].iter() {
+            let expected_levels = reference_levels(number_of_vertices, &edges, sources);
+            for direction in DIRECTIONS.iter() {
+                let levels = BreadthFirstSearch::new(*direction)
+                    .levels(&adjacency, sources)
+                    .unwrap();
+                assert_eq!(levels.to_dense(-1).unwrap(), expected_levels);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parents_are_on_shortest_paths() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let number_of_vertices = 100;
+        let edges = pseudo_random_edges(number_of_vertices, 250);
+        let adjacency = adjacency(&context, number_of_vertices, &edges, 1.5f32);
+        let sources = [5, 50];
+        let expected_levels = reference_levels(number_of_vertices, &edges, &sources);
+
+        for direction in DIRECTIONS.iter() {
+            let (levels, parents) = BreadthFirstSearch::new(*direction)
+                .levels_and_parents(&adjacency, &sources)
+                .unwrap();
+            let levels = levels.to_dense(-1).unwrap();
+            let parents = parents.to_dense(-1).unwrap();
+            assert_eq!(levels, expected_levels);
+
+            for vertex in 0..number_of_vertices {
+                let parent = parents[vertex];
+                if levels[vertex] == -1 {
+                    assert_eq!(parent, -1);
+                } else if levels[vertex] == 0 {
+                    assert_eq!(parent, vertex as i64);
+                } else {
+                    let parent = parent as usize;
+                    assert_eq!(levels[parent], levels[vertex] - 1);
+                    assert!(edges.contains(&(parent, vertex)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_unreachable_vertices_are_not_stored() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        // 0 -> 1 -> 2, 3 -> 0
+        let adjacency = adjacency(&context, 4, &[(0, 1), (1, 2), (3, 0)], 1.5f32);
+        let levels = BreadthFirstSearch::new(TraversalDirection::Push)
+            .levels(&adjacency, &[0])
+            .unwrap();
+        assert_eq!(levels.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(levels.to_dense(-1).unwrap(), vec![0, 1, 2, -1]);
+
+        assert!(BreadthFirstSearch::new(TraversalDirection::Push)
+            .levels(&adjacency, &[4])
+            .is_err());
+    }
+}
@@ -0,0 +1,354 @@
+use std::sync::Arc;
+
+use crate::algorithms::validation::number_of_vertices;
+use crate::context::Context;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{First, Min, NoAccumulator, NotEqual};
+use crate::operators::element_wise_addition::ElementWiseVectorAdditionBinaryOperator;
+use crate::operators::element_wise_multiplication::ElementWiseVectorMultiplicationBinaryOperator;
+use crate::operators::extract::SubVectorExtractor;
+use crate::operators::monoid::{LogicalOr, Plus as PlusMonoid};
+use crate::operators::multiplication::MatrixVectorMultiplicationOperator;
+use crate::operators::options::OperatorOptions;
+use crate::operators::reduce::{MonoidReducer, MonoidScalarReducer};
+use crate::operators::semiring::MinSecond;
+use crate::util::{ElementIndex, ElementIndexSelector};
+use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::sparse_vector::{
+    FromVectorElementList, SparseVector, ToDenseVector, VectorElementList,
+};
+use crate::value_types::value_type::{BuiltInValueType, ValueType};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConnectedComponentsAlgorithm {
+    /// FastSV: hooks the trees of a forest onto the smallest grandparent among their neighbours,
+    /// and shortcuts them to their grandparents, until the grandparents no longer change
+    FastSV,
+    /// Replaces the label of each vertex by the smallest label among its neighbours,
+    /// until no label changes. Takes as many iterations as the largest component is wide.
+    LabelPropagation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EdgeDirection {
+    /// The adjacency matrix is symmetric, every edge is only followed from i to j
+    Undirected,
+    /// Every edge is followed in both directions, such that the components are weakly connected
+    Directed,
+}
+
+/// The component of every vertex, labeled by the smallest vertex in the component
+#[derive(Debug, Clone)]
+pub struct ComponentLabels {
+    labels: SparseVector<u64>,
+    number_of_components: u64,
+}
+
+impl ComponentLabels {
+    // Counts the components as the vertices that label themselves
+    pub(crate) fn new(labels: SparseVector<u64>) -> Result<Self, SparseLinearAlgebraError> {
+        let number_of_components = labels
+            .to_dense(0)?
+            .iter()
+            .enumerate()
+            .filter(|(vertex, label)| **label == *vertex as u64)
+            .count() as u64;
+        Ok(Self {
+            labels,
+            number_of_components,
+        })
+    }
+
+    /// Stores a label for every vertex
+    pub fn labels(&self) -> &SparseVector<u64> {
+        &self.labels
+    }
+
+    pub fn into_labels(self) -> SparseVector<u64> {
+        self.labels
+    }
+
+    pub fn number_of_components(&self) -> u64 {
+        self.number_of_components
+    }
+}
+
+/// Connected components of a graph.
+///
+/// The adjacency matrix stores A(i, j) for an edge from vertex i to vertex j, its values are ignored.
+#[derive(Debug, Clone)]
+pub struct ConnectedComponents {
+    algorithm: ConnectedComponentsAlgorithm,
+    edge_direction: EdgeDirection,
+}
+
+impl ConnectedComponents {
+    pub fn new(algorithm: ConnectedComponentsAlgorithm, edge_direction: EdgeDirection) -> Self {
+        Self {
+            algorithm,
+            edge_direction,
+        }
+    }
+
+    pub fn components<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+    ) -> Result<ComponentLabels, SparseLinearAlgebraError> {
+        let number_of_vertices = number_of_vertices(adjacency)?;
+        let vertices = vertex_indices(adjacency.context_ref(), number_of_vertices)?;
+
+        let labels = match self.algorithm {
+            ConnectedComponentsAlgorithm::FastSV => self.fast_sv(adjacency, vertices)?,
+            ConnectedComponentsAlgorithm::LabelPropagation => {
+                self.label_propagation(adjacency, vertices)?
+            }
+        };
+
+        ComponentLabels::new(labels)
+    }
+
+    // The parents may start as any forest of stars, each labeled by its smallest vertex,
+    // such as the components of a subgraph
+    pub(crate) fn fast_sv<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+        mut parents: SparseVector<u64>,
+    ) -> Result<SparseVector<u64>, SparseLinearAlgebraError> {
+        let context = adjacency.context_ref();
+        let number_of_vertices = parents.length()?;
+        let options = OperatorOptions::new_default();
+        let minimum = ElementWiseVectorAdditionBinaryOperator::<u64, u64, u64>::new(
+            &Min::<u64, u64, u64>::new(),
+            &options,
+            &NoAccumulator,
+        );
+        let grandparent_extractor = SubVectorExtractor::<u64, u64>::new(&options, &NoAccumulator);
+        let has_changed = ElementWiseVectorMultiplicationBinaryOperator::<u64, u64, bool>::new(
+            &NotEqual::<u64, u64, bool>::new(),
+            &options,
+            &NoAccumulator,
+        );
+        let any = MonoidReducer::new(&LogicalOr::<bool>::new(), &options, &NoAccumulator);
+
+        let mut host_parents = element_indices(&parents)?;
+        let mut grandparents = parents.clone();
+        loop {
+            let mut minimum_neighbouring_grandparents = grandparents.clone();
+            self.propagate_minimum(
+                adjacency,
+                &grandparents,
+                &mut minimum_neighbouring_grandparents,
+            )?;
+
+            // Stochastic hooking: parents(parents(i)) = min(parents(parents(i)), mngp(i)),
+            // duplicate parents keep the smallest grandparent
+            let hooks = SparseVector::<u64>::from_element_list(
+                context,
+                &number_of_vertices,
+                &VectorElementList::from_vectors(
+                    host_parents,
+                    minimum_neighbouring_grandparents.to_dense(0)?,
+                )?,
+                &Min::<u64, u64, u64>::new(),
+            )?;
+            // Aggressive hooking: parents(i) = min(parents(i), mngp(i)), which also shortcuts
+            // parents(i) = min(parents(i), grandparents(i)), as mngp(i) <= grandparents(i)
+            parents = minimum.compute(
+                &minimum.compute(&parents, &hooks)?,
+                &minimum_neighbouring_grandparents,
+            )?;
+
+            host_parents = element_indices(&parents)?;
+            let next_grandparents = grandparent_extractor
+                .compute(&parents, &ElementIndexSelector::Index(&host_parents))?;
+
+            let mut grandparents_have_changed = false;
+            any.vector_to_scalar(
+                &has_changed.compute(&grandparents, &next_grandparents)?,
+                &mut grandparents_have_changed,
+            )?;
+            if !grandparents_have_changed {
+                return Ok(parents);
+            }
+            grandparents = next_grandparents;
+        }
+    }
+
+    // The labels only decrease, so they have stopped changing when their sum has
+    fn label_propagation<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+        mut labels: SparseVector<u64>,
+    ) -> Result<SparseVector<u64>, SparseLinearAlgebraError> {
+        let sum = MonoidReducer::new(
+            &PlusMonoid::<u64>::new(),
+            &OperatorOptions::new_default(),
+            &NoAccumulator,
+        );
+
+        let mut sum_of_labels = 0;
+        sum.vector_to_scalar(&labels, &mut sum_of_labels)?;
+        loop {
+            let mut next_labels = labels.clone();
+            self.propagate_minimum(adjacency, &labels, &mut next_labels)?;
+            labels = next_labels;
+
+            let mut next_sum_of_labels = 0;
+            sum.vector_to_scalar(&labels, &mut next_sum_of_labels)?;
+            if next_sum_of_labels == sum_of_labels {
+                return Ok(labels);
+            }
+            sum_of_labels = next_sum_of_labels;
+        }
+    }
+
+    // minimum(i) = min(minimum(i), values(j)) for the edges between i and j
+    fn propagate_minimum<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+        values: &SparseVector<u64>,
+        minimum: &mut SparseVector<u64>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let min_second = MinSecond::<T, u64, u64>::new();
+        let accumulator = Min::<u64, u64, u64>::new();
+
+        MatrixVectorMultiplicationOperator::<T, u64, u64>::new(
+            &min_second,
+            &OperatorOptions::new_default(),
+            &accumulator,
+        )
+        .apply(adjacency, values, minimum)?;
+        if self.edge_direction == EdgeDirection::Directed {
+            MatrixVectorMultiplicationOperator::<T, u64, u64>::new(
+                &min_second,
+                &OperatorOptions::new(false, true, false),
+                &accumulator,
+            )
+            .apply(adjacency, values, minimum)?;
+        }
+        Ok(())
+    }
+}
+
+// Every vertex labeled by itself
+pub(crate) fn vertex_indices(
+    context: &Arc<Context>,
+    number_of_vertices: ElementIndex,
+) -> Result<SparseVector<u64>, SparseLinearAlgebraError> {
+    SparseVector::<u64>::from_element_list(
+        context,
+        &number_of_vertices,
+        &VectorElementList::from_vectors(
+            (0..number_of_vertices).collect(),
+            (0..number_of_vertices as u64).collect(),
+        )?,
+        &First::<u64, u64, u64>::new(),
+    )
+}
+
+// The stored values of a vector of vertices, such as parents, as indices
+pub(crate) fn element_indices(
+    vertices: &SparseVector<u64>,
+) -> Result<Vec<ElementIndex>, SparseLinearAlgebraError> {
+    Ok(vertices
+        .to_dense(0)?
+        .iter()
+        .map(|vertex| *vertex as ElementIndex)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::algorithms::test_graphs::{adjacency, pseudo_random_edges};
+    use crate::context::Mode;
+
+    const ALGORITHMS: [ConnectedComponentsAlgorithm; 2] = [
+        ConnectedComponentsAlgorithm::FastSV,
+        ConnectedComponentsAlgorithm::LabelPropagation,
+    ];
+
+    // Union-find, labeling every component by its smallest vertex
+    fn reference_labels(number_of_vertices: usize, edges: &[(usize, usize)]) -> Vec<u64> {
+        fn root(parents: &mut [usize], vertex: usize) -> usize {
+            let mut root = vertex;
+            while parents[root] != root {
+                root = parents[root];
+            }
+            parents[vertex] = root;
+            root
+        }
+
+        let mut parents: Vec<usize> = (0..number_of_vertices).collect();
+        for (from, to) in edges.iter() {
+            let (from_root, to_root) = (root(&mut parents, *from), root(&mut parents, *to));
+            parents[from_root.max(to_root)] = from_root.min(to_root);
+        }
+        (0..number_of_vertices)
+            .map(|vertex| root(&mut parents, vertex) as u64)
+            .collect()
+    }
+
+    #[test]
+    fn test_components_match_reference() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let number_of_vertices = 300;
+        let edges = pseudo_random_edges(number_of_vertices, 200);
+        let symmetric_edges: Vec<(usize, usize)> = edges
+            .iter()
+            .flat_map(|(from, to)| vec![(*from, *to), (*to, *from)])
+            .collect();
+        let expected_labels = reference_labels(number_of_vertices, &edges);
+        let expected_number_of_components = expected_labels
+            .iter()
+            .enumerate()
+            .filter(|(vertex, label)| **label == *vertex as u64)
+            .count() as u64;
+        assert!(expected_number_of_components > 1);
+
+        let directed = adjacency(&context, number_of_vertices, &edges, 0.5);
+        let undirected = adjacency(&context, number_of_vertices, &symmetric_edges, 0.5);
+        for algorithm in ALGORITHMS.iter() {
+            for (adjacency, edge_direction) in [
+                (&directed, EdgeDirection::Directed),
+                (&undirected, EdgeDirection::Undirected),
+                (&undirected, EdgeDirection::Directed),
+            ]
+            .iter()
+            {
+                let components = ConnectedComponents::new(*algorithm, *edge_direction)
+                    .components(*adjacency)
+                    .unwrap();
+                assert_eq!(
+                    components.number_of_components(),
+                    expected_number_of_components
+                );
+                assert_eq!(
+                    components.labels().number_of_stored_elements().unwrap(),
+                    number_of_vertices
+                );
+                assert_eq!(components.labels().to_dense(0).unwrap(), expected_labels);
+            }
+        }
+    }
+
+    #[test]
+    fn test_components_of_a_path() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        // 1 -> 2 -> 3 -> 4, 0 and 5 are isolated
+        let adjacency = adjacency(&context, 6, &[(1, 2), (2, 3), (3, 4)], 0.5);
+        for algorithm in ALGORITHMS.iter() {
+            let components = ConnectedComponents::new(*algorithm, EdgeDirection::Directed)
+                .components(&adjacency)
+                .unwrap();
+            assert_eq!(components.number_of_components(), 3);
+            assert_eq!(
+                components.into_labels().to_dense(0).unwrap(),
+                vec![0, 1, 1, 1, 1, 5]
+            );
+        }
+    }
+}
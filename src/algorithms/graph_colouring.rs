@@ -0,0 +1,180 @@
+use crate::algorithms::maximal_independent_set::{
+    edges_without_self_loops, independent_subset, RandomWeights,
+};
+use crate::algorithms::validation::number_of_vertices;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::UnaryOperatorApplier;
+use crate::operators::binary_operator::NoAccumulator;
+use crate::operators::insert::{InsertScalarIntoVector, InsertScalarIntoVectorTrait};
+use crate::operators::mask::VectorMask;
+use crate::operators::options::OperatorOptions;
+use crate::operators::unary_operator::Identity;
+use crate::util::ElementIndexSelector;
+use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::sparse_vector::SparseVector;
+use crate::value_types::value_type::{BuiltInValueType, ValueType};
+
+/// The colour of every vertex, numbered from 0, such that no two neighbours share a colour
+#[derive(Debug, Clone)]
+pub struct VertexColours {
+    colours: SparseVector<u64>,
+    number_of_colours: u64,
+}
+
+impl VertexColours {
+    /// Stores a colour for every vertex
+    pub fn colours(&self) -> &SparseVector<u64> {
+        &self.colours
+    }
+
+    pub fn into_colours(self) -> SparseVector<u64> {
+        self.colours
+    }
+
+    pub fn number_of_colours(&self) -> u64 {
+        self.number_of_colours
+    }
+}
+
+/// Greedy colouring of an undirected graph in the style of Jones-Plassmann.
+///
+/// The adjacency matrix must be symmetric, its values are ignored, and so are self-loops.
+/// Every round, the uncoloured vertices of a maximal independent set of the uncoloured subgraph,
+/// found by Luby's algorithm, are given the next colour, until every vertex has a colour.
+/// The vertices of a colour are not neighbours, such that they can be updated in parallel.
+/// The seed makes the random vertex weights, and thereby the colouring, reproducible.
+/// The weights are drawn as u64 values that never tie, see MaximalIndependentSet.
+#[derive(Debug, Clone)]
+pub struct GraphColouring {
+    seed: u64,
+}
+
+impl GraphColouring {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    pub fn colouring<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+    ) -> Result<VertexColours, SparseLinearAlgebraError> {
+        let number_of_vertices = number_of_vertices(adjacency)?;
+        let context = adjacency.context_ref();
+        let options = OperatorOptions::new_default();
+        let edges = edges_without_self_loops(adjacency)?;
+        let mut random_weights = RandomWeights::new(self.seed);
+        let colour_setter = InsertScalarIntoVector::<u64, u64>::new(&options, &NoAccumulator);
+        let copy =
+            UnaryOperatorApplier::<bool>::new(&Identity::<bool>::new(), &options, &NoAccumulator);
+
+        let mut uncoloured = SparseVector::<bool>::new(context, &number_of_vertices)?;
+        InsertScalarIntoVector::<bool, bool>::new(&options, &NoAccumulator).apply(
+            &mut uncoloured,
+            &ElementIndexSelector::All,
+            &true,
+        )?;
+
+        let mut colours = SparseVector::<u64>::new(context, &number_of_vertices)?;
+        let mut colour: u64 = 0;
+        while uncoloured.number_of_stored_elements()? > 0 {
+            let independent_set =
+                independent_subset(&edges, uncoloured.clone(), &mut random_weights)?;
+            colour_setter.apply_with_mask(
+                &mut colours,
+                &ElementIndexSelector::All,
+                &colour,
+                &VectorMask::new(&independent_set).structure(),
+            )?;
+            uncoloured = copy.compute_vector_with_mask(
+                &uncoloured,
+                &VectorMask::new(&independent_set).structure().complement(),
+            )?;
+            colour += 1;
+        }
+        Ok(VertexColours {
+            colours,
+            number_of_colours: colour,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::algorithms::test_graphs::{adjacency, pseudo_random_undirected_edges};
+    use crate::context::{Context, Mode};
+    use crate::value_types::sparse_vector::ToDenseVector;
+
+    #[test]
+    fn test_colouring_is_proper() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let number_of_vertices = 200;
+        let edges = pseudo_random_undirected_edges(number_of_vertices, 600);
+        let adjacency = adjacency(&context, number_of_vertices, &edges, 2.0);
+        let largest_degree = (0..number_of_vertices)
+            .map(|vertex| {
+                edges
+                    .iter()
+                    .filter(|(from, to)| *from == vertex && from != to)
+                    .count()
+            })
+            .max()
+            .unwrap() as u64;
+
+        let colouring = GraphColouring::new(42).colouring(&adjacency).unwrap();
+        assert_eq!(
+            colouring.colours().number_of_stored_elements().unwrap(),
+            number_of_vertices
+        );
+        assert!(colouring.number_of_colours() > 2);
+        assert!(colouring.number_of_colours() <= largest_degree + 1);
+
+        let colours = colouring.colours().to_dense(u64::MAX).unwrap();
+        assert!(colours
+            .iter()
+            .all(|colour| *colour < colouring.number_of_colours()));
+        for (from, to) in edges.iter().filter(|(from, to)| from != to) {
+            assert_ne!(colours[*from], colours[*to]);
+        }
+
+        let same_colouring = GraphColouring::new(42).colouring(&adjacency).unwrap();
+        assert_eq!(same_colouring.into_colours().to_dense(0).unwrap(), colours);
+    }
+
+    #[test]
+    fn test_colouring_of_a_clique_and_a_star() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        // The clique 0, 1, 2, 3, the star with centre 4 and leaves 5, 6, 7, and 8 with a self-loop
+        let edges = [
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (4, 5),
+            (4, 6),
+            (4, 7),
+            (8, 8),
+        ];
+        let symmetric_edges: Vec<(usize, usize)> = edges
+            .iter()
+            .flat_map(|(from, to)| vec![(*from, *to), (*to, *from)])
+            .collect();
+        let adjacency = adjacency(&context, 9, &symmetric_edges, 2.0);
+
+        let colouring = GraphColouring::new(3).colouring(&adjacency).unwrap();
+        assert_eq!(colouring.number_of_colours(), 4);
+
+        let colours = colouring.colours().to_dense(u64::MAX).unwrap();
+        let mut clique_colours = colours[0..4].to_vec();
+        clique_colours.sort_unstable();
+        assert_eq!(clique_colours, vec![0, 1, 2, 3]);
+        assert!(colours[4] < 2);
+        assert!(colours[5..8].iter().all(|colour| *colour == 1 - colours[4]));
+        assert_eq!(colours[8], 0);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::algorithms::validation::number_of_vertices;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::{UnaryOperatorApplier, UnaryOperatorApplierTrait};
+use crate::operators::binary_operator::{Minus, NoAccumulator};
+use crate::operators::extract::SubMatrixExtractor;
+use crate::operators::insert::{InsertScalarIntoVector, InsertScalarIntoVectorTrait};
+use crate::operators::mask::VectorMask;
+use crate::operators::monoid::Plus as PlusMonoid;
+use crate::operators::multiplication::MatrixVectorMultiplicationOperator;
+use crate::operators::options::OperatorOptions;
+use crate::operators::reduce::MonoidReducer;
+use crate::operators::select::{
+    DiagonalIndex, MatrixSelector, SelectVectorLessThanOrEqualToScalar, VectorSelector,
+};
+use crate::operators::semiring::PlusFirst;
+use crate::operators::unary_operator::{Identity, One};
+use crate::util::ElementIndexSelector;
+use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::sparse_vector::SparseVector;
+use crate::value_types::value_type::{BuiltInValueType, ValueType};
+
+/// k-core decomposition of an undirected graph, the k-core is the largest subgraph in which every
+/// vertex has at least k neighbours.
+///
+/// The adjacency matrix must be symmetric, its values are ignored, and so are self-loops.
+/// The vertices are peeled off by their degree in the remaining graph: for k = 0, 1, 2, ..., the
+/// vertices of degree k or less are removed until none are left, and have core number k.
+#[derive(Debug, Clone, Default)]
+pub struct KCore {}
+
+impl KCore {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// The largest k of the k-cores that each vertex is in, stored for every vertex
+    pub fn core_numbers<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+    ) -> Result<SparseVector<u64>, SparseLinearAlgebraError> {
+        let number_of_vertices = number_of_vertices(adjacency)?;
+        let context = adjacency.context_ref();
+        let options = OperatorOptions::new_default();
+        let edges = ones_without_self_loops(adjacency)?;
+
+        let mut core_numbers = SparseVector::<u64>::new(context, &number_of_vertices)?;
+        let core_number_setter = InsertScalarIntoVector::<u64, u64>::new(&options, &NoAccumulator);
+        core_number_setter.apply(&mut core_numbers, &ElementIndexSelector::All, &0)?;
+
+        // Stores the degrees of the remaining vertices, vertices without edges have core number 0
+        let mut degrees = SparseVector::<u64>::new(context, &number_of_vertices)?;
+        MonoidReducer::new(&PlusMonoid::<u64>::new(), &options, &NoAccumulator)
+            .to_vector(&edges, &mut degrees)?;
+
+        let selector = VectorSelector::<u64>::new(&options, &NoAccumulator);
+        let copy =
+            UnaryOperatorApplier::<u64>::new(&Identity::<u64>::new(), &options, &NoAccumulator);
+        let subtract_peeled_neighbours = MatrixVectorMultiplicationOperator::<u64, u64, u64>::new(
+            &PlusFirst::<u64, u64, u64>::new(),
+            &OperatorOptions::new(true, false, false),
+            &Minus::<u64, u64, u64>::new(),
+        );
+
+        let mut core_number: u64 = 0;
+        while degrees.number_of_stored_elements()? > 0 {
+            loop {
+                let mut peeled = SparseVector::<u64>::new(context, &number_of_vertices)?;
+                selector.less_than_or_equal_to_scalar(&degrees, &mut peeled, &core_number)?;
+                if peeled.number_of_stored_elements()? == 0 {
+                    break;
+                }
+                core_number_setter.apply_with_mask(
+                    &mut core_numbers,
+                    &ElementIndexSelector::All,
+                    &core_number,
+                    &VectorMask::new(&peeled).structure(),
+                )?;
+
+                // degrees<remaining, replace> -= edges * peeled
+                let remaining = copy.compute_vector_with_mask(
+                    &degrees,
+                    &VectorMask::new(&peeled).structure().complement(),
+                )?;
+                subtract_peeled_neighbours.apply_with_mask(
+                    &VectorMask::new(&remaining).structure(),
+                    &edges,
+                    &peeled,
+                    &mut degrees,
+                )?;
+            }
+            core_number += 1;
+        }
+        Ok(core_numbers)
+    }
+}
+
+// The edges of the graph without its self-loops, with a value of 1
+fn ones_without_self_loops<T: ValueType + BuiltInValueType<T>>(
+    adjacency: &SparseMatrix<T>,
+) -> Result<SparseMatrix<u64>, SparseLinearAlgebraError> {
+    let options = OperatorOptions::new_default();
+    let edges = SubMatrixExtractor::<T, u64>::new(&options, &NoAccumulator).compute(
+        &MatrixSelector::<T>::new(&options, &NoAccumulator)
+            .compute_clear_diagonal(adjacency, &DiagonalIndex::Default())?,
+        &ElementIndexSelector::All,
+        &ElementIndexSelector::All,
+    )?;
+    let mut ones = SparseMatrix::<u64>::new(adjacency.context_ref(), &edges.size()?)?;
+    UnaryOperatorApplier::<u64>::new(&One::<u64>::new(), &options, &NoAccumulator)
+        .apply_to_matrix(&edges, &mut ones)?;
+    Ok(ones)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::algorithms::test_graphs::{adjacency, pseudo_random_undirected_edges};
+    use crate::context::{Context, Mode};
+    use crate::value_types::sparse_vector::ToDenseVector;
+
+    // Repeatedly removes a vertex of the smallest degree
+    fn reference_core_numbers(number_of_vertices: usize, edges: &[(usize, usize)]) -> Vec<u64> {
+        let mut neighbours = vec![Vec::new(); number_of_vertices];
+        for (from, to) in edges.iter() {
+            if from != to && !neighbours[*from].contains(to) {
+                neighbours[*from].push(*to);
+            }
+        }
+        let mut degrees: Vec<usize> = neighbours.iter().map(|list| list.len()).collect();
+        let mut removed = vec![false; number_of_vertices];
+        let mut core_numbers = vec![0; number_of_vertices];
+        let mut core_number = 0;
+        for _ in 0..number_of_vertices {
+            let vertex = (0..number_of_vertices)
+                .filter(|vertex| !removed[*vertex])
+                .min_by_key(|vertex| degrees[*vertex])
+                .unwrap();
+            core_number = core_number.max(degrees[vertex]);
+            core_numbers[vertex] = core_number as u64;
+            removed[vertex] = true;
+            for neighbour in neighbours[vertex].iter() {
+                degrees[*neighbour] -= 1;
+            }
+        }
+        core_numbers
+    }
+
+    #[test]
+    fn test_core_numbers_match_reference() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let number_of_vertices = 100;
+        let edges = pseudo_random_undirected_edges(number_of_vertices, 300);
+        let adjacency = adjacency(&context, number_of_vertices, &edges, 0.25);
+        let expected_core_numbers = reference_core_numbers(number_of_vertices, &edges);
+        assert!(expected_core_numbers.iter().max().unwrap() > &2);
+
+        let core_numbers = KCore::new().core_numbers(&adjacency).unwrap();
+        assert_eq!(
+            core_numbers.number_of_stored_elements().unwrap(),
+            number_of_vertices
+        );
+        assert_eq!(core_numbers.to_dense(0).unwrap(), expected_core_numbers);
+    }
+
+    #[test]
+    fn test_core_numbers_of_a_clique_with_a_tail() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        // The clique 0, 1, 2, 3, with the path 3 - 4 - 5, a self-loop on 5, and 6 isolated
+        let edges = [
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 5),
+        ];
+        let symmetric_edges: Vec<(usize, usize)> = edges
+            .iter()
+            .flat_map(|(from, to)| vec![(*from, *to), (*to, *from)])
+            .collect();
+        let adjacency = adjacency(&context, 7, &symmetric_edges, 0.25);
+
+        let core_numbers = KCore::new().core_numbers(&adjacency).unwrap();
+        assert_eq!(core_numbers.to_dense(9).unwrap(), vec![3, 3, 3, 3, 1, 1, 0]);
+    }
+}
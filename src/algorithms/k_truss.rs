@@ -0,0 +1,186 @@
+use crate::algorithms::validation::number_of_vertices;
+use crate::error::{LogicError, LogicErrorType, SparseLinearAlgebraError};
+use crate::operators::binary_operator::NoAccumulator;
+use crate::operators::mask::MatrixMask;
+use crate::operators::multiplication::MatrixMultiplicationOperator;
+use crate::operators::options::OperatorOptions;
+use crate::operators::select::{
+    DiagonalIndex, MatrixSelector, SelectMatrixGreaterThanOrEqualToScalar,
+};
+use crate::operators::semiring::PlusPair;
+use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::value_type::AsBoolean;
+
+/// k-truss of an undirected graph, the largest subgraph in which every edge is in at least k - 2
+/// triangles.
+///
+/// The adjacency matrix must be symmetric, its values are ignored, and so are self-loops.
+/// The support of every edge, the number of triangles it is in, is counted by A*A masked by A,
+/// the edges without enough support are removed, until every remaining edge has enough support.
+#[derive(Debug, Clone)]
+pub struct KTruss {
+    k: u64,
+}
+
+impl KTruss {
+    /// Every edge is in the 2-truss, so k must be at least 3
+    pub fn new(k: u64) -> Self {
+        Self { k }
+    }
+
+    /// The edges of the k-truss, which store their support within the k-truss
+    pub fn truss<T: AsBoolean<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+    ) -> Result<SparseMatrix<u64>, SparseLinearAlgebraError> {
+        number_of_vertices(adjacency)?;
+        if self.k < 3 {
+            return Err(LogicError::new(
+                LogicErrorType::Other,
+                format!("The k-truss requires k to be at least 3, found: {}", self.k),
+                None,
+            )
+            .into());
+        }
+
+        let edges = MatrixSelector::<T>::new(&OperatorOptions::new_default(), &NoAccumulator)
+            .compute_clear_diagonal(adjacency, &DiagonalIndex::Default())?;
+        let mut truss = self.supported_edges(&support(&edges)?)?;
+        loop {
+            let supported_edges = self.supported_edges(&support(&truss)?)?;
+            if supported_edges.number_of_stored_elements()? == truss.number_of_stored_elements()? {
+                return Ok(supported_edges);
+            }
+            truss = supported_edges;
+        }
+    }
+
+    fn supported_edges(
+        &self,
+        support: &SparseMatrix<u64>,
+    ) -> Result<SparseMatrix<u64>, SparseLinearAlgebraError> {
+        let mut supported_edges =
+            SparseMatrix::<u64>::new(support.context_ref(), &support.size()?)?;
+        MatrixSelector::<u64>::new(&OperatorOptions::new_default(), &NoAccumulator)
+            .greater_than_or_equal_to_scalar(support, &mut supported_edges, &(self.k - 2))?;
+        Ok(supported_edges)
+    }
+}
+
+// The number of triangles of each edge, edges without triangles are not stored
+fn support<T: AsBoolean<T>>(
+    edges: &SparseMatrix<T>,
+) -> Result<SparseMatrix<u64>, SparseLinearAlgebraError> {
+    MatrixMultiplicationOperator::<T, T, u64>::new(
+        &PlusPair::<T, T, u64>::new(),
+        &OperatorOptions::new_default(),
+        &NoAccumulator,
+    )
+    .compute_with_mask(&MatrixMask::new(edges).structure(), edges, edges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::BTreeMap;
+
+    use crate::algorithms::test_graphs::{adjacency, pseudo_random_undirected_edges};
+    use crate::context::{Context, Mode};
+    use crate::value_types::sparse_matrix::GetMatrixElementList;
+
+    // Repeatedly removes the edges without enough triangles, returns the support of the others
+    fn reference_truss(
+        number_of_vertices: usize,
+        edges: &[(usize, usize)],
+        k: u64,
+    ) -> BTreeMap<(usize, usize), u64> {
+        let mut is_edge = vec![vec![false; number_of_vertices]; number_of_vertices];
+        for (from, to) in edges.iter() {
+            if from != to {
+                is_edge[*from][*to] = true;
+            }
+        }
+        loop {
+            let mut support = BTreeMap::new();
+            for from in 0..number_of_vertices {
+                for to in 0..number_of_vertices {
+                    if is_edge[from][to] {
+                        let triangles = (0..number_of_vertices)
+                            .filter(|via| is_edge[from][*via] && is_edge[*via][to])
+                            .count() as u64;
+                        support.insert((from, to), triangles);
+                    }
+                }
+            }
+            let unsupported: Vec<(usize, usize)> = support
+                .iter()
+                .filter(|(_, triangles)| **triangles < k - 2)
+                .map(|(edge, _)| *edge)
+                .collect();
+            if unsupported.is_empty() {
+                return support;
+            }
+            for (from, to) in unsupported.iter() {
+                is_edge[*from][*to] = false;
+            }
+        }
+    }
+
+    fn truss_elements(truss: &SparseMatrix<u64>) -> BTreeMap<(usize, usize), u64> {
+        let elements = truss.get_element_list().unwrap();
+        elements
+            .row_indices_ref()
+            .iter()
+            .zip(elements.column_indices_ref().iter())
+            .zip(elements.values_ref().iter())
+            .map(|((row, column), value)| ((*row, *column), *value))
+            .collect()
+    }
+
+    #[test]
+    fn test_truss_matches_reference() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let number_of_vertices = 40;
+        let edges = pseudo_random_undirected_edges(number_of_vertices, 200);
+        let adjacency = adjacency(&context, number_of_vertices, &edges, 1);
+
+        for k in [3, 4, 5].iter() {
+            let expected_truss = reference_truss(number_of_vertices, &edges, *k);
+            let truss = KTruss::new(*k).truss(&adjacency).unwrap();
+            assert_eq!(truss_elements(&truss), expected_truss);
+        }
+        assert!(!reference_truss(number_of_vertices, &edges, 4).is_empty());
+    }
+
+    #[test]
+    fn test_truss_of_a_clique_with_a_triangle() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        // The clique 0, 1, 2, 3, sharing the edge 2 - 3 with the triangle 2, 3, 4
+        let edges = [
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (2, 4),
+            (3, 4),
+        ];
+        let symmetric_edges: Vec<(usize, usize)> = edges
+            .iter()
+            .flat_map(|(from, to)| vec![(*from, *to), (*to, *from)])
+            .collect();
+        let adjacency = adjacency(&context, 5, &symmetric_edges, 1);
+
+        let truss = KTruss::new(4).truss(&adjacency).unwrap();
+        let truss = truss_elements(&truss);
+        assert_eq!(truss.len(), 12);
+        assert!(truss.values().all(|support| *support == 2));
+        assert!(!truss.contains_key(&(2, 4)));
+
+        assert!(KTruss::new(2).truss(&adjacency).is_err());
+    }
+}
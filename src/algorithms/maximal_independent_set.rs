@@ -0,0 +1,300 @@
+use crate::algorithms::validation::number_of_vertices;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::UnaryOperatorApplier;
+use crate::operators::binary_operator::{First, LessThan, NoAccumulator, Second};
+use crate::operators::element_wise_multiplication::ElementWiseVectorMultiplicationBinaryOperator;
+use crate::operators::insert::{InsertScalarIntoVector, InsertScalarIntoVectorTrait};
+use crate::operators::mask::VectorMask;
+use crate::operators::multiplication::MatrixVectorMultiplicationOperator;
+use crate::operators::options::OperatorOptions;
+use crate::operators::select::{DiagonalIndex, MatrixSelector};
+use crate::operators::semiring::{AnyPair, MaxSecond};
+use crate::operators::unary_operator::Identity;
+use crate::util::ElementIndexSelector;
+use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::sparse_vector::{
+    FromVectorElementList, GetVectorElementList, SparseVector, VectorElementList,
+};
+use crate::value_types::value_type::{BuiltInValueType, ValueType};
+
+/// Maximal independent set of an undirected graph by Luby's algorithm, a set of vertices of which
+/// no two are neighbours, to which no other vertex can be added.
+///
+/// The adjacency matrix must be symmetric, its values are ignored, and so are self-loops.
+/// Every round, each candidate vertex draws a random weight, the candidates with a larger weight
+/// than all their candidate neighbours join the set, and they and their neighbours are no longer
+/// candidates. The seed makes the random weights, and thereby the set, reproducible.
+///
+/// The weights are u64 rather than f64: random high bits above the vertex index in the low bits.
+/// Thereby no two vertices draw the same weight, such that neighbours never tie and the candidate
+/// with the largest weight joins the set every round, which random f64 weights cannot ensure.
+#[derive(Debug, Clone)]
+pub struct MaximalIndependentSet {
+    seed: u64,
+}
+
+impl MaximalIndependentSet {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Stores true for the vertices in the set, and nothing for the others
+    pub fn independent_set<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+    ) -> Result<SparseVector<bool>, SparseLinearAlgebraError> {
+        let number_of_vertices = number_of_vertices(adjacency)?;
+        let edges = edges_without_self_loops(adjacency)?;
+
+        let mut candidates =
+            SparseVector::<bool>::new(adjacency.context_ref(), &number_of_vertices)?;
+        InsertScalarIntoVector::<bool, bool>::new(&OperatorOptions::new_default(), &NoAccumulator)
+            .apply(&mut candidates, &ElementIndexSelector::All, &true)?;
+        independent_subset(&edges, candidates, &mut RandomWeights::new(self.seed))
+    }
+}
+
+// A maximal independent set of the subgraph induced by the candidates
+pub(crate) fn independent_subset<T: ValueType + BuiltInValueType<T>>(
+    edges: &SparseMatrix<T>,
+    mut candidates: SparseVector<bool>,
+    random_weights: &mut RandomWeights,
+) -> Result<SparseVector<bool>, SparseLinearAlgebraError> {
+    let context = edges.context_ref();
+    let number_of_vertices = candidates.length()?;
+    let options = OperatorOptions::new_default();
+    let membership_setter = InsertScalarIntoVector::<bool, bool>::new(&options, &NoAccumulator);
+    let weight_setter = InsertScalarIntoVector::<u64, u64>::new(&options, &NoAccumulator);
+    let copy =
+        UnaryOperatorApplier::<bool>::new(&Identity::<bool>::new(), &options, &NoAccumulator);
+    let largest_neighbouring_weight = MatrixVectorMultiplicationOperator::<T, u64, u64>::new(
+        &MaxSecond::<T, u64, u64>::new(),
+        &options,
+        &Second::<u64, u64, u64>::new(),
+    );
+    let is_larger = ElementWiseVectorMultiplicationBinaryOperator::<u64, u64, bool>::new(
+        &LessThan::<u64, u64, bool>::new(),
+        &options,
+        &NoAccumulator,
+    );
+    let neighbours_of = MatrixVectorMultiplicationOperator::<T, bool, bool>::new(
+        &AnyPair::<T, bool, bool>::new(),
+        &options,
+        &NoAccumulator,
+    );
+
+    let mut independent_set = SparseVector::<bool>::new(context, &number_of_vertices)?;
+    while candidates.number_of_stored_elements()? > 0 {
+        let weights = random_weights.weights_of(&candidates)?;
+
+        // Candidates without candidate neighbours compare their weight to 0
+        let mut largest_neighbouring_weights =
+            SparseVector::<u64>::new(context, &number_of_vertices)?;
+        weight_setter.apply_with_mask(
+            &mut largest_neighbouring_weights,
+            &ElementIndexSelector::All,
+            &0,
+            &VectorMask::new(&candidates).structure(),
+        )?;
+        largest_neighbouring_weight.apply_with_mask(
+            &VectorMask::new(&candidates).structure(),
+            edges,
+            &weights,
+            &mut largest_neighbouring_weights,
+        )?;
+
+        let is_local_maximum = is_larger.compute(&largest_neighbouring_weights, &weights)?;
+        let mut new_members = SparseVector::<bool>::new(context, &number_of_vertices)?;
+        membership_setter.apply_with_mask(
+            &mut new_members,
+            &ElementIndexSelector::All,
+            &true,
+            &VectorMask::new(&is_local_maximum),
+        )?;
+        membership_setter.apply_with_mask(
+            &mut independent_set,
+            &ElementIndexSelector::All,
+            &true,
+            &VectorMask::new(&new_members).structure(),
+        )?;
+
+        // The new members and their neighbours are no longer candidates
+        let neighbours_of_new_members = neighbours_of.compute_with_mask(
+            &VectorMask::new(&candidates).structure(),
+            edges,
+            &new_members,
+        )?;
+        candidates = copy.compute_vector_with_mask(
+            &copy.compute_vector_with_mask(
+                &candidates,
+                &VectorMask::new(&new_members).structure().complement(),
+            )?,
+            &VectorMask::new(&neighbours_of_new_members)
+                .structure()
+                .complement(),
+        )?;
+    }
+    Ok(independent_set)
+}
+
+// The edges of the graph without its self-loops
+pub(crate) fn edges_without_self_loops<T: ValueType + BuiltInValueType<T>>(
+    adjacency: &SparseMatrix<T>,
+) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+    MatrixSelector::<T>::new(&OperatorOptions::new_default(), &NoAccumulator)
+        .compute_clear_diagonal(adjacency, &DiagonalIndex::Default())
+}
+
+// Draws the random vertex weights from a linear congruential generator
+#[derive(Debug, Clone)]
+pub(crate) struct RandomWeights {
+    state: u64,
+}
+
+impl RandomWeights {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    // The weights are positive and differ by their vertex, which takes their low bits, such that
+    // every round the candidate with the largest weight joins the set
+    fn weights_of(
+        &mut self,
+        vertices: &SparseVector<bool>,
+    ) -> Result<SparseVector<u64>, SparseLinearAlgebraError> {
+        let number_of_vertices = vertices.length()?;
+        let bits_of_vertices = 64 - (number_of_vertices as u64).leading_zeros();
+        let vertex_bits = u64::MAX >> (64 - bits_of_vertices);
+        let indices = vertices.get_element_list()?.indices_ref().to_vec();
+        let weights = indices
+            .iter()
+            .map(|vertex| {
+                self.state = self
+                    .state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (self.state & !vertex_bits) | (*vertex as u64 + 1)
+            })
+            .collect();
+        SparseVector::<u64>::from_element_list(
+            vertices.context_ref(),
+            &number_of_vertices,
+            &VectorElementList::from_vectors(indices, weights)?,
+            &First::<u64, u64, u64>::new(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::algorithms::test_graphs::{adjacency, pseudo_random_undirected_edges};
+    use crate::context::{Context, Mode};
+    use crate::util::ElementIndex;
+    use crate::value_types::sparse_vector::ToDenseVector;
+
+    #[test]
+    fn test_independent_set_is_maximal() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let number_of_vertices = 200;
+        let edges = pseudo_random_undirected_edges(number_of_vertices, 400);
+        let adjacency = adjacency(&context, number_of_vertices, &edges, 1);
+
+        for seed in [1, 2, 3].iter() {
+            let independent_set = MaximalIndependentSet::new(*seed)
+                .independent_set(&adjacency)
+                .unwrap();
+            let is_member = independent_set.to_dense(false).unwrap();
+            assert_eq!(
+                independent_set.number_of_stored_elements().unwrap(),
+                is_member.iter().filter(|is_member| **is_member).count()
+            );
+
+            // No two members are neighbours, and every other vertex has a neighbouring member
+            let mut has_neighbouring_member = vec![false; number_of_vertices];
+            for (from, to) in edges.iter().filter(|(from, to)| from != to) {
+                assert!(!(is_member[*from] && is_member[*to]));
+                has_neighbouring_member[*from] |= is_member[*to];
+            }
+            for vertex in 0..number_of_vertices {
+                assert!(is_member[vertex] || has_neighbouring_member[vertex]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_independent_set_is_reproducible() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let number_of_vertices = 100;
+        let edges = pseudo_random_undirected_edges(number_of_vertices, 300);
+        let adjacency = adjacency(&context, number_of_vertices, &edges, 1);
+
+        let independent_set = MaximalIndependentSet::new(7)
+            .independent_set(&adjacency)
+            .unwrap()
+            .to_dense(false)
+            .unwrap();
+        let same_independent_set = MaximalIndependentSet::new(7)
+            .independent_set(&adjacency)
+            .unwrap()
+            .to_dense(false)
+            .unwrap();
+        assert_eq!(independent_set, same_independent_set);
+    }
+
+    #[test]
+    fn test_independent_set_of_a_star() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        // The centre 0 with the leaves 1, 2, 3, 4, a self-loop on 5, and 6 isolated
+        let edges = [(0, 1), (0, 2), (0, 3), (0, 4), (5, 5)];
+        let symmetric_edges: Vec<(usize, usize)> = edges
+            .iter()
+            .flat_map(|(from, to)| vec![(*from, *to), (*to, *from)])
+            .collect();
+        let adjacency = adjacency(&context, 7, &symmetric_edges, 1);
+
+        let is_member = MaximalIndependentSet::new(0)
+            .independent_set(&adjacency)
+            .unwrap()
+            .to_dense(false)
+            .unwrap();
+        assert!(is_member[5] && is_member[6]);
+        if is_member[0] {
+            assert_eq!(is_member[1..5], [false; 4]);
+        } else {
+            assert_eq!(is_member[1..5], [true; 4]);
+        }
+    }
+
+    #[test]
+    fn test_random_weights_differ_among_many_vertices() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        // Random numbers of 32 bits times as many vertices exceed 2^53, beyond which floating point
+        // weights of neighbouring vertices could be equal
+        let number_of_vertices = 1 << 40;
+        let vertices: Vec<ElementIndex> = (number_of_vertices - 1000..number_of_vertices).collect();
+        let candidates = SparseVector::<bool>::from_element_list(
+            &context,
+            &number_of_vertices,
+            &VectorElementList::from_vectors(vertices, vec![true; 1000]).unwrap(),
+            &First::<bool, bool, bool>::new(),
+        )
+        .unwrap();
+
+        let weights = RandomWeights::new(5)
+            .weights_of(&candidates)
+            .unwrap()
+            .get_element_list()
+            .unwrap();
+        let mut distinct_weights = weights.values_ref().to_vec();
+        distinct_weights.sort_unstable();
+        distinct_weights.dedup();
+        assert_eq!(distinct_weights.len(), 1000);
+        assert!(distinct_weights[0] > 0);
+    }
+}
@@ -0,0 +1,519 @@
+use std::sync::Arc;
+
+use crate::algorithms::connected_components::{
+    element_indices, vertex_indices, ConnectedComponents, ConnectedComponentsAlgorithm,
+    EdgeDirection,
+};
+use crate::algorithms::validation::number_of_vertices;
+use crate::context::Context;
+use crate::error::{LogicError, LogicErrorType, SparseLinearAlgebraError};
+use crate::operators::apply::{
+    BinaryOperatorApplier, UnaryOperatorApplier, UnaryOperatorApplierTrait,
+};
+use crate::operators::binary_operator::{Equal, First, Min, NoAccumulator, Plus, Times};
+use crate::operators::element_wise_addition::ElementWiseMatrixAdditionBinaryOperator;
+use crate::operators::element_wise_multiplication::ElementWiseMatrixMultiplicationBinaryOperator;
+use crate::operators::insert::{
+    InsertScalarIntoMatrix, InsertScalarIntoMatrixTrait, InsertVectorIntoColumn,
+    InsertVectorIntoColumnTrait,
+};
+use crate::operators::mask::MatrixMask;
+use crate::operators::monoid::{Min as MinMonoid, Plus as PlusMonoid};
+use crate::operators::multiplication::MatrixMultiplicationOperator;
+use crate::operators::options::OperatorOptions;
+use crate::operators::reduce::{MonoidReducer, MonoidScalarReducer};
+use crate::operators::select::{DiagonalIndex, MatrixSelector};
+use crate::operators::semiring::{AnyPair, MinSecond, MinSecondIndex, PlusFirst};
+use crate::operators::unary_operator::Identity;
+use crate::util::ElementIndexSelector;
+use crate::value_types::sparse_matrix::{FromMatrixElementList, MatrixElementList, SparseMatrix};
+use crate::value_types::sparse_vector::SparseVector;
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
+
+/// The edges of a minimum spanning forest, stored in both directions, and their total weight
+#[derive(Debug, Clone)]
+pub struct SpanningForest<T: ValueType> {
+    forest: SparseMatrix<T>,
+    total_weight: T,
+}
+
+impl<T: ValueType + Copy> SpanningForest<T> {
+    pub fn forest(&self) -> &SparseMatrix<T> {
+        &self.forest
+    }
+
+    pub fn into_forest(self) -> SparseMatrix<T> {
+        self.forest
+    }
+
+    pub fn total_weight(&self) -> T {
+        self.total_weight
+    }
+}
+
+/// Minimum spanning forest of a weighted undirected graph by Boruvka's algorithm.
+///
+/// The adjacency matrix must be symmetric and stores the weight A(i, j) of the edge between
+/// vertices i and j, self-loops are ignored. Every round, each component adds its lightest edge
+/// to another component to the forest, and the labels of the components it connects are hooked
+/// by FastSV, until no edges between components are left. Edges of equal weight are ordered by
+/// their vertices.
+#[derive(Debug, Clone)]
+pub struct MinimumSpanningForest<T: ValueType> {
+    copy: UnaryOperatorApplier<T>,
+    lightest: ComponentMinimum<T>,
+    sum: MonoidReducer<T>,
+    matrix_selector: MatrixSelector<T>,
+}
+
+// Selects the stored elements of a matrix that equal the smallest element in the rows of their
+// component. The rows are permuted to their components by P(c, i) for every vertex i of the
+// component c.
+#[derive(Debug, Clone)]
+struct ComponentMinimum<V: ValueType> {
+    minimum_of_components: MatrixMultiplicationOperator<bool, V, V>,
+    minimum: MonoidReducer<V>,
+    column_inserter: InsertVectorIntoColumn<V, V>,
+    minimum_of_rows: MatrixMultiplicationOperator<bool, V, V>,
+    broadcast: MatrixMultiplicationOperator<V, bool, V>,
+    equal: ElementWiseMatrixMultiplicationBinaryOperator<V, V, bool>,
+    copy: UnaryOperatorApplier<V>,
+}
+
+macro_rules! implement_minimum_spanning_forest {
+    ($value_type:ty) => {
+        impl MinimumSpanningForest<$value_type> {
+            pub fn new() -> Self {
+                let options = OperatorOptions::new_default();
+                Self {
+                    copy: UnaryOperatorApplier::new(
+                        &Identity::<$value_type>::new(),
+                        &options,
+                        &NoAccumulator,
+                    ),
+                    lightest: ComponentMinimum::<$value_type>::new(),
+                    sum: MonoidReducer::new(
+                        &PlusMonoid::<$value_type>::new(),
+                        &options,
+                        &NoAccumulator,
+                    ),
+                    matrix_selector: MatrixSelector::new(&options, &NoAccumulator),
+                }
+            }
+        }
+
+        impl Default for MinimumSpanningForest<$value_type> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl ComponentMinimum<$value_type> {
+            fn new() -> Self {
+                let options = OperatorOptions::new_default();
+                Self {
+                    minimum_of_components: MatrixMultiplicationOperator::new(
+                        &MinSecond::<bool, $value_type, $value_type>::new(),
+                        &options,
+                        &NoAccumulator,
+                    ),
+                    minimum: MonoidReducer::new(
+                        &MinMonoid::<$value_type>::new(),
+                        &options,
+                        &NoAccumulator,
+                    ),
+                    column_inserter: InsertVectorIntoColumn::new(&options, &NoAccumulator),
+                    minimum_of_rows: MatrixMultiplicationOperator::new(
+                        &MinSecond::<bool, $value_type, $value_type>::new(),
+                        &OperatorOptions::new(false, true, false),
+                        &NoAccumulator,
+                    ),
+                    broadcast: MatrixMultiplicationOperator::new(
+                        &PlusFirst::<$value_type, bool, $value_type>::new(),
+                        &options,
+                        &NoAccumulator,
+                    ),
+                    equal: ElementWiseMatrixMultiplicationBinaryOperator::new(
+                        &Equal::<$value_type, $value_type, bool>::new(),
+                        &options,
+                        &NoAccumulator,
+                    ),
+                    copy: UnaryOperatorApplier::new(
+                        &Identity::<$value_type>::new(),
+                        &options,
+                        &NoAccumulator,
+                    ),
+                }
+            }
+        }
+    };
+}
+
+implement_minimum_spanning_forest!(i8);
+implement_minimum_spanning_forest!(i16);
+implement_minimum_spanning_forest!(i32);
+implement_minimum_spanning_forest!(i64);
+implement_minimum_spanning_forest!(u8);
+implement_minimum_spanning_forest!(u16);
+implement_minimum_spanning_forest!(u32);
+implement_minimum_spanning_forest!(u64);
+implement_minimum_spanning_forest!(f32);
+implement_minimum_spanning_forest!(f64);
+
+impl<T> MinimumSpanningForest<T>
+where
+    T: ValueType + BuiltInValueType<T> + AsBoolean<T> + Copy + Default,
+    MonoidReducer<T>: MonoidScalarReducer<T>,
+    UnaryOperatorApplier<T>: UnaryOperatorApplierTrait<T, T>,
+    InsertVectorIntoColumn<T, T>: InsertVectorIntoColumnTrait<T, T>,
+{
+    pub fn spanning_forest(
+        &self,
+        adjacency: &SparseMatrix<T>,
+    ) -> Result<SpanningForest<T>, SparseLinearAlgebraError> {
+        let number_of_vertices = number_of_vertices(adjacency)?;
+        let context = adjacency.context_ref();
+        let options = OperatorOptions::new_default();
+        let same_component = MatrixMultiplicationOperator::<bool, bool, bool>::new(
+            &AnyPair::<bool, bool, bool>::new(),
+            &OperatorOptions::new(false, true, false),
+            &NoAccumulator,
+        );
+        let row_index = MatrixMultiplicationOperator::<bool, T, i64>::new(
+            &MinSecondIndex::<bool, T, i64>::new(),
+            &options,
+            &NoAccumulator,
+        );
+        let column_index = MatrixMultiplicationOperator::<T, bool, i64>::new(
+            &MinSecondIndex::<T, bool, i64>::new(),
+            &options,
+            &NoAccumulator,
+        );
+        let index_sum = ElementWiseMatrixMultiplicationBinaryOperator::<i64, i64, i64>::new(
+            &Plus::<i64, i64, i64>::new(),
+            &options,
+            &NoAccumulator,
+        );
+        let index_minimum = ElementWiseMatrixMultiplicationBinaryOperator::<i64, i64, i64>::new(
+            &Min::<i64, i64, i64>::new(),
+            &options,
+            &NoAccumulator,
+        );
+        let scale =
+            BinaryOperatorApplier::new(&Times::<i64, i64, i64>::new(), &options, &NoAccumulator);
+        let smallest_key = ComponentMinimum::<i64>::new();
+        let forest_inserter = InsertScalarIntoMatrix::<bool, bool>::new(&options, &NoAccumulator);
+        let hooking = ConnectedComponents::new(
+            ConnectedComponentsAlgorithm::FastSV,
+            EdgeDirection::Directed,
+        );
+        let identity = SparseMatrix::<bool>::from_element_list(
+            context,
+            &(number_of_vertices, number_of_vertices).into(),
+            &MatrixElementList::from_vectors(
+                (0..number_of_vertices).collect(),
+                (0..number_of_vertices).collect(),
+                vec![true; number_of_vertices],
+            )?,
+            &First::<bool, bool, bool>::new(),
+        )?;
+        let ones = SparseMatrix::<bool>::from_element_list(
+            context,
+            &(1, number_of_vertices).into(),
+            &MatrixElementList::from_vectors(
+                vec![0; number_of_vertices],
+                (0..number_of_vertices).collect(),
+                vec![true; number_of_vertices],
+            )?,
+            &First::<bool, bool, bool>::new(),
+        )?;
+
+        // Every component is labeled by its smallest vertex
+        let mut labels = vertex_indices(context, number_of_vertices)?;
+        let mut in_forest =
+            SparseMatrix::<bool>::new(context, &(number_of_vertices, number_of_vertices).into())?;
+        let without_self_loops = self
+            .matrix_selector
+            .compute_clear_diagonal(adjacency, &DiagonalIndex::Default())?;
+        let mut edges = without_self_loops.clone();
+        loop {
+            // Keeps the edges between components
+            let permutation = label_permutation(context, &labels)?;
+            let within_components = same_component.compute_with_mask(
+                &MatrixMask::new(&edges).structure(),
+                &permutation,
+                &permutation,
+            )?;
+            edges = self.copy.compute_matrix_with_mask(
+                &edges,
+                &MatrixMask::new(&within_components).structure().complement(),
+            )?;
+            if edges.number_of_stored_elements()? == 0 {
+                break;
+            }
+
+            // Of the lightest edges of every component, the one with the smallest key
+            // (i + j) * n + min(i, j), which is the same in both directions of an edge
+            let lightest_edges = self.lightest.select(&edges, &permutation, &ones)?;
+            let rows = row_index.compute(&identity, &lightest_edges)?;
+            let columns = column_index.compute(&lightest_edges, &identity)?;
+            let keys = index_sum.compute(
+                &scale.compute_with_matrix_as_first_argument(
+                    &index_sum.compute(&rows, &columns)?,
+                    &(number_of_vertices as i64),
+                )?,
+                &index_minimum.compute(&rows, &columns)?,
+            )?;
+            let chosen_edges = smallest_key.select(&keys, &permutation, &ones)?;
+            if chosen_edges.number_of_stored_elements()? == 0 {
+                // Without progress the loop would not end, e.g. when all weights are NaN
+                return Err(LogicError::new(
+                    LogicErrorType::Other,
+                    String::from("No lightest edge was found between the remaining components"),
+                    None,
+                )
+                .into());
+            }
+
+            // Two components may choose the same edge, in opposite directions
+            forest_inserter.apply_with_mask(
+                &mut in_forest,
+                &ElementIndexSelector::All,
+                &ElementIndexSelector::All,
+                &true,
+                &MatrixMask::new(&chosen_edges).structure(),
+            )?;
+            labels = hooking.fast_sv(&chosen_edges, labels)?;
+        }
+
+        let in_either_direction = ElementWiseMatrixAdditionBinaryOperator::<bool, bool, bool>::new(
+            &First::<bool, bool, bool>::new(),
+            &OperatorOptions::new(false, false, true),
+            &NoAccumulator,
+        )
+        .compute(&in_forest, &in_forest)?;
+        let forest = self.copy.compute_matrix_with_mask(
+            &without_self_loops,
+            &MatrixMask::new(&in_either_direction).structure(),
+        )?;
+        let mut total_weight = T::default();
+        self.sum.matrix_to_scalar(
+            &self
+                .matrix_selector
+                .compute_upper_triangle(&forest, &DiagonalIndex::Default())?,
+            &mut total_weight,
+        )?;
+        Ok(SpanningForest {
+            forest,
+            total_weight,
+        })
+    }
+}
+
+impl<V> ComponentMinimum<V>
+where
+    V: ValueType + BuiltInValueType<V> + AsBoolean<V>,
+    UnaryOperatorApplier<V>: UnaryOperatorApplierTrait<V, V>,
+    InsertVectorIntoColumn<V, V>: InsertVectorIntoColumnTrait<V, V>,
+{
+    // The ones are a single row, which spreads a column over the stored elements of the values
+    fn select(
+        &self,
+        values: &SparseMatrix<V>,
+        permutation: &SparseMatrix<bool>,
+        ones: &SparseMatrix<bool>,
+    ) -> Result<SparseMatrix<V>, SparseLinearAlgebraError> {
+        let number_of_vertices = values.row_height()?;
+        let minimum_of_components = self
+            .minimum
+            .compute_vector(&self.minimum_of_components.compute(permutation, values)?)?;
+        let mut column =
+            SparseMatrix::<V>::new(values.context_ref(), &(number_of_vertices, 1).into())?;
+        self.column_inserter.apply(
+            &mut column,
+            &ElementIndexSelector::All,
+            &0,
+            &minimum_of_components,
+        )?;
+        let minimum_of_rows = self.broadcast.compute_with_mask(
+            &MatrixMask::new(values).structure(),
+            &self.minimum_of_rows.compute(permutation, &column)?,
+            ones,
+        )?;
+        // Compares rather than subtracts, as an infinite minimum minus itself is not zero
+        let is_minimum = self.equal.compute(values, &minimum_of_rows)?;
+        self.copy
+            .compute_matrix_with_mask(values, &MatrixMask::new(&is_minimum))
+    }
+}
+
+// P(c, i) for every vertex i of the component c
+fn label_permutation(
+    context: &Arc<Context>,
+    labels: &SparseVector<u64>,
+) -> Result<SparseMatrix<bool>, SparseLinearAlgebraError> {
+    let number_of_vertices = labels.length()?;
+    SparseMatrix::<bool>::from_element_list(
+        context,
+        &(number_of_vertices, number_of_vertices).into(),
+        &MatrixElementList::from_vectors(
+            element_indices(labels)?,
+            (0..number_of_vertices).collect(),
+            vec![true; number_of_vertices],
+        )?,
+        &First::<bool, bool, bool>::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::BTreeMap;
+
+    use crate::algorithms::test_graphs::{
+        adjacency, pseudo_random_weighted_edges, weighted_adjacency,
+    };
+    use crate::context::Mode;
+    use crate::value_types::sparse_matrix::{GetMatrixElementList, GetMatrixElementValue};
+
+    // Symmetric, the lightest of parallel edges is kept
+    fn symmetric_adjacency(
+        context: &Arc<Context>,
+        number_of_vertices: usize,
+        edges: &[(usize, usize, i32)],
+    ) -> SparseMatrix<i32> {
+        let mut lightest_edges = BTreeMap::new();
+        for (from, to, weight) in edges.iter() {
+            for pair in [(*from, *to), (*to, *from)].iter() {
+                let lightest_weight = lightest_edges.entry(*pair).or_insert(*weight);
+                *lightest_weight = (*lightest_weight).min(*weight);
+            }
+        }
+        let symmetric_edges: Vec<(usize, usize, i32)> = lightest_edges
+            .into_iter()
+            .map(|((from, to), weight)| (from, to, weight))
+            .collect();
+        weighted_adjacency(context, number_of_vertices, &symmetric_edges)
+    }
+
+    fn root(parents: &mut [usize], vertex: usize) -> usize {
+        let mut root = vertex;
+        while parents[root] != root {
+            root = parents[root];
+        }
+        parents[vertex] = root;
+        root
+    }
+
+    // Kruskal's algorithm, returns the total weight and the number of edges
+    fn reference_forest(number_of_vertices: usize, edges: &[(usize, usize, i32)]) -> (i32, usize) {
+        let mut sorted_edges = edges.to_vec();
+        sorted_edges.sort_by_key(|(_, _, weight)| *weight);
+        let mut parents: Vec<usize> = (0..number_of_vertices).collect();
+        let mut total_weight = 0;
+        let mut number_of_edges = 0;
+        for (from, to, weight) in sorted_edges.iter() {
+            let (from_root, to_root) = (root(&mut parents, *from), root(&mut parents, *to));
+            if from_root != to_root {
+                parents[from_root] = to_root;
+                total_weight += weight;
+                number_of_edges += 1;
+            }
+        }
+        (total_weight, number_of_edges)
+    }
+
+    #[test]
+    fn test_spanning_forest_matches_reference() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let number_of_vertices = 120;
+        let edges =
+            pseudo_random_weighted_edges(number_of_vertices, 200, |value| (value % 20) as i32 - 5);
+        let adjacency = symmetric_adjacency(&context, number_of_vertices, &edges);
+        let (expected_total_weight, expected_number_of_edges) =
+            reference_forest(number_of_vertices, &edges);
+
+        let spanning_forest = MinimumSpanningForest::<i32>::new()
+            .spanning_forest(&adjacency)
+            .unwrap();
+        assert_eq!(spanning_forest.total_weight(), expected_total_weight);
+
+        // Every forest edge is an edge of the graph, and the forest has no cycles
+        let forest = spanning_forest.into_forest().get_element_list().unwrap();
+        assert_eq!(forest.length(), 2 * expected_number_of_edges);
+        let mut parents: Vec<usize> = (0..number_of_vertices).collect();
+        for ((from, to), weight) in forest
+            .row_indices_ref()
+            .iter()
+            .zip(forest.column_indices_ref().iter())
+            .zip(forest.values_ref().iter())
+        {
+            assert!(edges
+                .iter()
+                .any(|edge| *edge == (*from, *to, *weight) || *edge == (*to, *from, *weight)));
+            if from < to {
+                let (from_root, to_root) = (root(&mut parents, *from), root(&mut parents, *to));
+                assert_ne!(from_root, to_root);
+                parents[from_root] = to_root;
+            }
+        }
+    }
+
+    #[test]
+    fn test_spanning_forest_of_equal_weights() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        // The square 0, 1, 2, 3 with its diagonal 0 - 2, all of weight 1.5, and 4, 5 connected
+        let edges = [(0, 1), (1, 2), (2, 3), (3, 0), (0, 2), (4, 5)];
+        let symmetric_edges: Vec<(usize, usize)> = edges
+            .iter()
+            .flat_map(|(from, to)| vec![(*from, *to), (*to, *from)])
+            .collect();
+        let adjacency = adjacency(&context, 7, &symmetric_edges, 1.5);
+
+        let spanning_forest = MinimumSpanningForest::<f64>::new()
+            .spanning_forest(&adjacency)
+            .unwrap();
+        assert_eq!(spanning_forest.total_weight(), 6.0);
+        assert_eq!(
+            spanning_forest
+                .forest()
+                .number_of_stored_elements()
+                .unwrap(),
+            8
+        );
+    }
+
+    #[test]
+    fn test_spanning_forest_of_infinite_weights() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        // The path 0 - 1 - 2 of infinite weight, closed by 2 - 0 of weight 2.0, and 3 - 4
+        let edges = [
+            (0, 1, f64::INFINITY),
+            (1, 2, f64::INFINITY),
+            (2, 0, 2.0),
+            (3, 4, f64::INFINITY),
+        ];
+        let symmetric_edges: Vec<(usize, usize, f64)> = edges
+            .iter()
+            .flat_map(|(from, to, weight)| vec![(*from, *to, *weight), (*to, *from, *weight)])
+            .collect();
+        let adjacency = weighted_adjacency(&context, 5, &symmetric_edges);
+
+        let spanning_forest = MinimumSpanningForest::<f64>::new()
+            .spanning_forest(&adjacency)
+            .unwrap();
+        assert_eq!(spanning_forest.total_weight(), f64::INFINITY);
+
+        let forest = spanning_forest.into_forest();
+        assert_eq!(forest.number_of_stored_elements().unwrap(), 6);
+        assert_eq!(forest.get_element_value(&(0, 2).into()).unwrap(), 2.0);
+        assert_eq!(
+            forest.get_element_value(&(3, 4).into()).unwrap(),
+            f64::INFINITY
+        );
+    }
+}
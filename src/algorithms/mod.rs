@@ -0,0 +1,15 @@
+pub mod betweenness_centrality;
+pub mod breadth_first_search;
+pub mod connected_components;
+pub mod graph_colouring;
+pub mod k_core;
+pub mod k_truss;
+pub mod maximal_independent_set;
+pub mod minimum_spanning_forest;
+pub mod page_rank;
+pub mod single_source_shortest_paths;
+pub mod strongly_connected_components;
+pub mod triangle_counting;
+#[cfg(test)]
+mod test_graphs;
+mod validation;
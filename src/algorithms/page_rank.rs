@@ -0,0 +1,449 @@
+use crate::algorithms::validation::number_of_vertices;
+use crate::error::{LogicError, LogicErrorType, SparseLinearAlgebraError};
+use crate::operators::apply::{BinaryOperatorApplier, UnaryOperatorApplier};
+use crate::operators::binary_operator::{Divide, First, Minus, NoAccumulator, Plus, Times};
+use crate::operators::element_wise_addition::{
+    ElementWiseVectorAdditionBinaryOperator, ElementWiseVectorUnionBinaryOperator,
+};
+use crate::operators::element_wise_multiplication::ElementWiseVectorMultiplicationBinaryOperator;
+use crate::operators::mask::VectorMask;
+use crate::operators::monoid::{Min as MinMonoid, Plus as PlusMonoid};
+use crate::operators::multiplication::MatrixVectorMultiplicationOperator;
+use crate::operators::options::OperatorOptions;
+use crate::operators::reduce::{MonoidReducer, MonoidScalarReducer};
+use crate::operators::semiring::{PlusPair, PlusSecond};
+use crate::operators::unary_operator::{AbsoluteValue, Identity};
+use crate::util::ElementIndex;
+use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::sparse_vector::{FromVectorElementList, SparseVector, VectorElementList};
+use crate::value_types::value_type::{BuiltInValueType, ValueType};
+
+/// What happens to the rank of vertices without outgoing edges
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DanglingVertices {
+    /// Distributes their rank by the teleport distribution, such that the ranks sum to 1
+    Redistribute,
+    /// Their rank is lost, such that the ranks may sum to less than 1
+    Discard,
+}
+
+/// The ranks, with the number of iterations it took to compute them and the residual of the last iteration
+#[derive(Debug, Clone)]
+pub struct PageRankScores {
+    ranks: SparseVector<f64>,
+    number_of_iterations: usize,
+    residual: f64,
+}
+
+impl PageRankScores {
+    pub fn ranks(&self) -> &SparseVector<f64> {
+        &self.ranks
+    }
+
+    pub fn into_ranks(self) -> SparseVector<f64> {
+        self.ranks
+    }
+
+    pub fn number_of_iterations(&self) -> usize {
+        self.number_of_iterations
+    }
+
+    /// The sum of the absolute changes of the ranks in the last iteration
+    pub fn residual(&self) -> f64 {
+        self.residual
+    }
+}
+
+/// PageRank by power iteration.
+///
+/// The adjacency matrix stores A(i, j) for an edge from vertex i to vertex j, its values are ignored.
+/// In each iteration, a vertex passes the damping factor times its rank in equal parts along its edges,
+/// and the rest of the rank teleports to the vertices by the teleport distribution.
+/// Iterating stops when the residual is smaller than the tolerance, or after the maximum number of iterations.
+#[derive(Debug, Clone)]
+pub struct PageRank {
+    damping_factor: f64,
+    tolerance: f64,
+    maximum_number_of_iterations: usize,
+    dangling_vertices: DanglingVertices,
+}
+
+impl PageRank {
+    pub fn new(
+        damping_factor: f64, // the probability of following an edge instead of teleporting
+        tolerance: f64,
+        maximum_number_of_iterations: usize,
+        dangling_vertices: DanglingVertices,
+    ) -> Self {
+        Self {
+            damping_factor,
+            tolerance,
+            maximum_number_of_iterations,
+            dangling_vertices,
+        }
+    }
+
+    /// Damping factor 0.85, tolerance 1e-6, at most 100 iterations, and redistribution of dangling vertices
+    pub fn new_default() -> Self {
+        Self::new(0.85, 1e-6, 100, DanglingVertices::Redistribute)
+    }
+
+    /// Teleports uniformly to every vertex
+    pub fn ranks<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+    ) -> Result<PageRankScores, SparseLinearAlgebraError> {
+        let number_of_vertices = number_of_vertices(adjacency)?;
+        let teleport_distribution =
+            uniform_vector(adjacency, 1.0 / number_of_vertices.max(1) as f64)?;
+        self.iterate(adjacency, teleport_distribution)
+    }
+
+    /// Teleports by the given distribution, which is scaled to sum to 1.
+    /// The distribution must not store negative values.
+    pub fn personalized_ranks<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+        teleport_distribution: &SparseVector<f64>,
+    ) -> Result<PageRankScores, SparseLinearAlgebraError> {
+        let number_of_vertices = number_of_vertices(adjacency)?;
+        if teleport_distribution.length()? != number_of_vertices {
+            return Err(LogicError::new(
+                LogicErrorType::Other,
+                format!(
+                    "The teleport distribution must have a length of {}, found {}",
+                    number_of_vertices,
+                    teleport_distribution.length()?
+                ),
+                None,
+            )
+            .into());
+        }
+
+        let smallest_value = minimum(teleport_distribution)?;
+        if smallest_value < 0.0 {
+            return Err(LogicError::new(
+                LogicErrorType::Other,
+                format!(
+                    "The teleport distribution must not be negative, found {}",
+                    smallest_value
+                ),
+                None,
+            )
+            .into());
+        }
+
+        let total = sum(teleport_distribution)?;
+        if total.is_nan() || total <= 0.0 {
+            return Err(LogicError::new(
+                LogicErrorType::Other,
+                format!(
+                    "The teleport distribution must have a positive sum, found {}",
+                    total
+                ),
+                None,
+            )
+            .into());
+        }
+        let teleport_distribution = BinaryOperatorApplier::new(
+            &Divide::<f64, f64, f64>::new(),
+            &OperatorOptions::new_default(),
+            &NoAccumulator,
+        )
+        .compute_with_vector_as_first_argument(teleport_distribution, &total)?;
+        self.iterate(adjacency, teleport_distribution)
+    }
+
+    fn iterate<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+        teleport_distribution: SparseVector<f64>,
+    ) -> Result<PageRankScores, SparseLinearAlgebraError> {
+        self.check_parameters()?;
+
+        let options = OperatorOptions::new_default();
+        let out_degrees = MatrixVectorMultiplicationOperator::<T, f64, f64>::new(
+            &PlusPair::<T, f64, f64>::new(),
+            &options,
+            &NoAccumulator,
+        )
+        .compute(adjacency, &uniform_vector(adjacency, 1.0)?)?;
+
+        let divide = ElementWiseVectorMultiplicationBinaryOperator::<f64, f64, f64>::new(
+            &Divide::<f64, f64, f64>::new(),
+            &options,
+            &NoAccumulator,
+        );
+        let pull = MatrixVectorMultiplicationOperator::<T, f64, f64>::new(
+            &PlusSecond::<T, f64, f64>::new(),
+            &OperatorOptions::new(false, true, false),
+            &NoAccumulator,
+        );
+        let scale =
+            BinaryOperatorApplier::new(&Times::<f64, f64, f64>::new(), &options, &NoAccumulator);
+        let add = ElementWiseVectorAdditionBinaryOperator::<f64, f64, f64>::new(
+            &Plus::<f64, f64, f64>::new(),
+            &options,
+            &NoAccumulator,
+        );
+        let difference = ElementWiseVectorUnionBinaryOperator::<f64, f64, f64>::new(
+            &Minus::<f64, f64, f64>::new(),
+            0.0,
+            0.0,
+            &options,
+            &NoAccumulator,
+        );
+        let copy = UnaryOperatorApplier::new(&Identity::<f64>::new(), &options, &NoAccumulator);
+        let absolute_value =
+            UnaryOperatorApplier::new(&AbsoluteValue::<f64>::new(), &options, &NoAccumulator);
+
+        let mut ranks = teleport_distribution.clone();
+        let mut number_of_iterations = 0;
+        let mut residual = f64::INFINITY;
+        while number_of_iterations < self.maximum_number_of_iterations {
+            let rank_per_edge = divide.compute(&ranks, &out_degrees)?;
+            let incoming_rank = pull.compute(adjacency, &rank_per_edge)?;
+
+            let mut teleported_rank = 1.0 - self.damping_factor;
+            if self.dangling_vertices == DanglingVertices::Redistribute {
+                let dangling_ranks = copy.compute_vector_with_mask(
+                    &ranks,
+                    &VectorMask::new(&out_degrees).structure().complement(),
+                )?;
+                teleported_rank += self.damping_factor * sum(&dangling_ranks)?;
+            }
+
+            let next_ranks = add.compute(
+                &scale
+                    .compute_with_vector_as_first_argument(&incoming_rank, &self.damping_factor)?,
+                &scale.compute_with_vector_as_first_argument(
+                    &teleport_distribution,
+                    &teleported_rank,
+                )?,
+            )?;
+
+            residual =
+                sum(&absolute_value.compute_vector(&difference.compute(&next_ranks, &ranks)?)?)?;
+            ranks = next_ranks;
+            number_of_iterations += 1;
+            if residual < self.tolerance {
+                break;
+            }
+        }
+
+        Ok(PageRankScores {
+            ranks,
+            number_of_iterations,
+            residual,
+        })
+    }
+
+    fn check_parameters(&self) -> Result<(), SparseLinearAlgebraError> {
+        if !(0.0..1.0).contains(&self.damping_factor) {
+            return Err(LogicError::new(
+                LogicErrorType::Other,
+                format!(
+                    "The damping factor must be at least 0 and less than 1, found {}",
+                    self.damping_factor
+                ),
+                None,
+            )
+            .into());
+        }
+        if self.tolerance.is_nan() || self.tolerance < 0.0 {
+            return Err(LogicError::new(
+                LogicErrorType::Other,
+                format!(
+                    "The tolerance must not be negative, found {}",
+                    self.tolerance
+                ),
+                None,
+            )
+            .into());
+        }
+        Ok(())
+    }
+}
+
+// Stores the value for every vertex
+fn uniform_vector<T: ValueType>(
+    adjacency: &SparseMatrix<T>,
+    value: f64,
+) -> Result<SparseVector<f64>, SparseLinearAlgebraError> {
+    let number_of_vertices = number_of_vertices(adjacency)?;
+    let indices: Vec<ElementIndex> = (0..number_of_vertices).collect();
+    SparseVector::<f64>::from_element_list(
+        adjacency.context_ref(),
+        &number_of_vertices,
+        &VectorElementList::from_vectors(indices, vec![value; number_of_vertices])?,
+        &First::<f64, f64, f64>::new(),
+    )
+}
+
+fn sum(vector: &SparseVector<f64>) -> Result<f64, SparseLinearAlgebraError> {
+    let mut sum = 0.0;
+    MonoidReducer::new(
+        &PlusMonoid::<f64>::new(),
+        &OperatorOptions::new_default(),
+        &NoAccumulator,
+    )
+    .vector_to_scalar(vector, &mut sum)?;
+    Ok(sum)
+}
+
+fn minimum(vector: &SparseVector<f64>) -> Result<f64, SparseLinearAlgebraError> {
+    let mut minimum = f64::INFINITY;
+    MonoidReducer::new(
+        &MinMonoid::<f64>::new(),
+        &OperatorOptions::new_default(),
+        &NoAccumulator,
+    )
+    .vector_to_scalar(vector, &mut minimum)?;
+    Ok(minimum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::algorithms::test_graphs::adjacency;
+    use crate::context::{Context, Mode};
+    use crate::value_types::sparse_vector::ToDenseVector;
+
+    fn reference_ranks(
+        number_of_vertices: usize,
+        edges: &[(usize, usize)],
+        teleport_distribution: &[f64],
+        damping_factor: f64,
+        number_of_iterations: usize,
+    ) -> Vec<f64> {
+        let mut out_degrees = vec![0; number_of_vertices];
+        for (from, _to) in edges.iter() {
+            out_degrees[*from] += 1;
+        }
+        let mut ranks = teleport_distribution.to_vec();
+        for _ in 0..number_of_iterations {
+            let dangling_rank: f64 = (0..number_of_vertices)
+                .filter(|vertex| out_degrees[*vertex] == 0)
+                .map(|vertex| ranks[vertex])
+                .sum();
+            let mut next_ranks: Vec<f64> = teleport_distribution
+                .iter()
+                .map(|probability| {
+                    probability * (1.0 - damping_factor + damping_factor * dangling_rank)
+                })
+                .collect();
+            for (from, to) in edges.iter() {
+                next_ranks[*to] += damping_factor * ranks[*from] / out_degrees[*from] as f64;
+            }
+            ranks = next_ranks;
+        }
+        ranks
+    }
+
+    fn assert_close(ranks: &[f64], expected_ranks: &[f64]) {
+        assert_eq!(ranks.len(), expected_ranks.len());
+        for (rank, expected_rank) in ranks.iter().zip(expected_ranks.iter()) {
+            assert!((rank - expected_rank).abs() < 1e-9);
+        }
+    }
+
+    // Vertex 4 is dangling
+    const EDGES: [(usize, usize); 7] = [(0, 1), (0, 2), (1, 2), (2, 0), (3, 2), (3, 4), (1, 4)];
+
+    #[test]
+    fn test_ranks_match_reference() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+        let adjacency = adjacency(&context, 5, &EDGES, true);
+
+        let scores = PageRank::new(0.85, 1e-12, 500, DanglingVertices::Redistribute)
+            .ranks(&adjacency)
+            .unwrap();
+        assert!(scores.residual() < 1e-12);
+        assert!(scores.number_of_iterations() < 500);
+
+        let ranks = scores.ranks().to_dense(0.0).unwrap();
+        assert_close(
+            &ranks,
+            &reference_ranks(5, &EDGES, &[0.2; 5], 0.85, scores.number_of_iterations()),
+        );
+        assert!((ranks.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(ranks[2] > ranks[3]);
+    }
+
+    #[test]
+    fn test_iterations_stop_at_maximum() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+        let adjacency = adjacency(&context, 5, &EDGES, true);
+
+        let scores = PageRank::new(0.85, 0.0, 3, DanglingVertices::Redistribute)
+            .ranks(&adjacency)
+            .unwrap();
+        assert_eq!(scores.number_of_iterations(), 3);
+        assert!(scores.residual() > 0.0);
+        assert_close(
+            &scores.ranks().to_dense(0.0).unwrap(),
+            &reference_ranks(5, &EDGES, &[0.2; 5], 0.85, 3),
+        );
+    }
+
+    #[test]
+    fn test_discarded_dangling_rank_is_lost() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+        let adjacency = adjacency(&context, 5, &EDGES, true);
+
+        let scores = PageRank::new(0.85, 1e-9, 100, DanglingVertices::Discard)
+            .ranks(&adjacency)
+            .unwrap();
+        let ranks = scores.ranks().to_dense(0.0).unwrap();
+        assert!(ranks.iter().sum::<f64>() < 1.0);
+    }
+
+    #[test]
+    fn test_personalized_ranks() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+        let adjacency = adjacency(&context, 5, &EDGES, true);
+
+        // Scaled to teleport to vertex 3 only
+        let teleport_distribution = SparseVector::<f64>::from_element_list(
+            &context,
+            &5,
+            &VectorElementList::<f64>::from_element_vector(vec![(3, 2.0).into()]),
+            &First::<f64, f64, f64>::new(),
+        )
+        .unwrap();
+
+        let scores = PageRank::new_default()
+            .personalized_ranks(&adjacency, &teleport_distribution)
+            .unwrap();
+        let ranks = scores.ranks().to_dense(0.0).unwrap();
+        assert_close(
+            &ranks,
+            &reference_ranks(
+                5,
+                &EDGES,
+                &[0.0, 0.0, 0.0, 1.0, 0.0],
+                0.85,
+                scores.number_of_iterations(),
+            ),
+        );
+        assert!(ranks[3] > ranks[1]);
+
+        let empty_distribution = SparseVector::<f64>::new(&context, &5).unwrap();
+        assert!(PageRank::new_default()
+            .personalized_ranks(&adjacency, &empty_distribution)
+            .is_err());
+
+        // Sums to 1, but a negative teleport probability is not a distribution
+        let negative_distribution = SparseVector::<f64>::from_element_list(
+            &context,
+            &5,
+            &VectorElementList::<f64>::from_element_vector(vec![(1, -1.0).into(), (3, 2.0).into()]),
+            &First::<f64, f64, f64>::new(),
+        )
+        .unwrap();
+        assert!(PageRank::new_default()
+            .personalized_ranks(&adjacency, &negative_distribution)
+            .is_err());
+    }
+}
@@ -0,0 +1,589 @@
+use std::cmp::Ordering;
+
+use crate::algorithms::breadth_first_search::{BreadthFirstSearch, TraversalDirection};
+use crate::algorithms::validation::{check_vertices, number_of_vertices};
+use crate::error::{LogicError, LogicErrorType, SparseLinearAlgebraError};
+use crate::operators::apply::{UnaryOperatorApplier, UnaryOperatorApplierTrait};
+use crate::operators::binary_operator::{BinaryOperator, First, LessThan, Min, NoAccumulator};
+use crate::operators::element_wise_multiplication::ElementWiseVectorMultiplicationBinaryOperator;
+use crate::operators::mask::VectorMask;
+use crate::operators::monoid::Min as MinMonoid;
+use crate::operators::multiplication::{
+    MatrixMultiplicationOperator, VectorMatrixMultiplicationOperator,
+};
+use crate::operators::options::OperatorOptions;
+use crate::operators::reduce::{MonoidReducer, MonoidScalarReducer};
+use crate::operators::select::{
+    MatrixSelector, SelectMatrixGreaterThanScalar, SelectMatrixLessThanOrEqualToScalar,
+    SelectVectorLessThanScalar, VectorSelector,
+};
+use crate::operators::semiring::{MinMinus, MinPlus};
+use crate::operators::unary_operator::Identity;
+use crate::util::ElementIndex;
+use crate::value_types::sparse_matrix::{FromMatrixElementList, MatrixElementList, SparseMatrix};
+use crate::value_types::sparse_vector::{GetVectorElementList, SetVectorElement, SparseVector};
+use crate::value_types::value_type::{AsBoolean, ValueType};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShortestPathsAlgorithm<T: ValueType> {
+    /// Relaxes the edges leaving every vertex whose distance decreased, until no distance decreases.
+    /// Supports negative weights, a negative cycle that is reachable from the source is an error.
+    BellmanFord,
+    /// Settles the vertices in buckets of width delta, the weights must not be negative
+    DeltaStepping { delta: T },
+}
+
+/// The end of a delta-stepping bucket that starts at a distance
+pub trait EndOfBucket: Sized {
+    /// None when the end cannot be represented above the start,
+    /// such that the bucket holds all remaining distances
+    fn end_of_bucket(self, delta: Self) -> Option<Self>;
+}
+
+macro_rules! implement_end_of_bucket_for_integer {
+    ($value_type:ty) => {
+        impl EndOfBucket for $value_type {
+            fn end_of_bucket(self, delta: Self) -> Option<Self> {
+                self.checked_add(delta)
+            }
+        }
+    };
+}
+
+implement_end_of_bucket_for_integer!(i8);
+implement_end_of_bucket_for_integer!(i16);
+implement_end_of_bucket_for_integer!(i32);
+implement_end_of_bucket_for_integer!(i64);
+implement_end_of_bucket_for_integer!(u8);
+implement_end_of_bucket_for_integer!(u16);
+implement_end_of_bucket_for_integer!(u32);
+implement_end_of_bucket_for_integer!(u64);
+
+// A small delta may vanish in the sum, a large one may round it to infinity
+macro_rules! implement_end_of_bucket_for_floating_point {
+    ($value_type:ty) => {
+        impl EndOfBucket for $value_type {
+            fn end_of_bucket(self, delta: Self) -> Option<Self> {
+                let end = self + delta;
+                if end > self && end.is_finite() {
+                    Some(end)
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+
+implement_end_of_bucket_for_floating_point!(f32);
+implement_end_of_bucket_for_floating_point!(f64);
+
+/// Shortest paths from a source vertex over the min-plus semiring.
+///
+/// The adjacency matrix stores the weight A(i, j) of the edge from vertex i to vertex j.
+/// The distance of a vertex is the smallest sum of weights over the paths from the source,
+/// its predecessor is the vertex before it on such a path. The source is its own predecessor.
+/// Vertices that cannot be reached are not stored in the results.
+#[derive(Debug, Clone)]
+pub struct SingleSourceShortestPaths<T: ValueType> {
+    algorithm: ShortestPathsAlgorithm<T>,
+
+    relaxation: VectorMatrixMultiplicationOperator<T, T, T>,
+    path_lengths: MatrixMultiplicationOperator<T, T, T>,
+    slack: MatrixMultiplicationOperator<T, T, T>,
+    is_shorter: ElementWiseVectorMultiplicationBinaryOperator<T, T, bool>,
+    copy: UnaryOperatorApplier<T>,
+    keep_shortest: UnaryOperatorApplier<T>,
+    minimum: MonoidReducer<T>,
+    vector_selector: VectorSelector<T>,
+    matrix_selector: MatrixSelector<T>,
+    duplicate_operator: First<T, T, T>,
+}
+
+macro_rules! implement_single_source_shortest_paths {
+    ($value_type:ty) => {
+        impl SingleSourceShortestPaths<$value_type> {
+            pub fn new(algorithm: ShortestPathsAlgorithm<$value_type>) -> Self {
+                let options = OperatorOptions::new_default();
+                Self {
+                    algorithm,
+
+                    relaxation: VectorMatrixMultiplicationOperator::new(
+                        &MinPlus::<$value_type, $value_type, $value_type>::new(),
+                        &options,
+                        &NoAccumulator,
+                    ),
+                    path_lengths: MatrixMultiplicationOperator::new(
+                        &MinPlus::<$value_type, $value_type, $value_type>::new(),
+                        &options,
+                        &NoAccumulator,
+                    ),
+                    slack: MatrixMultiplicationOperator::new(
+                        &MinMinus::<$value_type, $value_type, $value_type>::new(),
+                        &options,
+                        &NoAccumulator,
+                    ),
+                    is_shorter: ElementWiseVectorMultiplicationBinaryOperator::new(
+                        &LessThan::<$value_type, $value_type, bool>::new(),
+                        &options,
+                        &NoAccumulator,
+                    ),
+                    copy: UnaryOperatorApplier::new(
+                        &Identity::<$value_type>::new(),
+                        &options,
+                        &NoAccumulator,
+                    ),
+                    keep_shortest: UnaryOperatorApplier::new(
+                        &Identity::<$value_type>::new(),
+                        &options,
+                        &Min::<$value_type, $value_type, $value_type>::new(),
+                    ),
+                    minimum: MonoidReducer::new(
+                        &MinMonoid::<$value_type>::new(),
+                        &options,
+                        &NoAccumulator,
+                    ),
+                    vector_selector: VectorSelector::new(&options, &NoAccumulator),
+                    matrix_selector: MatrixSelector::new(&options, &NoAccumulator),
+                    duplicate_operator: First::<$value_type, $value_type, $value_type>::new(),
+                }
+            }
+        }
+    };
+}
+
+implement_single_source_shortest_paths!(i8);
+implement_single_source_shortest_paths!(i16);
+implement_single_source_shortest_paths!(i32);
+implement_single_source_shortest_paths!(i64);
+implement_single_source_shortest_paths!(u8);
+implement_single_source_shortest_paths!(u16);
+implement_single_source_shortest_paths!(u32);
+implement_single_source_shortest_paths!(u64);
+implement_single_source_shortest_paths!(f32);
+implement_single_source_shortest_paths!(f64);
+
+impl<T> SingleSourceShortestPaths<T>
+where
+    T: AsBoolean<T> + Copy + Default + PartialOrd + EndOfBucket,
+    First<T, T, T>: BinaryOperator<T, T, T>,
+    MonoidReducer<T>: MonoidScalarReducer<T>,
+    UnaryOperatorApplier<T>: UnaryOperatorApplierTrait<T, T>,
+    VectorSelector<T>: SelectVectorLessThanScalar<T>,
+    MatrixSelector<T>: SelectMatrixLessThanOrEqualToScalar<T> + SelectMatrixGreaterThanScalar<T>,
+    SparseVector<T>: SetVectorElement<T> + GetVectorElementList<T>,
+    SparseMatrix<T>: FromMatrixElementList<T>,
+{
+    pub fn distances(
+        &self,
+        adjacency: &SparseMatrix<T>,
+        source: ElementIndex,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let number_of_vertices = number_of_vertices(adjacency)?;
+        check_vertices("source", &[source], number_of_vertices)?;
+
+        let mut distances = SparseVector::<T>::new(adjacency.context_ref(), &number_of_vertices)?;
+        distances.set_element((source, T::default()).into())?;
+
+        match self.algorithm {
+            ShortestPathsAlgorithm::BellmanFord => self.bellman_ford(adjacency, &mut distances)?,
+            ShortestPathsAlgorithm::DeltaStepping { delta } => {
+                self.delta_stepping(adjacency, &mut distances, delta)?
+            }
+        }
+        Ok(distances)
+    }
+
+    /// Returns (distances, predecessors)
+    pub fn distances_and_predecessors(
+        &self,
+        adjacency: &SparseMatrix<T>,
+        source: ElementIndex,
+    ) -> Result<(SparseVector<T>, SparseVector<i64>), SparseLinearAlgebraError> {
+        let distances = self.distances(adjacency, source)?;
+        let predecessors = self.predecessors(adjacency, source, &distances)?;
+        Ok((distances, predecessors))
+    }
+
+    // After k rounds, no path of at most k edges is shorter than the distances.
+    // Without negative cycles, a shortest path has less edges than there are vertices.
+    fn bellman_ford(
+        &self,
+        adjacency: &SparseMatrix<T>,
+        distances: &mut SparseVector<T>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let mut frontier = distances.clone();
+        for _round in 0..number_of_vertices(adjacency)? {
+            frontier = self.relax(&frontier, adjacency, distances)?;
+            if frontier.number_of_stored_elements()? == 0 {
+                return Ok(());
+            }
+        }
+        Err(LogicError::new(
+            LogicErrorType::Other,
+            String::from("The graph has a negative cycle that is reachable from the source"),
+            None,
+        )
+        .into())
+    }
+
+    // Each bucket starts at the nearest unsettled vertex. Light edges, not heavier than delta,
+    // may stay in the bucket and are relaxed until no distance in the bucket decreases.
+    // The distances in the bucket are then final, and heavy edges leave the bucket.
+    fn delta_stepping(
+        &self,
+        adjacency: &SparseMatrix<T>,
+        distances: &mut SparseVector<T>,
+        delta: T,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if delta.partial_cmp(&T::default()) != Some(Ordering::Greater) {
+            return Err(LogicError::new(
+                LogicErrorType::Other,
+                String::from("Delta-stepping requires a positive delta"),
+                None,
+            )
+            .into());
+        }
+        if self
+            .matrix_selector
+            .compute_negative(adjacency)?
+            .number_of_stored_elements()?
+            > 0
+        {
+            return Err(LogicError::new(
+                LogicErrorType::Other,
+                String::from("Delta-stepping requires weights that are not negative"),
+                None,
+            )
+            .into());
+        }
+
+        let context = adjacency.context_ref();
+        let mut light_edges = SparseMatrix::<T>::new(context, &adjacency.size()?)?;
+        self.matrix_selector
+            .less_than_or_equal_to_scalar(adjacency, &mut light_edges, &delta)?;
+        let mut heavy_edges = SparseMatrix::<T>::new(context, &adjacency.size()?)?;
+        self.matrix_selector
+            .greater_than_scalar(adjacency, &mut heavy_edges, &delta)?;
+
+        let mut settled = SparseVector::<T>::new(context, &distances.length()?)?;
+        loop {
+            let unsettled = self.copy.compute_vector_with_mask(
+                distances,
+                &VectorMask::new(&settled).structure().complement(),
+            )?;
+            if unsettled.number_of_stored_elements()? == 0 {
+                return Ok(());
+            }
+            let mut nearest = T::default();
+            self.minimum.vector_to_scalar(&unsettled, &mut nearest)?;
+            let end_of_bucket = nearest.end_of_bucket(delta);
+
+            let mut frontier = self.select_shorter_than(&unsettled, &end_of_bucket)?;
+            while frontier.number_of_stored_elements()? > 0 {
+                let improved = self.relax(&frontier, &light_edges, distances)?;
+                frontier = self.select_shorter_than(&improved, &end_of_bucket)?;
+            }
+
+            let unsettled = self.copy.compute_vector_with_mask(
+                distances,
+                &VectorMask::new(&settled).structure().complement(),
+            )?;
+            let bucket = self.select_shorter_than(&unsettled, &end_of_bucket)?;
+            self.relax(&bucket, &heavy_edges, distances)?;
+            self.keep_shortest.apply_to_vector(&bucket, &mut settled)?;
+        }
+    }
+
+    // Relaxes the edges leaving the frontier, returns the vertices whose distance decreased
+    fn relax(
+        &self,
+        frontier: &SparseVector<T>,
+        edges: &SparseMatrix<T>,
+        distances: &mut SparseVector<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let path_lengths = self.relaxation.compute(frontier, edges)?;
+        let is_shorter = self.is_shorter.compute(&path_lengths, distances)?;
+
+        let mut improved = SparseVector::<T>::new(distances.context_ref(), &distances.length()?)?;
+        self.copy.apply_to_vector_with_mask(
+            &path_lengths,
+            &mut improved,
+            &VectorMask::new(&*distances).structure().complement(),
+        )?;
+        self.copy.apply_to_vector_with_mask(
+            &path_lengths,
+            &mut improved,
+            &VectorMask::new(&is_shorter),
+        )?;
+
+        self.keep_shortest.apply_to_vector(&improved, distances)?;
+        Ok(improved)
+    }
+
+    // Without a bound, all distances are selected
+    fn select_shorter_than(
+        &self,
+        distances: &SparseVector<T>,
+        bound: &Option<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let bound = match bound {
+            Some(bound) => bound,
+            None => return Ok(distances.clone()),
+        };
+        let mut selected = SparseVector::<T>::new(distances.context_ref(), &distances.length()?)?;
+        self.vector_selector
+            .less_than_scalar(distances, &mut selected, bound)?;
+        Ok(selected)
+    }
+
+    // An edge (u, v) is tight when d(u) + w(u, v) = d(v), such that it ends a shortest path to v.
+    // Searching the tight edges breadth-first gives a tree of shortest paths,
+    // also when cycles of zero weight make the tight edges cyclic.
+    fn predecessors(
+        &self,
+        adjacency: &SparseMatrix<T>,
+        source: ElementIndex,
+        distances: &SparseVector<T>,
+    ) -> Result<SparseVector<i64>, SparseLinearAlgebraError> {
+        let number_of_vertices = distances.length()?;
+        let elements = distances.get_element_list()?;
+        let diagonal = SparseMatrix::<T>::from_element_list(
+            adjacency.context_ref(),
+            &(number_of_vertices, number_of_vertices).into(),
+            &MatrixElementList::from_vectors(
+                elements.indices_ref().to_vec(),
+                elements.indices_ref().to_vec(),
+                elements.values_ref().to_vec(),
+            )?,
+            &self.duplicate_operator,
+        )?;
+
+        // d(u) + w(u, v) - d(v)
+        let path_lengths = self.path_lengths.compute(&diagonal, adjacency)?;
+        let slack = self.slack.compute(&path_lengths, &diagonal)?;
+        let tight_edges = self.matrix_selector.compute_zero(&slack)?;
+
+        BreadthFirstSearch::new(TraversalDirection::DirectionOptimizing)
+            .parents(&tight_edges, &[source])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::BTreeMap;
+
+    use crate::algorithms::test_graphs::{pseudo_random_weighted_edges, weighted_adjacency};
+    use crate::context::{Context, Mode};
+    use crate::value_types::sparse_vector::ToDenseVector;
+
+    // Without parallel edges, such that the adjacency matrix stores every edge
+    fn pseudo_random_distinct_edges(
+        number_of_vertices: usize,
+        number_of_edges: usize,
+        maximum_weight: i64,
+    ) -> Vec<(usize, usize, i64)> {
+        let mut edges = BTreeMap::new();
+        for (from, to, weight) in
+            pseudo_random_weighted_edges(number_of_vertices, number_of_edges, |value| {
+                (value % (maximum_weight as u64 + 1)) as i64
+            })
+        {
+            edges.entry((from, to)).or_insert(weight);
+        }
+        edges
+            .into_iter()
+            .map(|((from, to), weight)| (from, to, weight))
+            .collect()
+    }
+
+    // Bellman-Ford on the edge list, -1 for unreachable vertices
+    fn reference_distances(
+        number_of_vertices: usize,
+        edges: &[(usize, usize, i64)],
+        source: usize,
+    ) -> Vec<i64> {
+        let mut distances: Vec<Option<i64>> = vec![None; number_of_vertices];
+        distances[source] = Some(0);
+        for _round in 0..number_of_vertices {
+            for (from, to, weight) in edges.iter() {
+                if let Some(distance) = distances[*from] {
+                    match distances[*to] {
+                        Some(current) if current <= distance + weight => {}
+                        _ => distances[*to] = Some(distance + weight),
+                    }
+                }
+            }
+        }
+        distances
+            .into_iter()
+            .map(|distance| distance.unwrap_or(-1))
+            .collect()
+    }
+
+    #[test]
+    fn test_distances_match_reference() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let number_of_vertices = 150;
+        let edges = pseudo_random_distinct_edges(number_of_vertices, 500, 20);
+        let adjacency = weighted_adjacency(&context, number_of_vertices, &edges);
+        let expected_distances = reference_distances(number_of_vertices, &edges, 7);
+
+        for algorithm in [
+            ShortestPathsAlgorithm::BellmanFord,
+            ShortestPathsAlgorithm::DeltaStepping { delta: 1 },
+            ShortestPathsAlgorithm::DeltaStepping { delta: 6 },
+            ShortestPathsAlgorithm::DeltaStepping { delta: 1000 },
+        ]
+        .iter()
+        {
+            let distances = SingleSourceShortestPaths::<i64>::new(*algorithm)
+                .distances(&adjacency, 7)
+                .unwrap();
+            assert_eq!(distances.to_dense(-1).unwrap(), expected_distances);
+        }
+    }
+
+    #[test]
+    fn test_distances_with_floating_point_weights() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        // 0 -(0.5)-> 1 -(0.25)-> 2, 0 -(1.0)-> 2, 3 -(0.5)-> 0
+        let adjacency = SparseMatrix::<f64>::from_element_list(
+            &context,
+            &(4, 4).into(),
+            &MatrixElementList::<f64>::from_element_vector(vec![
+                (0, 1, 0.5).into(),
+                (1, 2, 0.25).into(),
+                (0, 2, 1.0).into(),
+                (3, 0, 0.5).into(),
+            ]),
+            &First::<f64, f64, f64>::new(),
+        )
+        .unwrap();
+
+        for algorithm in [
+            ShortestPathsAlgorithm::BellmanFord,
+            ShortestPathsAlgorithm::DeltaStepping { delta: 0.3 },
+        ]
+        .iter()
+        {
+            let distances = SingleSourceShortestPaths::<f64>::new(*algorithm)
+                .distances(&adjacency, 0)
+                .unwrap();
+            assert_eq!(distances.number_of_stored_elements().unwrap(), 3);
+            assert_eq!(
+                distances.to_dense(-1.0).unwrap(),
+                vec![0.0, 0.5, 0.75, -1.0]
+            );
+        }
+    }
+
+    #[test]
+    fn test_delta_stepping_with_weights_near_the_largest_value() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        // 0 -(max - 10)-> 1 -(5)-> 2, 0 -(max)-> 3, the last buckets end beyond the largest value
+        let adjacency = weighted_adjacency(
+            &context,
+            4,
+            &[(0, 1, u32::MAX - 10), (1, 2, 5), (0, 3, u32::MAX)],
+        );
+
+        for delta in [1, 100, u32::MAX].iter() {
+            let distances =
+                SingleSourceShortestPaths::<u32>::new(ShortestPathsAlgorithm::DeltaStepping {
+                    delta: *delta,
+                })
+                .distances(&adjacency, 0)
+                .unwrap();
+            assert_eq!(
+                distances.to_dense(0).unwrap(),
+                vec![0, u32::MAX - 10, u32::MAX - 5, u32::MAX]
+            );
+        }
+    }
+
+    #[test]
+    fn test_predecessors_are_on_shortest_paths() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        // Weights of zero make cycles of tight edges
+        let number_of_vertices = 100;
+        let edges = pseudo_random_distinct_edges(number_of_vertices, 400, 2);
+        let adjacency = weighted_adjacency(&context, number_of_vertices, &edges);
+        let weights: BTreeMap<(usize, usize), i64> = edges
+            .iter()
+            .map(|(from, to, weight)| ((*from, *to), *weight))
+            .collect();
+        let source = 3;
+
+        for algorithm in [
+            ShortestPathsAlgorithm::BellmanFord,
+            ShortestPathsAlgorithm::DeltaStepping { delta: 2 },
+        ]
+        .iter()
+        {
+            let (distances, predecessors) = SingleSourceShortestPaths::<i64>::new(*algorithm)
+                .distances_and_predecessors(&adjacency, source)
+                .unwrap();
+            let distances = distances.to_dense(-1).unwrap();
+            let predecessors = predecessors.to_dense(-1).unwrap();
+            assert_eq!(
+                distances,
+                reference_distances(number_of_vertices, &edges, source)
+            );
+
+            for vertex in 0..number_of_vertices {
+                if distances[vertex] == -1 {
+                    assert_eq!(predecessors[vertex], -1);
+                    continue;
+                }
+
+                let mut path_vertex = vertex;
+                let mut path_length = 0;
+                while path_vertex != source {
+                    let predecessor = predecessors[path_vertex] as usize;
+                    path_length += weights[&(predecessor, path_vertex)];
+                    path_vertex = predecessor;
+                    assert!(path_length <= distances[vertex]);
+                }
+                assert_eq!(path_length, distances[vertex]);
+            }
+            assert_eq!(predecessors[source], source as i64);
+        }
+    }
+
+    #[test]
+    fn test_negative_weights() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        // 0 -(4)-> 1 -(-3)-> 2, 0 -(2)-> 2
+        let adjacency_with_negative_weight =
+            weighted_adjacency(&context, 3, &[(0, 1, 4), (1, 2, -3), (0, 2, 2)]);
+        let distances = SingleSourceShortestPaths::<i64>::new(ShortestPathsAlgorithm::BellmanFord)
+            .distances(&adjacency_with_negative_weight, 0)
+            .unwrap();
+        assert_eq!(distances.to_dense(-1).unwrap(), vec![0, 4, 1]);
+
+        assert!(
+            SingleSourceShortestPaths::<i64>::new(ShortestPathsAlgorithm::DeltaStepping {
+                delta: 2
+            })
+            .distances(&adjacency_with_negative_weight, 0)
+            .is_err()
+        );
+
+        // 2 -(1)-> 1 closes the cycle 1 -> 2 -> 1 of weight -2
+        let adjacency_with_negative_cycle =
+            weighted_adjacency(&context, 3, &[(0, 1, 4), (1, 2, -3), (2, 1, 1)]);
+        assert!(
+            SingleSourceShortestPaths::<i64>::new(ShortestPathsAlgorithm::BellmanFord)
+                .distances(&adjacency_with_negative_cycle, 0)
+                .is_err()
+        );
+    }
+}
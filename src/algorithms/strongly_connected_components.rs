@@ -0,0 +1,379 @@
+use crate::algorithms::connected_components::ComponentLabels;
+use crate::algorithms::validation::number_of_vertices;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::UnaryOperatorApplier;
+use crate::operators::binary_operator::{First, NoAccumulator};
+use crate::operators::element_wise_addition::ElementWiseVectorAdditionBinaryOperator;
+use crate::operators::element_wise_multiplication::ElementWiseVectorMultiplicationBinaryOperator;
+use crate::operators::mask::VectorMask;
+use crate::operators::multiplication::{
+    MatrixMultiplicationOperator, VectorMatrixMultiplicationOperator,
+};
+use crate::operators::options::OperatorOptions;
+use crate::operators::select::{DiagonalIndex, MatrixSelector};
+use crate::operators::semiring::AnyPair;
+use crate::operators::unary_operator::Identity;
+use crate::util::ElementIndex;
+use crate::value_types::sparse_matrix::{FromMatrixElementList, MatrixElementList, SparseMatrix};
+use crate::value_types::sparse_vector::{
+    FromVectorElementList, GetVectorElementList, SparseVector, ToDenseVector, VectorElementList,
+};
+use crate::value_types::value_type::{BuiltInValueType, ValueType};
+
+/// Strongly connected components of a directed graph, by forward-backward reachability.
+///
+/// The adjacency matrix stores A(i, j) for an edge from vertex i to vertex j, its values are ignored.
+/// The vertices that are reachable both from and to a pivot form its component. The remaining
+/// vertices that are only reachable from it, only reachable to it, or neither, cannot share a
+/// component with each other, and are divided into components independently. Vertices without
+/// predecessors or successors in their part of the graph are trimmed off as their own component.
+#[derive(Debug, Clone, Default)]
+pub struct StronglyConnectedComponents {}
+
+impl StronglyConnectedComponents {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Labels every vertex by the smallest vertex in its component
+    pub fn components<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+    ) -> Result<ComponentLabels, SparseLinearAlgebraError> {
+        let number_of_vertices = number_of_vertices(adjacency)?;
+        let context = adjacency.context_ref();
+        let sets = VertexSets::new();
+        let successors = VectorMatrixMultiplicationOperator::<bool, T, bool>::new(
+            &AnyPair::<bool, T, bool>::new(),
+            &OperatorOptions::new_default(),
+            &NoAccumulator,
+        );
+        let predecessors = VectorMatrixMultiplicationOperator::<bool, T, bool>::new(
+            &AnyPair::<bool, T, bool>::new(),
+            &OperatorOptions::new(false, false, true),
+            &NoAccumulator,
+        );
+
+        let mut labels: Vec<u64> = (0..number_of_vertices as u64).collect();
+        let mut parts = vec![SparseVector::<bool>::from_element_list(
+            context,
+            &number_of_vertices,
+            &VectorElementList::from_vectors(
+                (0..number_of_vertices).collect(),
+                vec![true; number_of_vertices],
+            )?,
+            &First::<bool, bool, bool>::new(),
+        )?];
+        while let Some(part) = parts.pop() {
+            if part.number_of_stored_elements()? == 0 {
+                continue;
+            }
+            let part = trim(adjacency, &successors, &predecessors, part)?;
+            let pivot = match part.get_element_list()?.indices_ref().iter().min() {
+                Some(pivot) => *pivot,
+                None => continue,
+            };
+
+            let reachable_from_pivot = reachable(adjacency, &successors, &sets, pivot, &part)?;
+            let reachable_to_pivot = reachable(adjacency, &predecessors, &sets, pivot, &part)?;
+            let component = sets.intersection(&reachable_from_pivot, &reachable_to_pivot)?;
+            // The pivot is the smallest vertex of the part, and so of the component
+            for vertex in component.get_element_list()?.indices_ref().iter() {
+                labels[*vertex] = pivot as u64;
+            }
+
+            let unreachable = sets.difference(
+                &part,
+                &sets.union(&reachable_from_pivot, &reachable_to_pivot)?,
+            )?;
+            parts.push(sets.difference(&reachable_from_pivot, &component)?);
+            parts.push(sets.difference(&reachable_to_pivot, &component)?);
+            parts.push(unreachable);
+        }
+
+        ComponentLabels::new(SparseVector::<u64>::from_element_list(
+            context,
+            &number_of_vertices,
+            &VectorElementList::from_vectors((0..number_of_vertices).collect(), labels)?,
+            &First::<u64, u64, u64>::new(),
+        )?)
+    }
+
+    /// The directed acyclic graph of the components, which stores C(i, j) for an edge from
+    /// component i to another component j. The components are indexed by their label,
+    /// such that the condensation is as large as the adjacency matrix.
+    pub fn condensation<T: ValueType + BuiltInValueType<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+        components: &ComponentLabels,
+    ) -> Result<SparseMatrix<bool>, SparseLinearAlgebraError> {
+        let number_of_vertices = number_of_vertices(adjacency)?;
+        let labels = components.labels().to_dense(0)?;
+
+        // membership(v, label(v))
+        let membership = SparseMatrix::<bool>::from_element_list(
+            adjacency.context_ref(),
+            &(number_of_vertices, number_of_vertices).into(),
+            &MatrixElementList::from_vectors(
+                (0..number_of_vertices).collect(),
+                labels.iter().map(|label| *label as ElementIndex).collect(),
+                vec![true; number_of_vertices],
+            )?,
+            &First::<bool, bool, bool>::new(),
+        )?;
+
+        let edges_from_components = MatrixMultiplicationOperator::<bool, T, bool>::new(
+            &AnyPair::<bool, T, bool>::new(),
+            &OperatorOptions::new(false, true, false),
+            &NoAccumulator,
+        )
+        .compute(&membership, adjacency)?;
+        let edges_between_components = MatrixMultiplicationOperator::<bool, bool, bool>::new(
+            &AnyPair::<bool, bool, bool>::new(),
+            &OperatorOptions::new_default(),
+            &NoAccumulator,
+        )
+        .compute(&edges_from_components, &membership)?;
+
+        MatrixSelector::<bool>::new(&OperatorOptions::new_default(), &NoAccumulator)
+            .compute_clear_diagonal(&edges_between_components, &DiagonalIndex::Default())
+    }
+}
+
+// Repeatedly removes the vertices without a predecessor or without a successor in the part,
+// which are components of their own, and are already labeled by themselves
+fn trim<T: ValueType + BuiltInValueType<T>>(
+    adjacency: &SparseMatrix<T>,
+    successors: &VectorMatrixMultiplicationOperator<bool, T, bool>,
+    predecessors: &VectorMatrixMultiplicationOperator<bool, T, bool>,
+    mut part: SparseVector<bool>,
+) -> Result<SparseVector<bool>, SparseLinearAlgebraError> {
+    loop {
+        let with_predecessor =
+            successors.compute_with_mask(&VectorMask::new(&part).structure(), &part, adjacency)?;
+        let with_predecessor_and_successor = predecessors.compute_with_mask(
+            &VectorMask::new(&with_predecessor).structure(),
+            &part,
+            adjacency,
+        )?;
+        if with_predecessor_and_successor.number_of_stored_elements()?
+            == part.number_of_stored_elements()?
+        {
+            return Ok(part);
+        }
+        part = with_predecessor_and_successor;
+    }
+}
+
+// The vertices of the part that are reachable from the pivot along the edges of the part,
+// which are followed backwards when the multiplication transposes the adjacency matrix
+fn reachable<T: ValueType + BuiltInValueType<T>>(
+    adjacency: &SparseMatrix<T>,
+    multiplication: &VectorMatrixMultiplicationOperator<bool, T, bool>,
+    sets: &VertexSets,
+    pivot: ElementIndex,
+    part: &SparseVector<bool>,
+) -> Result<SparseVector<bool>, SparseLinearAlgebraError> {
+    let mut reached = SparseVector::<bool>::from_element_list(
+        adjacency.context_ref(),
+        &part.length()?,
+        &VectorElementList::from_vectors(vec![pivot], vec![true])?,
+        &First::<bool, bool, bool>::new(),
+    )?;
+    let mut frontier = reached.clone();
+    let mut unreached = sets.difference(part, &reached)?;
+    loop {
+        frontier = multiplication.compute_with_mask(
+            &VectorMask::new(&unreached).structure(),
+            &frontier,
+            adjacency,
+        )?;
+        if frontier.number_of_stored_elements()? == 0 {
+            return Ok(reached);
+        }
+        reached = sets.union(&reached, &frontier)?;
+        unreached = sets.difference(&unreached, &frontier)?;
+    }
+}
+
+// Sets of vertices, stored as the structure of boolean vectors
+struct VertexSets {
+    union: ElementWiseVectorAdditionBinaryOperator<bool, bool, bool>,
+    intersection: ElementWiseVectorMultiplicationBinaryOperator<bool, bool, bool>,
+    copy: UnaryOperatorApplier<bool>,
+}
+
+impl VertexSets {
+    fn new() -> Self {
+        let options = OperatorOptions::new_default();
+        Self {
+            union: ElementWiseVectorAdditionBinaryOperator::<bool, bool, bool>::new(
+                &First::<bool, bool, bool>::new(),
+                &options,
+                &NoAccumulator,
+            ),
+            intersection: ElementWiseVectorMultiplicationBinaryOperator::<bool, bool, bool>::new(
+                &First::<bool, bool, bool>::new(),
+                &options,
+                &NoAccumulator,
+            ),
+            copy: UnaryOperatorApplier::<bool>::new(
+                &Identity::<bool>::new(),
+                &options,
+                &NoAccumulator,
+            ),
+        }
+    }
+
+    fn union(
+        &self,
+        set: &SparseVector<bool>,
+        other_set: &SparseVector<bool>,
+    ) -> Result<SparseVector<bool>, SparseLinearAlgebraError> {
+        self.union.compute(set, other_set)
+    }
+
+    fn intersection(
+        &self,
+        set: &SparseVector<bool>,
+        other_set: &SparseVector<bool>,
+    ) -> Result<SparseVector<bool>, SparseLinearAlgebraError> {
+        self.intersection.compute(set, other_set)
+    }
+
+    fn difference(
+        &self,
+        set: &SparseVector<bool>,
+        set_to_remove: &SparseVector<bool>,
+    ) -> Result<SparseVector<bool>, SparseLinearAlgebraError> {
+        self.copy.compute_vector_with_mask(
+            set,
+            &VectorMask::new(set_to_remove).structure().complement(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::BTreeSet;
+
+    use crate::algorithms::test_graphs::{adjacency, pseudo_random_edges};
+    use crate::context::{Context, Mode};
+    use crate::value_types::sparse_matrix::GetMatrixElementList;
+
+    // Two vertices share a component when each reaches the other
+    fn reference_labels(number_of_vertices: usize, edges: &[(usize, usize)]) -> Vec<u64> {
+        let mut neighbours = vec![Vec::new(); number_of_vertices];
+        for (from, to) in edges.iter() {
+            neighbours[*from].push(*to);
+        }
+        let reaches: Vec<Vec<bool>> = (0..number_of_vertices)
+            .map(|source| {
+                let mut reached = vec![false; number_of_vertices];
+                let mut stack = vec![source];
+                reached[source] = true;
+                while let Some(vertex) = stack.pop() {
+                    for neighbour in neighbours[vertex].iter() {
+                        if !reached[*neighbour] {
+                            reached[*neighbour] = true;
+                            stack.push(*neighbour);
+                        }
+                    }
+                }
+                reached
+            })
+            .collect();
+        (0..number_of_vertices)
+            .map(|vertex| {
+                (0..number_of_vertices)
+                    .find(|other| reaches[vertex][*other] && reaches[*other][vertex])
+                    .unwrap() as u64
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_components_match_reference() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let number_of_vertices = 120;
+        let edges = pseudo_random_edges(number_of_vertices, 150);
+        let adjacency = adjacency(&context, number_of_vertices, &edges, -2);
+        let expected_labels = reference_labels(number_of_vertices, &edges);
+        let expected_number_of_components = expected_labels
+            .iter()
+            .enumerate()
+            .filter(|(vertex, label)| **label == *vertex as u64)
+            .count() as u64;
+        assert!(expected_number_of_components > 1);
+        assert!(expected_number_of_components < number_of_vertices as u64);
+
+        let components = StronglyConnectedComponents::new()
+            .components(&adjacency)
+            .unwrap();
+        assert_eq!(
+            components.number_of_components(),
+            expected_number_of_components
+        );
+        assert_eq!(components.labels().to_dense(0).unwrap(), expected_labels);
+    }
+
+    #[test]
+    fn test_condensation_connects_components() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let number_of_vertices = 120;
+        let edges = pseudo_random_edges(number_of_vertices, 150);
+        let adjacency = adjacency(&context, number_of_vertices, &edges, -2);
+        let labels = reference_labels(number_of_vertices, &edges);
+        let expected_edges: BTreeSet<(usize, usize)> = edges
+            .iter()
+            .map(|(from, to)| (labels[*from] as usize, labels[*to] as usize))
+            .filter(|(from, to)| from != to)
+            .collect();
+
+        let algorithm = StronglyConnectedComponents::new();
+        let components = algorithm.components(&adjacency).unwrap();
+        let condensation = algorithm.condensation(&adjacency, &components).unwrap();
+        let condensation = condensation.get_element_list().unwrap();
+        let condensation_edges: BTreeSet<(usize, usize)> = condensation
+            .row_indices_ref()
+            .iter()
+            .cloned()
+            .zip(condensation.column_indices_ref().iter().cloned())
+            .collect();
+        assert_eq!(condensation_edges, expected_edges);
+    }
+
+    #[test]
+    fn test_components_of_cycles() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        // The cycle 3 -> 1 -> 4 -> 3, with a self-loop on 0, leading to the cycle 5 <-> 2
+        let adjacency = adjacency(
+            &context,
+            6,
+            &[
+                (3, 1),
+                (1, 4),
+                (4, 3),
+                (0, 0),
+                (0, 3),
+                (4, 5),
+                (5, 2),
+                (2, 5),
+            ],
+            -2,
+        );
+        let components = StronglyConnectedComponents::new()
+            .components(&adjacency)
+            .unwrap();
+        assert_eq!(components.number_of_components(), 3);
+        assert_eq!(
+            components.labels().to_dense(0).unwrap(),
+            vec![0, 1, 2, 1, 1, 2]
+        );
+    }
+}
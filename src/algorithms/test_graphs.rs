@@ -0,0 +1,93 @@
+// Graphs for the tests of the algorithms. The edges are drawn from a linear congruential generator
+// with a fixed seed, such that every run tests the same graphs.
+
+use std::sync::Arc;
+
+use crate::context::Context;
+use crate::value_types::sparse_matrix::{SetMatrixElement, SparseMatrix};
+use crate::value_types::value_type::{BuiltInValueType, ValueType};
+
+// Draws the values from which the vertices of the edges are taken
+fn pseudo_random_values() -> impl FnMut() -> u64 {
+    let mut state: u64 = 12345;
+    move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        state >> 33
+    }
+}
+
+// Directed, may contain self-loops and parallel edges
+pub(crate) fn pseudo_random_edges(
+    number_of_vertices: usize,
+    number_of_edges: usize,
+) -> Vec<(usize, usize)> {
+    let mut next = pseudo_random_values();
+    let mut next_vertex = || (next() as usize) % number_of_vertices;
+    (0..number_of_edges)
+        .map(|_| (next_vertex(), next_vertex()))
+        .collect()
+}
+
+// Symmetric, with a few self-loops
+pub(crate) fn pseudo_random_undirected_edges(
+    number_of_vertices: usize,
+    number_of_edges: usize,
+) -> Vec<(usize, usize)> {
+    pseudo_random_edges(number_of_vertices, number_of_edges)
+        .into_iter()
+        .flat_map(|(from, to)| vec![(from, to), (to, from)])
+        .collect()
+}
+
+// Stores the value for every edge, and parallel edges once
+pub(crate) fn adjacency<T: ValueType + BuiltInValueType<T> + Copy>(
+    context: &Arc<Context>,
+    number_of_vertices: usize,
+    edges: &[(usize, usize)],
+    value: T,
+) -> SparseMatrix<T>
+where
+    SparseMatrix<T>: SetMatrixElement<T>,
+{
+    let mut adjacency =
+        SparseMatrix::<T>::new(context, &(number_of_vertices, number_of_vertices).into()).unwrap();
+    for (from, to) in edges.iter() {
+        adjacency.set_element((*from, *to, value).into()).unwrap();
+    }
+    adjacency
+}
+
+// Directed like pseudo_random_edges, with the weight of each edge drawn after its vertices
+pub(crate) fn pseudo_random_weighted_edges<T>(
+    number_of_vertices: usize,
+    number_of_edges: usize,
+    weight_of: impl Fn(u64) -> T,
+) -> Vec<(usize, usize, T)> {
+    let mut next = pseudo_random_values();
+    (0..number_of_edges)
+        .map(|_| {
+            let from = (next() as usize) % number_of_vertices;
+            let to = (next() as usize) % number_of_vertices;
+            (from, to, weight_of(next()))
+        })
+        .collect()
+}
+
+// Stores the weight of every edge, and the last of parallel edges
+pub(crate) fn weighted_adjacency<T: ValueType + BuiltInValueType<T> + Copy>(
+    context: &Arc<Context>,
+    number_of_vertices: usize,
+    edges: &[(usize, usize, T)],
+) -> SparseMatrix<T>
+where
+    SparseMatrix<T>: SetMatrixElement<T>,
+{
+    let mut adjacency =
+        SparseMatrix::<T>::new(context, &(number_of_vertices, number_of_vertices).into()).unwrap();
+    for (from, to, weight) in edges.iter() {
+        adjacency.set_element((*from, *to, *weight).into()).unwrap();
+    }
+    adjacency
+}
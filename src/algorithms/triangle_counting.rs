@@ -0,0 +1,369 @@
+use std::cmp::Reverse;
+
+use crate::algorithms::validation::number_of_vertices;
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::BinaryOperatorApplier;
+use crate::operators::binary_operator::{Divide, First, Minus, NoAccumulator, Times};
+use crate::operators::element_wise_multiplication::ElementWiseVectorMultiplicationBinaryOperator;
+use crate::operators::extract::SubMatrixExtractor;
+use crate::operators::mask::MatrixMask;
+use crate::operators::monoid::Plus as PlusMonoid;
+use crate::operators::multiplication::{
+    MatrixMultiplicationOperator, MatrixVectorMultiplicationOperator,
+};
+use crate::operators::options::OperatorOptions;
+use crate::operators::reduce::{MonoidReducer, MonoidScalarReducer};
+use crate::operators::select::{DiagonalIndex, MatrixSelector};
+use crate::operators::semiring::{PlusPair, Semiring};
+use crate::util::{ElementIndex, ElementIndexSelector};
+use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::sparse_vector::{
+    FromVectorElementList, SparseVector, ToDenseVector, VectorElementList,
+};
+use crate::value_types::value_type::AsBoolean;
+
+/// How the triangles are counted from a masked product of the adjacency matrix A,
+/// or of its strictly lower and upper triangles L and U.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TriangleCountingMethod {
+    /// Sums A*A masked by A, which counts every triangle six times
+    Burkhardt,
+    /// Sums L*U masked by A, which counts every triangle twice
+    Cohen,
+    /// Sums L*L masked by L, which counts every triangle once
+    SandiaLL,
+    /// Sums U*U masked by U, which counts every triangle once
+    SandiaUU,
+}
+
+/// Relabels the vertices by their degree before counting, which changes the lengths of the rows
+/// of L and U, and may make counting faster. Increasing degree suits SandiaLL, decreasing SandiaUU.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VertexOrdering {
+    Original,
+    IncreasingDegree,
+    DecreasingDegree,
+}
+
+/// Triangle counting in an undirected graph.
+///
+/// The adjacency matrix must be symmetric, its values are ignored, and so are self-loops.
+#[derive(Debug, Clone)]
+pub struct TriangleCounting {
+    method: TriangleCountingMethod,
+    ordering: VertexOrdering,
+}
+
+impl TriangleCounting {
+    pub fn new(method: TriangleCountingMethod, ordering: VertexOrdering) -> Self {
+        Self { method, ordering }
+    }
+
+    pub fn number_of_triangles<T: AsBoolean<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+    ) -> Result<u64, SparseLinearAlgebraError> {
+        let options = OperatorOptions::new_default();
+        let selector = MatrixSelector::<T>::new(&options, &NoAccumulator);
+        let multiplication = MatrixMultiplicationOperator::<T, T, u64>::new(
+            &PlusPair::<T, T, u64>::new(),
+            &options,
+            &NoAccumulator,
+        );
+
+        let adjacency = self.reorder(&without_self_loops(adjacency)?)?;
+        let (product, multiplicity) = match self.method {
+            TriangleCountingMethod::Burkhardt => (
+                multiplication.compute_with_mask(
+                    &MatrixMask::new(&adjacency).structure(),
+                    &adjacency,
+                    &adjacency,
+                )?,
+                6,
+            ),
+            TriangleCountingMethod::Cohen => {
+                let lower =
+                    selector.compute_lower_triangle(&adjacency, &DiagonalIndex::Index(-1))?;
+                let upper =
+                    selector.compute_upper_triangle(&adjacency, &DiagonalIndex::Index(1))?;
+                (
+                    multiplication.compute_with_mask(
+                        &MatrixMask::new(&adjacency).structure(),
+                        &lower,
+                        &upper,
+                    )?,
+                    2,
+                )
+            }
+            TriangleCountingMethod::SandiaLL => {
+                let lower =
+                    selector.compute_lower_triangle(&adjacency, &DiagonalIndex::Index(-1))?;
+                (
+                    multiplication.compute_with_mask(
+                        &MatrixMask::new(&lower).structure(),
+                        &lower,
+                        &lower,
+                    )?,
+                    1,
+                )
+            }
+            TriangleCountingMethod::SandiaUU => {
+                let upper =
+                    selector.compute_upper_triangle(&adjacency, &DiagonalIndex::Index(1))?;
+                (
+                    multiplication.compute_with_mask(
+                        &MatrixMask::new(&upper).structure(),
+                        &upper,
+                        &upper,
+                    )?,
+                    1,
+                )
+            }
+        };
+
+        let mut count = 0;
+        MonoidReducer::new(&PlusMonoid::<u64>::new(), &options, &NoAccumulator)
+            .matrix_to_scalar(&product, &mut count)?;
+        Ok(count / multiplicity)
+    }
+
+    /// The number of triangles of each vertex, which are counted from A*A masked by A.
+    /// An associated function, as the methods count every triangle at only some of its vertices.
+    /// Vertices without triangles are not stored.
+    pub fn triangles_per_vertex<T: AsBoolean<T>>(
+        adjacency: &SparseMatrix<T>,
+    ) -> Result<SparseVector<u64>, SparseLinearAlgebraError> {
+        let options = OperatorOptions::new_default();
+        let twice_the_triangles =
+            MonoidReducer::new(&PlusMonoid::<u64>::new(), &options, &NoAccumulator)
+                .compute_vector(&triangles_per_edge::<T, u64>(
+                    &without_self_loops(adjacency)?,
+                    &PlusPair::<T, T, u64>::new(),
+                )?)?;
+        BinaryOperatorApplier::new(&Divide::<u64, u64, u64>::new(), &options, &NoAccumulator)
+            .compute_with_vector_as_first_argument(&twice_the_triangles, &2)
+    }
+
+    /// The fraction of the pairs of neighbours of each vertex that are neighbours themselves,
+    /// 2 * triangles / (degree * (degree - 1)), with the triangles of triangles_per_vertex().
+    /// Vertices without triangles are not stored.
+    pub fn local_clustering_coefficients<T: AsBoolean<T>>(
+        adjacency: &SparseMatrix<T>,
+    ) -> Result<SparseVector<f64>, SparseLinearAlgebraError> {
+        let options = OperatorOptions::new_default();
+        let adjacency = without_self_loops(adjacency)?;
+
+        let twice_the_triangles =
+            MonoidReducer::new(&PlusMonoid::<f64>::new(), &options, &NoAccumulator)
+                .compute_vector(&triangles_per_edge::<T, f64>(
+                    &adjacency,
+                    &PlusPair::<T, T, f64>::new(),
+                )?)?;
+
+        let degrees = degrees(&adjacency)?;
+        let degrees_minus_one =
+            BinaryOperatorApplier::new(&Minus::<f64, f64, f64>::new(), &options, &NoAccumulator)
+                .compute_with_vector_as_first_argument(&degrees, &1.0)?;
+        let pairs_of_neighbours =
+            ElementWiseVectorMultiplicationBinaryOperator::<f64, f64, f64>::new(
+                &Times::<f64, f64, f64>::new(),
+                &options,
+                &NoAccumulator,
+            )
+            .compute(&degrees, &degrees_minus_one)?;
+
+        ElementWiseVectorMultiplicationBinaryOperator::<f64, f64, f64>::new(
+            &Divide::<f64, f64, f64>::new(),
+            &options,
+            &NoAccumulator,
+        )
+        .compute(&twice_the_triangles, &pairs_of_neighbours)
+    }
+
+    fn reorder<T: AsBoolean<T>>(
+        &self,
+        adjacency: &SparseMatrix<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let degrees = match self.ordering {
+            VertexOrdering::Original => return Ok(adjacency.clone()),
+            _ => degrees(adjacency)?.to_dense(0.0)?,
+        };
+
+        let mut permutation: Vec<ElementIndex> = (0..degrees.len()).collect();
+        match self.ordering {
+            VertexOrdering::IncreasingDegree => {
+                permutation.sort_by_key(|vertex| degrees[*vertex] as u64)
+            }
+            _ => permutation.sort_by_key(|vertex| Reverse(degrees[*vertex] as u64)),
+        }
+
+        SubMatrixExtractor::<T, T>::new(&OperatorOptions::new_default(), &NoAccumulator).compute(
+            adjacency,
+            &ElementIndexSelector::Index(&permutation),
+            &ElementIndexSelector::Index(&permutation),
+        )
+    }
+}
+
+fn without_self_loops<T: AsBoolean<T>>(
+    adjacency: &SparseMatrix<T>,
+) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+    number_of_vertices(adjacency)?;
+    MatrixSelector::<T>::new(&OperatorOptions::new_default(), &NoAccumulator)
+        .compute_clear_diagonal(adjacency, &DiagonalIndex::Default())
+}
+
+// A*A masked by A, the number of triangles that each edge is in
+fn triangles_per_edge<T: AsBoolean<T>, P: AsBoolean<P>>(
+    adjacency: &SparseMatrix<T>,
+    plus_pair: &PlusPair<T, T, P>,
+) -> Result<SparseMatrix<P>, SparseLinearAlgebraError>
+where
+    PlusPair<T, T, P>: Semiring<T, T, P>,
+{
+    MatrixMultiplicationOperator::<T, T, P>::new(
+        plus_pair,
+        &OperatorOptions::new_default(),
+        &NoAccumulator,
+    )
+    .compute_with_mask(
+        &MatrixMask::new(adjacency).structure(),
+        adjacency,
+        adjacency,
+    )
+}
+
+// The number of stored elements in each row, vertices without edges are not stored
+fn degrees<T: AsBoolean<T>>(
+    adjacency: &SparseMatrix<T>,
+) -> Result<SparseVector<f64>, SparseLinearAlgebraError> {
+    let number_of_vertices = number_of_vertices(adjacency)?;
+    let every_vertex = SparseVector::<bool>::from_element_list(
+        adjacency.context_ref(),
+        &number_of_vertices,
+        &VectorElementList::from_vectors(
+            (0..number_of_vertices).collect(),
+            vec![true; number_of_vertices],
+        )?,
+        &First::<bool, bool, bool>::new(),
+    )?;
+    MatrixVectorMultiplicationOperator::<T, bool, f64>::new(
+        &PlusPair::<T, bool, f64>::new(),
+        &OperatorOptions::new_default(),
+        &NoAccumulator,
+    )
+    .compute(adjacency, &every_vertex)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::algorithms::test_graphs::{adjacency, pseudo_random_undirected_edges};
+    use crate::context::{Context, Mode};
+
+    const METHODS: [TriangleCountingMethod; 4] = [
+        TriangleCountingMethod::Burkhardt,
+        TriangleCountingMethod::Cohen,
+        TriangleCountingMethod::SandiaLL,
+        TriangleCountingMethod::SandiaUU,
+    ];
+
+    const ORDERINGS: [VertexOrdering; 3] = [
+        VertexOrdering::Original,
+        VertexOrdering::IncreasingDegree,
+        VertexOrdering::DecreasingDegree,
+    ];
+
+    fn reference_triangles_per_vertex(
+        number_of_vertices: usize,
+        edges: &[(usize, usize)],
+    ) -> Vec<u64> {
+        let mut is_edge = vec![vec![false; number_of_vertices]; number_of_vertices];
+        for (from, to) in edges.iter() {
+            if from != to {
+                is_edge[*from][*to] = true;
+            }
+        }
+        let mut triangles = vec![0; number_of_vertices];
+        for a in 0..number_of_vertices {
+            for b in (a + 1)..number_of_vertices {
+                for c in (b + 1)..number_of_vertices {
+                    if is_edge[a][b] && is_edge[b][c] && is_edge[a][c] {
+                        triangles[a] += 1;
+                        triangles[b] += 1;
+                        triangles[c] += 1;
+                    }
+                }
+            }
+        }
+        triangles
+    }
+
+    #[test]
+    fn test_number_of_triangles_match_reference() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let number_of_vertices = 60;
+        let edges = pseudo_random_undirected_edges(number_of_vertices, 400);
+        let adjacency = adjacency(&context, number_of_vertices, &edges, 7);
+        let expected_number_of_triangles: u64 =
+            reference_triangles_per_vertex(number_of_vertices, &edges)
+                .iter()
+                .sum::<u64>()
+                / 3;
+        assert!(expected_number_of_triangles > 0);
+
+        for method in METHODS.iter() {
+            for ordering in ORDERINGS.iter() {
+                assert_eq!(
+                    TriangleCounting::new(*method, *ordering)
+                        .number_of_triangles(&adjacency)
+                        .unwrap(),
+                    expected_number_of_triangles
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_triangles_per_vertex_match_reference() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let number_of_vertices = 60;
+        let edges = pseudo_random_undirected_edges(number_of_vertices, 400);
+        let adjacency = adjacency(&context, number_of_vertices, &edges, 7);
+
+        let triangles = TriangleCounting::triangles_per_vertex(&adjacency).unwrap();
+        assert_eq!(
+            triangles.to_dense(0).unwrap(),
+            reference_triangles_per_vertex(number_of_vertices, &edges)
+        );
+    }
+
+    #[test]
+    fn test_local_clustering_coefficients() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        // The triangle 0, 1, 2, with 3 attached to 2, and a self-loop on 0
+        let edges = [(0, 1), (1, 2), (0, 2), (2, 3), (0, 0)];
+        let symmetric_edges: Vec<(usize, usize)> = edges
+            .iter()
+            .flat_map(|(from, to)| vec![(*from, *to), (*to, *from)])
+            .collect();
+        let adjacency = adjacency(&context, 5, &symmetric_edges, 7);
+
+        let counting =
+            TriangleCounting::new(TriangleCountingMethod::Burkhardt, VertexOrdering::Original);
+        assert_eq!(counting.number_of_triangles(&adjacency).unwrap(), 1);
+
+        let coefficients = TriangleCounting::local_clustering_coefficients(&adjacency).unwrap();
+        assert_eq!(coefficients.number_of_stored_elements().unwrap(), 3);
+        let coefficients = coefficients.to_dense(0.0).unwrap();
+        assert_eq!(coefficients[0], 1.0);
+        assert_eq!(coefficients[1], 1.0);
+        assert!((coefficients[2] - 1.0 / 3.0).abs() < 1e-12);
+        assert_eq!(coefficients[3], 0.0);
+        assert_eq!(coefficients[4], 0.0);
+    }
+}
@@ -0,0 +1,34 @@
+use crate::error::{GraphBlasError, GraphBlasErrorType, SparseLinearAlgebraError};
+use crate::operators::validation::check_index;
+use crate::util::ElementIndex;
+use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::value_type::ValueType;
+
+// Algorithms take a graph as its adjacency matrix, which stores A(i, j) for an edge from vertex i to vertex j.
+
+/// The number of vertices of the graph, the adjacency matrix must be square
+pub(crate) fn number_of_vertices<T: ValueType>(
+    adjacency: &SparseMatrix<T>,
+) -> Result<ElementIndex, SparseLinearAlgebraError> {
+    let size = adjacency.size()?;
+    if size.row_height() == size.column_width() {
+        Ok(size.row_height())
+    } else {
+        Err(GraphBlasError::new(
+            GraphBlasErrorType::DimensionMismatch,
+            format!("The adjacency matrix must be square, found: {:?}", size),
+        )
+        .into())
+    }
+}
+
+pub(crate) fn check_vertices(
+    argument_name: &str,
+    vertices: &[ElementIndex],
+    number_of_vertices: ElementIndex,
+) -> Result<(), SparseLinearAlgebraError> {
+    for vertex in vertices.iter() {
+        check_index(argument_name, *vertex, number_of_vertices)?;
+    }
+    Ok(())
+}
@@ -1,3 +1,4 @@
+pub mod algorithms;
 pub mod bindings_to_graphblas_implementation;
 pub mod context;
 pub mod error;
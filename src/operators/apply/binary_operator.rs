@@ -2,28 +2,36 @@ use std::marker::PhantomData;
 use std::ptr;
 
 use crate::error::SparseLinearAlgebraError;
+use crate::operators::sparse_scalar_operand::{
+    is_empty, write_empty_matrix_result, write_empty_vector_result,
+};
+use crate::operators::validation::{check_length, check_size};
 use crate::operators::{
-    binary_operator::BinaryOperator,
+    binary_operator::{Accumulator, BinaryOperator},
     mask::{MatrixMask, VectorMask},
-    options::OperatorOptions,
+    options::{
+        size_of_first_argument, size_of_second_argument, OperatorOptions,
+    },
 };
 use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::sparse_scalar::SparseScalar;
 use crate::value_types::sparse_vector::SparseVector;
-use crate::value_types::value_type::{AsBoolean, ValueType};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
 
 use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GrB_Matrix_apply_BinaryOp1st_BOOL,
-    GrB_Matrix_apply_BinaryOp1st_FP32, GrB_Matrix_apply_BinaryOp1st_FP64,
-    GrB_Matrix_apply_BinaryOp1st_INT16, GrB_Matrix_apply_BinaryOp1st_INT32,
-    GrB_Matrix_apply_BinaryOp1st_INT64, GrB_Matrix_apply_BinaryOp1st_INT8,
-    GrB_Matrix_apply_BinaryOp1st_UINT16, GrB_Matrix_apply_BinaryOp1st_UINT32,
-    GrB_Matrix_apply_BinaryOp1st_UINT64, GrB_Matrix_apply_BinaryOp1st_UINT8,
-    GrB_Matrix_apply_BinaryOp2nd_BOOL, GrB_Matrix_apply_BinaryOp2nd_FP32,
-    GrB_Matrix_apply_BinaryOp2nd_FP64, GrB_Matrix_apply_BinaryOp2nd_INT16,
-    GrB_Matrix_apply_BinaryOp2nd_INT32, GrB_Matrix_apply_BinaryOp2nd_INT64,
-    GrB_Matrix_apply_BinaryOp2nd_INT8, GrB_Matrix_apply_BinaryOp2nd_UINT16,
-    GrB_Matrix_apply_BinaryOp2nd_UINT32, GrB_Matrix_apply_BinaryOp2nd_UINT64,
-    GrB_Matrix_apply_BinaryOp2nd_UINT8, GrB_Vector_apply_BinaryOp1st_BOOL,
+    GrB_BinaryOp, GrB_Descriptor, GrB_Matrix,
+    GrB_Matrix_apply_BinaryOp1st_BOOL, GrB_Matrix_apply_BinaryOp1st_FP32,
+    GrB_Matrix_apply_BinaryOp1st_FP64, GrB_Matrix_apply_BinaryOp1st_INT16,
+    GrB_Matrix_apply_BinaryOp1st_INT32, GrB_Matrix_apply_BinaryOp1st_INT64,
+    GrB_Matrix_apply_BinaryOp1st_INT8, GrB_Matrix_apply_BinaryOp1st_UINT16,
+    GrB_Matrix_apply_BinaryOp1st_UINT32, GrB_Matrix_apply_BinaryOp1st_UINT64,
+    GrB_Matrix_apply_BinaryOp1st_UINT8, GrB_Matrix_apply_BinaryOp2nd_BOOL,
+    GrB_Matrix_apply_BinaryOp2nd_FP32, GrB_Matrix_apply_BinaryOp2nd_FP64,
+    GrB_Matrix_apply_BinaryOp2nd_INT16, GrB_Matrix_apply_BinaryOp2nd_INT32,
+    GrB_Matrix_apply_BinaryOp2nd_INT64, GrB_Matrix_apply_BinaryOp2nd_INT8,
+    GrB_Matrix_apply_BinaryOp2nd_UINT16, GrB_Matrix_apply_BinaryOp2nd_UINT32,
+    GrB_Matrix_apply_BinaryOp2nd_UINT64, GrB_Matrix_apply_BinaryOp2nd_UINT8,
+    GrB_Vector, GrB_Vector_apply_BinaryOp1st_BOOL,
     GrB_Vector_apply_BinaryOp1st_FP32, GrB_Vector_apply_BinaryOp1st_FP64,
     GrB_Vector_apply_BinaryOp1st_INT16, GrB_Vector_apply_BinaryOp1st_INT32,
     GrB_Vector_apply_BinaryOp1st_INT64, GrB_Vector_apply_BinaryOp1st_INT8,
@@ -34,7 +42,9 @@ use crate::bindings_to_graphblas_implementation::{
     GrB_Vector_apply_BinaryOp2nd_INT32, GrB_Vector_apply_BinaryOp2nd_INT64,
     GrB_Vector_apply_BinaryOp2nd_INT8, GrB_Vector_apply_BinaryOp2nd_UINT16,
     GrB_Vector_apply_BinaryOp2nd_UINT32, GrB_Vector_apply_BinaryOp2nd_UINT64,
-    GrB_Vector_apply_BinaryOp2nd_UINT8,
+    GrB_Vector_apply_BinaryOp2nd_UINT8, GxB_Matrix_apply_BinaryOp1st,
+    GxB_Matrix_apply_BinaryOp2nd, GxB_Vector_apply_BinaryOp1st,
+    GxB_Vector_apply_BinaryOp2nd,
 };
 
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
@@ -76,7 +86,7 @@ pub struct BinaryOperatorApplier<
 
     binary_operator: GrB_BinaryOp,
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<
@@ -86,28 +96,17 @@ impl<
     > BinaryOperatorApplier<FirstArgument, SecondArgument, Product>
 {
     pub fn new(
-        binary_operator: &dyn BinaryOperator<
-            FirstArgument,
-            SecondArgument,
-            Product,
-        >,
+        binary_operator: &(impl BinaryOperator<FirstArgument, SecondArgument, Product>
+              + ?Sized),
         options: &OperatorOptions,
-        accumulator: Option<
-            &dyn BinaryOperator<SecondArgument, SecondArgument, Product>,
-        >, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<Product, Product, Product> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => {
-                accumulator_to_use = accumulator.graphblas_type()
-            }
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             binary_operator: binary_operator.graphblas_type(),
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _first_argument: PhantomData,
             _second_argument: PhantomData,
@@ -195,6 +194,519 @@ where
     ) -> Result<(), SparseLinearAlgebraError>;
 }
 
+impl<FirstArgument, SecondArgument, Product>
+    BinaryOperatorApplier<FirstArgument, SecondArgument, Product>
+where
+    FirstArgument: ValueType,
+    SecondArgument: ValueType,
+    Product: ValueType + BuiltInValueType<Product>,
+    Self: BinaryOperatorApplierTrait<FirstArgument, SecondArgument, Product>,
+{
+    /// Like apply_with_vector_as_first_argument(), but allocates the product
+    pub fn compute_with_vector_as_first_argument(
+        &self,
+        first_argument: &SparseVector<FirstArgument>,
+        second_argument: &SecondArgument,
+    ) -> Result<SparseVector<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::new(
+            first_argument.context_ref(),
+            &first_argument.length()?,
+        )?;
+        self.apply_with_vector_as_first_argument(
+            first_argument,
+            second_argument,
+            &mut product,
+        )?;
+        Ok(product)
+    }
+
+    /// Like apply_with_vector_as_second_argument(), but allocates the product
+    pub fn compute_with_vector_as_second_argument(
+        &self,
+        first_argument: &FirstArgument,
+        second_argument: &SparseVector<SecondArgument>,
+    ) -> Result<SparseVector<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::new(
+            second_argument.context_ref(),
+            &second_argument.length()?,
+        )?;
+        self.apply_with_vector_as_second_argument(
+            first_argument,
+            second_argument,
+            &mut product,
+        )?;
+        Ok(product)
+    }
+
+    /// Like apply_with_vector_as_first_argument_and_mask(), but allocates the product
+    pub fn compute_with_vector_as_first_argument_and_mask<
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+    >(
+        &self,
+        first_argument: &SparseVector<FirstArgument>,
+        second_argument: &SecondArgument,
+        mask: &VectorMask<MaskValueType, AsBool>,
+    ) -> Result<SparseVector<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::new(
+            first_argument.context_ref(),
+            &first_argument.length()?,
+        )?;
+        self.apply_with_vector_as_first_argument_and_mask(
+            first_argument,
+            second_argument,
+            &mut product,
+            mask,
+        )?;
+        Ok(product)
+    }
+
+    /// Like apply_with_vector_as_second_argument_and_mask(), but allocates the product
+    pub fn compute_with_vector_as_second_argument_and_mask<
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+    >(
+        &self,
+        first_argument: &FirstArgument,
+        second_argument: &SparseVector<SecondArgument>,
+        mask: &VectorMask<MaskValueType, AsBool>,
+    ) -> Result<SparseVector<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::new(
+            second_argument.context_ref(),
+            &second_argument.length()?,
+        )?;
+        self.apply_with_vector_as_second_argument_and_mask(
+            first_argument,
+            second_argument,
+            &mut product,
+            mask,
+        )?;
+        Ok(product)
+    }
+
+    /// Like apply_with_matrix_as_first_argument(), but allocates the product
+    pub fn compute_with_matrix_as_first_argument(
+        &self,
+        first_argument: &SparseMatrix<FirstArgument>,
+        second_argument: &SecondArgument,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::new(
+            first_argument.context_ref(),
+            &size_of_first_argument(first_argument, &self.options)?,
+        )?;
+        self.apply_with_matrix_as_first_argument(
+            first_argument,
+            second_argument,
+            &mut product,
+        )?;
+        Ok(product)
+    }
+
+    /// Like apply_with_matrix_as_second_argument(), but allocates the product
+    pub fn compute_with_matrix_as_second_argument(
+        &self,
+        first_argument: &FirstArgument,
+        second_argument: &SparseMatrix<SecondArgument>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::new(
+            second_argument.context_ref(),
+            &size_of_second_argument(second_argument, &self.options)?,
+        )?;
+        self.apply_with_matrix_as_second_argument(
+            first_argument,
+            second_argument,
+            &mut product,
+        )?;
+        Ok(product)
+    }
+
+    /// Like apply_with_matrix_as_first_argument_and_mask(), but allocates the product
+    pub fn compute_with_matrix_as_first_argument_and_mask<
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+    >(
+        &self,
+        first_argument: &SparseMatrix<FirstArgument>,
+        second_argument: &SecondArgument,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::new(
+            first_argument.context_ref(),
+            &size_of_first_argument(first_argument, &self.options)?,
+        )?;
+        self.apply_with_matrix_as_first_argument_and_mask(
+            first_argument,
+            second_argument,
+            &mut product,
+            mask,
+        )?;
+        Ok(product)
+    }
+
+    /// Like apply_with_matrix_as_second_argument_and_mask(), but allocates the product
+    pub fn compute_with_matrix_as_second_argument_and_mask<
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+    >(
+        &self,
+        first_argument: &FirstArgument,
+        second_argument: &SparseMatrix<SecondArgument>,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::new(
+            second_argument.context_ref(),
+            &size_of_second_argument(second_argument, &self.options)?,
+        )?;
+        self.apply_with_matrix_as_second_argument_and_mask(
+            first_argument,
+            second_argument,
+            &mut product,
+            mask,
+        )?;
+        Ok(product)
+    }
+}
+
+// Binds a SparseScalar instead of a plain value. An empty scalar has no value to bind,
+// such that no element is computed and the product is written as if the result was empty.
+impl<FirstArgument, SecondArgument, Product>
+    BinaryOperatorApplier<FirstArgument, SecondArgument, Product>
+where
+    FirstArgument: ValueType,
+    SecondArgument: ValueType,
+    Product: ValueType + BuiltInValueType<Product>,
+{
+    pub fn apply_sparse_scalar_with_vector_as_first_argument(
+        &self,
+        first_argument: &SparseVector<FirstArgument>,
+        second_argument: &SparseScalar<SecondArgument>,
+        product: &mut SparseVector<Product>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_length(
+                "product",
+                first_argument.length()?,
+                product.length()?,
+            )?;
+        }
+
+        self.apply_vector_and_sparse_scalar(
+            first_argument,
+            second_argument,
+            product,
+            ptr::null_mut(),
+            self.options.to_graphblas_descriptor(),
+        )
+    }
+
+    pub fn apply_sparse_scalar_with_vector_as_second_argument(
+        &self,
+        first_argument: &SparseScalar<FirstArgument>,
+        second_argument: &SparseVector<SecondArgument>,
+        product: &mut SparseVector<Product>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_length(
+                "product",
+                second_argument.length()?,
+                product.length()?,
+            )?;
+        }
+
+        self.apply_sparse_scalar_and_vector(
+            first_argument,
+            second_argument,
+            product,
+            ptr::null_mut(),
+            self.options.to_graphblas_descriptor(),
+        )
+    }
+
+    pub fn apply_sparse_scalar_with_vector_as_first_argument_and_mask<
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+    >(
+        &self,
+        first_argument: &SparseVector<FirstArgument>,
+        second_argument: &SparseScalar<SecondArgument>,
+        product: &mut SparseVector<Product>,
+        mask: &VectorMask<MaskValueType, AsBool>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_length(
+                "product",
+                first_argument.length()?,
+                product.length()?,
+            )?;
+            check_length("mask", product.length()?, mask.length()?)?;
+        }
+
+        self.apply_vector_and_sparse_scalar(
+            first_argument,
+            second_argument,
+            product,
+            mask.graphblas_vector(),
+            mask.graphblas_descriptor(&self.options),
+        )
+    }
+
+    pub fn apply_sparse_scalar_with_vector_as_second_argument_and_mask<
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+    >(
+        &self,
+        first_argument: &SparseScalar<FirstArgument>,
+        second_argument: &SparseVector<SecondArgument>,
+        product: &mut SparseVector<Product>,
+        mask: &VectorMask<MaskValueType, AsBool>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_length(
+                "product",
+                second_argument.length()?,
+                product.length()?,
+            )?;
+            check_length("mask", product.length()?, mask.length()?)?;
+        }
+
+        self.apply_sparse_scalar_and_vector(
+            first_argument,
+            second_argument,
+            product,
+            mask.graphblas_vector(),
+            mask.graphblas_descriptor(&self.options),
+        )
+    }
+
+    pub fn apply_sparse_scalar_with_matrix_as_first_argument(
+        &self,
+        first_argument: &SparseMatrix<FirstArgument>,
+        second_argument: &SparseScalar<SecondArgument>,
+        product: &mut SparseMatrix<Product>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_size(
+                "product",
+                &size_of_first_argument(first_argument, &self.options)?,
+                &product.size()?,
+            )?;
+        }
+
+        self.apply_matrix_and_sparse_scalar(
+            first_argument,
+            second_argument,
+            product,
+            ptr::null_mut(),
+            self.options.to_graphblas_descriptor(),
+        )
+    }
+
+    pub fn apply_sparse_scalar_with_matrix_as_second_argument(
+        &self,
+        first_argument: &SparseScalar<FirstArgument>,
+        second_argument: &SparseMatrix<SecondArgument>,
+        product: &mut SparseMatrix<Product>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_size(
+                "product",
+                &size_of_second_argument(second_argument, &self.options)?,
+                &product.size()?,
+            )?;
+        }
+
+        self.apply_sparse_scalar_and_matrix(
+            first_argument,
+            second_argument,
+            product,
+            ptr::null_mut(),
+            self.options.to_graphblas_descriptor(),
+        )
+    }
+
+    pub fn apply_sparse_scalar_with_matrix_as_first_argument_and_mask<
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+    >(
+        &self,
+        first_argument: &SparseMatrix<FirstArgument>,
+        second_argument: &SparseScalar<SecondArgument>,
+        product: &mut SparseMatrix<Product>,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_size(
+                "product",
+                &size_of_first_argument(first_argument, &self.options)?,
+                &product.size()?,
+            )?;
+            check_size("mask", &product.size()?, &mask.size()?)?;
+        }
+
+        self.apply_matrix_and_sparse_scalar(
+            first_argument,
+            second_argument,
+            product,
+            mask.graphblas_matrix(),
+            mask.graphblas_descriptor(&self.options),
+        )
+    }
+
+    pub fn apply_sparse_scalar_with_matrix_as_second_argument_and_mask<
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+    >(
+        &self,
+        first_argument: &SparseScalar<FirstArgument>,
+        second_argument: &SparseMatrix<SecondArgument>,
+        product: &mut SparseMatrix<Product>,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_size(
+                "product",
+                &size_of_second_argument(second_argument, &self.options)?,
+                &product.size()?,
+            )?;
+            check_size("mask", &product.size()?, &mask.size()?)?;
+        }
+
+        self.apply_sparse_scalar_and_matrix(
+            first_argument,
+            second_argument,
+            product,
+            mask.graphblas_matrix(),
+            mask.graphblas_descriptor(&self.options),
+        )
+    }
+
+    fn apply_vector_and_sparse_scalar(
+        &self,
+        first_argument: &SparseVector<FirstArgument>,
+        second_argument: &SparseScalar<SecondArgument>,
+        product: &mut SparseVector<Product>,
+        mask: GrB_Vector,
+        descriptor: GrB_Descriptor,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if is_empty(second_argument)? {
+            return write_empty_vector_result(
+                product,
+                mask,
+                self.accumulator,
+                descriptor,
+            );
+        }
+
+        product.context_ref().call(|| unsafe {
+            GxB_Vector_apply_BinaryOp2nd(
+                product.graphblas_vector(),
+                mask,
+                self.accumulator,
+                self.binary_operator,
+                first_argument.graphblas_vector(),
+                second_argument.graphblas_scalar(),
+                descriptor,
+            )
+        })?;
+        Ok(())
+    }
+
+    fn apply_sparse_scalar_and_vector(
+        &self,
+        first_argument: &SparseScalar<FirstArgument>,
+        second_argument: &SparseVector<SecondArgument>,
+        product: &mut SparseVector<Product>,
+        mask: GrB_Vector,
+        descriptor: GrB_Descriptor,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if is_empty(first_argument)? {
+            return write_empty_vector_result(
+                product,
+                mask,
+                self.accumulator,
+                descriptor,
+            );
+        }
+
+        product.context_ref().call(|| unsafe {
+            GxB_Vector_apply_BinaryOp1st(
+                product.graphblas_vector(),
+                mask,
+                self.accumulator,
+                self.binary_operator,
+                first_argument.graphblas_scalar(),
+                second_argument.graphblas_vector(),
+                descriptor,
+            )
+        })?;
+        Ok(())
+    }
+
+    fn apply_matrix_and_sparse_scalar(
+        &self,
+        first_argument: &SparseMatrix<FirstArgument>,
+        second_argument: &SparseScalar<SecondArgument>,
+        product: &mut SparseMatrix<Product>,
+        mask: GrB_Matrix,
+        descriptor: GrB_Descriptor,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if is_empty(second_argument)? {
+            return write_empty_matrix_result(
+                product,
+                mask,
+                self.accumulator,
+                &self.options,
+                descriptor,
+            );
+        }
+
+        product.context_ref().call(|| unsafe {
+            GxB_Matrix_apply_BinaryOp2nd(
+                product.graphblas_matrix(),
+                mask,
+                self.accumulator,
+                self.binary_operator,
+                first_argument.graphblas_matrix(),
+                second_argument.graphblas_scalar(),
+                descriptor,
+            )
+        })?;
+        Ok(())
+    }
+
+    fn apply_sparse_scalar_and_matrix(
+        &self,
+        first_argument: &SparseScalar<FirstArgument>,
+        second_argument: &SparseMatrix<SecondArgument>,
+        product: &mut SparseMatrix<Product>,
+        mask: GrB_Matrix,
+        descriptor: GrB_Descriptor,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if is_empty(first_argument)? {
+            return write_empty_matrix_result(
+                product,
+                mask,
+                self.accumulator,
+                &self.options,
+                descriptor,
+            );
+        }
+
+        product.context_ref().call(|| unsafe {
+            GxB_Matrix_apply_BinaryOp1st(
+                product.graphblas_matrix(),
+                mask,
+                self.accumulator,
+                self.binary_operator,
+                first_argument.graphblas_scalar(),
+                second_argument.graphblas_matrix(),
+                descriptor,
+            )
+        })?;
+        Ok(())
+    }
+}
+
 macro_rules! implement_binary_operator {
     ($first_argument_type:ty, $second_argument_type:ty, $product_type:ty, $operator_vector_as_first_argument:ident, $operator_vector_as_second_argument:ident, $operator_matrix_as_first_argument:ident, $operator_matrix_as_second_argument:ident) => {
         impl
@@ -215,6 +727,14 @@ macro_rules! implement_binary_operator {
                 second_argument: &$second_argument_type,
                 product: &mut SparseVector<$product_type>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    check_length(
+                        "product",
+                        first_argument.length()?,
+                        product.length()?,
+                    )?;
+                }
+
                 let context = product.context();
 
                 context.call(|| unsafe {
@@ -225,7 +745,7 @@ macro_rules! implement_binary_operator {
                         self.binary_operator,
                         first_argument.graphblas_vector(),
                         *second_argument,
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
 
@@ -238,6 +758,14 @@ macro_rules! implement_binary_operator {
                 second_argument: &SparseVector<$second_argument_type>,
                 product: &mut SparseVector<$product_type>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    check_length(
+                        "product",
+                        second_argument.length()?,
+                        product.length()?,
+                    )?;
+                }
+
                 let context = product.context();
 
                 context.call(|| unsafe {
@@ -248,7 +776,7 @@ macro_rules! implement_binary_operator {
                         self.binary_operator,
                         *first_argument,
                         second_argument.graphblas_vector(),
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
 
@@ -265,6 +793,15 @@ macro_rules! implement_binary_operator {
                 product: &mut SparseVector<$product_type>,
                 mask: &VectorMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    check_length(
+                        "product",
+                        first_argument.length()?,
+                        product.length()?,
+                    )?;
+                    check_length("mask", product.length()?, mask.length()?)?;
+                }
+
                 let context = product.context();
 
                 context.call(|| unsafe {
@@ -275,7 +812,7 @@ macro_rules! implement_binary_operator {
                         self.binary_operator,
                         first_argument.graphblas_vector(),
                         *second_argument,
-                        self.options,
+                        mask.graphblas_descriptor(&self.options),
                     )
                 })?;
 
@@ -292,6 +829,15 @@ macro_rules! implement_binary_operator {
                 product: &mut SparseVector<$product_type>,
                 mask: &VectorMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    check_length(
+                        "product",
+                        second_argument.length()?,
+                        product.length()?,
+                    )?;
+                    check_length("mask", product.length()?, mask.length()?)?;
+                }
+
                 let context = product.context();
 
                 context.call(|| unsafe {
@@ -302,7 +848,7 @@ macro_rules! implement_binary_operator {
                         self.binary_operator,
                         *first_argument,
                         second_argument.graphblas_vector(),
-                        self.options,
+                        mask.graphblas_descriptor(&self.options),
                     )
                 })?;
 
@@ -315,6 +861,14 @@ macro_rules! implement_binary_operator {
                 second_argument: &$second_argument_type,
                 product: &mut SparseMatrix<$product_type>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    check_size(
+                        "product",
+                        &size_of_first_argument(first_argument, &self.options)?,
+                        &product.size()?,
+                    )?;
+                }
+
                 let context = product.context();
 
                 context.call(|| unsafe {
@@ -325,7 +879,7 @@ macro_rules! implement_binary_operator {
                         self.binary_operator,
                         first_argument.graphblas_matrix(),
                         *second_argument,
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
 
@@ -338,6 +892,17 @@ macro_rules! implement_binary_operator {
                 second_argument: &SparseMatrix<$second_argument_type>,
                 product: &mut SparseMatrix<$product_type>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    check_size(
+                        "product",
+                        &size_of_second_argument(
+                            second_argument,
+                            &self.options,
+                        )?,
+                        &product.size()?,
+                    )?;
+                }
+
                 let context = product.context();
 
                 context.call(|| unsafe {
@@ -348,7 +913,7 @@ macro_rules! implement_binary_operator {
                         self.binary_operator,
                         *first_argument,
                         second_argument.graphblas_matrix(),
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
 
@@ -365,6 +930,15 @@ macro_rules! implement_binary_operator {
                 product: &mut SparseMatrix<$product_type>,
                 mask: &MatrixMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    check_size(
+                        "product",
+                        &size_of_first_argument(first_argument, &self.options)?,
+                        &product.size()?,
+                    )?;
+                    check_size("mask", &product.size()?, &mask.size()?)?;
+                }
+
                 let context = product.context();
 
                 context.call(|| unsafe {
@@ -375,7 +949,7 @@ macro_rules! implement_binary_operator {
                         self.binary_operator,
                         first_argument.graphblas_matrix(),
                         *second_argument,
-                        self.options,
+                        mask.graphblas_descriptor(&self.options),
                     )
                 })?;
 
@@ -392,6 +966,18 @@ macro_rules! implement_binary_operator {
                 product: &mut SparseMatrix<$product_type>,
                 mask: &MatrixMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    check_size(
+                        "product",
+                        &size_of_second_argument(
+                            second_argument,
+                            &self.options,
+                        )?,
+                        &product.size()?,
+                    )?;
+                    check_size("mask", &product.size()?, &mask.size()?)?;
+                }
+
                 let context = product.context();
 
                 context.call(|| unsafe {
@@ -402,7 +988,7 @@ macro_rules! implement_binary_operator {
                         self.binary_operator,
                         *first_argument,
                         second_argument.graphblas_matrix(),
-                        self.options,
+                        mask.graphblas_descriptor(&self.options),
                     )
                 })?;
 
@@ -517,10 +1103,11 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator, Plus, Times};
     use crate::value_types::sparse_matrix::{
         FromMatrixElementList, GetMatrixElementValue, MatrixElementList, Size,
     };
+    use crate::value_types::sparse_scalar::SetScalarValue;
     use crate::value_types::sparse_vector::{
         FromVectorElementList, GetVectorElementValue, VectorElementList,
     };
@@ -551,7 +1138,7 @@ mod tests {
         let operator = BinaryOperatorApplier::new(
             &First::<u8, u8, u8>::new(),
             &OperatorOptions::new_default(),
-            None,
+            &NoAccumulator,
         );
 
         operator
@@ -577,7 +1164,7 @@ mod tests {
         let operator = BinaryOperatorApplier::new(
             &First::<u8, u8, u8>::new(),
             &OperatorOptions::new_default(),
-            None,
+            &NoAccumulator,
         );
         operator
             .apply_with_matrix_as_second_argument(
@@ -627,7 +1214,7 @@ mod tests {
         let operator = BinaryOperatorApplier::new(
             &First::<u8, u8, u8>::new(),
             &OperatorOptions::new_default(),
-            None,
+            &NoAccumulator,
         );
 
         operator
@@ -647,7 +1234,7 @@ mod tests {
         let operator = BinaryOperatorApplier::new(
             &First::<u8, u8, u8>::new(),
             &OperatorOptions::new_default(),
-            None,
+            &NoAccumulator,
         );
         operator
             .apply_with_vector_as_second_argument(
@@ -664,4 +1251,74 @@ mod tests {
         assert_eq!(product_vector.get_element_value(&2).unwrap(), 10);
         assert_eq!(product_vector.get_element_value(&9).unwrap(), 0);
     }
+
+    #[test]
+    fn test_sparse_scalar_binary_operator_application() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let element_list = VectorElementList::<u8>::from_element_vector(vec![
+            (1, 1).into(),
+            (2, 2).into(),
+            (4, 4).into(),
+        ]);
+
+        let vector_length: usize = 10;
+        let vector = SparseVector::<u8>::from_element_list(
+            &context.clone(),
+            &vector_length,
+            &element_list,
+            &First::<u8, u8, u8>::new(),
+        )
+        .unwrap();
+
+        let mut product_vector =
+            SparseVector::<u8>::new(&context, &vector_length).unwrap();
+        let mut scalar = SparseScalar::<u8>::new(&context).unwrap();
+        scalar.set_value(&3).unwrap();
+
+        let operator = BinaryOperatorApplier::new(
+            &Times::<u8, u8, u8>::new(),
+            &OperatorOptions::new_default(),
+            &NoAccumulator,
+        );
+        operator
+            .apply_sparse_scalar_with_vector_as_second_argument(
+                &scalar,
+                &vector,
+                &mut product_vector,
+            )
+            .unwrap();
+
+        assert_eq!(product_vector.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product_vector.get_element_value(&2).unwrap(), 6);
+        assert_eq!(product_vector.get_element_value(&4).unwrap(), 12);
+
+        // An empty scalar computes no elements, the accumulator keeps the product
+        let empty_scalar = SparseScalar::<u8>::new(&context).unwrap();
+        let accumulating_operator = BinaryOperatorApplier::new(
+            &Times::<u8, u8, u8>::new(),
+            &OperatorOptions::new_default(),
+            &Plus::<u8, u8, u8>::new(),
+        );
+        accumulating_operator
+            .apply_sparse_scalar_with_vector_as_first_argument(
+                &vector,
+                &empty_scalar,
+                &mut product_vector,
+            )
+            .unwrap();
+
+        assert_eq!(product_vector.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product_vector.get_element_value(&2).unwrap(), 6);
+
+        operator
+            .apply_sparse_scalar_with_vector_as_first_argument(
+                &vector,
+                &empty_scalar,
+                &mut product_vector,
+            )
+            .unwrap();
+
+        assert_eq!(product_vector.number_of_stored_elements().unwrap(), 0);
+    }
 }
@@ -2,18 +2,19 @@ use std::marker::PhantomData;
 use std::ptr;
 
 use crate::error::SparseLinearAlgebraError;
+use crate::operators::validation::{check_length, check_size};
 use crate::operators::{
-    binary_operator::BinaryOperator,
+    binary_operator::Accumulator,
     mask::{MatrixMask, VectorMask},
-    options::OperatorOptions,
+    options::{size_of_first_argument, OperatorOptions},
     unary_operator::UnaryOperator,
 };
 use crate::value_types::sparse_matrix::SparseMatrix;
 use crate::value_types::sparse_vector::SparseVector;
-use crate::value_types::value_type::{AsBoolean, ValueType};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
 
 use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GrB_Matrix_apply, GrB_UnaryOp, GrB_Vector_apply,
+    GrB_BinaryOp, GrB_Matrix_apply, GrB_UnaryOp, GrB_Vector_apply,
 };
 
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
@@ -49,25 +50,21 @@ pub struct UnaryOperatorApplier<T: ValueType> {
 
     unary_operator: GrB_UnaryOp,
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<T: ValueType> UnaryOperatorApplier<T> {
     pub fn new(
-        unary_operator: &dyn UnaryOperator<T>,
+        unary_operator: &(impl UnaryOperator<T> + ?Sized),
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<T, T, T>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<T, T, T> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             unary_operator: unary_operator.graphblas_type(),
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _result: PhantomData,
         }
@@ -106,6 +103,59 @@ where
     ) -> Result<(), SparseLinearAlgebraError>;
 }
 
+impl<T: ValueType + BuiltInValueType<T>> UnaryOperatorApplier<T>
+where
+    Self: UnaryOperatorApplierTrait<T, T>,
+{
+    /// Like apply_to_vector(), but allocates the product, sized by the argument
+    pub fn compute_vector(
+        &self,
+        argument: &SparseVector<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::new(argument.context_ref(), &argument.length()?)?;
+        self.apply_to_vector(argument, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like apply_to_vector_with_mask(), but allocates the product, sized by the argument
+    pub fn compute_vector_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        argument: &SparseVector<T>,
+        mask: &VectorMask<MaskValueType, AsBool>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::new(argument.context_ref(), &argument.length()?)?;
+        self.apply_to_vector_with_mask(argument, &mut product, mask)?;
+        Ok(product)
+    }
+
+    /// Like apply_to_matrix(), but allocates the product, sized by the argument and options
+    pub fn compute_matrix(
+        &self,
+        argument: &SparseMatrix<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::new(
+            argument.context_ref(),
+            &size_of_first_argument(argument, &self.options)?,
+        )?;
+        self.apply_to_matrix(argument, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like apply_to_matrix_with_mask(), but allocates the product, sized by the argument and options
+    pub fn compute_matrix_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        argument: &SparseMatrix<T>,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::new(
+            argument.context_ref(),
+            &size_of_first_argument(argument, &self.options)?,
+        )?;
+        self.apply_to_matrix_with_mask(argument, &mut product, mask)?;
+        Ok(product)
+    }
+}
+
 macro_rules! implement_unary_operator {
     ($argument_type:ty, $product_type:ty) => {
         impl UnaryOperatorApplierTrait<$argument_type, $product_type>
@@ -116,6 +166,10 @@ macro_rules! implement_unary_operator {
                 argument: &SparseVector<$argument_type>,
                 product: &mut SparseVector<$product_type>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    check_length("product", argument.length()?, product.length()?)?;
+                }
+
                 let context = argument.context();
 
                 context.call(|| unsafe {
@@ -125,7 +179,7 @@ macro_rules! implement_unary_operator {
                         self.accumulator,
                         self.unary_operator,
                         argument.graphblas_vector(),
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
 
@@ -141,6 +195,11 @@ macro_rules! implement_unary_operator {
                 product: &mut SparseVector<$product_type>,
                 mask: &VectorMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    check_length("product", argument.length()?, product.length()?)?;
+                    check_length("mask", product.length()?, mask.length()?)?;
+                }
+
                 let context = argument.context();
 
                 context.call(|| unsafe {
@@ -150,7 +209,7 @@ macro_rules! implement_unary_operator {
                         self.accumulator,
                         self.unary_operator,
                         argument.graphblas_vector(),
-                        self.options,
+                        mask.graphblas_descriptor(&self.options),
                     )
                 })?;
 
@@ -162,6 +221,14 @@ macro_rules! implement_unary_operator {
                 argument: &SparseMatrix<$argument_type>,
                 product: &mut SparseMatrix<$product_type>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    check_size(
+                        "product",
+                        &size_of_first_argument(argument, &self.options)?,
+                        &product.size()?,
+                    )?;
+                }
+
                 let context = argument.context();
 
                 context.call(|| unsafe {
@@ -171,7 +238,7 @@ macro_rules! implement_unary_operator {
                         self.accumulator,
                         self.unary_operator,
                         argument.graphblas_matrix(),
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
 
@@ -187,6 +254,15 @@ macro_rules! implement_unary_operator {
                 product: &mut SparseMatrix<$product_type>,
                 mask: &MatrixMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    check_size(
+                        "product",
+                        &size_of_first_argument(argument, &self.options)?,
+                        &product.size()?,
+                    )?;
+                    check_size("mask", &product.size()?, &mask.size()?)?;
+                }
+
                 let context = argument.context();
 
                 context.call(|| unsafe {
@@ -196,7 +272,7 @@ macro_rules! implement_unary_operator {
                         self.accumulator,
                         self.unary_operator,
                         argument.graphblas_matrix(),
-                        self.options,
+                        mask.graphblas_descriptor(&self.options),
                     )
                 })?;
 
@@ -215,13 +291,15 @@ implement_unary_operator!(i8, i8);
 implement_unary_operator!(i16, i16);
 implement_unary_operator!(i32, i32);
 implement_unary_operator!(i64, i64);
+implement_unary_operator!(f32, f32);
+implement_unary_operator!(f64, f64);
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator};
     use crate::operators::unary_operator::{Identity, One};
     use crate::value_types::sparse_matrix::{
         FromMatrixElementList, GetMatrixElementValue, MatrixElementList, Size,
@@ -252,8 +330,11 @@ mod tests {
 
         let mut product_matrix = SparseMatrix::<u8>::new(&context, &matrix_size).unwrap();
 
-        let operator =
-            UnaryOperatorApplier::new(&One::<u8>::new(), &OperatorOptions::new_default(), None);
+        let operator = UnaryOperatorApplier::new(
+            &One::<u8>::new(),
+            &OperatorOptions::new_default(),
+            &NoAccumulator,
+        );
 
         operator
             .apply_to_matrix(&matrix, &mut product_matrix)
@@ -268,7 +349,7 @@ mod tests {
         let operator = UnaryOperatorApplier::new(
             &Identity::<u8>::new(),
             &OperatorOptions::new_default(),
-            None,
+            &NoAccumulator,
         );
         operator
             .apply_to_matrix(&matrix, &mut product_matrix)
@@ -304,8 +385,11 @@ mod tests {
 
         let mut product_vector = SparseVector::<u8>::new(&context, &vector_length).unwrap();
 
-        let operator =
-            UnaryOperatorApplier::new(&One::<u8>::new(), &OperatorOptions::new_default(), None);
+        let operator = UnaryOperatorApplier::new(
+            &One::<u8>::new(),
+            &OperatorOptions::new_default(),
+            &NoAccumulator,
+        );
 
         operator
             .apply_to_vector(&vector, &mut product_vector)
@@ -320,7 +404,7 @@ mod tests {
         let operator = UnaryOperatorApplier::new(
             &Identity::<u8>::new(),
             &OperatorOptions::new_default(),
-            None,
+            &NoAccumulator,
         );
         operator
             .apply_to_vector(&vector, &mut product_vector)
@@ -1,4 +1,5 @@
 use std::marker::PhantomData;
+use std::ptr;
 
 use crate::bindings_to_graphblas_implementation::*;
 use crate::value_types::value_type::ValueType;
@@ -12,6 +13,55 @@ where
     fn graphblas_type(&self) -> GrB_BinaryOp;
 }
 
+/// Accumulates the result T of an operation into its output C: Z = accumulator(C, T).
+/// Implemented by the binary operators, and by NoAccumulator to overwrite the output instead.
+pub trait Accumulator<X, Y, Z>
+where
+    X: ValueType,
+    Y: ValueType,
+    Z: ValueType,
+{
+    fn accumulator_graphblas_type(&self) -> GrB_BinaryOp;
+}
+
+/// Writes the result of an operation into its output without accumulating
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoAccumulator;
+
+impl<X: ValueType, Y: ValueType, Z: ValueType> Accumulator<X, Y, Z> for NoAccumulator {
+    fn accumulator_graphblas_type(&self) -> GrB_BinaryOp {
+        ptr::null_mut()
+    }
+}
+
+impl<X, Y, Z, A> Accumulator<X, Y, Z> for &A
+where
+    X: ValueType,
+    Y: ValueType,
+    Z: ValueType,
+    A: Accumulator<X, Y, Z> + ?Sized,
+{
+    fn accumulator_graphblas_type(&self) -> GrB_BinaryOp {
+        (**self).accumulator_graphblas_type()
+    }
+}
+
+macro_rules! implement_accumulator {
+    ($operator_name:ident) => {
+        impl<X, Y, Z> Accumulator<X, Y, Z> for $operator_name<X, Y, Z>
+        where
+            X: ValueType,
+            Y: ValueType,
+            Z: ValueType,
+            $operator_name<X, Y, Z>: BinaryOperator<X, Y, Z>,
+        {
+            fn accumulator_graphblas_type(&self) -> GrB_BinaryOp {
+                self.graphblas_type()
+            }
+        }
+    };
+}
+
 macro_rules! implement_binary_operator {
     ($operator_name:ident,
      $graphblas_operator_name:ident,
@@ -43,7 +93,7 @@ macro_rules! implement_binary_operator {
                 $value_type_output,
             >
         {
-            pub fn new() -> Self {
+            pub const fn new() -> Self {
                 Self {
                     _value_type_left_input: PhantomData,
                     _value_type_right_input: PhantomData,
@@ -55,7 +105,7 @@ macro_rules! implement_binary_operator {
 }
 
 // x = first(x,y)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct First<X, Y, Z>
 where
     X: ValueType,
@@ -67,6 +117,8 @@ where
     _value_type_output: PhantomData<Z>,
 }
 
+implement_accumulator!(First);
+
 implement_binary_operator!(First, GrB_FIRST_BOOL, bool, bool, bool);
 implement_binary_operator!(First, GrB_FIRST_INT8, i8, i8, i8);
 implement_binary_operator!(First, GrB_FIRST_INT16, i16, i16, i16);
@@ -80,7 +132,7 @@ implement_binary_operator!(First, GrB_FIRST_FP32, f32, f32, f32);
 implement_binary_operator!(First, GrB_FIRST_FP64, f64, f64, f64);
 
 // y = second(x,y)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct Second<X, Y, Z>
 where
     X: ValueType,
@@ -92,6 +144,8 @@ where
     _value_type_output: PhantomData<Z>,
 }
 
+implement_accumulator!(Second);
+
 implement_binary_operator!(Second, GrB_SECOND_BOOL, bool, i32, i32);
 implement_binary_operator!(Second, GrB_SECOND_BOOL, bool, bool, bool);
 implement_binary_operator!(Second, GrB_SECOND_INT8, i8, i8, i8);
@@ -106,7 +160,7 @@ implement_binary_operator!(Second, GrB_SECOND_FP32, f32, f32, f32);
 implement_binary_operator!(Second, GrB_SECOND_FP64, f64, f64, f64);
 
 // z = x^y (z = x.pow(y))
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct Power<X, Y, Z>
 where
     X: ValueType,
@@ -118,6 +172,8 @@ where
     _value_type_output: PhantomData<Z>,
 }
 
+implement_accumulator!(Power);
+
 implement_binary_operator!(Power, GxB_POW_BOOL, bool, bool, bool);
 implement_binary_operator!(Power, GxB_POW_INT8, i8, i8, i8);
 implement_binary_operator!(Power, GxB_POW_INT16, i16, i16, i16);
@@ -131,7 +187,7 @@ implement_binary_operator!(Power, GxB_POW_FP32, f32, f32, f32);
 implement_binary_operator!(Power, GxB_POW_FP64, f64, f64, f64);
 
 // z = x+y
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct Plus<X, Y, Z>
 where
     X: ValueType,
@@ -143,6 +199,8 @@ where
     _value_type_output: PhantomData<Z>,
 }
 
+implement_accumulator!(Plus);
+
 implement_binary_operator!(Plus, GrB_PLUS_BOOL, bool, bool, bool);
 implement_binary_operator!(Plus, GrB_PLUS_INT8, i8, i8, i8);
 implement_binary_operator!(Plus, GrB_PLUS_INT16, i16, i16, i16);
@@ -156,7 +214,7 @@ implement_binary_operator!(Plus, GrB_PLUS_FP32, f32, f32, f32);
 implement_binary_operator!(Plus, GrB_PLUS_FP64, f64, f64, f64);
 
 // z = x-y
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct Minus<X, Y, Z>
 where
     X: ValueType,
@@ -168,6 +226,8 @@ where
     _value_type_output: PhantomData<Z>,
 }
 
+implement_accumulator!(Minus);
+
 implement_binary_operator!(Minus, GrB_MINUS_BOOL, bool, bool, bool);
 implement_binary_operator!(Minus, GrB_MINUS_INT8, i8, i8, i8);
 implement_binary_operator!(Minus, GrB_MINUS_INT16, i16, i16, i16);
@@ -181,7 +241,7 @@ implement_binary_operator!(Minus, GrB_MINUS_FP32, f32, f32, f32);
 implement_binary_operator!(Minus, GrB_MINUS_FP64, f64, f64, f64);
 
 // z = x*y
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct Times<X, Y, Z>
 where
     X: ValueType,
@@ -193,6 +253,8 @@ where
     _value_type_output: PhantomData<Z>,
 }
 
+implement_accumulator!(Times);
+
 implement_binary_operator!(Times, GrB_TIMES_BOOL, bool, bool, bool);
 implement_binary_operator!(Times, GrB_TIMES_INT8, i8, i8, i8);
 implement_binary_operator!(Times, GrB_TIMES_INT16, i16, i16, i16);
@@ -206,7 +268,7 @@ implement_binary_operator!(Times, GrB_TIMES_FP32, f32, f32, f32);
 implement_binary_operator!(Times, GrB_TIMES_FP64, f64, f64, f64);
 
 // z = x*y
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct Divide<X, Y, Z>
 where
     X: ValueType,
@@ -218,6 +280,8 @@ where
     _value_type_output: PhantomData<Z>,
 }
 
+implement_accumulator!(Divide);
+
 implement_binary_operator!(Divide, GrB_DIV_BOOL, bool, bool, bool);
 implement_binary_operator!(Divide, GrB_DIV_INT8, i8, i8, i8);
 implement_binary_operator!(Divide, GrB_DIV_INT16, i16, i16, i16);
@@ -230,6 +294,114 @@ implement_binary_operator!(Divide, GrB_DIV_UINT64, u64, u64, u64);
 implement_binary_operator!(Divide, GrB_DIV_FP32, f32, f32, f32);
 implement_binary_operator!(Divide, GrB_DIV_FP64, f64, f64, f64);
 
+// z = min(x,y)
+#[derive(Debug, Clone, Copy)]
+pub struct Min<X, Y, Z>
+where
+    X: ValueType,
+    Y: ValueType,
+    Z: ValueType,
+{
+    _value_type_left_input: PhantomData<X>,
+    _value_type_right_input: PhantomData<Y>,
+    _value_type_output: PhantomData<Z>,
+}
+
+implement_accumulator!(Min);
+
+implement_binary_operator!(Min, GrB_MIN_BOOL, bool, bool, bool);
+implement_binary_operator!(Min, GrB_MIN_INT8, i8, i8, i8);
+implement_binary_operator!(Min, GrB_MIN_INT16, i16, i16, i16);
+implement_binary_operator!(Min, GrB_MIN_INT32, i32, i32, i32);
+implement_binary_operator!(Min, GrB_MIN_INT64, i64, i64, i64);
+implement_binary_operator!(Min, GrB_MIN_UINT8, u8, u8, u8);
+implement_binary_operator!(Min, GrB_MIN_UINT16, u16, u16, u16);
+implement_binary_operator!(Min, GrB_MIN_UINT32, u32, u32, u32);
+implement_binary_operator!(Min, GrB_MIN_UINT64, u64, u64, u64);
+implement_binary_operator!(Min, GrB_MIN_FP32, f32, f32, f32);
+implement_binary_operator!(Min, GrB_MIN_FP64, f64, f64, f64);
+
+// z = (x < y)
+#[derive(Debug, Clone, Copy)]
+pub struct LessThan<X, Y, Z>
+where
+    X: ValueType,
+    Y: ValueType,
+    Z: ValueType,
+{
+    _value_type_left_input: PhantomData<X>,
+    _value_type_right_input: PhantomData<Y>,
+    _value_type_output: PhantomData<Z>,
+}
+
+implement_accumulator!(LessThan);
+
+implement_binary_operator!(LessThan, GrB_LT_BOOL, bool, bool, bool);
+implement_binary_operator!(LessThan, GrB_LT_INT8, i8, i8, bool);
+implement_binary_operator!(LessThan, GrB_LT_INT16, i16, i16, bool);
+implement_binary_operator!(LessThan, GrB_LT_INT32, i32, i32, bool);
+implement_binary_operator!(LessThan, GrB_LT_INT64, i64, i64, bool);
+implement_binary_operator!(LessThan, GrB_LT_UINT8, u8, u8, bool);
+implement_binary_operator!(LessThan, GrB_LT_UINT16, u16, u16, bool);
+implement_binary_operator!(LessThan, GrB_LT_UINT32, u32, u32, bool);
+implement_binary_operator!(LessThan, GrB_LT_UINT64, u64, u64, bool);
+implement_binary_operator!(LessThan, GrB_LT_FP32, f32, f32, bool);
+implement_binary_operator!(LessThan, GrB_LT_FP64, f64, f64, bool);
+
+// z = (x == y)
+#[derive(Debug, Clone, Copy)]
+pub struct Equal<X, Y, Z>
+where
+    X: ValueType,
+    Y: ValueType,
+    Z: ValueType,
+{
+    _value_type_left_input: PhantomData<X>,
+    _value_type_right_input: PhantomData<Y>,
+    _value_type_output: PhantomData<Z>,
+}
+
+implement_accumulator!(Equal);
+
+implement_binary_operator!(Equal, GrB_EQ_BOOL, bool, bool, bool);
+implement_binary_operator!(Equal, GrB_EQ_INT8, i8, i8, bool);
+implement_binary_operator!(Equal, GrB_EQ_INT16, i16, i16, bool);
+implement_binary_operator!(Equal, GrB_EQ_INT32, i32, i32, bool);
+implement_binary_operator!(Equal, GrB_EQ_INT64, i64, i64, bool);
+implement_binary_operator!(Equal, GrB_EQ_UINT8, u8, u8, bool);
+implement_binary_operator!(Equal, GrB_EQ_UINT16, u16, u16, bool);
+implement_binary_operator!(Equal, GrB_EQ_UINT32, u32, u32, bool);
+implement_binary_operator!(Equal, GrB_EQ_UINT64, u64, u64, bool);
+implement_binary_operator!(Equal, GrB_EQ_FP32, f32, f32, bool);
+implement_binary_operator!(Equal, GrB_EQ_FP64, f64, f64, bool);
+
+// z = (x != y)
+#[derive(Debug, Clone, Copy)]
+pub struct NotEqual<X, Y, Z>
+where
+    X: ValueType,
+    Y: ValueType,
+    Z: ValueType,
+{
+    _value_type_left_input: PhantomData<X>,
+    _value_type_right_input: PhantomData<Y>,
+    _value_type_output: PhantomData<Z>,
+}
+
+implement_accumulator!(NotEqual);
+
+implement_binary_operator!(NotEqual, GrB_NE_BOOL, bool, bool, bool);
+implement_binary_operator!(NotEqual, GrB_NE_INT8, i8, i8, bool);
+implement_binary_operator!(NotEqual, GrB_NE_INT16, i16, i16, bool);
+implement_binary_operator!(NotEqual, GrB_NE_INT32, i32, i32, bool);
+implement_binary_operator!(NotEqual, GrB_NE_INT64, i64, i64, bool);
+implement_binary_operator!(NotEqual, GrB_NE_UINT8, u8, u8, bool);
+implement_binary_operator!(NotEqual, GrB_NE_UINT16, u16, u16, bool);
+implement_binary_operator!(NotEqual, GrB_NE_UINT32, u32, u32, bool);
+implement_binary_operator!(NotEqual, GrB_NE_UINT64, u64, u64, bool);
+implement_binary_operator!(NotEqual, GrB_NE_FP32, f32, f32, bool);
+implement_binary_operator!(NotEqual, GrB_NE_FP64, f64, f64, bool);
+
 #[cfg(test)]
 mod tests {
     use super::*;
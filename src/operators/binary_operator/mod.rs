@@ -1,5 +1,7 @@
 mod binary_operator;
 
-pub use binary_operator::BinaryOperator;
+pub use binary_operator::{Accumulator, BinaryOperator, NoAccumulator};
 // pub use binary_operator::BinaryOperatorType;
-pub use binary_operator::{Divide, First, Minus, Plus, Second, Times};
+pub use binary_operator::{
+    Divide, Equal, First, LessThan, Min, Minus, NotEqual, Plus, Second, Times,
+};
@@ -2,15 +2,20 @@ use std::marker::PhantomData;
 use std::ptr;
 
 use crate::error::SparseLinearAlgebraError;
+use crate::operators::options::size_of_first_argument;
+use crate::operators::validation::{check_element_wise_matrix_arguments, check_size};
 use crate::operators::{
-    binary_operator::BinaryOperator, mask::MatrixMask, monoid::Monoid, options::OperatorOptions,
+    binary_operator::{Accumulator, BinaryOperator},
+    mask::MatrixMask,
+    monoid::Monoid,
+    options::OperatorOptions,
     semiring::Semiring,
 };
-use crate::value_types::sparse_matrix::SparseMatrix;
-use crate::value_types::value_type::{AsBoolean, ValueType};
+use crate::value_types::sparse_matrix::{Size, SparseMatrix};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
 
 use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GrB_Matrix_eWiseAdd_BinaryOp, GrB_Matrix_eWiseAdd_Monoid,
+    GrB_BinaryOp, GrB_Matrix_eWiseAdd_BinaryOp, GrB_Matrix_eWiseAdd_Monoid,
     GrB_Matrix_eWiseAdd_Semiring, GrB_Monoid, GrB_Semiring,
 };
 
@@ -54,7 +59,7 @@ where
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     multiplication_operator: GrB_Semiring, // defines element-wise multiplication operator Multiplier.*Multiplicant
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<Multiplier, Multiplicant, Product>
@@ -65,20 +70,16 @@ where
     Product: ValueType,
 {
     pub fn new(
-        multiplication_operator: &dyn Semiring<Multiplier, Multiplicant, Product>, // defines element-wise multiplication operator Multiplier.*Multiplicant
+        multiplication_operator: &(impl Semiring<Multiplier, Multiplicant, Product> + ?Sized), // defines element-wise multiplication operator Multiplier.*Multiplicant
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<Product, Product, Product>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<Product, Product, Product> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
             multiplication_operator: multiplication_operator.graphblas_type(),
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _multiplier: PhantomData,
             _multiplicant: PhantomData,
@@ -92,6 +93,10 @@ where
         multiplicant: &SparseMatrix<Multiplicant>,
         product: &mut SparseMatrix<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_element_wise_matrix_arguments(multiplier, multiplicant, product, &self.options)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -102,7 +107,7 @@ where
                 self.multiplication_operator,
                 multiplier.graphblas_matrix(),
                 multiplicant.graphblas_matrix(),
-                self.options,
+                self.options.to_graphblas_descriptor(),
             )
         })?;
 
@@ -116,6 +121,11 @@ where
         multiplicant: &SparseMatrix<Multiplicant>,
         product: &mut SparseMatrix<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_element_wise_matrix_arguments(multiplier, multiplicant, product, &self.options)?;
+            check_size("mask", &product.size()?, &mask.size()?)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -126,13 +136,58 @@ where
                 self.multiplication_operator,
                 multiplier.graphblas_matrix(),
                 multiplicant.graphblas_matrix(),
-                self.options,
+                mask.graphblas_descriptor(&self.options),
             )
         })?;
         Ok(())
     }
 }
 
+impl<Multiplier, Multiplicant, Product>
+    ElementWiseMatrixAdditionSemiring<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType + BuiltInValueType<Product>,
+{
+    /// Like apply(), but allocates the product, sized by the arguments and options
+    pub fn compute(
+        &self,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply(multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like apply_with_mask(), but allocates the product, sized by the arguments and options
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply_with_mask(mask, multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    fn product_size(
+        &self,
+        multiplier: &SparseMatrix<Multiplier>,
+        _multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<Size, SparseLinearAlgebraError> {
+        size_of_first_argument(multiplier, &self.options)
+    }
+}
+
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
 // Code review must consider that no mtable access is provided.
 // https://doc.rust-lang.org/nomicon/send-and-sync.html
@@ -166,25 +221,21 @@ pub struct ElementWiseMatrixAdditionMonoidOperator<T: ValueType> {
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     multiplication_operator: GrB_Monoid, // defines element-wise multiplication operator Multiplier.*Multiplicant
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<T: ValueType> ElementWiseMatrixAdditionMonoidOperator<T> {
     pub fn new(
-        multiplication_operator: &dyn Monoid<T>, // defines element-wise multiplication operator Multiplier.*Multiplicant
+        multiplication_operator: &(impl Monoid<T> + ?Sized), // defines element-wise multiplication operator Multiplier.*Multiplicant
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<T, T, T>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<T, T, T> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
             multiplication_operator: multiplication_operator.graphblas_type(),
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _value: PhantomData,
         }
@@ -196,6 +247,10 @@ impl<T: ValueType> ElementWiseMatrixAdditionMonoidOperator<T> {
         multiplicant: &SparseMatrix<T>,
         product: &mut SparseMatrix<T>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_element_wise_matrix_arguments(multiplier, multiplicant, product, &self.options)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -206,7 +261,7 @@ impl<T: ValueType> ElementWiseMatrixAdditionMonoidOperator<T> {
                 self.multiplication_operator,
                 multiplier.graphblas_matrix(),
                 multiplicant.graphblas_matrix(),
-                self.options,
+                self.options.to_graphblas_descriptor(),
             )
         })?;
 
@@ -220,6 +275,11 @@ impl<T: ValueType> ElementWiseMatrixAdditionMonoidOperator<T> {
         multiplicant: &SparseMatrix<T>,
         product: &mut SparseMatrix<T>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_element_wise_matrix_arguments(multiplier, multiplicant, product, &self.options)?;
+            check_size("mask", &product.size()?, &mask.size()?)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -230,7 +290,7 @@ impl<T: ValueType> ElementWiseMatrixAdditionMonoidOperator<T> {
                 self.multiplication_operator,
                 multiplier.graphblas_matrix(),
                 multiplicant.graphblas_matrix(),
-                self.options,
+                mask.graphblas_descriptor(&self.options),
             )
         })?;
 
@@ -238,6 +298,45 @@ impl<T: ValueType> ElementWiseMatrixAdditionMonoidOperator<T> {
     }
 }
 
+impl<T: ValueType + BuiltInValueType<T>> ElementWiseMatrixAdditionMonoidOperator<T> {
+    /// Like apply(), but allocates the product, sized by the arguments and options
+    pub fn compute(
+        &self,
+        multiplier: &SparseMatrix<T>,
+        multiplicant: &SparseMatrix<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<T>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply(multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like apply_with_mask(), but allocates the product, sized by the arguments and options
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+        multiplier: &SparseMatrix<T>,
+        multiplicant: &SparseMatrix<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<T>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply_with_mask(mask, multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    fn product_size(
+        &self,
+        multiplier: &SparseMatrix<T>,
+        _multiplicant: &SparseMatrix<T>,
+    ) -> Result<Size, SparseLinearAlgebraError> {
+        size_of_first_argument(multiplier, &self.options)
+    }
+}
+
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
 // Code review must consider that no mtable access is provided.
 // https://doc.rust-lang.org/nomicon/send-and-sync.html
@@ -273,7 +372,7 @@ pub struct ElementWiseMatrixAdditionBinaryOperator<Multiplier, Multiplicant, Pro
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     multiplication_operator: GrB_BinaryOp, // defines element-wise multiplication operator Multiplier.*Multiplicant
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<Multiplier, Multiplicant, Product>
@@ -284,20 +383,16 @@ where
     Product: ValueType,
 {
     pub fn new(
-        multiplication_operator: &dyn BinaryOperator<Multiplier, Multiplicant, Product>, // defines element-wise multiplication operator Multiplier.*Multiplicant
+        multiplication_operator: &(impl BinaryOperator<Multiplier, Multiplicant, Product> + ?Sized), // defines element-wise multiplication operator Multiplier.*Multiplicant
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<Product, Product, Product>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<Product, Product, Product> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
             multiplication_operator: multiplication_operator.graphblas_type(),
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _multiplier: PhantomData,
             _multiplicant: PhantomData,
@@ -311,6 +406,10 @@ where
         multiplicant: &SparseMatrix<Multiplicant>,
         product: &mut SparseMatrix<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_element_wise_matrix_arguments(multiplier, multiplicant, product, &self.options)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -321,7 +420,7 @@ where
                 self.multiplication_operator,
                 multiplier.graphblas_matrix(),
                 multiplicant.graphblas_matrix(),
-                self.options,
+                self.options.to_graphblas_descriptor(),
             )
         })?;
 
@@ -335,6 +434,11 @@ where
         multiplicant: &SparseMatrix<Multiplicant>,
         product: &mut SparseMatrix<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_element_wise_matrix_arguments(multiplier, multiplicant, product, &self.options)?;
+            check_size("mask", &product.size()?, &mask.size()?)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -345,7 +449,7 @@ where
                 self.multiplication_operator,
                 multiplier.graphblas_matrix(),
                 multiplicant.graphblas_matrix(),
-                self.options,
+                mask.graphblas_descriptor(&self.options),
             )
         })?;
 
@@ -353,12 +457,57 @@ where
     }
 }
 
+impl<Multiplier, Multiplicant, Product>
+    ElementWiseMatrixAdditionBinaryOperator<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType + BuiltInValueType<Product>,
+{
+    /// Like apply(), but allocates the product, sized by the arguments and options
+    pub fn compute(
+        &self,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply(multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like apply_with_mask(), but allocates the product, sized by the arguments and options
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply_with_mask(mask, multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    fn product_size(
+        &self,
+        multiplier: &SparseMatrix<Multiplier>,
+        _multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<Size, SparseLinearAlgebraError> {
+        size_of_first_argument(multiplier, &self.options)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::{First, Plus, Times};
+    use crate::operators::binary_operator::{First, NoAccumulator, Plus, Times};
     use crate::value_types::sparse_matrix::{
         FromMatrixElementList, GetMatrixElementList, GetMatrixElementValue, MatrixElementList, Size,
     };
@@ -367,17 +516,18 @@ mod tests {
     fn create_matrix_adder() {
         let operator = Times::<i64, i64, i64>::new();
         let options = OperatorOptions::new_default();
-        let _element_wise_matrix_multiplier =
-            ElementWiseMatrixAdditionBinaryOperator::<i64, i64, i64>::new(
-                &operator, &options, None,
-            );
+        let _element_wise_matrix_multiplier = ElementWiseMatrixAdditionBinaryOperator::<
+            i64,
+            i64,
+            i64,
+        >::new(&operator, &options, &NoAccumulator);
 
         let accumulator = Times::<i64, i64, i64>::new();
 
         let _matrix_multiplier = ElementWiseMatrixAdditionBinaryOperator::<i64, i64, i64>::new(
             &operator,
             &options,
-            Some(&accumulator),
+            &accumulator,
         );
     }
 
@@ -389,7 +539,9 @@ mod tests {
         let options = OperatorOptions::new_default();
         let element_wise_matrix_multiplier =
             ElementWiseMatrixAdditionBinaryOperator::<i32, i32, i32>::new(
-                &operator, &options, None,
+                &operator,
+                &options,
+                &NoAccumulator,
             );
 
         let height = 2;
@@ -450,12 +602,11 @@ mod tests {
 
         // test the use of an accumulator
         let accumulator = Plus::<i32, i32, i32>::new();
-        let matrix_multiplier_with_accumulator =
-            ElementWiseMatrixAdditionBinaryOperator::<i32, i32, i32>::new(
-                &operator,
-                &options,
-                Some(&accumulator),
-            );
+        let matrix_multiplier_with_accumulator = ElementWiseMatrixAdditionBinaryOperator::<
+            i32,
+            i32,
+            i32,
+        >::new(&operator, &options, &accumulator);
 
         matrix_multiplier_with_accumulator
             .apply(&multiplier, &multiplicant, &mut product)
@@ -481,13 +632,15 @@ mod tests {
         .unwrap();
 
         let matrix_multiplier = ElementWiseMatrixAdditionBinaryOperator::<i32, i32, i32>::new(
-            &operator, &options, None,
+            &operator,
+            &options,
+            &NoAccumulator,
         );
 
         let mut product = SparseMatrix::<i32>::new(&context, &size).unwrap();
 
         matrix_multiplier
-            .apply_with_mask(&mask.into(), &multiplier, &multiplicant, &mut product)
+            .apply_with_mask(&(&mask).into(), &multiplier, &multiplicant, &mut product)
             .unwrap();
 
         assert_eq!(product.get_element_value(&(0, 0).into()).unwrap(), 5);
@@ -504,7 +657,9 @@ mod tests {
         let options = OperatorOptions::new_default();
         let element_wise_matrix_adder =
             ElementWiseMatrixAdditionBinaryOperator::<i32, i32, i32>::new(
-                &operator, &options, None,
+                &operator,
+                &options,
+                &NoAccumulator,
             );
 
         let height = 2;
@@ -0,0 +1,359 @@
+use std::marker::PhantomData;
+use std::ptr;
+
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::{BinaryOperatorApplier, BinaryOperatorApplierTrait};
+use crate::operators::element_wise_multiplication::ElementWiseMatrixMultiplicationBinaryOperator;
+use crate::operators::options::size_of_first_argument;
+use crate::operators::transpose::MatrixTranspose;
+use crate::operators::validation::{check_element_wise_matrix_arguments, check_size};
+use crate::operators::{
+    binary_operator::{Accumulator, BinaryOperator, NoAccumulator},
+    mask::MatrixMask,
+    options::OperatorOptions,
+};
+use crate::util::IndexConversion;
+use crate::value_types::sparse_matrix::{Size, SparseMatrix};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
+
+use crate::bindings_to_graphblas_implementation::{
+    GrB_ALL, GrB_BinaryOp, GrB_Descriptor, GrB_Matrix, GrB_Matrix_assign,
+};
+
+// Implemented methods do not provide mutable access to GraphBLAS operators or options.
+// Code review must consider that no mtable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl Send for ElementWiseMatrixUnionBinaryOperator<bool, bool, bool> {}
+unsafe impl Send for ElementWiseMatrixUnionBinaryOperator<u8, u8, u8> {}
+unsafe impl Send for ElementWiseMatrixUnionBinaryOperator<u16, u16, u16> {}
+unsafe impl Send for ElementWiseMatrixUnionBinaryOperator<u32, u32, u32> {}
+unsafe impl Send for ElementWiseMatrixUnionBinaryOperator<u64, u64, u64> {}
+unsafe impl Send for ElementWiseMatrixUnionBinaryOperator<i8, i8, i8> {}
+unsafe impl Send for ElementWiseMatrixUnionBinaryOperator<i16, i16, i16> {}
+unsafe impl Send for ElementWiseMatrixUnionBinaryOperator<i32, i32, i32> {}
+unsafe impl Send for ElementWiseMatrixUnionBinaryOperator<i64, i64, i64> {}
+unsafe impl Send for ElementWiseMatrixUnionBinaryOperator<f32, f32, f32> {}
+unsafe impl Send for ElementWiseMatrixUnionBinaryOperator<f64, f64, f64> {}
+
+unsafe impl Sync for ElementWiseMatrixUnionBinaryOperator<bool, bool, bool> {}
+unsafe impl Sync for ElementWiseMatrixUnionBinaryOperator<u8, u8, u8> {}
+unsafe impl Sync for ElementWiseMatrixUnionBinaryOperator<u16, u16, u16> {}
+unsafe impl Sync for ElementWiseMatrixUnionBinaryOperator<u32, u32, u32> {}
+unsafe impl Sync for ElementWiseMatrixUnionBinaryOperator<u64, u64, u64> {}
+unsafe impl Sync for ElementWiseMatrixUnionBinaryOperator<i8, i8, i8> {}
+unsafe impl Sync for ElementWiseMatrixUnionBinaryOperator<i16, i16, i16> {}
+unsafe impl Sync for ElementWiseMatrixUnionBinaryOperator<i32, i32, i32> {}
+unsafe impl Sync for ElementWiseMatrixUnionBinaryOperator<i64, i64, i64> {}
+unsafe impl Sync for ElementWiseMatrixUnionBinaryOperator<f32, f32, f32> {}
+unsafe impl Sync for ElementWiseMatrixUnionBinaryOperator<f64, f64, f64> {}
+
+/// Element-wise union of two matrices, with eWiseUnion semantics.
+///
+/// Unlike element-wise addition, which copies an element stored in only one argument to the product,
+/// the binary operator is applied to every element of the union. The fill value of the argument
+/// that does not store the element replaces the missing element, such that Minus computes A - B.
+#[derive(Debug, Clone)]
+pub struct ElementWiseMatrixUnionBinaryOperator<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType,
+{
+    _product: PhantomData<Product>,
+
+    multiplier_fill_value: Multiplier,
+    multiplicant_fill_value: Multiplicant,
+    intersection_operator:
+        ElementWiseMatrixMultiplicationBinaryOperator<Multiplier, Multiplicant, Product>,
+    fill_value_operator: BinaryOperatorApplier<Multiplier, Multiplicant, Product>,
+
+    accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+    options: OperatorOptions,
+    product_options: OperatorOptions, // the options without transposed inputs, the union is oriented as the product
+    union_options: OperatorOptions, // the product options without clearing the output, for the intermediate results
+}
+
+impl<Multiplier, Multiplicant, Product>
+    ElementWiseMatrixUnionBinaryOperator<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType,
+{
+    pub fn new(
+        multiplication_operator: &(impl BinaryOperator<Multiplier, Multiplicant, Product> + ?Sized), // defines element-wise multiplication operator Multiplier.*Multiplicant
+        multiplier_fill_value: Multiplier, // replaces elements only stored in the multiplicant
+        multiplicant_fill_value: Multiplicant, // replaces elements only stored in the multiplier
+        options: &OperatorOptions,
+        accumulator: &(impl Accumulator<Product, Product, Product> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+    ) -> Self {
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
+
+        // The union is computed without mask, accumulator and clearing the output, these only apply
+        // to writing the product, but with the extended options and validation of these options
+        let union_options = options
+            .without_transposed_inputs()
+            .without_clearing_output();
+
+        Self {
+            multiplier_fill_value,
+            multiplicant_fill_value,
+            intersection_operator: ElementWiseMatrixMultiplicationBinaryOperator::new(
+                multiplication_operator,
+                &union_options,
+                &NoAccumulator,
+            ),
+            fill_value_operator: BinaryOperatorApplier::new(
+                multiplication_operator,
+                &union_options,
+                &NoAccumulator,
+            ),
+
+            accumulator: accumulator_to_use,
+            options: options.clone(),
+            product_options: options.without_transposed_inputs(),
+            union_options,
+
+            _product: PhantomData,
+        }
+    }
+}
+
+impl<Multiplier, Multiplicant, Product>
+    ElementWiseMatrixUnionBinaryOperator<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType + AsBoolean<Multiplier>,
+    Multiplicant: ValueType + AsBoolean<Multiplicant>,
+    Product: ValueType + BuiltInValueType<Product>,
+    BinaryOperatorApplier<Multiplier, Multiplicant, Product>:
+        BinaryOperatorApplierTrait<Multiplier, Multiplicant, Product>,
+{
+    pub fn apply(
+        &self,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+        product: &mut SparseMatrix<Product>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_element_wise_matrix_arguments(multiplier, multiplicant, product, &self.options)?;
+        }
+
+        let union = self.union(multiplier, multiplicant)?;
+        self.write_product(
+            &union,
+            product,
+            ptr::null_mut(),
+            self.product_options.to_graphblas_descriptor(),
+        )
+    }
+
+    pub fn apply_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+        product: &mut SparseMatrix<Product>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_element_wise_matrix_arguments(multiplier, multiplicant, product, &self.options)?;
+            check_size("mask", &product.size()?, &mask.size()?)?;
+        }
+
+        let union = self.union(multiplier, multiplicant)?;
+        self.write_product(
+            &union,
+            product,
+            mask.graphblas_matrix(),
+            mask.graphblas_descriptor(&self.product_options),
+        )
+    }
+
+    /// Like apply(), but allocates the product, sized by the arguments and options
+    pub fn compute(
+        &self,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply(multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like apply_with_mask(), but allocates the product, sized by the arguments and options
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply_with_mask(mask, multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    // SuiteSparse:GraphBLAS 4 has no eWiseUnion. The union is composed of the intersection,
+    // and the fill values applied to the elements stored in only one of the arguments.
+    // Each argument masks the other, so transposed arguments are transposed first.
+    fn union(
+        &self,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let transposed_multiplier = transpose_if(
+            multiplier,
+            self.options.transpose_input0(),
+            &self.union_options,
+        )?;
+        let multiplier = transposed_multiplier.as_ref().unwrap_or(multiplier);
+        let transposed_multiplicant = transpose_if(
+            multiplicant,
+            self.options.transpose_input1(),
+            &self.union_options,
+        )?;
+        let multiplicant = transposed_multiplicant.as_ref().unwrap_or(multiplicant);
+
+        let mut union = self
+            .intersection_operator
+            .compute(multiplier, multiplicant)?;
+        self.fill_value_operator
+            .apply_with_matrix_as_first_argument_and_mask(
+                multiplier,
+                &self.multiplicant_fill_value,
+                &mut union,
+                &MatrixMask::new(multiplicant).structure().complement(),
+            )?;
+        self.fill_value_operator
+            .apply_with_matrix_as_second_argument_and_mask(
+                &self.multiplier_fill_value,
+                multiplicant,
+                &mut union,
+                &MatrixMask::new(multiplier).structure().complement(),
+            )?;
+        Ok(union)
+    }
+
+    fn write_product(
+        &self,
+        union: &SparseMatrix<Product>,
+        product: &mut SparseMatrix<Product>,
+        mask: GrB_Matrix,
+        descriptor: GrB_Descriptor,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let size = product.size()?;
+        let row_height = size.row_height().to_graphblas_index()?;
+        let column_width = size.column_width().to_graphblas_index()?;
+        product.context_ref().call(|| unsafe {
+            GrB_Matrix_assign(
+                product.graphblas_matrix(),
+                mask,
+                self.accumulator,
+                union.graphblas_matrix(),
+                GrB_ALL,
+                row_height,
+                GrB_ALL,
+                column_width,
+                descriptor,
+            )
+        })?;
+        Ok(())
+    }
+
+    fn product_size(
+        &self,
+        multiplier: &SparseMatrix<Multiplier>,
+        _multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<Size, SparseLinearAlgebraError> {
+        size_of_first_argument(multiplier, &self.options)
+    }
+}
+
+fn transpose_if<T: ValueType + BuiltInValueType<T>>(
+    matrix: &SparseMatrix<T>,
+    transpose: bool,
+    options: &OperatorOptions,
+) -> Result<Option<SparseMatrix<T>>, SparseLinearAlgebraError> {
+    if transpose {
+        Ok(Some(
+            MatrixTranspose::<T, T>::new(options, &NoAccumulator).compute(matrix)?,
+        ))
+    } else {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::context::{Context, Mode};
+    use crate::operators::binary_operator::{First, Minus, NoAccumulator};
+    use crate::value_types::sparse_matrix::{
+        FromMatrixElementList, GetMatrixElementValue, MatrixElementList,
+    };
+
+    #[test]
+    fn test_element_wise_matrix_union() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let multiplier = SparseMatrix::<i32>::from_element_list(
+            &context,
+            &(2, 3).into(),
+            &MatrixElementList::<i32>::from_element_vector(vec![
+                (0, 0, 5).into(),
+                (0, 1, 6).into(),
+            ]),
+            &First::<i32, i32, i32>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseMatrix::<i32>::from_element_list(
+            &context,
+            &(2, 3).into(),
+            &MatrixElementList::<i32>::from_element_vector(vec![
+                (0, 1, 2).into(),
+                (1, 2, 3).into(),
+            ]),
+            &First::<i32, i32, i32>::new(),
+        )
+        .unwrap();
+
+        let operator = Minus::<i32, i32, i32>::new();
+        let options = OperatorOptions::new_default();
+        let union_operator =
+            ElementWiseMatrixUnionBinaryOperator::new(&operator, 0, 1, &options, &NoAccumulator);
+
+        let product = union_operator.compute(&multiplier, &multiplicant).unwrap();
+        assert_eq!(product.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product.get_element_value(&(0, 0).into()).unwrap(), 4);
+        assert_eq!(product.get_element_value(&(0, 1).into()).unwrap(), 4);
+        assert_eq!(product.get_element_value(&(1, 2).into()).unwrap(), -3);
+
+        // the transposed multiplicant stores (1, 0) and (2, 1)
+        let multiplicant = SparseMatrix::<i32>::from_element_list(
+            &context,
+            &(3, 2).into(),
+            &MatrixElementList::<i32>::from_element_vector(vec![
+                (1, 0, 2).into(),
+                (2, 1, 3).into(),
+            ]),
+            &First::<i32, i32, i32>::new(),
+        )
+        .unwrap();
+        let union_operator = ElementWiseMatrixUnionBinaryOperator::new(
+            &operator,
+            0,
+            1,
+            &OperatorOptions::new(false, false, true),
+            &NoAccumulator,
+        );
+        let product = union_operator.compute(&multiplier, &multiplicant).unwrap();
+        assert_eq!(product.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product.get_element_value(&(0, 1).into()).unwrap(), 4);
+        assert_eq!(product.get_element_value(&(1, 2).into()).unwrap(), -3);
+    }
+}
@@ -2,15 +2,20 @@ use std::marker::PhantomData;
 use std::ptr;
 
 use crate::error::SparseLinearAlgebraError;
+use crate::operators::validation::{check_element_wise_vector_arguments, check_length};
 use crate::operators::{
-    binary_operator::BinaryOperator, mask::VectorMask, monoid::Monoid, options::OperatorOptions,
+    binary_operator::{Accumulator, BinaryOperator},
+    mask::VectorMask,
+    monoid::Monoid,
+    options::OperatorOptions,
     semiring::Semiring,
 };
+use crate::util::ElementIndex;
 use crate::value_types::sparse_vector::SparseVector;
-use crate::value_types::value_type::{AsBoolean, ValueType};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
 
 use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GrB_Monoid, GrB_Semiring, GrB_Vector_eWiseAdd_BinaryOp,
+    GrB_BinaryOp, GrB_Monoid, GrB_Semiring, GrB_Vector_eWiseAdd_BinaryOp,
     GrB_Vector_eWiseAdd_Monoid, GrB_Vector_eWiseAdd_Semiring,
 };
 
@@ -54,7 +59,7 @@ where
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     multiplication_operator: GrB_Semiring, // defines element-wise multiplication operator Multiplier.*Multiplicant
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<Multiplier, Multiplicant, Product>
@@ -65,20 +70,16 @@ where
     Product: ValueType,
 {
     pub fn new(
-        multiplication_operator: &dyn Semiring<Multiplier, Multiplicant, Product>, // defines element-wise multiplication operator Multiplier.*Multiplicant
+        multiplication_operator: &(impl Semiring<Multiplier, Multiplicant, Product> + ?Sized), // defines element-wise multiplication operator Multiplier.*Multiplicant
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<Product, Product, Product>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<Product, Product, Product> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
             multiplication_operator: multiplication_operator.graphblas_type(),
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _multiplier: PhantomData,
             _multiplicant: PhantomData,
@@ -92,6 +93,10 @@ where
         multiplicant: &SparseVector<Multiplicant>,
         product: &mut SparseVector<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_element_wise_vector_arguments(multiplier, multiplicant, product)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -102,7 +107,7 @@ where
                 self.multiplication_operator,
                 multiplier.graphblas_vector(),
                 multiplicant.graphblas_vector(),
-                self.options,
+                self.options.to_graphblas_descriptor(),
             )
         })?;
 
@@ -116,6 +121,11 @@ where
         multiplicant: &SparseVector<Multiplicant>,
         product: &mut SparseVector<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_element_wise_vector_arguments(multiplier, multiplicant, product)?;
+            check_length("mask", product.length()?, mask.length()?)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -126,7 +136,7 @@ where
                 self.multiplication_operator,
                 multiplier.graphblas_vector(),
                 multiplicant.graphblas_vector(),
-                self.options,
+                mask.graphblas_descriptor(&self.options),
             )
         })?;
 
@@ -134,6 +144,51 @@ where
     }
 }
 
+impl<Multiplier, Multiplicant, Product>
+    ElementWiseVectorAdditionSemiring<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType + BuiltInValueType<Product>,
+{
+    /// Like apply(), but allocates the product, sized by the arguments and options
+    pub fn compute(
+        &self,
+        multiplier: &SparseVector<Multiplier>,
+        multiplicant: &SparseVector<Multiplicant>,
+    ) -> Result<SparseVector<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply(multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like apply_with_mask(), but allocates the product, sized by the arguments and options
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        mask: &VectorMask<MaskValueType, AsBool>,
+        multiplier: &SparseVector<Multiplier>,
+        multiplicant: &SparseVector<Multiplicant>,
+    ) -> Result<SparseVector<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply_with_mask(mask, multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    fn product_size(
+        &self,
+        multiplier: &SparseVector<Multiplier>,
+        _multiplicant: &SparseVector<Multiplicant>,
+    ) -> Result<ElementIndex, SparseLinearAlgebraError> {
+        multiplier.length()
+    }
+}
+
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
 // Code review must consider that no mtable access is provided.
 // https://doc.rust-lang.org/nomicon/send-and-sync.html
@@ -167,25 +222,21 @@ pub struct ElementWiseVectorAdditionMonoidOperator<T: ValueType> {
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     multiplication_operator: GrB_Monoid, // defines element-wise multiplication operator Multiplier.*Multiplicant
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<T: ValueType> ElementWiseVectorAdditionMonoidOperator<T> {
     pub fn new(
-        multiplication_operator: &dyn Monoid<T>, // defines element-wise multiplication operator Multiplier.*Multiplicant
+        multiplication_operator: &(impl Monoid<T> + ?Sized), // defines element-wise multiplication operator Multiplier.*Multiplicant
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<T, T, T>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<T, T, T> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
             multiplication_operator: multiplication_operator.graphblas_type(),
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _value: PhantomData,
         }
@@ -197,6 +248,10 @@ impl<T: ValueType> ElementWiseVectorAdditionMonoidOperator<T> {
         multiplicant: &SparseVector<T>,
         product: &mut SparseVector<T>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_element_wise_vector_arguments(multiplier, multiplicant, product)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -207,7 +262,7 @@ impl<T: ValueType> ElementWiseVectorAdditionMonoidOperator<T> {
                 self.multiplication_operator,
                 multiplier.graphblas_vector(),
                 multiplicant.graphblas_vector(),
-                self.options,
+                self.options.to_graphblas_descriptor(),
             )
         })?;
 
@@ -221,6 +276,11 @@ impl<T: ValueType> ElementWiseVectorAdditionMonoidOperator<T> {
         multiplicant: &SparseVector<T>,
         product: &mut SparseVector<T>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_element_wise_vector_arguments(multiplier, multiplicant, product)?;
+            check_length("mask", product.length()?, mask.length()?)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -231,7 +291,7 @@ impl<T: ValueType> ElementWiseVectorAdditionMonoidOperator<T> {
                 self.multiplication_operator,
                 multiplier.graphblas_vector(),
                 multiplicant.graphblas_vector(),
-                self.options,
+                mask.graphblas_descriptor(&self.options),
             )
         })?;
 
@@ -239,6 +299,45 @@ impl<T: ValueType> ElementWiseVectorAdditionMonoidOperator<T> {
     }
 }
 
+impl<T: ValueType + BuiltInValueType<T>> ElementWiseVectorAdditionMonoidOperator<T> {
+    /// Like apply(), but allocates the product, sized by the arguments and options
+    pub fn compute(
+        &self,
+        multiplier: &SparseVector<T>,
+        multiplicant: &SparseVector<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::<T>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply(multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like apply_with_mask(), but allocates the product, sized by the arguments and options
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        mask: &VectorMask<MaskValueType, AsBool>,
+        multiplier: &SparseVector<T>,
+        multiplicant: &SparseVector<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::<T>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply_with_mask(mask, multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    fn product_size(
+        &self,
+        multiplier: &SparseVector<T>,
+        _multiplicant: &SparseVector<T>,
+    ) -> Result<ElementIndex, SparseLinearAlgebraError> {
+        multiplier.length()
+    }
+}
+
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
 // Code review must consider that no mtable access is provided.
 // https://doc.rust-lang.org/nomicon/send-and-sync.html
@@ -274,7 +373,7 @@ pub struct ElementWiseVectorAdditionBinaryOperator<Multiplier, Multiplicant, Pro
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     multiplication_operator: GrB_BinaryOp, // defines element-wise multiplication operator Multiplier.*Multiplicant
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<Multiplier, Multiplicant, Product>
@@ -285,20 +384,16 @@ where
     Product: ValueType,
 {
     pub fn new(
-        multiplication_operator: &dyn BinaryOperator<Multiplier, Multiplicant, Product>, // defines element-wise multiplication operator Multiplier.*Multiplicant
+        multiplication_operator: &(impl BinaryOperator<Multiplier, Multiplicant, Product> + ?Sized), // defines element-wise multiplication operator Multiplier.*Multiplicant
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<Product, Product, Product>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<Product, Product, Product> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
             multiplication_operator: multiplication_operator.graphblas_type(),
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _multiplier: PhantomData,
             _multiplicant: PhantomData,
@@ -312,6 +407,10 @@ where
         multiplicant: &SparseVector<Multiplicant>,
         product: &mut SparseVector<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_element_wise_vector_arguments(multiplier, multiplicant, product)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -322,7 +421,7 @@ where
                 self.multiplication_operator,
                 multiplier.graphblas_vector(),
                 multiplicant.graphblas_vector(),
-                self.options,
+                self.options.to_graphblas_descriptor(),
             )
         })?;
 
@@ -336,6 +435,11 @@ where
         multiplicant: &SparseVector<Multiplicant>,
         product: &mut SparseVector<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_element_wise_vector_arguments(multiplier, multiplicant, product)?;
+            check_length("mask", product.length()?, mask.length()?)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -346,7 +450,7 @@ where
                 self.multiplication_operator,
                 multiplier.graphblas_vector(),
                 multiplicant.graphblas_vector(),
-                self.options,
+                mask.graphblas_descriptor(&self.options),
             )
         })?;
 
@@ -354,12 +458,57 @@ where
     }
 }
 
+impl<Multiplier, Multiplicant, Product>
+    ElementWiseVectorAdditionBinaryOperator<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType + BuiltInValueType<Product>,
+{
+    /// Like apply(), but allocates the product, sized by the arguments and options
+    pub fn compute(
+        &self,
+        multiplier: &SparseVector<Multiplier>,
+        multiplicant: &SparseVector<Multiplicant>,
+    ) -> Result<SparseVector<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply(multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like apply_with_mask(), but allocates the product, sized by the arguments and options
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        mask: &VectorMask<MaskValueType, AsBool>,
+        multiplier: &SparseVector<Multiplier>,
+        multiplicant: &SparseVector<Multiplicant>,
+    ) -> Result<SparseVector<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply_with_mask(mask, multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    fn product_size(
+        &self,
+        multiplier: &SparseVector<Multiplier>,
+        _multiplicant: &SparseVector<Multiplicant>,
+    ) -> Result<ElementIndex, SparseLinearAlgebraError> {
+        multiplier.length()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::{First, Plus, Times};
+    use crate::operators::binary_operator::{First, NoAccumulator, Plus, Times};
     use crate::value_types::sparse_vector::{
         FromVectorElementList, GetVectorElementList, GetVectorElementValue, VectorElementList,
     };
@@ -368,17 +517,18 @@ mod tests {
     fn create_vector_multiplier() {
         let operator = Times::<i64, i64, i64>::new();
         let options = OperatorOptions::new_default();
-        let _element_wise_matrix_multiplier =
-            ElementWiseVectorAdditionBinaryOperator::<i64, i64, i64>::new(
-                &operator, &options, None,
-            );
+        let _element_wise_matrix_multiplier = ElementWiseVectorAdditionBinaryOperator::<
+            i64,
+            i64,
+            i64,
+        >::new(&operator, &options, &NoAccumulator);
 
         let accumulator = Times::<i64, i64, i64>::new();
 
         let _matrix_multiplier = ElementWiseVectorAdditionBinaryOperator::<i64, i64, i64>::new(
             &operator,
             &options,
-            Some(&accumulator),
+            &accumulator,
         );
     }
 
@@ -390,7 +540,9 @@ mod tests {
         let options = OperatorOptions::new_default();
         let element_wise_vector_multiplier =
             ElementWiseVectorAdditionBinaryOperator::<i32, i32, i32>::new(
-                &operator, &options, None,
+                &operator,
+                &options,
+                &NoAccumulator,
             );
 
         let length = 4;
@@ -449,12 +601,11 @@ mod tests {
 
         // test the use of an accumulator
         let accumulator = Plus::<i32, i32, i32>::new();
-        let matrix_multiplier_with_accumulator =
-            ElementWiseVectorAdditionBinaryOperator::<i32, i32, i32>::new(
-                &operator,
-                &options,
-                Some(&accumulator),
-            );
+        let matrix_multiplier_with_accumulator = ElementWiseVectorAdditionBinaryOperator::<
+            i32,
+            i32,
+            i32,
+        >::new(&operator, &options, &accumulator);
 
         matrix_multiplier_with_accumulator
             .apply(&multiplier, &multiplicant, &mut product)
@@ -480,13 +631,15 @@ mod tests {
         .unwrap();
 
         let matrix_multiplier = ElementWiseVectorAdditionBinaryOperator::<i32, i32, i32>::new(
-            &operator, &options, None,
+            &operator,
+            &options,
+            &NoAccumulator,
         );
 
         let mut product = SparseVector::<i32>::new(&context, &length).unwrap();
 
         matrix_multiplier
-            .apply_with_mask(&mask.into(), &multiplier, &multiplicant, &mut product)
+            .apply_with_mask(&(&mask).into(), &multiplier, &multiplicant, &mut product)
             .unwrap();
 
         assert_eq!(product.get_element_value(&0).unwrap(), 5);
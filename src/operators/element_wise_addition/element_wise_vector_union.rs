@@ -0,0 +1,329 @@
+use std::marker::PhantomData;
+use std::ptr;
+
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::{BinaryOperatorApplier, BinaryOperatorApplierTrait};
+use crate::operators::element_wise_multiplication::ElementWiseVectorMultiplicationBinaryOperator;
+use crate::operators::validation::{check_element_wise_vector_arguments, check_length};
+use crate::operators::{
+    binary_operator::{Accumulator, BinaryOperator, NoAccumulator},
+    mask::VectorMask,
+    options::OperatorOptions,
+};
+use crate::util::{ElementIndex, IndexConversion};
+use crate::value_types::sparse_vector::SparseVector;
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
+
+use crate::bindings_to_graphblas_implementation::{
+    GrB_ALL, GrB_BinaryOp, GrB_Descriptor, GrB_Vector, GrB_Vector_assign,
+};
+
+// Implemented methods do not provide mutable access to GraphBLAS operators or options.
+// Code review must consider that no mtable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl Send for ElementWiseVectorUnionBinaryOperator<bool, bool, bool> {}
+unsafe impl Send for ElementWiseVectorUnionBinaryOperator<u8, u8, u8> {}
+unsafe impl Send for ElementWiseVectorUnionBinaryOperator<u16, u16, u16> {}
+unsafe impl Send for ElementWiseVectorUnionBinaryOperator<u32, u32, u32> {}
+unsafe impl Send for ElementWiseVectorUnionBinaryOperator<u64, u64, u64> {}
+unsafe impl Send for ElementWiseVectorUnionBinaryOperator<i8, i8, i8> {}
+unsafe impl Send for ElementWiseVectorUnionBinaryOperator<i16, i16, i16> {}
+unsafe impl Send for ElementWiseVectorUnionBinaryOperator<i32, i32, i32> {}
+unsafe impl Send for ElementWiseVectorUnionBinaryOperator<i64, i64, i64> {}
+unsafe impl Send for ElementWiseVectorUnionBinaryOperator<f32, f32, f32> {}
+unsafe impl Send for ElementWiseVectorUnionBinaryOperator<f64, f64, f64> {}
+
+unsafe impl Sync for ElementWiseVectorUnionBinaryOperator<bool, bool, bool> {}
+unsafe impl Sync for ElementWiseVectorUnionBinaryOperator<u8, u8, u8> {}
+unsafe impl Sync for ElementWiseVectorUnionBinaryOperator<u16, u16, u16> {}
+unsafe impl Sync for ElementWiseVectorUnionBinaryOperator<u32, u32, u32> {}
+unsafe impl Sync for ElementWiseVectorUnionBinaryOperator<u64, u64, u64> {}
+unsafe impl Sync for ElementWiseVectorUnionBinaryOperator<i8, i8, i8> {}
+unsafe impl Sync for ElementWiseVectorUnionBinaryOperator<i16, i16, i16> {}
+unsafe impl Sync for ElementWiseVectorUnionBinaryOperator<i32, i32, i32> {}
+unsafe impl Sync for ElementWiseVectorUnionBinaryOperator<i64, i64, i64> {}
+unsafe impl Sync for ElementWiseVectorUnionBinaryOperator<f32, f32, f32> {}
+unsafe impl Sync for ElementWiseVectorUnionBinaryOperator<f64, f64, f64> {}
+
+/// Element-wise union of two vectors, with eWiseUnion semantics.
+///
+/// Unlike element-wise addition, which copies an element stored in only one argument to the product,
+/// the binary operator is applied to every element of the union. The fill value of the argument
+/// that does not store the element replaces the missing element, such that Minus computes u - v.
+#[derive(Debug, Clone)]
+pub struct ElementWiseVectorUnionBinaryOperator<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType,
+{
+    _product: PhantomData<Product>,
+
+    multiplier_fill_value: Multiplier,
+    multiplicant_fill_value: Multiplicant,
+    intersection_operator:
+        ElementWiseVectorMultiplicationBinaryOperator<Multiplier, Multiplicant, Product>,
+    fill_value_operator: BinaryOperatorApplier<Multiplier, Multiplicant, Product>,
+
+    accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+    options: OperatorOptions,
+}
+
+impl<Multiplier, Multiplicant, Product>
+    ElementWiseVectorUnionBinaryOperator<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType,
+{
+    pub fn new(
+        multiplication_operator: &(impl BinaryOperator<Multiplier, Multiplicant, Product> + ?Sized), // defines element-wise multiplication operator Multiplier.*Multiplicant
+        multiplier_fill_value: Multiplier, // replaces elements only stored in the multiplicant
+        multiplicant_fill_value: Multiplicant, // replaces elements only stored in the multiplier
+        options: &OperatorOptions,
+        accumulator: &(impl Accumulator<Product, Product, Product> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+    ) -> Self {
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
+
+        // The union is computed without mask, accumulator and clearing the output, these only apply
+        // to writing the product, but with the extended options and validation of these options
+        let union_options = options
+            .without_transposed_inputs()
+            .without_clearing_output();
+
+        Self {
+            multiplier_fill_value,
+            multiplicant_fill_value,
+            intersection_operator: ElementWiseVectorMultiplicationBinaryOperator::new(
+                multiplication_operator,
+                &union_options,
+                &NoAccumulator,
+            ),
+            fill_value_operator: BinaryOperatorApplier::new(
+                multiplication_operator,
+                &union_options,
+                &NoAccumulator,
+            ),
+
+            accumulator: accumulator_to_use,
+            options: options.clone(),
+
+            _product: PhantomData,
+        }
+    }
+}
+
+impl<Multiplier, Multiplicant, Product>
+    ElementWiseVectorUnionBinaryOperator<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType + AsBoolean<Multiplier>,
+    Multiplicant: ValueType + AsBoolean<Multiplicant>,
+    Product: ValueType + BuiltInValueType<Product>,
+    BinaryOperatorApplier<Multiplier, Multiplicant, Product>:
+        BinaryOperatorApplierTrait<Multiplier, Multiplicant, Product>,
+{
+    pub fn apply(
+        &self,
+        multiplier: &SparseVector<Multiplier>,
+        multiplicant: &SparseVector<Multiplicant>,
+        product: &mut SparseVector<Product>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_element_wise_vector_arguments(multiplier, multiplicant, product)?;
+        }
+
+        let union = self.union(multiplier, multiplicant)?;
+        self.write_product(
+            &union,
+            product,
+            ptr::null_mut(),
+            self.options.to_graphblas_descriptor(),
+        )
+    }
+
+    pub fn apply_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        mask: &VectorMask<MaskValueType, AsBool>,
+        multiplier: &SparseVector<Multiplier>,
+        multiplicant: &SparseVector<Multiplicant>,
+        product: &mut SparseVector<Product>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_element_wise_vector_arguments(multiplier, multiplicant, product)?;
+            check_length("mask", product.length()?, mask.length()?)?;
+        }
+
+        let union = self.union(multiplier, multiplicant)?;
+        self.write_product(
+            &union,
+            product,
+            mask.graphblas_vector(),
+            mask.graphblas_descriptor(&self.options),
+        )
+    }
+
+    /// Like apply(), but allocates the product, sized by the arguments and options
+    pub fn compute(
+        &self,
+        multiplier: &SparseVector<Multiplier>,
+        multiplicant: &SparseVector<Multiplicant>,
+    ) -> Result<SparseVector<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply(multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like apply_with_mask(), but allocates the product, sized by the arguments and options
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        mask: &VectorMask<MaskValueType, AsBool>,
+        multiplier: &SparseVector<Multiplier>,
+        multiplicant: &SparseVector<Multiplicant>,
+    ) -> Result<SparseVector<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply_with_mask(mask, multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    // SuiteSparse:GraphBLAS 4 has no eWiseUnion. The union is composed of the intersection,
+    // and the fill values applied to the elements stored in only one of the arguments.
+    fn union(
+        &self,
+        multiplier: &SparseVector<Multiplier>,
+        multiplicant: &SparseVector<Multiplicant>,
+    ) -> Result<SparseVector<Product>, SparseLinearAlgebraError> {
+        let mut union = self
+            .intersection_operator
+            .compute(multiplier, multiplicant)?;
+        self.fill_value_operator
+            .apply_with_vector_as_first_argument_and_mask(
+                multiplier,
+                &self.multiplicant_fill_value,
+                &mut union,
+                &VectorMask::new(multiplicant).structure().complement(),
+            )?;
+        self.fill_value_operator
+            .apply_with_vector_as_second_argument_and_mask(
+                &self.multiplier_fill_value,
+                multiplicant,
+                &mut union,
+                &VectorMask::new(multiplier).structure().complement(),
+            )?;
+        Ok(union)
+    }
+
+    fn write_product(
+        &self,
+        union: &SparseVector<Product>,
+        product: &mut SparseVector<Product>,
+        mask: GrB_Vector,
+        descriptor: GrB_Descriptor,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let length = product.length()?.to_graphblas_index()?;
+        product.context_ref().call(|| unsafe {
+            GrB_Vector_assign(
+                product.graphblas_vector(),
+                mask,
+                self.accumulator,
+                union.graphblas_vector(),
+                GrB_ALL,
+                length,
+                descriptor,
+            )
+        })?;
+        Ok(())
+    }
+
+    fn product_size(
+        &self,
+        multiplier: &SparseVector<Multiplier>,
+        _multiplicant: &SparseVector<Multiplicant>,
+    ) -> Result<ElementIndex, SparseLinearAlgebraError> {
+        multiplier.length()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::context::{Context, Mode};
+    use crate::operators::binary_operator::{First, Minus, NoAccumulator, Plus};
+    use crate::value_types::sparse_vector::{
+        FromVectorElementList, GetVectorElementValue, VectorElementList,
+    };
+
+    #[test]
+    fn test_element_wise_vector_union() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let length = 4;
+        let multiplier = SparseVector::<i32>::from_element_list(
+            &context,
+            &length,
+            &VectorElementList::<i32>::from_element_vector(vec![(0, 5).into(), (1, 6).into()]),
+            &First::<i32, i32, i32>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseVector::<i32>::from_element_list(
+            &context,
+            &length,
+            &VectorElementList::<i32>::from_element_vector(vec![(1, 2).into(), (2, 3).into()]),
+            &First::<i32, i32, i32>::new(),
+        )
+        .unwrap();
+
+        let operator = Minus::<i32, i32, i32>::new();
+        let options = OperatorOptions::new_default();
+        let union_operator =
+            ElementWiseVectorUnionBinaryOperator::new(&operator, 0, 0, &options, &NoAccumulator);
+
+        let product = union_operator.compute(&multiplier, &multiplicant).unwrap();
+        assert_eq!(product.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product.get_element_value(&0).unwrap(), 5);
+        assert_eq!(product.get_element_value(&1).unwrap(), 4);
+        // only stored in the multiplicant, element-wise addition would give 3
+        assert_eq!(product.get_element_value(&2).unwrap(), -3);
+
+        let union_operator =
+            ElementWiseVectorUnionBinaryOperator::new(&operator, 10, 1, &options, &NoAccumulator);
+        let product = union_operator.compute(&multiplier, &multiplicant).unwrap();
+        assert_eq!(product.get_element_value(&0).unwrap(), 4);
+        assert_eq!(product.get_element_value(&2).unwrap(), 7);
+
+        // mask and accumulator apply to writing the product
+        let mask = SparseVector::<bool>::from_element_list(
+            &context,
+            &length,
+            &VectorElementList::<bool>::from_element_vector(vec![
+                (1, true).into(),
+                (2, true).into(),
+            ]),
+            &First::<bool, bool, bool>::new(),
+        )
+        .unwrap();
+        let accumulator = Plus::<i32, i32, i32>::new();
+        let union_operator =
+            ElementWiseVectorUnionBinaryOperator::new(&operator, 0, 0, &options, &accumulator);
+        let mut product = SparseVector::<i32>::from_element_list(
+            &context,
+            &length,
+            &VectorElementList::<i32>::from_element_vector(vec![(0, 1).into(), (1, 1).into()]),
+            &First::<i32, i32, i32>::new(),
+        )
+        .unwrap();
+        union_operator
+            .apply_with_mask(&(&mask).into(), &multiplier, &multiplicant, &mut product)
+            .unwrap();
+        assert_eq!(product.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product.get_element_value(&0).unwrap(), 1);
+        assert_eq!(product.get_element_value(&1).unwrap(), 5);
+        assert_eq!(product.get_element_value(&2).unwrap(), -3);
+    }
+}
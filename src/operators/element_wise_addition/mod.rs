@@ -1,11 +1,15 @@
 mod element_wise_matrix_addition;
+mod element_wise_matrix_union;
 mod element_wise_vector_addition;
+mod element_wise_vector_union;
 
 pub use element_wise_matrix_addition::{
     ElementWiseMatrixAdditionBinaryOperator, ElementWiseMatrixAdditionMonoidOperator,
     ElementWiseMatrixAdditionSemiring,
 };
+pub use element_wise_matrix_union::ElementWiseMatrixUnionBinaryOperator;
 pub use element_wise_vector_addition::{
     ElementWiseVectorAdditionBinaryOperator, ElementWiseVectorAdditionMonoidOperator,
     ElementWiseVectorAdditionSemiring,
 };
+pub use element_wise_vector_union::ElementWiseVectorUnionBinaryOperator;
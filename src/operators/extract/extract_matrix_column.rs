@@ -2,17 +2,17 @@ use std::marker::PhantomData;
 use std::ptr;
 
 use crate::error::SparseLinearAlgebraError;
-use crate::operators::{
-    binary_operator::BinaryOperator, mask::VectorMask, options::OperatorOptions,
-};
+use crate::operators::options::size_of_first_argument;
+use crate::operators::validation::{check_index, check_indices, check_length};
+use crate::operators::{binary_operator::Accumulator, mask::VectorMask, options::OperatorOptions};
 use crate::util::{
     ElementIndex, ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion,
 };
 use crate::value_types::sparse_matrix::SparseMatrix;
 use crate::value_types::sparse_vector::SparseVector;
-use crate::value_types::value_type::{AsBoolean, ValueType};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
 
-use crate::bindings_to_graphblas_implementation::{GrB_BinaryOp, GrB_Col_extract, GrB_Descriptor};
+use crate::bindings_to_graphblas_implementation::{GrB_BinaryOp, GrB_Col_extract};
 
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
 // Code review must consider that no mtable access is provided.
@@ -51,7 +51,7 @@ where
     _column: PhantomData<Column>,
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<Matrix, Column> MatrixColumnExtractor<Matrix, Column>
@@ -61,17 +61,13 @@ where
 {
     pub fn new(
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<Column, Column, Column>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<Column, Column, Column> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _matrix: PhantomData,
             _column: PhantomData,
@@ -85,16 +81,19 @@ where
         indices_to_extract: &ElementIndexSelector,
         column_vector: &mut SparseVector<Column>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            self.check_arguments(
+                matrix_to_extract_from,
+                column_index_to_extract,
+                indices_to_extract,
+                column_vector,
+            )?;
+        }
+
         let context = matrix_to_extract_from.context();
 
-        let number_of_indices_to_extract: ElementIndex;
-        match indices_to_extract {
-            ElementIndexSelector::Index(indices) => number_of_indices_to_extract = indices.len(),
-            ElementIndexSelector::All => {
-                number_of_indices_to_extract = matrix_to_extract_from.row_height()?
-            }
-        }
-        let number_of_indices_to_extract = number_of_indices_to_extract.to_graphblas_index()?;
+        let number_of_indices_to_extract =
+            indices_to_extract.graphblas_number_of_indices(matrix_to_extract_from.row_height()?)?;
 
         let indices_to_extract = indices_to_extract.to_graphblas_type()?;
 
@@ -111,7 +110,7 @@ where
                         index.as_ptr(),
                         number_of_indices_to_extract,
                         column_index_to_extract,
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
             }
@@ -125,7 +124,7 @@ where
                         index,
                         number_of_indices_to_extract,
                         column_index_to_extract,
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
             }
@@ -142,16 +141,20 @@ where
         column_vector: &mut SparseVector<Column>,
         mask: &VectorMask<MaskValueType, AsBool>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            self.check_arguments(
+                matrix_to_extract_from,
+                column_index_to_extract,
+                indices_to_extract,
+                column_vector,
+            )?;
+            check_length("mask", column_vector.length()?, mask.length()?)?;
+        }
+
         let context = matrix_to_extract_from.context();
 
-        let number_of_indices_to_extract: ElementIndex;
-        match indices_to_extract {
-            ElementIndexSelector::Index(indices) => number_of_indices_to_extract = indices.len(),
-            ElementIndexSelector::All => {
-                number_of_indices_to_extract = matrix_to_extract_from.row_height()?
-            }
-        }
-        let number_of_indices_to_extract = number_of_indices_to_extract.to_graphblas_index()?;
+        let number_of_indices_to_extract =
+            indices_to_extract.graphblas_number_of_indices(matrix_to_extract_from.row_height()?)?;
 
         let indices_to_extract = indices_to_extract.to_graphblas_type()?;
 
@@ -168,7 +171,7 @@ where
                         index.as_ptr(),
                         number_of_indices_to_extract,
                         column_index_to_extract,
-                        self.options,
+                        mask.graphblas_descriptor(&self.options),
                     )
                 })?;
             }
@@ -182,7 +185,7 @@ where
                         index,
                         number_of_indices_to_extract,
                         column_index_to_extract,
-                        self.options,
+                        mask.graphblas_descriptor(&self.options),
                     )
                 })?;
             }
@@ -190,6 +193,85 @@ where
 
         Ok(())
     }
+
+    fn check_arguments(
+        &self,
+        matrix_to_extract_from: &SparseMatrix<Matrix>,
+        column_index_to_extract: &ElementIndex,
+        indices_to_extract: &ElementIndexSelector,
+        column_vector: &SparseVector<Column>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let size = size_of_first_argument(matrix_to_extract_from, &self.options)?;
+        check_index(
+            "column_index_to_extract",
+            *column_index_to_extract,
+            size.column_width(),
+        )?;
+        check_indices("indices_to_extract", indices_to_extract, size.row_height())?;
+        check_length(
+            "column_vector",
+            self.column_length(matrix_to_extract_from, indices_to_extract)?,
+            column_vector.length()?,
+        )
+    }
+
+    fn column_length(
+        &self,
+        matrix_to_extract_from: &SparseMatrix<Matrix>,
+        indices_to_extract: &ElementIndexSelector,
+    ) -> Result<ElementIndex, SparseLinearAlgebraError> {
+        indices_to_extract.number_of_selected_elements(
+            size_of_first_argument(matrix_to_extract_from, &self.options)?.row_height(),
+        )
+    }
+}
+
+impl<Matrix, Column> MatrixColumnExtractor<Matrix, Column>
+where
+    Matrix: ValueType,
+    Column: ValueType + BuiltInValueType<Column>,
+{
+    /// Like apply(), but allocates the column vector, sized by the selector and options
+    pub fn compute(
+        &self,
+        matrix_to_extract_from: &SparseMatrix<Matrix>,
+        column_index_to_extract: &ElementIndex,
+        indices_to_extract: &ElementIndexSelector,
+    ) -> Result<SparseVector<Column>, SparseLinearAlgebraError> {
+        let mut column_vector = SparseVector::<Column>::new(
+            matrix_to_extract_from.context_ref(),
+            &self.column_length(matrix_to_extract_from, indices_to_extract)?,
+        )?;
+        self.apply(
+            matrix_to_extract_from,
+            column_index_to_extract,
+            indices_to_extract,
+            &mut column_vector,
+        )?;
+        Ok(column_vector)
+    }
+
+    /// Like apply_with_mask(), but allocates the column vector, sized by the selector and options
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        matrix_to_extract_from: &SparseMatrix<Matrix>,
+        column_index_to_extract: &ElementIndex,
+        indices_to_extract: &ElementIndexSelector,
+        mask: &VectorMask<MaskValueType, AsBool>,
+    ) -> Result<SparseVector<Column>, SparseLinearAlgebraError> {
+        let mut column_vector = SparseVector::<Column>::new(
+            matrix_to_extract_from.context_ref(),
+            &self.column_length(matrix_to_extract_from, indices_to_extract)?,
+        )?;
+        self.apply_with_mask(
+            matrix_to_extract_from,
+            column_index_to_extract,
+            indices_to_extract,
+            &mut column_vector,
+            mask,
+        )?;
+        Ok(column_vector)
+    }
 }
 
 #[cfg(test)]
@@ -197,7 +279,7 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator};
     use crate::value_types::sparse_matrix::{FromMatrixElementList, MatrixElementList};
     use crate::value_types::sparse_vector::GetVectorElementValue;
 
@@ -227,7 +309,7 @@ mod tests {
         let indices_to_extract: Vec<ElementIndex> = vec![0, 2];
         let indices_to_extract = ElementIndexSelector::Index(&indices_to_extract);
 
-        let extractor = MatrixColumnExtractor::new(&OperatorOptions::new_default(), None);
+        let extractor = MatrixColumnExtractor::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         extractor
             .apply(&matrix, &0, &indices_to_extract, &mut column_vector)
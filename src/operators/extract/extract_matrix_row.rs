@@ -1,38 +1,91 @@
+use std::marker::PhantomData;
+use std::ptr;
+
 use crate::error::SparseLinearAlgebraError;
+use crate::operators::options::size_of_first_argument;
+use crate::operators::validation::{check_index, check_indices, check_length};
 use crate::operators::{
-    binary_operator::BinaryOperator, extract::MatrixColumnExtractor, mask::VectorMask,
-    options::OperatorOptions, transpose::MatrixTranspose,
+    binary_operator::Accumulator,
+    extract::SubMatrixExtractor,
+    mask::{MatrixMask, VectorMask},
+    options::OperatorOptions,
+};
+use crate::util::{
+    ElementIndex, ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion,
 };
-use crate::util::{ElementIndex, ElementIndexSelector};
-use crate::value_types::sparse_matrix::{Size, SparseMatrix};
+use crate::value_types::sparse_matrix::SparseMatrix;
 use crate::value_types::sparse_vector::SparseVector;
-use crate::value_types::value_type::{AsBoolean, ValueType};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
+
+use crate::bindings_to_graphblas_implementation::{
+    GrB_BinaryOp, GrB_Col_extract, GrB_Descriptor, GrB_Vector,
+};
+
+// Implemented methods do not provide mutable access to GraphBLAS operators or options.
+// Code review must consider that no mtable access is provided.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl Send for MatrixRowExtractor<bool, bool> {}
+unsafe impl Send for MatrixRowExtractor<u8, u8> {}
+unsafe impl Send for MatrixRowExtractor<u16, u16> {}
+unsafe impl Send for MatrixRowExtractor<u32, u32> {}
+unsafe impl Send for MatrixRowExtractor<u64, u64> {}
+unsafe impl Send for MatrixRowExtractor<i8, i8> {}
+unsafe impl Send for MatrixRowExtractor<i16, i16> {}
+unsafe impl Send for MatrixRowExtractor<i32, i32> {}
+unsafe impl Send for MatrixRowExtractor<i64, i64> {}
+unsafe impl Send for MatrixRowExtractor<f32, f32> {}
+unsafe impl Send for MatrixRowExtractor<f64, f64> {}
 
+unsafe impl Sync for MatrixRowExtractor<bool, bool> {}
+unsafe impl Sync for MatrixRowExtractor<u8, u8> {}
+unsafe impl Sync for MatrixRowExtractor<u16, u16> {}
+unsafe impl Sync for MatrixRowExtractor<u32, u32> {}
+unsafe impl Sync for MatrixRowExtractor<u64, u64> {}
+unsafe impl Sync for MatrixRowExtractor<i8, i8> {}
+unsafe impl Sync for MatrixRowExtractor<i16, i16> {}
+unsafe impl Sync for MatrixRowExtractor<i32, i32> {}
+unsafe impl Sync for MatrixRowExtractor<i64, i64> {}
+unsafe impl Sync for MatrixRowExtractor<f32, f32> {}
+unsafe impl Sync for MatrixRowExtractor<f64, f64> {}
+
+/// Extracts rows from a matrix, without transposing the matrix.
+///
+/// A single row is extracted as a column of the transposed matrix, by GrB_Col_extract with
+/// the transposition of its input inverted. apply_to_rows() extracts many rows at once into a sub-matrix.
 #[derive(Debug, Clone)]
-pub struct MatrixRowExtractor<Matrix, Column>
+pub struct MatrixRowExtractor<Matrix, Row>
 where
     Matrix: ValueType,
-    Column: ValueType,
+    Row: ValueType,
 {
-    transpose_operator: MatrixTranspose<Matrix, Matrix>,
-    column_extractor: MatrixColumnExtractor<Matrix, Column>,
+    _matrix: PhantomData<Matrix>,
+    _row: PhantomData<Row>,
+
+    accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+    options: OperatorOptions,
+    row_options: OperatorOptions, // the options with the transposition of the first input inverted
+    sub_matrix_extractor: SubMatrixExtractor<Matrix, Row>,
 }
 
-impl<Matrix, Column> MatrixRowExtractor<Matrix, Column>
+impl<Matrix, Row> MatrixRowExtractor<Matrix, Row>
 where
     Matrix: ValueType,
-    Column: ValueType,
+    Row: ValueType,
 {
     pub fn new(
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<Column, Column, Column>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<Row, Row, Row> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let transpose_operator = MatrixTranspose::new(options, None);
-        let column_extractor = MatrixColumnExtractor::new(options, accumulator);
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
-            transpose_operator,
-            column_extractor,
+            accumulator: accumulator_to_use,
+            options: options.clone(),
+            row_options: options.with_inverted_transpose_input0(),
+            sub_matrix_extractor: SubMatrixExtractor::new(options, accumulator),
+
+            _matrix: PhantomData,
+            _row: PhantomData,
         }
     }
 
@@ -41,67 +94,252 @@ where
         matrix_to_extract_from: &SparseMatrix<Matrix>,
         row_index_to_extract: &ElementIndex,
         indices_to_extract: &ElementIndexSelector,
-        row_vector: &mut SparseVector<Column>,
+        row_vector: &mut SparseVector<Row>,
     ) -> Result<(), SparseLinearAlgebraError> {
-        let _context = matrix_to_extract_from.context();
+        if self.options.validate_arguments() {
+            self.check_arguments(
+                matrix_to_extract_from,
+                row_index_to_extract,
+                indices_to_extract,
+                row_vector,
+            )?;
+        }
 
-        let size_of_transposed_matrix: Size = (
-            matrix_to_extract_from.column_width()?,
-            matrix_to_extract_from.row_height()?,
+        self.extract_row(
+            matrix_to_extract_from,
+            row_index_to_extract,
+            indices_to_extract,
+            row_vector,
+            ptr::null_mut(),
+            self.row_options.to_graphblas_descriptor(),
         )
-            .into();
-        // creating a new matrix, instead of cloning, requires to specify if if the value type is built-in or custom, as this is required for the SparseMatrix::new() constructor.
-        // let mut transposed_matrix = SparseMatrix::<ValueType>::new(&context, &size_of_transposed_matrix)?;
-        let mut transposed_matrix = matrix_to_extract_from.clone();
-        transposed_matrix.resize(&size_of_transposed_matrix)?;
+    }
 
-        self.transpose_operator
-            .apply(&matrix_to_extract_from, &mut transposed_matrix)?;
+    pub fn apply_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        matrix_to_extract_from: &SparseMatrix<Matrix>,
+        row_index_to_extract: &ElementIndex,
+        indices_to_extract: &ElementIndexSelector,
+        row_vector: &mut SparseVector<Row>,
+        mask: &VectorMask<MaskValueType, AsBool>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            self.check_arguments(
+                matrix_to_extract_from,
+                row_index_to_extract,
+                indices_to_extract,
+                row_vector,
+            )?;
+            check_length("mask", row_vector.length()?, mask.length()?)?;
+        }
 
-        self.column_extractor.apply(
-            &transposed_matrix,
+        self.extract_row(
+            matrix_to_extract_from,
             row_index_to_extract,
             indices_to_extract,
             row_vector,
+            mask.graphblas_vector(),
+            mask.graphblas_descriptor(&self.row_options),
+        )
+    }
+
+    /// Extracts the selected rows into the rows of a sub-matrix, with one GrB_Matrix_extract call
+    pub fn apply_to_rows(
+        &self,
+        matrix_to_extract_from: &SparseMatrix<Matrix>,
+        rows_to_extract: &ElementIndexSelector, // length must equal row_height of rows
+        indices_to_extract: &ElementIndexSelector, // length must equal column_width of rows
+        rows: &mut SparseMatrix<Row>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        self.sub_matrix_extractor.apply(
+            matrix_to_extract_from,
+            rows_to_extract,
+            indices_to_extract,
+            rows,
+        )
+    }
+
+    pub fn apply_to_rows_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        matrix_to_extract_from: &SparseMatrix<Matrix>,
+        rows_to_extract: &ElementIndexSelector,
+        indices_to_extract: &ElementIndexSelector,
+        rows: &mut SparseMatrix<Row>,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        self.sub_matrix_extractor.apply_with_mask(
+            matrix_to_extract_from,
+            rows_to_extract,
+            indices_to_extract,
+            rows,
+            mask,
+        )
+    }
+
+    fn extract_row(
+        &self,
+        matrix_to_extract_from: &SparseMatrix<Matrix>,
+        row_index_to_extract: &ElementIndex,
+        indices_to_extract: &ElementIndexSelector,
+        row_vector: &mut SparseVector<Row>,
+        mask: GrB_Vector,
+        descriptor: GrB_Descriptor,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let context = matrix_to_extract_from.context();
+
+        let number_of_indices_to_extract = indices_to_extract.graphblas_number_of_indices(
+            size_of_first_argument(matrix_to_extract_from, &self.options)?.column_width(),
         )?;
 
+        let indices_to_extract = indices_to_extract.to_graphblas_type()?;
+
+        let row_index_to_extract = row_index_to_extract.to_graphblas_index()?;
+
+        match indices_to_extract {
+            ElementIndexSelectorGraphblasType::Index(index) => {
+                context.call(|| unsafe {
+                    GrB_Col_extract(
+                        row_vector.graphblas_vector(),
+                        mask,
+                        self.accumulator,
+                        matrix_to_extract_from.graphblas_matrix(),
+                        index.as_ptr(),
+                        number_of_indices_to_extract,
+                        row_index_to_extract,
+                        descriptor,
+                    )
+                })?;
+            }
+            ElementIndexSelectorGraphblasType::All(index) => {
+                context.call(|| unsafe {
+                    GrB_Col_extract(
+                        row_vector.graphblas_vector(),
+                        mask,
+                        self.accumulator,
+                        matrix_to_extract_from.graphblas_matrix(),
+                        index,
+                        number_of_indices_to_extract,
+                        row_index_to_extract,
+                        descriptor,
+                    )
+                })?;
+            }
+        }
+
         Ok(())
     }
 
-    pub fn apply_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+    fn check_arguments(
         &self,
         matrix_to_extract_from: &SparseMatrix<Matrix>,
         row_index_to_extract: &ElementIndex,
         indices_to_extract: &ElementIndexSelector,
-        row_vector: &mut SparseVector<Column>,
-        mask: &VectorMask<MaskValueType, AsBool>,
+        row_vector: &SparseVector<Row>,
     ) -> Result<(), SparseLinearAlgebraError> {
-        let _context = matrix_to_extract_from.context();
-
-        let size_of_transposed_matrix: Size = (
-            matrix_to_extract_from.column_width()?,
-            matrix_to_extract_from.row_height()?,
+        let size = size_of_first_argument(matrix_to_extract_from, &self.options)?;
+        check_index(
+            "row_index_to_extract",
+            *row_index_to_extract,
+            size.row_height(),
+        )?;
+        check_indices(
+            "indices_to_extract",
+            indices_to_extract,
+            size.column_width(),
+        )?;
+        check_length(
+            "row_vector",
+            self.row_length(matrix_to_extract_from, indices_to_extract)?,
+            row_vector.length()?,
         )
-            .into();
-        // creating a new matrix, instead of cloning, requires to specify if if the value type is built-in or custom, as this is required for the SparseMatrix::new() constructor.
-        // let mut transposed_matrix = SparseMatrix::<ValueType>::new(&context, &size_of_transposed_matrix)?;
-        let mut transposed_matrix = matrix_to_extract_from.clone();
-        transposed_matrix.resize(&size_of_transposed_matrix)?;
+    }
 
-        self.transpose_operator
-            .apply(&matrix_to_extract_from, &mut transposed_matrix)?;
+    fn row_length(
+        &self,
+        matrix_to_extract_from: &SparseMatrix<Matrix>,
+        indices_to_extract: &ElementIndexSelector,
+    ) -> Result<ElementIndex, SparseLinearAlgebraError> {
+        indices_to_extract.number_of_selected_elements(
+            size_of_first_argument(matrix_to_extract_from, &self.options)?.column_width(),
+        )
+    }
+}
 
-        // let row_index_to_extract = ElementIndex::from(matrix_to_extract_from.row_height()? - row_index_to_extract);
+impl<Matrix, Row> MatrixRowExtractor<Matrix, Row>
+where
+    Matrix: ValueType,
+    Row: ValueType + BuiltInValueType<Row>,
+{
+    /// Like apply(), but allocates the row vector, sized by the selector and options
+    pub fn compute(
+        &self,
+        matrix_to_extract_from: &SparseMatrix<Matrix>,
+        row_index_to_extract: &ElementIndex,
+        indices_to_extract: &ElementIndexSelector,
+    ) -> Result<SparseVector<Row>, SparseLinearAlgebraError> {
+        let mut row_vector = SparseVector::<Row>::new(
+            matrix_to_extract_from.context_ref(),
+            &self.row_length(matrix_to_extract_from, indices_to_extract)?,
+        )?;
+        self.apply(
+            matrix_to_extract_from,
+            row_index_to_extract,
+            indices_to_extract,
+            &mut row_vector,
+        )?;
+        Ok(row_vector)
+    }
 
-        self.column_extractor.apply_with_mask(
-            &matrix_to_extract_from,
-            &row_index_to_extract,
+    /// Like apply_with_mask(), but allocates the row vector, sized by the selector and options
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        matrix_to_extract_from: &SparseMatrix<Matrix>,
+        row_index_to_extract: &ElementIndex,
+        indices_to_extract: &ElementIndexSelector,
+        mask: &VectorMask<MaskValueType, AsBool>,
+    ) -> Result<SparseVector<Row>, SparseLinearAlgebraError> {
+        let mut row_vector = SparseVector::<Row>::new(
+            matrix_to_extract_from.context_ref(),
+            &self.row_length(matrix_to_extract_from, indices_to_extract)?,
+        )?;
+        self.apply_with_mask(
+            matrix_to_extract_from,
+            row_index_to_extract,
             indices_to_extract,
-            row_vector,
+            &mut row_vector,
             mask,
         )?;
+        Ok(row_vector)
+    }
 
-        Ok(())
+    /// Like apply_to_rows(), but allocates the sub-matrix, sized by the selectors and options
+    pub fn compute_rows(
+        &self,
+        matrix_to_extract_from: &SparseMatrix<Matrix>,
+        rows_to_extract: &ElementIndexSelector,
+        indices_to_extract: &ElementIndexSelector,
+    ) -> Result<SparseMatrix<Row>, SparseLinearAlgebraError> {
+        self.sub_matrix_extractor.compute(
+            matrix_to_extract_from,
+            rows_to_extract,
+            indices_to_extract,
+        )
+    }
+
+    /// Like apply_to_rows_with_mask(), but allocates the sub-matrix, sized by the selectors and options
+    pub fn compute_rows_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        matrix_to_extract_from: &SparseMatrix<Matrix>,
+        rows_to_extract: &ElementIndexSelector,
+        indices_to_extract: &ElementIndexSelector,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+    ) -> Result<SparseMatrix<Row>, SparseLinearAlgebraError> {
+        self.sub_matrix_extractor.compute_with_mask(
+            matrix_to_extract_from,
+            rows_to_extract,
+            indices_to_extract,
+            mask,
+        )
     }
 }
 
@@ -110,9 +348,13 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
-    use crate::value_types::sparse_matrix::{FromMatrixElementList, MatrixElementList};
-    use crate::value_types::sparse_vector::GetVectorElementValue;
+    use crate::operators::binary_operator::{First, NoAccumulator};
+    use crate::value_types::sparse_matrix::{
+        FromMatrixElementList, GetMatrixElementValue, MatrixElementList,
+    };
+    use crate::value_types::sparse_vector::{
+        FromVectorElementList, GetVectorElementValue, VectorElementList,
+    };
 
     #[test]
     fn test_row_extraction() {
@@ -140,7 +382,7 @@ mod tests {
         let indices_to_extract: Vec<ElementIndex> = vec![0, 1];
         let indices_to_extract = ElementIndexSelector::Index(&indices_to_extract);
 
-        let extractor = MatrixRowExtractor::new(&OperatorOptions::new_default(), None);
+        let extractor = MatrixRowExtractor::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         extractor
             .apply(&matrix, &2, &indices_to_extract, &mut column_vector)
@@ -149,5 +391,71 @@ mod tests {
         assert_eq!(column_vector.number_of_stored_elements().unwrap(), 2);
         assert_eq!(column_vector.get_element_value(&0).unwrap(), 3);
         assert_eq!(column_vector.get_element_value(&1).unwrap(), 6);
+
+        let mask = SparseVector::<bool>::from_element_list(
+            &context,
+            &2,
+            &VectorElementList::<bool>::from_element_vector(vec![(1, true).into()]),
+            &First::<bool, bool, bool>::new(),
+        )
+        .unwrap();
+        let row_vector = extractor
+            .compute_with_mask(&matrix, &1, &ElementIndexSelector::All, &(&mask).into())
+            .unwrap();
+        assert_eq!(row_vector.number_of_stored_elements().unwrap(), 1);
+        assert_eq!(row_vector.get_element_value(&1).unwrap(), 5);
+
+        // a row of the transposed matrix is a column of the matrix
+        let transposing_extractor = MatrixRowExtractor::new(
+            &OperatorOptions::new(false, true, false),
+            &NoAccumulator,
+        );
+        let row_vector: SparseVector<u8> = transposing_extractor
+            .compute(&matrix, &1, &ElementIndexSelector::All)
+            .unwrap();
+        assert_eq!(row_vector.length().unwrap(), 3);
+        assert_eq!(row_vector.get_element_value(&0).unwrap(), 4);
+        assert_eq!(row_vector.get_element_value(&2).unwrap(), 6);
+
+        assert!(extractor
+            .apply(&matrix, &3, &indices_to_extract, &mut column_vector)
+            .is_err());
+    }
+
+    #[test]
+    fn test_rows_extraction() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let element_list = MatrixElementList::<u8>::from_element_vector(vec![
+            (0, 0, 1).into(),
+            (1, 0, 2).into(),
+            (2, 0, 3).into(),
+            (0, 1, 4).into(),
+            (1, 1, 5).into(),
+            (2, 1, 6).into(),
+        ]);
+
+        let matrix = SparseMatrix::<u8>::from_element_list(
+            &context.clone(),
+            &(3, 2).into(),
+            &element_list,
+            &First::<u8, u8, u8>::new(),
+        )
+        .unwrap();
+
+        let rows_to_extract: Vec<ElementIndex> = vec![2, 0];
+        let rows_to_extract = ElementIndexSelector::Index(&rows_to_extract);
+
+        let extractor = MatrixRowExtractor::new(&OperatorOptions::new_default(), &NoAccumulator);
+        let rows: SparseMatrix<u8> = extractor
+            .compute_rows(&matrix, &rows_to_extract, &ElementIndexSelector::All)
+            .unwrap();
+
+        assert_eq!(rows.row_height().unwrap(), 2);
+        assert_eq!(rows.column_width().unwrap(), 2);
+        assert_eq!(rows.get_element_value(&(0, 0).into()).unwrap(), 3);
+        assert_eq!(rows.get_element_value(&(0, 1).into()).unwrap(), 6);
+        assert_eq!(rows.get_element_value(&(1, 0).into()).unwrap(), 1);
+        assert_eq!(rows.get_element_value(&(1, 1).into()).unwrap(), 4);
     }
 }
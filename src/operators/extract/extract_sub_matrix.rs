@@ -2,18 +2,14 @@ use std::marker::PhantomData;
 use std::ptr;
 
 use crate::error::SparseLinearAlgebraError;
-use crate::operators::{
-    binary_operator::BinaryOperator, mask::MatrixMask, options::OperatorOptions,
-};
-use crate::util::{
-    ElementIndex, ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion,
-};
-use crate::value_types::sparse_matrix::SparseMatrix;
-use crate::value_types::value_type::{AsBoolean, ValueType};
-
-use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GrB_Matrix_extract,
-};
+use crate::operators::options::size_of_first_argument;
+use crate::operators::validation::{check_indices, check_size};
+use crate::operators::{binary_operator::Accumulator, mask::MatrixMask, options::OperatorOptions};
+use crate::util::{ElementIndexSelector, ElementIndexSelectorGraphblasType};
+use crate::value_types::sparse_matrix::{Size, SparseMatrix};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
+
+use crate::bindings_to_graphblas_implementation::{GrB_BinaryOp, GrB_Matrix_extract};
 
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
 // Code review must consider that no mtable access is provided.
@@ -52,7 +48,7 @@ where
     _sub_matrix: PhantomData<SubMatrix>,
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<Matrix, SubMatrix> SubMatrixExtractor<Matrix, SubMatrix>
@@ -62,17 +58,13 @@ where
 {
     pub fn new(
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<SubMatrix, SubMatrix, SubMatrix>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<SubMatrix, SubMatrix, SubMatrix> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _matrix: PhantomData,
             _sub_matrix: PhantomData,
@@ -86,25 +78,22 @@ where
         columns_to_extract: &ElementIndexSelector, // length must equal column_width of sub_matrix
         sub_matrix: &mut SparseMatrix<SubMatrix>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            self.check_arguments(
+                matrix_to_extract_from,
+                rows_to_extract,
+                columns_to_extract,
+                sub_matrix,
+            )?;
+        }
+
         let context = matrix_to_extract_from.context();
 
-        let number_of_rows_to_extract: ElementIndex;
-        match rows_to_extract {
-            ElementIndexSelector::Index(indices) => number_of_rows_to_extract = indices.len(),
-            ElementIndexSelector::All => {
-                number_of_rows_to_extract = matrix_to_extract_from.row_height()?
-            }
-        }
-        let number_of_rows_to_extract = number_of_rows_to_extract.to_graphblas_index()?;
+        let number_of_rows_to_extract =
+            rows_to_extract.graphblas_number_of_indices(matrix_to_extract_from.row_height()?)?;
 
-        let number_of_columns_to_extract: ElementIndex;
-        match columns_to_extract {
-            ElementIndexSelector::Index(indices) => number_of_columns_to_extract = indices.len(),
-            ElementIndexSelector::All => {
-                number_of_columns_to_extract = matrix_to_extract_from.column_width()?
-            }
-        }
-        let number_of_columns_to_extract = number_of_columns_to_extract.to_graphblas_index()?;
+        let number_of_columns_to_extract = columns_to_extract
+            .graphblas_number_of_indices(matrix_to_extract_from.column_width()?)?;
 
         let rows_to_extract = rows_to_extract.to_graphblas_type()?;
         let columns_to_extract = columns_to_extract.to_graphblas_type()?;
@@ -124,7 +113,7 @@ where
                         number_of_rows_to_extract,
                         column.as_ptr(),
                         number_of_columns_to_extract,
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
             }
@@ -142,7 +131,7 @@ where
                         number_of_rows_to_extract,
                         column.as_ptr(),
                         number_of_columns_to_extract,
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
             }
@@ -160,7 +149,7 @@ where
                         number_of_rows_to_extract,
                         column,
                         number_of_columns_to_extract,
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
             }
@@ -178,7 +167,7 @@ where
                         number_of_rows_to_extract,
                         column,
                         number_of_columns_to_extract,
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
             }
@@ -195,25 +184,23 @@ where
         sub_matrix: &mut SparseMatrix<SubMatrix>,
         mask: &MatrixMask<MaskValueType, AsBool>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            self.check_arguments(
+                matrix_to_extract_from,
+                rows_to_extract,
+                columns_to_extract,
+                sub_matrix,
+            )?;
+            check_size("mask", &sub_matrix.size()?, &mask.size()?)?;
+        }
+
         let context = matrix_to_extract_from.context();
 
-        let number_of_rows_to_extract: ElementIndex;
-        match rows_to_extract {
-            ElementIndexSelector::Index(indices) => number_of_rows_to_extract = indices.len(),
-            ElementIndexSelector::All => {
-                number_of_rows_to_extract = matrix_to_extract_from.row_height()?
-            }
-        }
-        let number_of_rows_to_extract = number_of_rows_to_extract.to_graphblas_index()?;
+        let number_of_rows_to_extract =
+            rows_to_extract.graphblas_number_of_indices(matrix_to_extract_from.row_height()?)?;
 
-        let number_of_columns_to_extract: ElementIndex;
-        match columns_to_extract {
-            ElementIndexSelector::Index(indices) => number_of_columns_to_extract = indices.len(),
-            ElementIndexSelector::All => {
-                number_of_columns_to_extract = matrix_to_extract_from.column_width()?
-            }
-        }
-        let number_of_columns_to_extract = number_of_columns_to_extract.to_graphblas_index()?;
+        let number_of_columns_to_extract = columns_to_extract
+            .graphblas_number_of_indices(matrix_to_extract_from.column_width()?)?;
 
         let rows_to_extract = rows_to_extract.to_graphblas_type()?;
         let columns_to_extract = columns_to_extract.to_graphblas_type()?;
@@ -233,7 +220,7 @@ where
                         number_of_rows_to_extract,
                         column.as_ptr(),
                         number_of_columns_to_extract,
-                        self.options,
+                        mask.graphblas_descriptor(&self.options),
                     )
                 })?;
             }
@@ -251,7 +238,7 @@ where
                         number_of_rows_to_extract,
                         column.as_ptr(),
                         number_of_columns_to_extract,
-                        self.options,
+                        mask.graphblas_descriptor(&self.options),
                     )
                 })?;
             }
@@ -269,7 +256,7 @@ where
                         number_of_rows_to_extract,
                         column,
                         number_of_columns_to_extract,
-                        self.options,
+                        mask.graphblas_descriptor(&self.options),
                     )
                 })?;
             }
@@ -287,7 +274,7 @@ where
                         number_of_rows_to_extract,
                         column,
                         number_of_columns_to_extract,
-                        self.options,
+                        mask.graphblas_descriptor(&self.options),
                     )
                 })?;
             }
@@ -295,6 +282,88 @@ where
 
         Ok(())
     }
+
+    fn check_arguments(
+        &self,
+        matrix_to_extract_from: &SparseMatrix<Matrix>,
+        rows_to_extract: &ElementIndexSelector,
+        columns_to_extract: &ElementIndexSelector,
+        sub_matrix: &SparseMatrix<SubMatrix>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let size = size_of_first_argument(matrix_to_extract_from, &self.options)?;
+        check_indices("rows_to_extract", rows_to_extract, size.row_height())?;
+        check_indices(
+            "columns_to_extract",
+            columns_to_extract,
+            size.column_width(),
+        )?;
+        check_size(
+            "sub_matrix",
+            &self.sub_matrix_size(matrix_to_extract_from, rows_to_extract, columns_to_extract)?,
+            &sub_matrix.size()?,
+        )
+    }
+
+    fn sub_matrix_size(
+        &self,
+        matrix_to_extract_from: &SparseMatrix<Matrix>,
+        rows_to_extract: &ElementIndexSelector,
+        columns_to_extract: &ElementIndexSelector,
+    ) -> Result<Size, SparseLinearAlgebraError> {
+        let size = size_of_first_argument(matrix_to_extract_from, &self.options)?;
+        Ok(Size::new(
+            rows_to_extract.number_of_selected_elements(size.row_height())?,
+            columns_to_extract.number_of_selected_elements(size.column_width())?,
+        ))
+    }
+}
+
+impl<Matrix, SubMatrix> SubMatrixExtractor<Matrix, SubMatrix>
+where
+    Matrix: ValueType,
+    SubMatrix: ValueType + BuiltInValueType<SubMatrix>,
+{
+    /// Like apply(), but allocates the sub-matrix, sized by the selectors and options
+    pub fn compute(
+        &self,
+        matrix_to_extract_from: &SparseMatrix<Matrix>,
+        rows_to_extract: &ElementIndexSelector,
+        columns_to_extract: &ElementIndexSelector,
+    ) -> Result<SparseMatrix<SubMatrix>, SparseLinearAlgebraError> {
+        let mut sub_matrix = SparseMatrix::<SubMatrix>::new(
+            matrix_to_extract_from.context_ref(),
+            &self.sub_matrix_size(matrix_to_extract_from, rows_to_extract, columns_to_extract)?,
+        )?;
+        self.apply(
+            matrix_to_extract_from,
+            rows_to_extract,
+            columns_to_extract,
+            &mut sub_matrix,
+        )?;
+        Ok(sub_matrix)
+    }
+
+    /// Like apply_with_mask(), but allocates the sub-matrix, sized by the selectors and options
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        matrix_to_extract_from: &SparseMatrix<Matrix>,
+        rows_to_extract: &ElementIndexSelector,
+        columns_to_extract: &ElementIndexSelector,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+    ) -> Result<SparseMatrix<SubMatrix>, SparseLinearAlgebraError> {
+        let mut sub_matrix = SparseMatrix::<SubMatrix>::new(
+            matrix_to_extract_from.context_ref(),
+            &self.sub_matrix_size(matrix_to_extract_from, rows_to_extract, columns_to_extract)?,
+        )?;
+        self.apply_with_mask(
+            matrix_to_extract_from,
+            rows_to_extract,
+            columns_to_extract,
+            &mut sub_matrix,
+            mask,
+        )?;
+        Ok(sub_matrix)
+    }
 }
 
 #[cfg(test)]
@@ -302,7 +371,8 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator};
+    use crate::util::ElementIndex;
     use crate::value_types::sparse_matrix::{
         FromMatrixElementList, GetMatrixElementValue, MatrixElementList,
     };
@@ -333,7 +403,7 @@ mod tests {
         let columns_to_extract: Vec<ElementIndex> = (0..6).collect();
         let columns_to_extract = ElementIndexSelector::Index(&columns_to_extract);
 
-        let extractor = SubMatrixExtractor::new(&OperatorOptions::new_default(), None);
+        let extractor = SubMatrixExtractor::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         extractor
             .apply(
@@ -2,18 +2,13 @@ use std::marker::PhantomData;
 use std::ptr;
 
 use crate::error::SparseLinearAlgebraError;
-use crate::operators::{
-    binary_operator::BinaryOperator, mask::VectorMask, options::OperatorOptions,
-};
-use crate::util::{
-    ElementIndex, ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion,
-};
+use crate::operators::validation::{check_indices, check_length};
+use crate::operators::{binary_operator::Accumulator, mask::VectorMask, options::OperatorOptions};
+use crate::util::{ElementIndexSelector, ElementIndexSelectorGraphblasType};
 use crate::value_types::sparse_vector::SparseVector;
-use crate::value_types::value_type::{AsBoolean, ValueType};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
 
-use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GrB_Vector_extract,
-};
+use crate::bindings_to_graphblas_implementation::{GrB_BinaryOp, GrB_Vector_extract};
 
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
 // Code review must consider that no mtable access is provided.
@@ -52,7 +47,7 @@ where
     _product: PhantomData<Product>,
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<Vector, SubVector> SubVectorExtractor<Vector, SubVector>
@@ -62,17 +57,13 @@ where
 {
     pub fn new(
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<SubVector, SubVector, SubVector>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<SubVector, SubVector, SubVector> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _argument: PhantomData,
             _product: PhantomData,
@@ -85,16 +76,14 @@ where
         indices_to_extract: &ElementIndexSelector,
         sub_vector: &mut SparseVector<SubVector>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            self.check_arguments(vector_to_extract_from, indices_to_extract, sub_vector)?;
+        }
+
         let context = vector_to_extract_from.context();
 
-        let number_of_indices_to_extract: ElementIndex;
-        match indices_to_extract {
-            ElementIndexSelector::Index(indices) => number_of_indices_to_extract = indices.len(),
-            ElementIndexSelector::All => {
-                number_of_indices_to_extract = vector_to_extract_from.length()?
-            }
-        }
-        let number_of_indices_to_extract = number_of_indices_to_extract.to_graphblas_index()?;
+        let number_of_indices_to_extract =
+            indices_to_extract.graphblas_number_of_indices(vector_to_extract_from.length()?)?;
 
         let indices_to_extract = indices_to_extract.to_graphblas_type()?;
 
@@ -108,7 +97,7 @@ where
                         vector_to_extract_from.graphblas_vector(),
                         index.as_ptr(),
                         number_of_indices_to_extract,
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
             }
@@ -121,7 +110,7 @@ where
                         vector_to_extract_from.graphblas_vector(),
                         index,
                         number_of_indices_to_extract,
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
             }
@@ -137,16 +126,15 @@ where
         sub_vector: &mut SparseVector<SubVector>,
         mask: &VectorMask<MaskValueType, AsBool>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            self.check_arguments(vector_to_extract_from, indices_to_extract, sub_vector)?;
+            check_length("mask", sub_vector.length()?, mask.length()?)?;
+        }
+
         let context = vector_to_extract_from.context();
 
-        let number_of_indices_to_extract: ElementIndex;
-        match indices_to_extract {
-            ElementIndexSelector::Index(indices) => number_of_indices_to_extract = indices.len(),
-            ElementIndexSelector::All => {
-                number_of_indices_to_extract = vector_to_extract_from.length()?
-            }
-        }
-        let number_of_indices_to_extract = number_of_indices_to_extract.to_graphblas_index()?;
+        let number_of_indices_to_extract =
+            indices_to_extract.graphblas_number_of_indices(vector_to_extract_from.length()?)?;
 
         let indices_to_extract = indices_to_extract.to_graphblas_type()?;
 
@@ -160,7 +148,7 @@ where
                         vector_to_extract_from.graphblas_vector(),
                         index.as_ptr(),
                         number_of_indices_to_extract,
-                        self.options,
+                        mask.graphblas_descriptor(&self.options),
                     )
                 })?;
             }
@@ -173,7 +161,7 @@ where
                         vector_to_extract_from.graphblas_vector(),
                         index,
                         number_of_indices_to_extract,
-                        self.options,
+                        mask.graphblas_descriptor(&self.options),
                     )
                 })?;
             }
@@ -181,6 +169,61 @@ where
 
         Ok(())
     }
+
+    fn check_arguments(
+        &self,
+        vector_to_extract_from: &SparseVector<Vector>,
+        indices_to_extract: &ElementIndexSelector,
+        sub_vector: &SparseVector<SubVector>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let length = vector_to_extract_from.length()?;
+        check_indices("indices_to_extract", indices_to_extract, length)?;
+        check_length(
+            "sub_vector",
+            indices_to_extract.number_of_selected_elements(length)?,
+            sub_vector.length()?,
+        )
+    }
+}
+
+impl<Vector, SubVector> SubVectorExtractor<Vector, SubVector>
+where
+    Vector: ValueType,
+    SubVector: ValueType + BuiltInValueType<SubVector>,
+{
+    /// Like apply(), but allocates the sub-vector, sized by the selector
+    pub fn compute(
+        &self,
+        vector_to_extract_from: &SparseVector<Vector>,
+        indices_to_extract: &ElementIndexSelector,
+    ) -> Result<SparseVector<SubVector>, SparseLinearAlgebraError> {
+        let mut sub_vector = SparseVector::<SubVector>::new(
+            vector_to_extract_from.context_ref(),
+            &indices_to_extract.number_of_selected_elements(vector_to_extract_from.length()?)?,
+        )?;
+        self.apply(vector_to_extract_from, indices_to_extract, &mut sub_vector)?;
+        Ok(sub_vector)
+    }
+
+    /// Like apply_with_mask(), but allocates the sub-vector, sized by the selector
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        vector_to_extract_from: &SparseVector<Vector>,
+        indices_to_extract: &ElementIndexSelector,
+        mask: &VectorMask<MaskValueType, AsBool>,
+    ) -> Result<SparseVector<SubVector>, SparseLinearAlgebraError> {
+        let mut sub_vector = SparseVector::<SubVector>::new(
+            vector_to_extract_from.context_ref(),
+            &indices_to_extract.number_of_selected_elements(vector_to_extract_from.length()?)?,
+        )?;
+        self.apply_with_mask(
+            vector_to_extract_from,
+            indices_to_extract,
+            &mut sub_vector,
+            mask,
+        )?;
+        Ok(sub_vector)
+    }
 }
 
 #[cfg(test)]
@@ -188,7 +231,8 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator};
+    use crate::util::ElementIndex;
     use crate::value_types::sparse_vector::{
         FromVectorElementList, GetVectorElementValue, VectorElementList,
     };
@@ -217,7 +261,7 @@ mod tests {
         let indices_to_extract: Vec<ElementIndex> = (0..3).collect();
         let indices_to_extract = ElementIndexSelector::Index(&indices_to_extract);
 
-        let extractor = SubVectorExtractor::new(&OperatorOptions::new_default(), None);
+        let extractor = SubVectorExtractor::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         extractor
             .apply(&vector, &indices_to_extract, &mut sub_vector)
@@ -225,5 +269,55 @@ mod tests {
 
         assert_eq!(sub_vector.number_of_stored_elements().unwrap(), 2);
         assert_eq!(sub_vector.get_element_value(&2).unwrap(), 2);
+
+        extractor
+            .apply(&vector, &ElementIndexSelector::Range(2..5), &mut sub_vector)
+            .unwrap();
+        assert_eq!(sub_vector.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(sub_vector.get_element_value(&0).unwrap(), 2);
+        assert_eq!(sub_vector.get_element_value(&2).unwrap(), 4);
+
+        // 5, 3, 1
+        let sub_vector = extractor
+            .compute(&vector, &ElementIndexSelector::Backwards(1..6, 2))
+            .unwrap();
+        assert_eq!(sub_vector.length().unwrap(), 3);
+        assert_eq!(sub_vector.get_element_value(&0).unwrap(), 5);
+        assert_eq!(sub_vector.get_element_value(&2).unwrap(), 1);
+
+        // 1, 4
+        let sub_vector = extractor
+            .compute(&vector, &ElementIndexSelector::Stride(1..6, 3))
+            .unwrap();
+        assert_eq!(sub_vector.length().unwrap(), 2);
+        assert_eq!(sub_vector.get_element_value(&1).unwrap(), 4);
+    }
+
+    #[test]
+    fn test_index_validation() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let vector = SparseVector::<u8>::new(&context, &10).unwrap();
+        let mut sub_vector = SparseVector::<u8>::new(&context, &2).unwrap();
+
+        let extractor = SubVectorExtractor::new(&OperatorOptions::new_default(), &NoAccumulator);
+
+        let indices_to_extract: Vec<ElementIndex> = vec![1, 10];
+        let indices_to_extract = ElementIndexSelector::Index(&indices_to_extract);
+        match extractor.apply(&vector, &indices_to_extract, &mut sub_vector) {
+            Err(SparseLinearAlgebraError::LogicError(error)) => {
+                assert!(error.explanation().contains("indices_to_extract 10"));
+            }
+            _ => panic!("Expected an index out of bounds"),
+        }
+
+        let indices_to_extract: Vec<ElementIndex> = vec![1, 2, 3];
+        let indices_to_extract = ElementIndexSelector::Index(&indices_to_extract);
+        match extractor.apply(&vector, &indices_to_extract, &mut sub_vector) {
+            Err(SparseLinearAlgebraError::LogicError(error)) => {
+                assert!(error.explanation().contains("sub_vector"));
+            }
+            _ => panic!("Expected a dimension mismatch"),
+        }
     }
 }
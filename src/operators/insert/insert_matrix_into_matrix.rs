@@ -2,16 +2,14 @@ use std::marker::PhantomData;
 use std::ptr;
 
 use crate::error::SparseLinearAlgebraError;
-use crate::operators::{
-    binary_operator::BinaryOperator, mask::MatrixMask, options::OperatorOptions,
-};
-use crate::util::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
-use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::operators::options::size_of_first_argument;
+use crate::operators::validation::{check_indices, check_size};
+use crate::operators::{binary_operator::Accumulator, mask::MatrixMask, options::OperatorOptions};
+use crate::util::{ElementIndexSelector, ElementIndexSelectorGraphblasType};
+use crate::value_types::sparse_matrix::{Size, SparseMatrix};
 use crate::value_types::value_type::{AsBoolean, ValueType};
 
-use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GrB_Matrix_assign,
-};
+use crate::bindings_to_graphblas_implementation::{GrB_BinaryOp, GrB_Matrix_assign};
 
 // TODO: explicitly define how dupicates are handled
 
@@ -48,7 +46,7 @@ pub struct InsertMatrixIntoMatrix<MatrixToInsertInto: ValueType, MatrixToInsert:
     _matrix_to_insert: PhantomData<MatrixToInsert>,
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<MatrixToInsertInto, MatrixToInsert> InsertMatrixIntoMatrix<MatrixToInsertInto, MatrixToInsert>
@@ -58,24 +56,47 @@ where
 {
     pub fn new(
         options: &OperatorOptions,
-        accumulator: Option<
-            &dyn BinaryOperator<MatrixToInsert, MatrixToInsertInto, MatrixToInsertInto>,
-        >, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<MatrixToInsert, MatrixToInsertInto, MatrixToInsertInto>
+              + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _matrix_to_insert_into: PhantomData,
             _matrix_to_insert: PhantomData,
         }
     }
+
+    fn check_arguments(
+        &self,
+        matrix_to_insert_into: &SparseMatrix<MatrixToInsertInto>,
+        rows_to_insert_into: &ElementIndexSelector,
+        columns_to_insert_into: &ElementIndexSelector,
+        matrix_to_insert: &SparseMatrix<MatrixToInsert>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let size = matrix_to_insert_into.size()?;
+        check_indices(
+            "rows_to_insert_into",
+            rows_to_insert_into,
+            size.row_height(),
+        )?;
+        check_indices(
+            "columns_to_insert_into",
+            columns_to_insert_into,
+            size.column_width(),
+        )?;
+        check_size(
+            "matrix_to_insert",
+            &Size::new(
+                rows_to_insert_into.number_of_selected_elements(size.row_height())?,
+                columns_to_insert_into.number_of_selected_elements(size.column_width())?,
+            ),
+            &size_of_first_argument(matrix_to_insert, &self.options)?,
+        )
+    }
 }
 
 pub trait InsertMatrixIntoMatrixTrait<MatrixToInsertInto, MatrixToInsert>
@@ -125,15 +146,22 @@ macro_rules! implement_insert_matrix_into_matrix_trait {
                 columns_to_insert_into: &ElementIndexSelector, // length must equal column_width of matrix_to_insert
                 matrix_to_insert: &SparseMatrix<$value_type_matrix_to_insert>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(
+                        matrix_to_insert_into,
+                        rows_to_insert_into,
+                        columns_to_insert_into,
+                        matrix_to_insert,
+                    )?;
+                }
+
                 let context = matrix_to_insert_into.context();
 
                 let number_of_rows_to_insert_into = rows_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.row_height()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.row_height()?)?;
 
                 let number_of_columns_to_insert_into = columns_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.column_width()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.column_width()?)?;
 
                 let rows_to_insert_into = rows_to_insert_into.to_graphblas_type()?;
                 let columns_to_insert_into = columns_to_insert_into.to_graphblas_type()?;
@@ -153,7 +181,7 @@ macro_rules! implement_insert_matrix_into_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column.as_ptr(),
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -171,7 +199,7 @@ macro_rules! implement_insert_matrix_into_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column.as_ptr(),
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -189,7 +217,7 @@ macro_rules! implement_insert_matrix_into_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column,
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -207,7 +235,7 @@ macro_rules! implement_insert_matrix_into_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column,
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -225,15 +253,27 @@ macro_rules! implement_insert_matrix_into_matrix_trait {
                 matrix_to_insert: &SparseMatrix<$value_type_matrix_to_insert>,
                 mask_for_matrix_to_insert_into: &MatrixMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(
+                        matrix_to_insert_into,
+                        rows_to_insert_into,
+                        columns_to_insert_into,
+                        matrix_to_insert,
+                    )?;
+                    check_size(
+                        "mask_for_matrix_to_insert_into",
+                        &matrix_to_insert_into.size()?,
+                        &mask_for_matrix_to_insert_into.size()?,
+                    )?;
+                }
+
                 let context = matrix_to_insert_into.context();
 
                 let number_of_rows_to_insert_into = rows_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.row_height()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.row_height()?)?;
 
                 let number_of_columns_to_insert_into = columns_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.column_width()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.column_width()?)?;
 
                 let rows_to_insert_into = rows_to_insert_into.to_graphblas_type()?;
                 let columns_to_insert_into = columns_to_insert_into.to_graphblas_type()?;
@@ -253,7 +293,7 @@ macro_rules! implement_insert_matrix_into_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column.as_ptr(),
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                mask_for_matrix_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -271,7 +311,7 @@ macro_rules! implement_insert_matrix_into_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column.as_ptr(),
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                mask_for_matrix_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -289,7 +329,7 @@ macro_rules! implement_insert_matrix_into_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column,
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                mask_for_matrix_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -307,7 +347,7 @@ macro_rules! implement_insert_matrix_into_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column,
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                mask_for_matrix_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -336,7 +376,7 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator};
     use crate::util::ElementIndex;
     use crate::value_types::sparse_matrix::{
         FromMatrixElementList, GetMatrixElementValue, MatrixElementList, Size,
@@ -397,7 +437,8 @@ mod tests {
         let columns_to_insert: Vec<ElementIndex> = (0..10).collect();
         let columns_to_insert = ElementIndexSelector::Index(&columns_to_insert);
 
-        let insert_operator = InsertMatrixIntoMatrix::new(&OperatorOptions::new_default(), None);
+        let insert_operator =
+            InsertMatrixIntoMatrix::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         insert_operator
             .apply(
@@ -431,7 +472,7 @@ mod tests {
                 &rows_to_insert,
                 &columns_to_insert,
                 &matrix_to_insert,
-                &mask.into(),
+                &(&mask).into(),
             )
             .unwrap();
 
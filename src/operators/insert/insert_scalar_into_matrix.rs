@@ -2,19 +2,18 @@ use std::marker::PhantomData;
 use std::ptr;
 
 use crate::error::SparseLinearAlgebraError;
+use crate::operators::validation::{check_indices, check_size};
 // use crate::operators::BinaryOperatorType;
-use crate::operators::{
-    binary_operator::BinaryOperator, mask::MatrixMask, options::OperatorOptions,
-};
-use crate::util::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
+use crate::operators::{binary_operator::Accumulator, mask::MatrixMask, options::OperatorOptions};
+use crate::util::{ElementIndexSelector, ElementIndexSelectorGraphblasType};
 use crate::value_types::sparse_matrix::SparseMatrix;
 use crate::value_types::value_type::{AsBoolean, ValueType};
 
 use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GrB_Matrix_assign_BOOL, GrB_Matrix_assign_FP32,
-    GrB_Matrix_assign_FP64, GrB_Matrix_assign_INT16, GrB_Matrix_assign_INT32,
-    GrB_Matrix_assign_INT64, GrB_Matrix_assign_INT8, GrB_Matrix_assign_UINT16,
-    GrB_Matrix_assign_UINT32, GrB_Matrix_assign_UINT64, GrB_Matrix_assign_UINT8,
+    GrB_BinaryOp, GrB_Matrix_assign_BOOL, GrB_Matrix_assign_FP32, GrB_Matrix_assign_FP64,
+    GrB_Matrix_assign_INT16, GrB_Matrix_assign_INT32, GrB_Matrix_assign_INT64,
+    GrB_Matrix_assign_INT8, GrB_Matrix_assign_UINT16, GrB_Matrix_assign_UINT32,
+    GrB_Matrix_assign_UINT64, GrB_Matrix_assign_UINT8,
 };
 
 // TODO: explicitly define how dupicates are handled
@@ -52,7 +51,7 @@ pub struct InsertScalarIntoMatrix<MatrixToInsertInto: ValueType, ScalarToInsert:
     _scalar_to_insert: PhantomData<ScalarToInsert>,
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<MatrixToInsertInto, ScalarToInsert> InsertScalarIntoMatrix<MatrixToInsertInto, ScalarToInsert>
@@ -62,24 +61,38 @@ where
 {
     pub fn new(
         options: &OperatorOptions,
-        accumulator: Option<
-            &dyn BinaryOperator<ScalarToInsert, MatrixToInsertInto, MatrixToInsertInto>,
-        >, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<ScalarToInsert, MatrixToInsertInto, MatrixToInsertInto>
+              + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _matrix_to_insert_into: PhantomData,
             _scalar_to_insert: PhantomData,
         }
     }
+
+    fn check_arguments(
+        &self,
+        matrix_to_insert_into: &SparseMatrix<MatrixToInsertInto>,
+        rows_to_insert_into: &ElementIndexSelector,
+        columns_to_insert_into: &ElementIndexSelector,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let size = matrix_to_insert_into.size()?;
+        check_indices(
+            "rows_to_insert_into",
+            rows_to_insert_into,
+            size.row_height(),
+        )?;
+        check_indices(
+            "columns_to_insert_into",
+            columns_to_insert_into,
+            size.column_width(),
+        )
+    }
 }
 
 pub trait InsertScalarIntoMatrixTrait<MatrixToInsertInto, ScalarToInsert>
@@ -129,15 +142,21 @@ macro_rules! implement_insert_scalar_into_matrix_trait {
                 columns_to_insert_into: &ElementIndexSelector, // length must equal column_width of matrix_to_insert
                 scalar_to_insert: &$value_type_scalar_to_insert,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(
+                        matrix_to_insert_into,
+                        rows_to_insert_into,
+                        columns_to_insert_into,
+                    )?;
+                }
+
                 let context = matrix_to_insert_into.context();
 
                 let number_of_rows_to_insert_into = rows_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.row_height()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.row_height()?)?;
 
                 let number_of_columns_to_insert_into = columns_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.column_width()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.column_width()?)?;
 
                 let rows_to_insert_into = rows_to_insert_into.to_graphblas_type()?;
                 let columns_to_insert_into = columns_to_insert_into.to_graphblas_type()?;
@@ -157,7 +176,7 @@ macro_rules! implement_insert_scalar_into_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column.as_ptr(),
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -175,7 +194,7 @@ macro_rules! implement_insert_scalar_into_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column.as_ptr(),
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -193,7 +212,7 @@ macro_rules! implement_insert_scalar_into_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column,
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -211,7 +230,7 @@ macro_rules! implement_insert_scalar_into_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column,
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -229,15 +248,26 @@ macro_rules! implement_insert_scalar_into_matrix_trait {
                 scalar_to_insert: &$value_type_scalar_to_insert,
                 mask_for_matrix_to_insert_into: &MatrixMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(
+                        matrix_to_insert_into,
+                        rows_to_insert_into,
+                        columns_to_insert_into,
+                    )?;
+                    check_size(
+                        "mask_for_matrix_to_insert_into",
+                        &matrix_to_insert_into.size()?,
+                        &mask_for_matrix_to_insert_into.size()?,
+                    )?;
+                }
+
                 let context = matrix_to_insert_into.context();
 
                 let number_of_rows_to_insert_into = rows_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.row_height()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.row_height()?)?;
 
                 let number_of_columns_to_insert_into = columns_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.column_width()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.column_width()?)?;
 
                 let rows_to_insert_into = rows_to_insert_into.to_graphblas_type()?;
                 let columns_to_insert_into = columns_to_insert_into.to_graphblas_type()?;
@@ -257,7 +287,7 @@ macro_rules! implement_insert_scalar_into_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column.as_ptr(),
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                mask_for_matrix_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -275,7 +305,7 @@ macro_rules! implement_insert_scalar_into_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column.as_ptr(),
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                mask_for_matrix_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -293,7 +323,7 @@ macro_rules! implement_insert_scalar_into_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column,
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                mask_for_matrix_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -311,7 +341,7 @@ macro_rules! implement_insert_scalar_into_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column,
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                mask_for_matrix_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -340,7 +370,7 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator};
     use crate::util::ElementIndex;
     use crate::value_types::sparse_matrix::{
         FromMatrixElementList, GetMatrixElementValue, MatrixElementList, Size,
@@ -385,7 +415,8 @@ mod tests {
         let columns_to_insert: Vec<ElementIndex> = (0..6).collect();
         let columns_to_insert = ElementIndexSelector::Index(&columns_to_insert);
 
-        let insert_operator = InsertScalarIntoMatrix::new(&OperatorOptions::new_default(), None);
+        let insert_operator =
+            InsertScalarIntoMatrix::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         let scalar_to_insert: u8 = 8;
 
@@ -418,7 +449,7 @@ mod tests {
                 &rows_to_insert,
                 &columns_to_insert,
                 &scalar_to_insert,
-                &mask.into(),
+                &(&mask).into(),
             )
             .unwrap();
 
@@ -2,19 +2,18 @@ use std::marker::PhantomData;
 use std::ptr;
 
 use crate::error::SparseLinearAlgebraError;
+use crate::operators::validation::{check_indices, check_length};
 // use crate::operators::BinaryOperatorType;
-use crate::operators::{
-    binary_operator::BinaryOperator, mask::VectorMask, options::OperatorOptions,
-};
-use crate::util::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
+use crate::operators::{binary_operator::Accumulator, mask::VectorMask, options::OperatorOptions};
+use crate::util::{ElementIndexSelector, ElementIndexSelectorGraphblasType};
 use crate::value_types::sparse_vector::SparseVector;
 use crate::value_types::value_type::{AsBoolean, ValueType};
 
 use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GrB_Vector_assign_BOOL, GrB_Vector_assign_FP32,
-    GrB_Vector_assign_FP64, GrB_Vector_assign_INT16, GrB_Vector_assign_INT32,
-    GrB_Vector_assign_INT64, GrB_Vector_assign_INT8, GrB_Vector_assign_UINT16,
-    GrB_Vector_assign_UINT32, GrB_Vector_assign_UINT64, GrB_Vector_assign_UINT8,
+    GrB_BinaryOp, GrB_Vector_assign_BOOL, GrB_Vector_assign_FP32, GrB_Vector_assign_FP64,
+    GrB_Vector_assign_INT16, GrB_Vector_assign_INT32, GrB_Vector_assign_INT64,
+    GrB_Vector_assign_INT8, GrB_Vector_assign_UINT16, GrB_Vector_assign_UINT32,
+    GrB_Vector_assign_UINT64, GrB_Vector_assign_UINT8,
 };
 
 // TODO: explicitly define how dupicates are handled
@@ -52,7 +51,7 @@ pub struct InsertScalarIntoVector<VectorToInsertInto: ValueType, ScalarToInsert:
     _scalar_to_insert: PhantomData<ScalarToInsert>,
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<VectorToInsertInto, ScalarToInsert> InsertScalarIntoVector<VectorToInsertInto, ScalarToInsert>
@@ -62,24 +61,31 @@ where
 {
     pub fn new(
         options: &OperatorOptions,
-        accumulator: Option<
-            &dyn BinaryOperator<ScalarToInsert, VectorToInsertInto, VectorToInsertInto>,
-        >, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<ScalarToInsert, VectorToInsertInto, VectorToInsertInto>
+              + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _vector_to_insert_into: PhantomData,
             _scalar_to_insert: PhantomData,
         }
     }
+
+    fn check_arguments(
+        &self,
+        vector_to_insert_into: &SparseVector<VectorToInsertInto>,
+        indices_to_insert_into: &ElementIndexSelector,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        check_indices(
+            "indices_to_insert_into",
+            indices_to_insert_into,
+            vector_to_insert_into.length()?,
+        )
+    }
 }
 
 pub trait InsertScalarIntoVectorTrait<VectorToInsertInto, ScalarToInsert>
@@ -126,11 +132,14 @@ macro_rules! implement_insert_scalar_into_vector_trait {
                 indices_to_insert_into: &ElementIndexSelector,
                 scalar_to_insert: &$value_type_scalar_to_insert,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(vector_to_insert_into, indices_to_insert_into)?;
+                }
+
                 let context = vector_to_insert_into.context();
 
                 let number_of_indices_to_insert_into = indices_to_insert_into
-                    .number_of_selected_elements(vector_to_insert_into.length()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(vector_to_insert_into.length()?)?;
 
                 let indices_to_insert_into = indices_to_insert_into.to_graphblas_type()?;
 
@@ -144,7 +153,7 @@ macro_rules! implement_insert_scalar_into_vector_trait {
                                 *scalar_to_insert,
                                 index.as_ptr(),
                                 number_of_indices_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -158,7 +167,7 @@ macro_rules! implement_insert_scalar_into_vector_trait {
                                 *scalar_to_insert,
                                 index,
                                 number_of_indices_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -175,11 +184,19 @@ macro_rules! implement_insert_scalar_into_vector_trait {
                 scalar_to_insert: &$value_type_scalar_to_insert,
                 mask_for_vector_to_insert_into: &VectorMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(vector_to_insert_into, indices_to_insert_into)?;
+                    check_length(
+                        "mask_for_vector_to_insert_into",
+                        vector_to_insert_into.length()?,
+                        mask_for_vector_to_insert_into.length()?,
+                    )?;
+                }
+
                 let context = vector_to_insert_into.context();
 
                 let number_of_indices_to_insert_into = indices_to_insert_into
-                    .number_of_selected_elements(vector_to_insert_into.length()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(vector_to_insert_into.length()?)?;
 
                 let indices_to_insert_into = indices_to_insert_into.to_graphblas_type()?;
 
@@ -193,7 +210,7 @@ macro_rules! implement_insert_scalar_into_vector_trait {
                                 *scalar_to_insert,
                                 index.as_ptr(),
                                 number_of_indices_to_insert_into,
-                                self.options,
+                                mask_for_vector_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -207,7 +224,7 @@ macro_rules! implement_insert_scalar_into_vector_trait {
                                 *scalar_to_insert,
                                 index,
                                 number_of_indices_to_insert_into,
-                                self.options,
+                                mask_for_vector_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -236,7 +253,7 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator};
     use crate::util::ElementIndex;
     use crate::value_types::sparse_vector::{
         FromVectorElementList, GetVectorElementValue, VectorElementList,
@@ -279,7 +296,8 @@ mod tests {
         let indices_to_insert: Vec<ElementIndex> = (0..3).collect();
         let indices_to_insert = ElementIndexSelector::Index(&indices_to_insert);
 
-        let insert_operator = InsertScalarIntoVector::new(&OperatorOptions::new_default(), None);
+        let insert_operator =
+            InsertScalarIntoVector::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         let scalar_to_insert: u8 = 8;
 
@@ -306,7 +324,7 @@ mod tests {
                 &mut vector,
                 &indices_to_insert,
                 &scalar_to_insert,
-                &mask.into(),
+                &(&mask).into(),
             )
             .unwrap();
 
@@ -318,5 +336,26 @@ mod tests {
         assert_eq!(vector.get_element_value(&4).unwrap(), 10);
         assert_eq!(vector.get_element_value(&5).unwrap(), 11);
         assert_eq!(vector.get_element_value(&1).unwrap(), 1);
+
+        // 6, 8
+        insert_operator
+            .apply(
+                &mut vector,
+                &ElementIndexSelector::Stride(6..10, 2),
+                &scalar_to_insert,
+            )
+            .unwrap();
+        assert_eq!(vector.number_of_stored_elements().unwrap(), 6);
+        assert_eq!(vector.get_element_value(&6).unwrap(), 8);
+        assert_eq!(vector.get_element_value(&7).unwrap(), 0);
+        assert_eq!(vector.get_element_value(&8).unwrap(), 8);
+
+        assert!(insert_operator
+            .apply(
+                &mut vector,
+                &ElementIndexSelector::Range(8..11),
+                &scalar_to_insert,
+            )
+            .is_err());
     }
 }
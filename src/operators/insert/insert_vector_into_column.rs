@@ -3,9 +3,8 @@ use std::ptr;
 use std::marker::PhantomData;
 
 use crate::error::SparseLinearAlgebraError;
-use crate::operators::{
-    binary_operator::BinaryOperator, mask::VectorMask, options::OperatorOptions,
-};
+use crate::operators::validation::{check_index, check_indices, check_length};
+use crate::operators::{binary_operator::Accumulator, mask::VectorMask, options::OperatorOptions};
 use crate::util::{
     ElementIndex, ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion,
 };
@@ -13,7 +12,7 @@ use crate::value_types::sparse_matrix::SparseMatrix;
 use crate::value_types::sparse_vector::SparseVector;
 use crate::value_types::value_type::{AsBoolean, ValueType};
 
-use crate::bindings_to_graphblas_implementation::{GrB_BinaryOp, GrB_Col_assign, GrB_Descriptor};
+use crate::bindings_to_graphblas_implementation::{GrB_BinaryOp, GrB_Col_assign};
 
 // TODO: explicitly define how dupicates are handled
 
@@ -50,7 +49,7 @@ pub struct InsertVectorIntoColumn<MatrixToInsertInto: ValueType, VectorToInsert:
     _vector_to_insert: PhantomData<VectorToInsert>,
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<MatrixToInsertInto, VectorToInsert> InsertVectorIntoColumn<MatrixToInsertInto, VectorToInsert>
@@ -60,24 +59,44 @@ where
 {
     pub fn new(
         options: &OperatorOptions,
-        accumulator: Option<
-            &dyn BinaryOperator<VectorToInsert, MatrixToInsertInto, MatrixToInsertInto>,
-        >, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<VectorToInsert, MatrixToInsertInto, MatrixToInsertInto>
+              + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _matrix_to_insert_into: PhantomData,
             _vector_to_insert: PhantomData,
         }
     }
+
+    fn check_arguments(
+        &self,
+        matrix_to_insert_into: &SparseMatrix<MatrixToInsertInto>,
+        column_indices_to_insert_into: &ElementIndexSelector,
+        column_to_insert_into: &ElementIndex,
+        vector_to_insert: &SparseVector<VectorToInsert>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let size = matrix_to_insert_into.size()?;
+        check_index(
+            "column_to_insert_into",
+            *column_to_insert_into,
+            size.column_width(),
+        )?;
+        check_indices(
+            "column_indices_to_insert_into",
+            column_indices_to_insert_into,
+            size.row_height(),
+        )?;
+        check_length(
+            "vector_to_insert",
+            column_indices_to_insert_into.number_of_selected_elements(size.row_height())?,
+            vector_to_insert.length()?,
+        )
+    }
 }
 
 pub trait InsertVectorIntoColumnTrait<MatrixToInsertInto, VectorToInsert>
@@ -127,11 +146,19 @@ macro_rules! implement_insert_vector_into_column_trait {
                 column_to_insert_into: &ElementIndex,
                 vector_to_insert: &SparseVector<$value_type_vector_to_insert>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(
+                        matrix_to_insert_into,
+                        column_indices_to_insert_into,
+                        column_to_insert_into,
+                        vector_to_insert,
+                    )?;
+                }
+
                 let context = matrix_to_insert_into.context();
 
                 let number_of_indices_to_insert_into = column_indices_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.row_height()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.row_height()?)?;
 
                 let indices_to_insert_into = column_indices_to_insert_into.to_graphblas_type()?;
                 let column_to_insert_into = column_to_insert_into.to_graphblas_index()?;
@@ -147,7 +174,7 @@ macro_rules! implement_insert_vector_into_column_trait {
                                 index.as_ptr(),
                                 number_of_indices_to_insert_into,
                                 column_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -162,7 +189,7 @@ macro_rules! implement_insert_vector_into_column_trait {
                                 index,
                                 number_of_indices_to_insert_into,
                                 column_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -180,11 +207,24 @@ macro_rules! implement_insert_vector_into_column_trait {
                 vector_to_insert: &SparseVector<$value_type_vector_to_insert>,
                 mask_for_column_to_insert_into: &VectorMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(
+                        matrix_to_insert_into,
+                        column_indices_to_insert_into,
+                        column_to_insert_into,
+                        vector_to_insert,
+                    )?;
+                    check_length(
+                        "mask_for_column_to_insert_into",
+                        matrix_to_insert_into.row_height()?,
+                        mask_for_column_to_insert_into.length()?,
+                    )?;
+                }
+
                 let context = matrix_to_insert_into.context();
 
                 let number_of_indices_to_insert_into = column_indices_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.row_height()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.row_height()?)?;
 
                 let indices_to_insert_into = column_indices_to_insert_into.to_graphblas_type()?;
                 let column_to_insert_into = column_to_insert_into.to_graphblas_index()?;
@@ -200,7 +240,7 @@ macro_rules! implement_insert_vector_into_column_trait {
                                 index.as_ptr(),
                                 number_of_indices_to_insert_into,
                                 column_to_insert_into,
-                                self.options,
+                                mask_for_column_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -215,7 +255,7 @@ macro_rules! implement_insert_vector_into_column_trait {
                                 index,
                                 number_of_indices_to_insert_into,
                                 column_to_insert_into,
-                                self.options,
+                                mask_for_column_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -244,7 +284,7 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator};
 
     use crate::value_types::sparse_matrix::{
         FromMatrixElementList, GetMatrixElementValue, MatrixElementList, Size,
@@ -304,7 +344,8 @@ mod tests {
         let indices_to_insert: Vec<ElementIndex> = (0..vector_to_insert_length).collect();
         let indices_to_insert = ElementIndexSelector::Index(&indices_to_insert);
 
-        let insert_operator = InsertVectorIntoColumn::new(&OperatorOptions::new_default(), None);
+        let insert_operator =
+            InsertVectorIntoColumn::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         let column_to_insert_into: ElementIndex = 2;
 
@@ -338,7 +379,7 @@ mod tests {
                 &indices_to_insert,
                 &column_to_insert_into,
                 &vector_to_insert,
-                &mask.into(),
+                &(&mask).into(),
             )
             .unwrap();
 
@@ -3,17 +3,14 @@ use std::ptr;
 use std::marker::PhantomData;
 
 use crate::error::SparseLinearAlgebraError;
-use crate::operators::{
-    binary_operator::BinaryOperator, mask::VectorMask, options::OperatorOptions,
-};
+use crate::operators::validation::{check_indices, check_length};
+use crate::operators::{binary_operator::Accumulator, mask::VectorMask, options::OperatorOptions};
 
-use crate::util::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
+use crate::util::{ElementIndexSelector, ElementIndexSelectorGraphblasType};
 use crate::value_types::sparse_vector::SparseVector;
 use crate::value_types::value_type::{AsBoolean, ValueType};
 
-use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GrB_Vector_assign,
-};
+use crate::bindings_to_graphblas_implementation::{GrB_BinaryOp, GrB_Vector_assign};
 
 // TODO: explicitly define how dupicates are handled
 
@@ -50,7 +47,7 @@ pub struct InsertVectorIntoVector<VectorToInsertInto: ValueType, VectorToInsert:
     _vector_to_insert: PhantomData<VectorToInsert>,
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<VectorToInsertInto, VectorToInsert> InsertVectorIntoVector<VectorToInsertInto, VectorToInsert>
@@ -60,24 +57,34 @@ where
 {
     pub fn new(
         options: &OperatorOptions,
-        accumulator: Option<
-            &dyn BinaryOperator<VectorToInsert, VectorToInsertInto, VectorToInsertInto>,
-        >, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<VectorToInsert, VectorToInsertInto, VectorToInsertInto>
+              + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _vector_to_insert_into: PhantomData,
             _vector_to_insert: PhantomData,
         }
     }
+
+    fn check_arguments(
+        &self,
+        vector_to_insert_into: &SparseVector<VectorToInsertInto>,
+        indices_to_insert_into: &ElementIndexSelector,
+        vector_to_insert: &SparseVector<VectorToInsert>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let length = vector_to_insert_into.length()?;
+        check_indices("indices_to_insert_into", indices_to_insert_into, length)?;
+        check_length(
+            "vector_to_insert",
+            indices_to_insert_into.number_of_selected_elements(length)?,
+            vector_to_insert.length()?,
+        )
+    }
 }
 
 pub trait InsertVectorIntoVectorTrait<VectorToInsertInto, VectorToInsert>
@@ -124,11 +131,18 @@ macro_rules! implement_insert_vector_into_vector_trait {
                 indices_to_insert_into: &ElementIndexSelector,
                 vector_to_insert: &SparseVector<$value_type_vector_to_insert>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(
+                        vector_to_insert_into,
+                        indices_to_insert_into,
+                        vector_to_insert,
+                    )?;
+                }
+
                 let context = vector_to_insert_into.context();
 
                 let number_of_indices_to_insert_into = indices_to_insert_into
-                    .number_of_selected_elements(vector_to_insert_into.length()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(vector_to_insert_into.length()?)?;
 
                 let indices_to_insert_into = indices_to_insert_into.to_graphblas_type()?;
 
@@ -142,7 +156,7 @@ macro_rules! implement_insert_vector_into_vector_trait {
                                 vector_to_insert.graphblas_vector(),
                                 index.as_ptr(),
                                 number_of_indices_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -156,7 +170,7 @@ macro_rules! implement_insert_vector_into_vector_trait {
                                 vector_to_insert.graphblas_vector(),
                                 index,
                                 number_of_indices_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -173,11 +187,23 @@ macro_rules! implement_insert_vector_into_vector_trait {
                 vector_to_insert: &SparseVector<$value_type_vector_to_insert>,
                 mask_for_vector_to_insert_into: &VectorMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(
+                        vector_to_insert_into,
+                        indices_to_insert_into,
+                        vector_to_insert,
+                    )?;
+                    check_length(
+                        "mask_for_vector_to_insert_into",
+                        vector_to_insert_into.length()?,
+                        mask_for_vector_to_insert_into.length()?,
+                    )?;
+                }
+
                 let context = vector_to_insert_into.context();
 
                 let number_of_indices_to_insert_into = indices_to_insert_into
-                    .number_of_selected_elements(vector_to_insert_into.length()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(vector_to_insert_into.length()?)?;
 
                 let indices_to_insert_into = indices_to_insert_into.to_graphblas_type()?;
 
@@ -191,7 +217,7 @@ macro_rules! implement_insert_vector_into_vector_trait {
                                 vector_to_insert.graphblas_vector(),
                                 index.as_ptr(),
                                 number_of_indices_to_insert_into,
-                                self.options,
+                                mask_for_vector_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -205,7 +231,7 @@ macro_rules! implement_insert_vector_into_vector_trait {
                                 vector_to_insert.graphblas_vector(),
                                 index,
                                 number_of_indices_to_insert_into,
-                                self.options,
+                                mask_for_vector_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -234,7 +260,7 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator};
 
     use crate::util::ElementIndex;
     use crate::value_types::sparse_vector::{
@@ -294,7 +320,8 @@ mod tests {
         let indices_to_insert: Vec<ElementIndex> = (0..5).collect();
         let indices_to_insert = ElementIndexSelector::Index(&indices_to_insert);
 
-        let insert_operator = InsertVectorIntoVector::new(&OperatorOptions::new_default(), None);
+        let insert_operator =
+            InsertVectorIntoVector::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         insert_operator
             .apply(&mut vector, &indices_to_insert, &vector_to_insert)
@@ -321,7 +348,7 @@ mod tests {
                 &mut vector,
                 &indices_to_insert,
                 &vector_to_insert,
-                &mask.into(),
+                &(&mask).into(),
             )
             .unwrap();
 
@@ -3,15 +3,20 @@ use std::ptr;
 use std::marker::PhantomData;
 
 use crate::error::SparseLinearAlgebraError;
+use crate::operators::options::{size_of_first_argument, size_of_second_argument};
+use crate::operators::validation::check_size;
 use crate::operators::{
-    binary_operator::BinaryOperator, mask::MatrixMask, monoid::Monoid, options::OperatorOptions,
+    binary_operator::{Accumulator, BinaryOperator},
+    mask::MatrixMask,
+    monoid::Monoid,
+    options::OperatorOptions,
     semiring::Semiring,
 };
-use crate::value_types::sparse_matrix::SparseMatrix;
-use crate::value_types::value_type::{AsBoolean, ValueType};
+use crate::value_types::sparse_matrix::{Size, SparseMatrix};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
 
 use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GrB_Matrix_kronecker_BinaryOp, GrB_Matrix_kronecker_Monoid,
+    GrB_BinaryOp, GrB_Matrix_kronecker_BinaryOp, GrB_Matrix_kronecker_Monoid,
     GrB_Matrix_kronecker_Semiring, GrB_Monoid, GrB_Semiring,
 };
 
@@ -55,7 +60,7 @@ where
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     multiplication_operator: GrB_Semiring, // defines element-wise multiplication operator Multiplier.*Multiplicant
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<Multiplier, Multiplicant, Product> SemiringKroneckerProduct<Multiplier, Multiplicant, Product>
@@ -65,20 +70,16 @@ where
     Product: ValueType,
 {
     pub fn new(
-        multiplication_operator: &dyn Semiring<Multiplier, Multiplicant, Product>, // defines element-wise multiplication operator Multiplier.*Multiplicant
+        multiplication_operator: &(impl Semiring<Multiplier, Multiplicant, Product> + ?Sized), // defines element-wise multiplication operator Multiplier.*Multiplicant
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<Product, Product, Product>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<Product, Product, Product> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
             multiplication_operator: multiplication_operator.graphblas_type(),
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _multiplier: PhantomData,
             _multiplicant: PhantomData,
@@ -92,6 +93,14 @@ where
         multiplicant: &SparseMatrix<Multiplicant>,
         product: &mut SparseMatrix<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_size(
+                "product",
+                &product_size(multiplier, multiplicant, &self.options)?,
+                &product.size()?,
+            )?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -102,7 +111,7 @@ where
                 self.multiplication_operator,
                 multiplier.graphblas_matrix(),
                 multiplicant.graphblas_matrix(),
-                self.options,
+                self.options.to_graphblas_descriptor(),
             )
         })?;
 
@@ -116,6 +125,15 @@ where
         multiplicant: &SparseMatrix<Multiplicant>,
         product: &mut SparseMatrix<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_size(
+                "product",
+                &product_size(multiplier, multiplicant, &self.options)?,
+                &product.size()?,
+            )?;
+            check_size("mask", &product.size()?, &mask.size()?)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -126,7 +144,7 @@ where
                 self.multiplication_operator,
                 multiplier.graphblas_matrix(),
                 multiplicant.graphblas_matrix(),
-                self.options,
+                mask.graphblas_descriptor(&self.options),
             )
         })?;
 
@@ -134,42 +152,82 @@ where
     }
 }
 
+impl<Multiplier, Multiplicant, Product> SemiringKroneckerProduct<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType + BuiltInValueType<Product>,
+{
+    /// Like apply(), but allocates the product, sized by the arguments and options
+    pub fn compute(
+        &self,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<Product>::new(
+            multiplier.context_ref(),
+            &product_size(multiplier, multiplicant, &self.options)?,
+        )?;
+        self.apply(multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like apply_with_mask(), but allocates the product, sized by the arguments and options
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<Product>::new(
+            multiplier.context_ref(),
+            &product_size(multiplier, multiplicant, &self.options)?,
+        )?;
+        self.apply_with_mask(mask, multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct MonoidKroneckerProduct<T: ValueType> {
     _value: PhantomData<T>,
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     multiplication_operator: GrB_Monoid, // defines element-wise multiplication operator Multiplier.*Multiplicant
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<T: ValueType> MonoidKroneckerProduct<T> {
     pub fn new(
-        multiplication_operator: &dyn Monoid<T>, // defines element-wise multiplication operator Multiplier.*Multiplicant
+        multiplication_operator: &(impl Monoid<T> + ?Sized), // defines element-wise multiplication operator Multiplier.*Multiplicant
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<T, T, T>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<T, T, T> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
             multiplication_operator: multiplication_operator.graphblas_type(),
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _value: PhantomData,
         }
     }
 
-    pub fn apply<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+    pub fn apply(
         &self,
         multiplier: &SparseMatrix<T>,
         multiplicant: &SparseMatrix<T>,
         product: &mut SparseMatrix<T>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_size(
+                "product",
+                &product_size(multiplier, multiplicant, &self.options)?,
+                &product.size()?,
+            )?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -180,7 +238,7 @@ impl<T: ValueType> MonoidKroneckerProduct<T> {
                 self.multiplication_operator,
                 multiplier.graphblas_matrix(),
                 multiplicant.graphblas_matrix(),
-                self.options,
+                self.options.to_graphblas_descriptor(),
             )
         })?;
 
@@ -194,6 +252,15 @@ impl<T: ValueType> MonoidKroneckerProduct<T> {
         multiplicant: &SparseMatrix<T>,
         product: &mut SparseMatrix<T>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_size(
+                "product",
+                &product_size(multiplier, multiplicant, &self.options)?,
+                &product.size()?,
+            )?;
+            check_size("mask", &product.size()?, &mask.size()?)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -204,7 +271,7 @@ impl<T: ValueType> MonoidKroneckerProduct<T> {
                 self.multiplication_operator,
                 multiplier.graphblas_matrix(),
                 multiplicant.graphblas_matrix(),
-                self.options,
+                mask.graphblas_descriptor(&self.options),
             )
         })?;
 
@@ -212,6 +279,37 @@ impl<T: ValueType> MonoidKroneckerProduct<T> {
     }
 }
 
+impl<T: ValueType + BuiltInValueType<T>> MonoidKroneckerProduct<T> {
+    /// Like apply(), but allocates the product, sized by the arguments and options
+    pub fn compute(
+        &self,
+        multiplier: &SparseMatrix<T>,
+        multiplicant: &SparseMatrix<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<T>::new(
+            multiplier.context_ref(),
+            &product_size(multiplier, multiplicant, &self.options)?,
+        )?;
+        self.apply(multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like apply_with_mask(), but allocates the product, sized by the arguments and options
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+        multiplier: &SparseMatrix<T>,
+        multiplicant: &SparseMatrix<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<T>::new(
+            multiplier.context_ref(),
+            &product_size(multiplier, multiplicant, &self.options)?,
+        )?;
+        self.apply_with_mask(mask, multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BinaryOperatorKroneckerProductOperator<Multiplier, Multiplicant, Product> {
     _multiplier: PhantomData<Multiplier>,
@@ -220,7 +318,7 @@ pub struct BinaryOperatorKroneckerProductOperator<Multiplier, Multiplicant, Prod
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     multiplication_operator: GrB_BinaryOp, // defines element-wise multiplication operator Multiplier.*Multiplicant
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<Multiplier, Multiplicant, Product>
@@ -231,20 +329,16 @@ where
     Product: ValueType,
 {
     pub fn new(
-        multiplication_operator: &dyn BinaryOperator<Multiplier, Multiplicant, Product>, // defines element-wise multiplication operator Multiplier.*Multiplicant
+        multiplication_operator: &(impl BinaryOperator<Multiplier, Multiplicant, Product> + ?Sized), // defines element-wise multiplication operator Multiplier.*Multiplicant
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<Product, Product, Product>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<Product, Product, Product> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
             multiplication_operator: multiplication_operator.graphblas_type(),
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _multiplier: PhantomData,
             _multiplicant: PhantomData,
@@ -258,6 +352,14 @@ where
         multiplicant: &SparseMatrix<Multiplicant>,
         product: &mut SparseMatrix<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_size(
+                "product",
+                &product_size(multiplier, multiplicant, &self.options)?,
+                &product.size()?,
+            )?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -268,7 +370,7 @@ where
                 self.multiplication_operator,
                 multiplier.graphblas_matrix(),
                 multiplicant.graphblas_matrix(),
-                self.options,
+                self.options.to_graphblas_descriptor(),
             )
         })?;
 
@@ -282,6 +384,15 @@ where
         multiplicant: &SparseMatrix<Multiplicant>,
         product: &mut SparseMatrix<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_size(
+                "product",
+                &product_size(multiplier, multiplicant, &self.options)?,
+                &product.size()?,
+            )?;
+            check_size("mask", &product.size()?, &mask.size()?)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -292,7 +403,7 @@ where
                 self.multiplication_operator,
                 multiplier.graphblas_matrix(),
                 multiplicant.graphblas_matrix(),
-                self.options,
+                mask.graphblas_descriptor(&self.options),
             )
         })?;
 
@@ -300,12 +411,62 @@ where
     }
 }
 
+impl<Multiplier, Multiplicant, Product>
+    BinaryOperatorKroneckerProductOperator<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType + BuiltInValueType<Product>,
+{
+    /// Like apply(), but allocates the product, sized by the arguments and options
+    pub fn compute(
+        &self,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<Product>::new(
+            multiplier.context_ref(),
+            &product_size(multiplier, multiplicant, &self.options)?,
+        )?;
+        self.apply(multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like apply_with_mask(), but allocates the product, sized by the arguments and options
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<Product>::new(
+            multiplier.context_ref(),
+            &product_size(multiplier, multiplicant, &self.options)?,
+        )?;
+        self.apply_with_mask(mask, multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+}
+
+fn product_size<Multiplier: ValueType, Multiplicant: ValueType>(
+    multiplier: &SparseMatrix<Multiplier>,
+    multiplicant: &SparseMatrix<Multiplicant>,
+    options: &OperatorOptions,
+) -> Result<Size, SparseLinearAlgebraError> {
+    let multiplier_size = size_of_first_argument(multiplier, options)?;
+    let multiplicant_size = size_of_second_argument(multiplicant, options)?;
+    Ok(Size::new(
+        multiplier_size.row_height() * multiplicant_size.row_height(),
+        multiplier_size.column_width() * multiplicant_size.column_width(),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::{First, Times};
+    use crate::operators::binary_operator::{First, NoAccumulator, Times};
 
     use crate::value_types::sparse_matrix::{
         FromMatrixElementList, GetMatrixElementList, GetMatrixElementValue, MatrixElementList, Size,
@@ -316,7 +477,11 @@ mod tests {
         let operator = Times::<i64, i64, i64>::new();
         let options = OperatorOptions::new_default();
         let _element_wise_matrix_multiplier =
-            BinaryOperatorKroneckerProductOperator::<i64, i64, i64>::new(&operator, &options, None);
+            BinaryOperatorKroneckerProductOperator::<i64, i64, i64>::new(
+                &operator,
+                &options,
+                &NoAccumulator,
+            );
 
         let _context = Context::init_ready(Mode::NonBlocking).unwrap();
 
@@ -329,7 +494,7 @@ mod tests {
         let _matrix_multiplier = BinaryOperatorKroneckerProductOperator::<i64, i64, i64>::new(
             &operator,
             &options,
-            Some(&accumulator),
+            &accumulator,
         );
     }
 
@@ -340,7 +505,11 @@ mod tests {
         let operator = Times::<i32, i32, i32>::new();
         let options = OperatorOptions::new_default();
         let element_wise_matrix_multiplier =
-            BinaryOperatorKroneckerProductOperator::<i32, i32, i32>::new(&operator, &options, None);
+            BinaryOperatorKroneckerProductOperator::<i32, i32, i32>::new(
+                &operator,
+                &options,
+                &NoAccumulator,
+            );
 
         let height = 2;
         let width = 2;
@@ -453,7 +622,7 @@ mod tests {
         // let mut product = SparseMatrix::<i32>::new(&context, &size).unwrap();
 
         // matrix_multiplier
-        //     .apply_with_mask(&mask.into(), &multiplier, &multiplicant, &mut product)
+        //     .apply_with_mask(&(&mask).into(), &multiplier, &multiplicant, &mut product)
         //     .unwrap();
 
         // assert_eq!(product.get_element_value((0, 0).into()).unwrap(), 5);
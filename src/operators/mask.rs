@@ -2,32 +2,88 @@ use std::convert::From;
 use std::marker::PhantomData;
 use std::ops::Deref;
 
+use crate::bindings_to_graphblas_implementation::GrB_Descriptor;
+use crate::operators::options::OperatorOptions;
 use crate::value_types::sparse_matrix::SparseMatrix;
 use crate::value_types::sparse_vector::SparseVector;
 use crate::value_types::value_type::{AsBoolean, ValueType};
 
-pub struct MatrixMask<T, MaskValueType>
+/// Borrows a matrix to mask the output of an operation.
+///
+/// By default, the mask is valued: the output is written where the mask stores a value
+/// that is true when cast to boolean. structure() makes the mask structural,
+/// such that any stored value selects the output, and complement() inverts the selection.
+/// The descriptor of the operation is derived from these settings.
+pub struct MatrixMask<'a, T, MaskValueType>
 where
     // T: ValueType,
     MaskValueType: AsBoolean<T>,
 {
-    matrix: SparseMatrix<MaskValueType>,
+    matrix: &'a SparseMatrix<MaskValueType>,
+    use_structure: bool,
+    use_complement: bool,
 
     _mask_value_type: PhantomData<T>,
 }
 
-impl<T: ValueType, MaskValueType: AsBoolean<T>> From<SparseMatrix<MaskValueType>>
-    for MatrixMask<T, MaskValueType>
-{
-    fn from(matrix: SparseMatrix<MaskValueType>) -> Self {
+impl<'a, T: ValueType, MaskValueType: AsBoolean<T>> MatrixMask<'a, T, MaskValueType> {
+    pub fn new(matrix: &'a SparseMatrix<MaskValueType>) -> Self {
         Self {
             matrix,
+            use_structure: false,
+            use_complement: false,
             _mask_value_type: PhantomData,
         }
     }
 }
 
-impl<T, MaskValueType> Deref for MatrixMask<T, MaskValueType>
+impl<'a, T, MaskValueType: AsBoolean<T>> MatrixMask<'a, T, MaskValueType> {
+    /// Selects the output by the stored elements of the mask, ignoring their values
+    pub fn structure(self) -> Self {
+        Self {
+            use_structure: true,
+            ..self
+        }
+    }
+
+    /// Inverts the selection of the mask
+    pub fn complement(self) -> Self {
+        Self {
+            use_complement: !self.use_complement,
+            ..self
+        }
+    }
+
+    pub fn is_structural(&self) -> bool {
+        self.use_structure
+    }
+
+    pub fn is_complemented(&self) -> bool {
+        self.use_complement
+    }
+
+    pub(crate) fn graphblas_descriptor(&self, options: &OperatorOptions) -> GrB_Descriptor {
+        options.to_graphblas_descriptor_with_mask(self.use_structure, self.use_complement)
+    }
+}
+
+impl<'a, T: ValueType, MaskValueType: AsBoolean<T>> From<&'a SparseMatrix<MaskValueType>>
+    for MatrixMask<'a, T, MaskValueType>
+{
+    fn from(matrix: &'a SparseMatrix<MaskValueType>) -> Self {
+        Self::new(matrix)
+    }
+}
+
+impl<'a, T, MaskValueType: AsBoolean<T>> Clone for MatrixMask<'a, T, MaskValueType> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, MaskValueType: AsBoolean<T>> Copy for MatrixMask<'a, T, MaskValueType> {}
+
+impl<'a, T, MaskValueType> Deref for MatrixMask<'a, T, MaskValueType>
 where
     // T: ValueType,
     MaskValueType: AsBoolean<T>,
@@ -35,32 +91,81 @@ where
     type Target = SparseMatrix<MaskValueType>;
 
     fn deref(&self) -> &Self::Target {
-        &self.matrix
+        self.matrix
     }
 }
 
-pub struct VectorMask<T, MaskValueType>
+/// Borrows a vector to mask the output of an operation, see MatrixMask
+pub struct VectorMask<'a, T, MaskValueType>
 where
     // T: ValueType,
     MaskValueType: AsBoolean<T>,
 {
-    vector: SparseVector<MaskValueType>,
+    vector: &'a SparseVector<MaskValueType>,
+    use_structure: bool,
+    use_complement: bool,
 
     _mask_value_type: PhantomData<T>,
 }
 
-impl<T: ValueType, MaskValueType: AsBoolean<T>> From<SparseVector<MaskValueType>>
-    for VectorMask<T, MaskValueType>
-{
-    fn from(vector: SparseVector<MaskValueType>) -> Self {
+impl<'a, T: ValueType, MaskValueType: AsBoolean<T>> VectorMask<'a, T, MaskValueType> {
+    pub fn new(vector: &'a SparseVector<MaskValueType>) -> Self {
         Self {
             vector,
+            use_structure: false,
+            use_complement: false,
             _mask_value_type: PhantomData,
         }
     }
 }
 
-impl<T, MaskValueType> Deref for VectorMask<T, MaskValueType>
+impl<'a, T, MaskValueType: AsBoolean<T>> VectorMask<'a, T, MaskValueType> {
+    /// Selects the output by the stored elements of the mask, ignoring their values
+    pub fn structure(self) -> Self {
+        Self {
+            use_structure: true,
+            ..self
+        }
+    }
+
+    /// Inverts the selection of the mask
+    pub fn complement(self) -> Self {
+        Self {
+            use_complement: !self.use_complement,
+            ..self
+        }
+    }
+
+    pub fn is_structural(&self) -> bool {
+        self.use_structure
+    }
+
+    pub fn is_complemented(&self) -> bool {
+        self.use_complement
+    }
+
+    pub(crate) fn graphblas_descriptor(&self, options: &OperatorOptions) -> GrB_Descriptor {
+        options.to_graphblas_descriptor_with_mask(self.use_structure, self.use_complement)
+    }
+}
+
+impl<'a, T: ValueType, MaskValueType: AsBoolean<T>> From<&'a SparseVector<MaskValueType>>
+    for VectorMask<'a, T, MaskValueType>
+{
+    fn from(vector: &'a SparseVector<MaskValueType>) -> Self {
+        Self::new(vector)
+    }
+}
+
+impl<'a, T, MaskValueType: AsBoolean<T>> Clone for VectorMask<'a, T, MaskValueType> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T, MaskValueType: AsBoolean<T>> Copy for VectorMask<'a, T, MaskValueType> {}
+
+impl<'a, T, MaskValueType> Deref for VectorMask<'a, T, MaskValueType>
 where
     // T: ValueType,
     MaskValueType: AsBoolean<T>,
@@ -68,6 +173,41 @@ where
     type Target = SparseVector<MaskValueType>;
 
     fn deref(&self) -> &Self::Target {
-        &self.vector
+        self.vector
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::context::{Context, Mode};
+    use crate::value_types::sparse_matrix::Size;
+
+    #[test]
+    fn test_mask_combinators() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+        let matrix = SparseMatrix::<bool>::new(&context, &Size::new(2, 2)).unwrap();
+
+        let mask: MatrixMask<bool, bool> = MatrixMask::new(&matrix);
+        assert!(!mask.is_structural());
+        assert!(!mask.is_complemented());
+
+        let structural_complement = mask.structure().complement();
+        assert!(structural_complement.is_structural());
+        assert!(structural_complement.is_complemented());
+        assert!(!structural_complement.complement().is_complemented());
+        // the combinators copy the mask, the borrowed matrix is shared
+        assert!(!mask.is_structural());
+
+        let options = OperatorOptions::new_default();
+        assert_eq!(
+            structural_complement.graphblas_descriptor(&options),
+            options.to_graphblas_descriptor_with_mask(true, true)
+        );
+        assert_ne!(
+            mask.graphblas_descriptor(&options),
+            structural_complement.graphblas_descriptor(&options)
+        );
     }
 }
@@ -8,6 +8,7 @@ pub mod kronecker_product;
 pub mod mask;
 pub mod monoid;
 pub mod multiplication;
+pub mod ops;
 pub mod options;
 pub mod reduce;
 pub mod select;
@@ -15,6 +16,8 @@ pub mod semiring;
 pub mod subinsert;
 pub mod transpose;
 pub mod unary_operator;
+mod sparse_scalar_operand;
+pub(crate) mod validation;
 
 // pub use apply::{
 //     BinaryOperatorApplier, BinaryOperatorApplierTrait, UnaryOperatorApplier,
@@ -1,3 +1,5 @@
 mod monoid;
 
-pub use monoid::{Any, Equal, LogicalAnd, LogicalExclusiveOr, LogicalOr, Monoid, Plus, Times};
+pub use monoid::{
+    Any, Equal, LogicalAnd, LogicalExclusiveOr, LogicalOr, Max, Min, Monoid, Plus, Times,
+};
@@ -23,7 +23,7 @@ macro_rules! implement_monoid_operator {
         }
 
         impl $operator_name<$value_type> {
-            pub fn new() -> Self {
+            pub const fn new() -> Self {
                 $operator_name {
                     _value_type: PhantomData,
                 }
@@ -32,7 +32,7 @@ macro_rules! implement_monoid_operator {
     };
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct Min<T: ValueType> {
     _value_type: PhantomData<T>,
 }
@@ -48,7 +48,7 @@ implement_monoid_operator!(Min, GrB_MIN_MONOID_UINT64, u64);
 implement_monoid_operator!(Min, GrB_MIN_MONOID_FP32, f32);
 implement_monoid_operator!(Min, GrB_MIN_MONOID_FP64, f64);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct Max<T: ValueType> {
     _value_type: PhantomData<T>,
 }
@@ -64,7 +64,7 @@ implement_monoid_operator!(Max, GrB_MAX_MONOID_UINT64, u64);
 implement_monoid_operator!(Max, GrB_MAX_MONOID_FP32, f32);
 implement_monoid_operator!(Max, GrB_MAX_MONOID_FP64, f64);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct Plus<T: ValueType> {
     _value_type: PhantomData<T>,
 }
@@ -80,7 +80,7 @@ implement_monoid_operator!(Plus, GrB_PLUS_MONOID_UINT64, u64);
 implement_monoid_operator!(Plus, GrB_PLUS_MONOID_FP32, f32);
 implement_monoid_operator!(Plus, GrB_PLUS_MONOID_FP64, f64);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct Times<T: ValueType> {
     _value_type: PhantomData<T>,
 }
@@ -96,7 +96,7 @@ implement_monoid_operator!(Times, GrB_TIMES_MONOID_UINT64, u64);
 implement_monoid_operator!(Times, GrB_TIMES_MONOID_FP32, f32);
 implement_monoid_operator!(Times, GrB_TIMES_MONOID_FP64, f64);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct Any<T: ValueType> {
     _value_type: PhantomData<T>,
 }
@@ -113,22 +113,22 @@ implement_monoid_operator!(Any, GxB_ANY_UINT64_MONOID, u64);
 implement_monoid_operator!(Any, GxB_ANY_FP32_MONOID, f32);
 implement_monoid_operator!(Any, GxB_ANY_FP64_MONOID, f64);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct LogicalOr<T: ValueType> {
     _value_type: PhantomData<T>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct LogicalAnd<T: ValueType> {
     _value_type: PhantomData<T>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct LogicalExclusiveOr<T: ValueType> {
     _value_type: PhantomData<T>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct Equal<T: ValueType> {
     _value_type: PhantomData<T>,
 }
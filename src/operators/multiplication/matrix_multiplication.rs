@@ -3,15 +3,15 @@ use std::ptr;
 use std::marker::PhantomData;
 
 use crate::error::SparseLinearAlgebraError;
-use crate::operators::binary_operator::BinaryOperator;
+use crate::operators::binary_operator::Accumulator;
+use crate::operators::options::{size_of_first_argument, size_of_second_argument};
 use crate::operators::semiring::Semiring;
+use crate::operators::validation::check_size;
 use crate::operators::{mask::MatrixMask, options::OperatorOptions};
-use crate::value_types::sparse_matrix::SparseMatrix;
-use crate::value_types::value_type::{AsBoolean, ValueType};
+use crate::value_types::sparse_matrix::{Size, SparseMatrix};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
 
-use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GrB_Semiring, GrB_mxm,
-};
+use crate::bindings_to_graphblas_implementation::{GrB_BinaryOp, GrB_Semiring, GrB_mxm};
 
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
 // Code review must consider that no mtable access is provided.
@@ -40,7 +40,6 @@ unsafe impl Sync for MatrixMultiplicationOperator<i64, i64, i64> {}
 unsafe impl Sync for MatrixMultiplicationOperator<f32, f32, f32> {}
 unsafe impl Sync for MatrixMultiplicationOperator<f64, f64, f64> {}
 
-// TODO: review the use of &'a dyn Trait, removing dynamic dispatch could provide a performance gain. (it might be negated if cloning is necessary though)
 // https://www.joshmcguigan.com/blog/cost-of-indirection-rust/
 #[derive(Debug, Clone)]
 pub struct MatrixMultiplicationOperator<Multiplier, Multiplicant, Product>
@@ -57,7 +56,7 @@ where
     // mask: GrB_Matrix,
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     semiring: GrB_Semiring,    // defines '+' and '*' for A*B (not optional for GrB_mxm)
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<Multiplier, Multiplicant, Product>
@@ -68,20 +67,16 @@ where
     Product: ValueType,
 {
     pub fn new(
-        semiring: &dyn Semiring<Multiplier, Multiplicant, Product>, // defines '+' and '*' for A*B (not optional for GrB_mxm)
+        semiring: &(impl Semiring<Multiplier, Multiplicant, Product> + ?Sized), // defines '+' and '*' for A*B (not optional for GrB_mxm)
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<Product, Product, Product>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<Product, Product, Product> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
             semiring: semiring.graphblas_type(),
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _multiplier: PhantomData,
             _multiplicant: PhantomData,
@@ -89,13 +84,16 @@ where
         }
     }
 
-    // TODO: consider a version where the resulting product matrix is generated in the function body
     pub fn apply(
         &self,
         multiplier: &SparseMatrix<Multiplier>,
         multiplicant: &SparseMatrix<Multiplicant>,
         product: &mut SparseMatrix<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            self.check_arguments(multiplier, multiplicant, product)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -106,7 +104,7 @@ where
                 self.semiring,
                 multiplier.graphblas_matrix(),
                 multiplicant.graphblas_matrix(),
-                self.options,
+                self.options.to_graphblas_descriptor(),
             )
         })?;
 
@@ -120,6 +118,11 @@ where
         multiplicant: &SparseMatrix<Multiplicant>,
         product: &mut SparseMatrix<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            self.check_arguments(multiplier, multiplicant, product)?;
+            check_size("mask", &product.size()?, &mask.size()?)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -130,12 +133,86 @@ where
                 self.semiring,
                 multiplier.graphblas_matrix(),
                 multiplicant.graphblas_matrix(),
-                self.options,
+                mask.graphblas_descriptor(&self.options),
             )
         })?;
 
         Ok(())
     }
+
+    fn check_arguments(
+        &self,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+        product: &SparseMatrix<Product>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let multiplier_size = size_of_first_argument(multiplier, &self.options)?;
+        let multiplicant_size = size_of_second_argument(multiplicant, &self.options)?;
+        check_size(
+            "multiplicant",
+            &Size::new(
+                multiplier_size.column_width(),
+                multiplicant_size.column_width(),
+            ),
+            &multiplicant_size,
+        )?;
+        check_size(
+            "product",
+            &Size::new(
+                multiplier_size.row_height(),
+                multiplicant_size.column_width(),
+            ),
+            &product.size()?,
+        )
+    }
+}
+
+impl<Multiplier, Multiplicant, Product>
+    MatrixMultiplicationOperator<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType + BuiltInValueType<Product>,
+{
+    /// Like apply(), but allocates the product, sized by the arguments and options
+    pub fn compute(
+        &self,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply(multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like apply_with_mask(), but allocates the product, sized by the arguments and options
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseMatrix::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply_with_mask(mask, multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    fn product_size(
+        &self,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<Size, SparseLinearAlgebraError> {
+        Ok(Size::new(
+            size_of_first_argument(multiplier, &self.options)?.row_height(),
+            size_of_second_argument(multiplicant, &self.options)?.column_width(),
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -143,8 +220,9 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
+    use crate::error::{GraphBlasErrorType, LogicErrorType};
     use crate::operators::binary_operator::First;
-    use crate::operators::binary_operator::{Plus, Times};
+    use crate::operators::binary_operator::{NoAccumulator, Plus, Times};
     use crate::operators::semiring::PlusTimes;
     use crate::value_types::sparse_matrix::{
         FromMatrixElementList, GetMatrixElementList, GetMatrixElementValue, MatrixElementList, Size,
@@ -155,7 +233,7 @@ mod tests {
         let semiring = PlusTimes::<i64, i64, i64>::new();
         let options = OperatorOptions::new_default();
         let _matrix_multiplier =
-            MatrixMultiplicationOperator::<i64, i64, i64>::new(&semiring, &options, None);
+            MatrixMultiplicationOperator::<i64, i64, i64>::new(&semiring, &options, &NoAccumulator);
 
         let context = Context::init_ready(Mode::NonBlocking).unwrap();
 
@@ -167,11 +245,8 @@ mod tests {
 
         let accumulator = Times::<i64, i64, i64>::new();
 
-        let _matrix_multiplier = MatrixMultiplicationOperator::<i64, i64, i64>::new(
-            &semiring,
-            &options,
-            Some(&accumulator),
-        );
+        let _matrix_multiplier =
+            MatrixMultiplicationOperator::<i64, i64, i64>::new(&semiring, &options, &accumulator);
     }
 
     #[test]
@@ -181,7 +256,7 @@ mod tests {
         let semiring = PlusTimes::<f32, f32, f32>::new();
         let options = OperatorOptions::new_default();
         let matrix_multiplier =
-            MatrixMultiplicationOperator::<f32, f32, f32>::new(&semiring, &options, None);
+            MatrixMultiplicationOperator::<f32, f32, f32>::new(&semiring, &options, &NoAccumulator);
 
         let height = 2;
         let width = 2;
@@ -252,11 +327,8 @@ mod tests {
 
         // test the use of an accumulator
         let accumulator = Plus::<f32, f32, f32>::new();
-        let matrix_multiplier_with_accumulator = MatrixMultiplicationOperator::<f32, f32, f32>::new(
-            &semiring,
-            &options,
-            Some(&accumulator),
-        );
+        let matrix_multiplier_with_accumulator =
+            MatrixMultiplicationOperator::<f32, f32, f32>::new(&semiring, &options, &accumulator);
 
         matrix_multiplier_with_accumulator
             .apply(&multiplier, &multiplicant, &mut product)
@@ -282,12 +354,12 @@ mod tests {
         .unwrap();
 
         let matrix_multiplier =
-            MatrixMultiplicationOperator::<f32, f32, f32>::new(&semiring, &options, None);
+            MatrixMultiplicationOperator::<f32, f32, f32>::new(&semiring, &options, &NoAccumulator);
 
         let mut product = SparseMatrix::<f32>::new(&context, &size).unwrap();
 
         matrix_multiplier
-            .apply_with_mask(&mask.into(), &multiplier, &multiplicant, &mut product)
+            .apply_with_mask(&(&mask).into(), &multiplier, &multiplicant, &mut product)
             .unwrap();
 
         assert_eq!(product.get_element_value(&(0, 0).into()).unwrap(), 23.);
@@ -295,4 +367,104 @@ mod tests {
         assert_eq!(product.get_element_value(&(0, 1).into()).unwrap(), 0.);
         assert_eq!(product.get_element_value(&(1, 1).into()).unwrap(), 46.);
     }
+
+    #[test]
+    fn test_compute_with_transposed_multiplier() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let semiring = PlusTimes::<f32, f32, f32>::new();
+        let options = OperatorOptions::new(false, true, false);
+        let matrix_multiplier =
+            MatrixMultiplicationOperator::<f32, f32, f32>::new(&semiring, &options, &NoAccumulator);
+
+        // 3x2, transposed to 2x3
+        let multiplier = SparseMatrix::<f32>::from_element_list(
+            &context,
+            &(3, 2).into(),
+            &MatrixElementList::<f32>::from_element_vector(vec![
+                (0, 0, 1.0).into(),
+                (2, 1, 2.0).into(),
+            ]),
+            &First::<f32, f32, f32>::new(),
+        )
+        .unwrap();
+
+        // 3x4
+        let multiplicant = SparseMatrix::<f32>::from_element_list(
+            &context,
+            &(3, 4).into(),
+            &MatrixElementList::<f32>::from_element_vector(vec![
+                (0, 3, 3.0).into(),
+                (2, 0, 4.0).into(),
+            ]),
+            &First::<f32, f32, f32>::new(),
+        )
+        .unwrap();
+
+        let product = matrix_multiplier
+            .compute(&multiplier, &multiplicant)
+            .unwrap();
+
+        assert_eq!(product.row_height().unwrap(), 2);
+        assert_eq!(product.column_width().unwrap(), 4);
+        assert_eq!(product.get_element_value(&(0, 3).into()).unwrap(), 3.0);
+        assert_eq!(product.get_element_value(&(1, 0).into()).unwrap(), 8.0);
+    }
+
+    #[test]
+    fn test_argument_validation() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let semiring = PlusTimes::<f32, f32, f32>::new();
+        let options = OperatorOptions::new_default();
+        let matrix_multiplier =
+            MatrixMultiplicationOperator::<f32, f32, f32>::new(&semiring, &options, &NoAccumulator);
+
+        let multiplier = SparseMatrix::<f32>::new(&context, &(2, 3).into()).unwrap();
+        let multiplicant = SparseMatrix::<f32>::new(&context, &(2, 4).into()).unwrap();
+        let mut product = SparseMatrix::<f32>::new(&context, &(2, 4).into()).unwrap();
+
+        match matrix_multiplier.apply(&multiplier, &multiplicant, &mut product) {
+            Err(SparseLinearAlgebraError::LogicError(error)) => {
+                assert_eq!(
+                    error.error_type(),
+                    LogicErrorType::GraphBlas(GraphBlasErrorType::DimensionMismatch)
+                );
+                assert!(error.explanation().contains("multiplicant"));
+            }
+            _ => panic!("Expected a dimension mismatch"),
+        }
+
+        // The transpose flag is taken into account
+        let options = OperatorOptions::new(false, false, true);
+        let matrix_multiplier =
+            MatrixMultiplicationOperator::<f32, f32, f32>::new(&semiring, &options, &NoAccumulator);
+        let multiplicant = SparseMatrix::<f32>::new(&context, &(4, 3).into()).unwrap();
+        matrix_multiplier
+            .apply(&multiplier, &multiplicant, &mut product)
+            .unwrap();
+
+        let mask = SparseMatrix::<bool>::new(&context, &(4, 2).into()).unwrap();
+        match matrix_multiplier.apply_with_mask(
+            &(&mask).into(),
+            &multiplier,
+            &multiplicant,
+            &mut product,
+        ) {
+            Err(SparseLinearAlgebraError::LogicError(error)) => {
+                assert!(error.explanation().contains("mask"));
+            }
+            _ => panic!("Expected a dimension mismatch"),
+        }
+
+        // GraphBLAS still rejects the arguments when validation is disabled
+        let matrix_multiplier = MatrixMultiplicationOperator::<f32, f32, f32>::new(
+            &semiring,
+            &OperatorOptions::new_default().with_argument_validation(false),
+            &NoAccumulator,
+        );
+        assert!(matrix_multiplier
+            .apply(&multiplier, &multiplicant, &mut product)
+            .is_err());
+    }
 }
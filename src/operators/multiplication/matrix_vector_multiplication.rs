@@ -3,16 +3,17 @@ use std::ptr;
 use std::marker::PhantomData;
 
 use crate::error::SparseLinearAlgebraError;
-use crate::operators::binary_operator::BinaryOperator;
+use crate::operators::binary_operator::Accumulator;
+use crate::operators::options::size_of_first_argument;
 use crate::operators::semiring::Semiring;
+use crate::operators::validation::check_length;
 use crate::operators::{mask::VectorMask, options::OperatorOptions};
+use crate::util::ElementIndex;
 use crate::value_types::sparse_matrix::SparseMatrix;
 use crate::value_types::sparse_vector::SparseVector;
-use crate::value_types::value_type::{AsBoolean, ValueType};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
 
-use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GrB_Semiring, GrB_mxv,
-};
+use crate::bindings_to_graphblas_implementation::{GrB_BinaryOp, GrB_Semiring, GrB_mxv};
 
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
 // Code review must consider that no mtable access is provided.
@@ -41,7 +42,6 @@ unsafe impl Sync for MatrixVectorMultiplicationOperator<i64, i64, i64> {}
 unsafe impl Sync for MatrixVectorMultiplicationOperator<f32, f32, f32> {}
 unsafe impl Sync for MatrixVectorMultiplicationOperator<f64, f64, f64> {}
 
-// TODO: review the use of &'a dyn Trait, removing dynamic dispatch could provide a performance gain. (it might be negated if cloning is necessary though)
 // https://www.joshmcguigan.com/blog/cost-of-indirection-rust/
 #[derive(Debug, Clone)]
 pub struct MatrixVectorMultiplicationOperator<Multiplier, Multiplicant, Product>
@@ -56,7 +56,7 @@ where
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     semiring: GrB_Semiring,    // defines '+' and '*' for A*B (not optional for GrB_mxm)
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<Multiplier, Multiplicant, Product>
@@ -67,20 +67,16 @@ where
     Product: ValueType,
 {
     pub fn new(
-        semiring: &dyn Semiring<Multiplier, Multiplicant, Product>, // defines '+' and '*' for A*B (not optional for GrB_mxm)
+        semiring: &(impl Semiring<Multiplier, Multiplicant, Product> + ?Sized), // defines '+' and '*' for A*B (not optional for GrB_mxm)
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<Product, Product, Product>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<Product, Product, Product> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
             semiring: semiring.graphblas_type(),
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _multiplier: PhantomData,
             _multiplicant: PhantomData,
@@ -88,7 +84,6 @@ where
         }
     }
 
-    // TODO: consider a version where the resulting product matrix is generated in the function body
     pub fn apply(
         &self,
         // mask: Option<&SparseMatrix<AsBoolean<ValueType>>>,
@@ -96,6 +91,10 @@ where
         multiplicant: &SparseVector<Multiplicant>,
         product: &mut SparseVector<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            self.check_arguments(multiplier, multiplicant, product)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -106,7 +105,7 @@ where
                 self.semiring,
                 multiplier.graphblas_matrix(),
                 multiplicant.graphblas_vector(),
-                self.options,
+                self.options.to_graphblas_descriptor(),
             )
         })?;
 
@@ -120,6 +119,11 @@ where
         multiplicant: &SparseVector<Multiplicant>,
         product: &mut SparseVector<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            self.check_arguments(multiplier, multiplicant, product)?;
+            check_length("mask", product.length()?, mask.length()?)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -130,12 +134,72 @@ where
                 self.semiring,
                 multiplier.graphblas_matrix(),
                 multiplicant.graphblas_vector(),
-                self.options,
+                mask.graphblas_descriptor(&self.options),
             )
         })?;
 
         Ok(())
     }
+
+    fn check_arguments(
+        &self,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseVector<Multiplicant>,
+        product: &SparseVector<Product>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let multiplier_size = size_of_first_argument(multiplier, &self.options)?;
+        check_length(
+            "multiplicant",
+            multiplier_size.column_width(),
+            multiplicant.length()?,
+        )?;
+        check_length("product", multiplier_size.row_height(), product.length()?)
+    }
+}
+
+impl<Multiplier, Multiplicant, Product>
+    MatrixVectorMultiplicationOperator<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType + BuiltInValueType<Product>,
+{
+    /// Like apply(), but allocates the product, sized by the arguments and options
+    pub fn compute(
+        &self,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseVector<Multiplicant>,
+    ) -> Result<SparseVector<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply(multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like apply_with_mask(), but allocates the product, sized by the arguments and options
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        mask: &VectorMask<MaskValueType, AsBool>,
+        multiplier: &SparseMatrix<Multiplier>,
+        multiplicant: &SparseVector<Multiplicant>,
+    ) -> Result<SparseVector<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply_with_mask(mask, multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    fn product_size(
+        &self,
+        multiplier: &SparseMatrix<Multiplier>,
+        _multiplicant: &SparseVector<Multiplicant>,
+    ) -> Result<ElementIndex, SparseLinearAlgebraError> {
+        Ok(size_of_first_argument(multiplier, &self.options)?.row_height())
+    }
 }
 
 #[cfg(test)]
@@ -143,8 +207,8 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
     use crate::operators::binary_operator::Plus;
+    use crate::operators::binary_operator::{First, NoAccumulator};
     use crate::operators::semiring::PlusTimes;
     use crate::value_types::sparse_matrix::{FromMatrixElementList, MatrixElementList, Size};
     use crate::value_types::sparse_vector::{
@@ -157,8 +221,11 @@ mod tests {
 
         let semiring = PlusTimes::<f32, f32, f32>::new();
         let options = OperatorOptions::new_default();
-        let matrix_multiplier =
-            MatrixVectorMultiplicationOperator::<f32, f32, f32>::new(&semiring, &options, None);
+        let matrix_multiplier = MatrixVectorMultiplicationOperator::<f32, f32, f32>::new(
+            &semiring,
+            &options,
+            &NoAccumulator,
+        );
 
         let length = 2;
         let size: Size = (length, length).into();
@@ -222,7 +289,7 @@ mod tests {
             MatrixVectorMultiplicationOperator::<f32, f32, f32>::new(
                 &semiring,
                 &options,
-                Some(&accumulator),
+                &accumulator,
             );
 
         matrix_multiplier_with_accumulator
@@ -243,13 +310,16 @@ mod tests {
         )
         .unwrap();
 
-        let matrix_multiplier =
-            MatrixVectorMultiplicationOperator::<f32, f32, f32>::new(&semiring, &options, None);
+        let matrix_multiplier = MatrixVectorMultiplicationOperator::<f32, f32, f32>::new(
+            &semiring,
+            &options,
+            &NoAccumulator,
+        );
 
         let mut product = SparseVector::<f32>::new(&context, &length).unwrap();
 
         matrix_multiplier
-            .apply_with_mask(&mask.into(), &multiplier, &multiplicant, &mut product)
+            .apply_with_mask(&(&mask).into(), &multiplier, &multiplicant, &mut product)
             .unwrap();
 
         assert_eq!(product.get_element_value(&0).unwrap(), 19.);
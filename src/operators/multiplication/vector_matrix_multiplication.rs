@@ -3,15 +3,18 @@ use std::ptr;
 use std::marker::PhantomData;
 
 use crate::error::SparseLinearAlgebraError;
-use crate::operators::binary_operator::BinaryOperator;
+use crate::operators::binary_operator::Accumulator;
+use crate::operators::options::size_of_second_argument;
 use crate::operators::semiring::Semiring;
+use crate::operators::validation::check_length;
 use crate::operators::{mask::VectorMask, options::OperatorOptions};
+use crate::util::ElementIndex;
 use crate::value_types::sparse_matrix::SparseMatrix;
 use crate::value_types::sparse_vector::SparseVector;
-use crate::value_types::value_type::{AsBoolean, ValueType};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
 
 use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GrB_Semiring, GrB_vxm,
+    GrB_BinaryOp, GrB_Semiring, GrB_vxm,
 };
 
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
@@ -41,7 +44,6 @@ unsafe impl Sync for VectorMatrixMultiplicationOperator<i64, i64, i64> {}
 unsafe impl Sync for VectorMatrixMultiplicationOperator<f32, f32, f32> {}
 unsafe impl Sync for VectorMatrixMultiplicationOperator<f64, f64, f64> {}
 
-// TODO: review the use of &'a dyn Trait, removing dynamic dispatch could provide a performance gain. (it might be negated if cloning is necessary though)
 // https://www.joshmcguigan.com/blog/cost-of-indirection-rust/
 #[derive(Debug, Clone)]
 pub struct VectorMatrixMultiplicationOperator<Multiplier, Multiplicant, Product>
@@ -56,7 +58,7 @@ where
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     semiring: GrB_Semiring, // defines '+' and '*' for A*B (not optional for GrB_mxm)
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<Multiplier, Multiplicant, Product>
@@ -69,22 +71,16 @@ where
     pub fn new(
         // semiring: Box<dyn Semiring<Multiplier, Multiplicant, Product>>,
         // defines '+' and '*' for A*B (not optional for GrB_mxm)
-        semiring: &dyn Semiring<Multiplier, Multiplicant, Product>,
+        semiring: &(impl Semiring<Multiplier, Multiplicant, Product> + ?Sized),
         options: &OperatorOptions,
-        accumulator: Option<Box<dyn BinaryOperator<Product, Product, Product>>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<Product, Product, Product> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => {
-                accumulator_to_use = accumulator.graphblas_type()
-            }
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
             semiring: semiring.graphblas_type(),
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _multiplier: PhantomData,
             _multiplicant: PhantomData,
@@ -92,13 +88,16 @@ where
         }
     }
 
-    // TODO: consider a version where the resulting product matrix is generated in the function body
     pub fn apply(
         &self,
         multiplier: &SparseVector<Multiplier>,
         multiplicant: &SparseMatrix<Multiplicant>,
         product: &mut SparseVector<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            self.check_arguments(multiplier, multiplicant, product)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -109,7 +108,7 @@ where
                 self.semiring,
                 multiplier.graphblas_vector(),
                 multiplicant.graphblas_matrix(),
-                self.options,
+                self.options.to_graphblas_descriptor(),
             )
         })?;
 
@@ -126,6 +125,11 @@ where
         multiplicant: &SparseMatrix<Multiplicant>,
         product: &mut SparseVector<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            self.check_arguments(multiplier, multiplicant, product)?;
+            check_length("mask", product.length()?, mask.length()?)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -136,12 +140,83 @@ where
                 self.semiring,
                 multiplier.graphblas_vector(),
                 multiplicant.graphblas_matrix(),
-                self.options,
+                mask.graphblas_descriptor(&self.options),
             )
         })?;
 
         Ok(())
     }
+
+    fn check_arguments(
+        &self,
+        multiplier: &SparseVector<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+        product: &SparseVector<Product>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let multiplicant_size =
+            size_of_second_argument(multiplicant, &self.options)?;
+        check_length(
+            "multiplier",
+            multiplicant_size.row_height(),
+            multiplier.length()?,
+        )?;
+        check_length(
+            "product",
+            multiplicant_size.column_width(),
+            product.length()?,
+        )
+    }
+}
+
+impl<Multiplier, Multiplicant, Product>
+    VectorMatrixMultiplicationOperator<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType + BuiltInValueType<Product>,
+{
+    /// Like apply(), but allocates the product, sized by the arguments and options
+    pub fn compute(
+        &self,
+        multiplier: &SparseVector<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<SparseVector<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply(multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like apply_with_mask(), but allocates the product, sized by the arguments and options
+    pub fn compute_with_mask<
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+    >(
+        &self,
+        mask: &VectorMask<MaskValueType, AsBool>,
+        multiplier: &SparseVector<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<SparseVector<Product>, SparseLinearAlgebraError> {
+        let mut product = SparseVector::<Product>::new(
+            multiplier.context_ref(),
+            &self.product_size(multiplier, multiplicant)?,
+        )?;
+        self.apply_with_mask(mask, multiplier, multiplicant, &mut product)?;
+        Ok(product)
+    }
+
+    fn product_size(
+        &self,
+        _multiplier: &SparseVector<Multiplier>,
+        multiplicant: &SparseMatrix<Multiplicant>,
+    ) -> Result<ElementIndex, SparseLinearAlgebraError> {
+        Ok(
+            size_of_second_argument(multiplicant, &self.options)?
+                .column_width(),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -149,7 +224,7 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator};
     use crate::operators::binary_operator::Plus;
     use crate::operators::semiring::PlusTimes;
     use crate::value_types::sparse_matrix::{
@@ -164,13 +239,13 @@ mod tests {
     fn test_multiplication_with_plus_times() {
         let context = Context::init_ready(Mode::NonBlocking).unwrap();
 
-        let semiring = Box::new(PlusTimes::<f32, f32, f32>::new());
+        let semiring = PlusTimes::<f32, f32, f32>::new();
         let options = OperatorOptions::new_default();
         let matrix_multiplier =
             VectorMatrixMultiplicationOperator::<f32, f32, f32>::new(
-                semiring.clone(),
-                options.clone(),
-                None,
+                &semiring,
+                &options,
+                &NoAccumulator,
             );
 
         let length = 2;
@@ -237,12 +312,12 @@ mod tests {
         assert_eq!(expected_product, product_element_list);
 
         // test the use of an accumulator
-        let accumulator = Box::new(Plus::<f32, f32, f32>::new());
+        let accumulator = Plus::<f32, f32, f32>::new();
         let matrix_multiplier_with_accumulator =
             VectorMatrixMultiplicationOperator::<f32, f32, f32>::new(
-                semiring.clone(),
-                options.clone(),
-                Some(accumulator),
+                &semiring,
+                &options,
+                &accumulator,
             );
 
         matrix_multiplier_with_accumulator
@@ -268,16 +343,16 @@ mod tests {
 
         let matrix_multiplier =
             VectorMatrixMultiplicationOperator::<f32, f32, f32>::new(
-                semiring.clone(),
-                options.clone(),
-                None,
+                &semiring,
+                &options,
+                &NoAccumulator,
             );
 
         let mut product = SparseVector::<f32>::new(&context, &length).unwrap();
 
         matrix_multiplier
             .apply_with_mask(
-                &mask.into(),
+                &(&mask).into(),
                 &multiplier,
                 &multiplicant,
                 &mut product,
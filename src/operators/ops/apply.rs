@@ -0,0 +1,182 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::{UnaryOperatorApplier, UnaryOperatorApplierTrait};
+use crate::operators::binary_operator::Accumulator;
+use crate::operators::mask::{MatrixMask, VectorMask};
+use crate::operators::transpose::MatrixTranspose;
+use crate::operators::unary_operator::UnaryOperator;
+use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::sparse_vector::SparseVector;
+use crate::value_types::value_type::{AsBoolean, ValueType};
+
+use super::operation::{NoMask, NoOperator, Operation, UsingUnaryOperator};
+
+pub struct ApplyArguments<'a, Argument: ?Sized> {
+    argument: &'a Argument,
+}
+
+pub struct TransposeArguments<'a, Applicant: ValueType> {
+    argument: &'a SparseMatrix<Applicant>,
+}
+
+/// Applies an operator to every stored element of a matrix or vector, requires a unary operator
+pub fn apply<'a, Argument: ?Sized, Product: ValueType>(
+    argument: &'a Argument,
+) -> Operation<'a, ApplyArguments<'a, Argument>, NoOperator, Product, NoMask> {
+    Operation::new(ApplyArguments { argument })
+}
+
+/// Transposes a matrix
+pub fn transpose<'a, Applicant: ValueType, Product: ValueType>(
+    argument: &'a SparseMatrix<Applicant>,
+) -> Operation<'a, TransposeArguments<'a, Applicant>, NoOperator, Product, NoMask> {
+    Operation::new(TransposeArguments { argument })
+}
+
+impl<'a, Argument: ?Sized, T: ValueType, Mask, Accumulate>
+    Operation<'a, ApplyArguments<'a, Argument>, NoOperator, T, Mask, Accumulate>
+{
+    pub fn unary_operator<Operator: UnaryOperator<T> + ?Sized>(
+        self,
+        unary_operator: &'a Operator,
+    ) -> Operation<
+        'a,
+        ApplyArguments<'a, Argument>,
+        UsingUnaryOperator<'a, Operator>,
+        T,
+        Mask,
+        Accumulate,
+    > {
+        self.with_operator(UsingUnaryOperator(unary_operator))
+    }
+}
+
+impl<'a, T: ValueType, Operator: UnaryOperator<T> + ?Sized, Accumulate: Accumulator<T, T, T>>
+    Operation<
+        'a,
+        ApplyArguments<'a, SparseMatrix<T>>,
+        UsingUnaryOperator<'a, Operator>,
+        T,
+        NoMask,
+        Accumulate,
+    >
+where
+    UnaryOperatorApplier<T>: UnaryOperatorApplierTrait<T, T>,
+{
+    pub fn into(self, product: &mut SparseMatrix<T>) -> Result<(), SparseLinearAlgebraError> {
+        UnaryOperatorApplier::new(self.operator.0, &self.options(), &self.accumulator)
+            .apply_to_matrix(self.arguments.argument, product)
+    }
+}
+
+impl<
+        'a,
+        T: ValueType,
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+        Operator: UnaryOperator<T> + ?Sized,
+        Accumulate: Accumulator<T, T, T>,
+    >
+    Operation<
+        'a,
+        ApplyArguments<'a, SparseMatrix<T>>,
+        UsingUnaryOperator<'a, Operator>,
+        T,
+        MatrixMask<'a, MaskValueType, AsBool>,
+        Accumulate,
+    >
+where
+    UnaryOperatorApplier<T>: UnaryOperatorApplierTrait<T, T>,
+{
+    pub fn into(self, product: &mut SparseMatrix<T>) -> Result<(), SparseLinearAlgebraError> {
+        UnaryOperatorApplier::new(self.operator.0, &self.options(), &self.accumulator)
+            .apply_to_matrix_with_mask(self.arguments.argument, product, &self.mask)
+    }
+}
+
+impl<'a, T: ValueType, Operator: UnaryOperator<T> + ?Sized, Accumulate: Accumulator<T, T, T>>
+    Operation<
+        'a,
+        ApplyArguments<'a, SparseVector<T>>,
+        UsingUnaryOperator<'a, Operator>,
+        T,
+        NoMask,
+        Accumulate,
+    >
+where
+    UnaryOperatorApplier<T>: UnaryOperatorApplierTrait<T, T>,
+{
+    pub fn into(self, product: &mut SparseVector<T>) -> Result<(), SparseLinearAlgebraError> {
+        UnaryOperatorApplier::new(self.operator.0, &self.options(), &self.accumulator)
+            .apply_to_vector(self.arguments.argument, product)
+    }
+}
+
+impl<
+        'a,
+        T: ValueType,
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+        Operator: UnaryOperator<T> + ?Sized,
+        Accumulate: Accumulator<T, T, T>,
+    >
+    Operation<
+        'a,
+        ApplyArguments<'a, SparseVector<T>>,
+        UsingUnaryOperator<'a, Operator>,
+        T,
+        VectorMask<'a, MaskValueType, AsBool>,
+        Accumulate,
+    >
+where
+    UnaryOperatorApplier<T>: UnaryOperatorApplierTrait<T, T>,
+{
+    pub fn into(self, product: &mut SparseVector<T>) -> Result<(), SparseLinearAlgebraError> {
+        UnaryOperatorApplier::new(self.operator.0, &self.options(), &self.accumulator)
+            .apply_to_vector_with_mask(self.arguments.argument, product, &self.mask)
+    }
+}
+
+impl<
+        'a,
+        Applicant: ValueType,
+        Product: ValueType,
+        Accumulate: Accumulator<Product, Product, Product>,
+    > Operation<'a, TransposeArguments<'a, Applicant>, NoOperator, Product, NoMask, Accumulate>
+{
+    pub fn into(
+        self,
+        transpose: &mut SparseMatrix<Product>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MatrixTranspose::new(&self.options(), &self.accumulator)
+            .apply(self.arguments.argument, transpose)
+    }
+}
+
+impl<
+        'a,
+        Applicant: ValueType,
+        Product: ValueType,
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+        Accumulate: Accumulator<Product, Product, Product>,
+    >
+    Operation<
+        'a,
+        TransposeArguments<'a, Applicant>,
+        NoOperator,
+        Product,
+        MatrixMask<'a, MaskValueType, AsBool>,
+        Accumulate,
+    >
+{
+    pub fn into(
+        self,
+        transpose: &mut SparseMatrix<Product>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MatrixTranspose::new(&self.options(), &self.accumulator).apply_with_mask(
+            self.arguments.argument,
+            transpose,
+            &self.mask,
+        )
+    }
+}
@@ -0,0 +1,400 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::Accumulator;
+use crate::operators::insert::{
+    InsertMatrixIntoMatrix, InsertMatrixIntoMatrixTrait, InsertScalarIntoMatrix,
+    InsertScalarIntoMatrixTrait, InsertScalarIntoVector, InsertScalarIntoVectorTrait,
+    InsertVectorIntoColumn, InsertVectorIntoColumnTrait, InsertVectorIntoRow,
+    InsertVectorIntoRowTrait, InsertVectorIntoVector, InsertVectorIntoVectorTrait,
+};
+use crate::operators::mask::{MatrixMask, VectorMask};
+use crate::operators::subinsert::{
+    InsertMatrixIntoSubMatrix, InsertMatrixIntoSubMatrixTrait, InsertScalarIntoSubMatrix,
+    InsertScalarIntoSubMatrixTrait, InsertScalarIntoSubVector, InsertScalarIntoSubVectorTrait,
+    InsertVectorIntoSubColumn, InsertVectorIntoSubColumnTrait, InsertVectorIntoSubRow,
+    InsertVectorIntoSubRowTrait, InsertVectorIntoSubVector, InsertVectorIntoSubVectorTrait,
+};
+use crate::util::{ElementIndex, ElementIndexSelector};
+use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::sparse_vector::SparseVector;
+use crate::value_types::value_type::{AsBoolean, ValueType};
+
+use super::operation::{NoMask, NoOperator, Operation};
+
+pub struct AssignToMatrixArguments<'a, Source: ?Sized> {
+    source: &'a Source,
+    rows: &'a ElementIndexSelector<'a>,
+    columns: &'a ElementIndexSelector<'a>,
+}
+
+pub struct AssignToVectorArguments<'a, Source: ?Sized> {
+    source: &'a Source,
+    indices: &'a ElementIndexSelector<'a>,
+}
+
+pub struct AssignToColumnArguments<'a, T: ValueType> {
+    source: &'a SparseVector<T>,
+    indices: &'a ElementIndexSelector<'a>,
+    column_index: ElementIndex,
+}
+
+pub struct AssignToRowArguments<'a, T: ValueType> {
+    source: &'a SparseVector<T>,
+    indices: &'a ElementIndexSelector<'a>,
+    row_index: ElementIndex,
+}
+
+/// The arguments of an assignment to a part of the output (GxB_subassign),
+/// which is masked by a mask of the size of the part instead of the size of the output
+pub struct SubAssignArguments<Arguments>(Arguments);
+
+/// Assigns a matrix to the selected rows and columns of the output matrix
+pub fn assign_matrix<'a, T: ValueType>(
+    matrix_to_insert: &'a SparseMatrix<T>,
+    rows: &'a ElementIndexSelector<'a>,
+    columns: &'a ElementIndexSelector<'a>,
+) -> Operation<'a, AssignToMatrixArguments<'a, SparseMatrix<T>>, NoOperator, T, NoMask> {
+    Operation::new(AssignToMatrixArguments {
+        source: matrix_to_insert,
+        rows,
+        columns,
+    })
+}
+
+/// Assigns a vector to the selected indices of the output vector
+pub fn assign_vector<'a, T: ValueType>(
+    vector_to_insert: &'a SparseVector<T>,
+    indices: &'a ElementIndexSelector<'a>,
+) -> Operation<'a, AssignToVectorArguments<'a, SparseVector<T>>, NoOperator, T, NoMask> {
+    Operation::new(AssignToVectorArguments {
+        source: vector_to_insert,
+        indices,
+    })
+}
+
+/// Assigns a scalar to the selected rows and columns of the output matrix
+pub fn assign_scalar_to_matrix<'a, T: ValueType>(
+    scalar_to_insert: &'a T,
+    rows: &'a ElementIndexSelector<'a>,
+    columns: &'a ElementIndexSelector<'a>,
+) -> Operation<'a, AssignToMatrixArguments<'a, T>, NoOperator, T, NoMask> {
+    Operation::new(AssignToMatrixArguments {
+        source: scalar_to_insert,
+        rows,
+        columns,
+    })
+}
+
+/// Assigns a scalar to the selected indices of the output vector
+pub fn assign_scalar_to_vector<'a, T: ValueType>(
+    scalar_to_insert: &'a T,
+    indices: &'a ElementIndexSelector<'a>,
+) -> Operation<'a, AssignToVectorArguments<'a, T>, NoOperator, T, NoMask> {
+    Operation::new(AssignToVectorArguments {
+        source: scalar_to_insert,
+        indices,
+    })
+}
+
+/// Assigns a vector to the selected row indices of a column of the output matrix
+pub fn assign_column<'a, T: ValueType>(
+    vector_to_insert: &'a SparseVector<T>,
+    indices: &'a ElementIndexSelector<'a>,
+    column_index: ElementIndex,
+) -> Operation<'a, AssignToColumnArguments<'a, T>, NoOperator, T, NoMask> {
+    Operation::new(AssignToColumnArguments {
+        source: vector_to_insert,
+        indices,
+        column_index,
+    })
+}
+
+/// Assigns a vector to the selected column indices of a row of the output matrix
+pub fn assign_row<'a, T: ValueType>(
+    vector_to_insert: &'a SparseVector<T>,
+    indices: &'a ElementIndexSelector<'a>,
+    row_index: ElementIndex,
+) -> Operation<'a, AssignToRowArguments<'a, T>, NoOperator, T, NoMask> {
+    Operation::new(AssignToRowArguments {
+        source: vector_to_insert,
+        indices,
+        row_index,
+    })
+}
+
+/// Assigns a matrix to the selected rows and columns of the output matrix,
+/// masked by a mask of the size of the assigned matrix
+pub fn subassign_matrix<'a, T: ValueType>(
+    matrix_to_insert: &'a SparseMatrix<T>,
+    rows: &'a ElementIndexSelector<'a>,
+    columns: &'a ElementIndexSelector<'a>,
+) -> Operation<
+    'a,
+    SubAssignArguments<AssignToMatrixArguments<'a, SparseMatrix<T>>>,
+    NoOperator,
+    T,
+    NoMask,
+> {
+    Operation::new(SubAssignArguments(AssignToMatrixArguments {
+        source: matrix_to_insert,
+        rows,
+        columns,
+    }))
+}
+
+/// Assigns a vector to the selected indices of the output vector,
+/// masked by a mask of the size of the assigned vector
+pub fn subassign_vector<'a, T: ValueType>(
+    vector_to_insert: &'a SparseVector<T>,
+    indices: &'a ElementIndexSelector<'a>,
+) -> Operation<
+    'a,
+    SubAssignArguments<AssignToVectorArguments<'a, SparseVector<T>>>,
+    NoOperator,
+    T,
+    NoMask,
+> {
+    Operation::new(SubAssignArguments(AssignToVectorArguments {
+        source: vector_to_insert,
+        indices,
+    }))
+}
+
+/// Assigns a scalar to the selected rows and columns of the output matrix,
+/// masked by a mask of the size of the selection
+pub fn subassign_scalar_to_matrix<'a, T: ValueType>(
+    scalar_to_insert: &'a T,
+    rows: &'a ElementIndexSelector<'a>,
+    columns: &'a ElementIndexSelector<'a>,
+) -> Operation<'a, SubAssignArguments<AssignToMatrixArguments<'a, T>>, NoOperator, T, NoMask> {
+    Operation::new(SubAssignArguments(AssignToMatrixArguments {
+        source: scalar_to_insert,
+        rows,
+        columns,
+    }))
+}
+
+/// Assigns a scalar to the selected indices of the output vector,
+/// masked by a mask of the size of the selection
+pub fn subassign_scalar_to_vector<'a, T: ValueType>(
+    scalar_to_insert: &'a T,
+    indices: &'a ElementIndexSelector<'a>,
+) -> Operation<'a, SubAssignArguments<AssignToVectorArguments<'a, T>>, NoOperator, T, NoMask> {
+    Operation::new(SubAssignArguments(AssignToVectorArguments {
+        source: scalar_to_insert,
+        indices,
+    }))
+}
+
+/// Assigns a vector to the selected row indices of a column of the output matrix,
+/// masked by a mask of the size of the assigned vector
+pub fn subassign_column<'a, T: ValueType>(
+    vector_to_insert: &'a SparseVector<T>,
+    indices: &'a ElementIndexSelector<'a>,
+    column_index: ElementIndex,
+) -> Operation<'a, SubAssignArguments<AssignToColumnArguments<'a, T>>, NoOperator, T, NoMask> {
+    Operation::new(SubAssignArguments(AssignToColumnArguments {
+        source: vector_to_insert,
+        indices,
+        column_index,
+    }))
+}
+
+/// Assigns a vector to the selected column indices of a row of the output matrix,
+/// masked by a mask of the size of the assigned vector
+pub fn subassign_row<'a, T: ValueType>(
+    vector_to_insert: &'a SparseVector<T>,
+    indices: &'a ElementIndexSelector<'a>,
+    row_index: ElementIndex,
+) -> Operation<'a, SubAssignArguments<AssignToRowArguments<'a, T>>, NoOperator, T, NoMask> {
+    Operation::new(SubAssignArguments(AssignToRowArguments {
+        source: vector_to_insert,
+        indices,
+        row_index,
+    }))
+}
+
+// The arguments are passed to apply() in the order given, after the product.
+// self is named by the invocation, such that the argument expressions can refer to it.
+macro_rules! implement_assign_operation {
+    (
+        $arguments:ty,
+        $product_container:ident,
+        $mask:ident,
+        $operator:ident,
+        $operator_trait:ident,
+        |$self:ident| ($($argument:expr),+)
+    ) => {
+        impl<'a, T: ValueType, Accumulate: Accumulator<T, T, T>>
+            Operation<'a, $arguments, NoOperator, T, NoMask, Accumulate>
+        where
+            $operator<T, T>: $operator_trait<T, T>,
+        {
+            pub fn into(
+                $self,
+                product: &mut $product_container<T>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                $operator::new(&$self.options(), &$self.accumulator).apply(product, $($argument),+)
+            }
+        }
+
+        impl<
+                'a,
+                T: ValueType,
+                MaskValueType: ValueType,
+                AsBool: AsBoolean<MaskValueType>,
+                Accumulate: Accumulator<T, T, T>,
+            >
+            Operation<
+                'a,
+                $arguments,
+                NoOperator,
+                T,
+                $mask<'a, MaskValueType, AsBool>,
+                Accumulate,
+            >
+        where
+            $operator<T, T>: $operator_trait<T, T>,
+        {
+            pub fn into(
+                $self,
+                product: &mut $product_container<T>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                $operator::new(&$self.options(), &$self.accumulator).apply_with_mask(
+                    product,
+                    $($argument),+,
+                    &$self.mask,
+                )
+            }
+        }
+    };
+}
+
+implement_assign_operation!(
+    AssignToMatrixArguments<'a, SparseMatrix<T>>,
+    SparseMatrix,
+    MatrixMask,
+    InsertMatrixIntoMatrix,
+    InsertMatrixIntoMatrixTrait,
+    |self| (
+        self.arguments.rows,
+        self.arguments.columns,
+        self.arguments.source
+    )
+);
+implement_assign_operation!(
+    AssignToMatrixArguments<'a, T>,
+    SparseMatrix,
+    MatrixMask,
+    InsertScalarIntoMatrix,
+    InsertScalarIntoMatrixTrait,
+    |self| (
+        self.arguments.rows,
+        self.arguments.columns,
+        self.arguments.source
+    )
+);
+implement_assign_operation!(
+    AssignToVectorArguments<'a, SparseVector<T>>,
+    SparseVector,
+    VectorMask,
+    InsertVectorIntoVector,
+    InsertVectorIntoVectorTrait,
+    |self| (self.arguments.indices, self.arguments.source)
+);
+implement_assign_operation!(
+    AssignToVectorArguments<'a, T>,
+    SparseVector,
+    VectorMask,
+    InsertScalarIntoVector,
+    InsertScalarIntoVectorTrait,
+    |self| (self.arguments.indices, self.arguments.source)
+);
+implement_assign_operation!(
+    AssignToColumnArguments<'a, T>,
+    SparseMatrix,
+    VectorMask,
+    InsertVectorIntoColumn,
+    InsertVectorIntoColumnTrait,
+    |self| (
+        self.arguments.indices,
+        &self.arguments.column_index,
+        self.arguments.source
+    )
+);
+implement_assign_operation!(
+    AssignToRowArguments<'a, T>,
+    SparseMatrix,
+    VectorMask,
+    InsertVectorIntoRow,
+    InsertVectorIntoRowTrait,
+    |self| (
+        self.arguments.indices,
+        &self.arguments.row_index,
+        self.arguments.source
+    )
+);
+
+implement_assign_operation!(
+    SubAssignArguments<AssignToMatrixArguments<'a, SparseMatrix<T>>>,
+    SparseMatrix,
+    MatrixMask,
+    InsertMatrixIntoSubMatrix,
+    InsertMatrixIntoSubMatrixTrait,
+    |self| (
+        self.arguments.0.rows,
+        self.arguments.0.columns,
+        self.arguments.0.source
+    )
+);
+implement_assign_operation!(
+    SubAssignArguments<AssignToMatrixArguments<'a, T>>,
+    SparseMatrix,
+    MatrixMask,
+    InsertScalarIntoSubMatrix,
+    InsertScalarIntoSubMatrixTrait,
+    |self| (
+        self.arguments.0.rows,
+        self.arguments.0.columns,
+        self.arguments.0.source
+    )
+);
+implement_assign_operation!(
+    SubAssignArguments<AssignToVectorArguments<'a, SparseVector<T>>>,
+    SparseVector,
+    VectorMask,
+    InsertVectorIntoSubVector,
+    InsertVectorIntoSubVectorTrait,
+    |self| (self.arguments.0.indices, self.arguments.0.source)
+);
+implement_assign_operation!(
+    SubAssignArguments<AssignToVectorArguments<'a, T>>,
+    SparseVector,
+    VectorMask,
+    InsertScalarIntoSubVector,
+    InsertScalarIntoSubVectorTrait,
+    |self| (self.arguments.0.indices, self.arguments.0.source)
+);
+implement_assign_operation!(
+    SubAssignArguments<AssignToColumnArguments<'a, T>>,
+    SparseMatrix,
+    VectorMask,
+    InsertVectorIntoSubColumn,
+    InsertVectorIntoSubColumnTrait,
+    |self| (
+        self.arguments.0.indices,
+        &self.arguments.0.column_index,
+        self.arguments.0.source
+    )
+);
+implement_assign_operation!(
+    SubAssignArguments<AssignToRowArguments<'a, T>>,
+    SparseMatrix,
+    VectorMask,
+    InsertVectorIntoSubRow,
+    InsertVectorIntoSubRowTrait,
+    |self| (
+        self.arguments.0.indices,
+        &self.arguments.0.row_index,
+        self.arguments.0.source
+    )
+);
@@ -0,0 +1,594 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::BinaryOperatorApplier;
+use crate::operators::binary_operator::{Accumulator, BinaryOperator};
+use crate::operators::element_wise_addition::{
+    ElementWiseMatrixAdditionBinaryOperator, ElementWiseMatrixAdditionMonoidOperator,
+    ElementWiseMatrixAdditionSemiring, ElementWiseVectorAdditionBinaryOperator,
+    ElementWiseVectorAdditionMonoidOperator, ElementWiseVectorAdditionSemiring,
+};
+use crate::operators::element_wise_multiplication::{
+    ElementWiseMatrixMultiplicationBinaryOperator, ElementWiseMatrixMultiplicationMonoidOperator,
+    ElementWiseMatrixMultiplicationSemiring, ElementWiseVectorMultiplicationBinaryOperator,
+    ElementWiseVectorMultiplicationMonoidOperator, ElementWiseVectorMultiplicationSemiring,
+};
+use crate::operators::kronecker_product::{
+    BinaryOperatorKroneckerProductOperator, MonoidKroneckerProduct, SemiringKroneckerProduct,
+};
+use crate::operators::mask::{MatrixMask, VectorMask};
+use crate::operators::monoid::Monoid;
+use crate::operators::semiring::Semiring;
+use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::sparse_scalar::SparseScalar;
+use crate::value_types::sparse_vector::SparseVector;
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
+
+use super::operation::{
+    NoMask, NoOperator, Operation, UsingBinaryOperator, UsingMonoid, UsingSemiring,
+};
+
+pub struct ElementWiseAdditionArguments<'a, Left: ?Sized, Right: ?Sized> {
+    left: &'a Left,
+    right: &'a Right,
+}
+
+pub struct ElementWiseMultiplicationArguments<'a, Left: ?Sized, Right: ?Sized> {
+    left: &'a Left,
+    right: &'a Right,
+}
+
+pub struct KroneckerProductArguments<'a, Left: ?Sized, Right: ?Sized> {
+    left: &'a Left,
+    right: &'a Right,
+}
+
+/// Element-wise addition (union of the stored elements) of two matrices or two vectors.
+/// Requires a semiring, monoid or binary operator.
+/// A SparseScalar is not accepted, as its union with a matrix or vector would store every element.
+pub fn ewise_add<'a, Left: ?Sized, Right: ?Sized, Product: ValueType>(
+    left: &'a Left,
+    right: &'a Right,
+) -> Operation<'a, ElementWiseAdditionArguments<'a, Left, Right>, NoOperator, Product, NoMask> {
+    Operation::new(ElementWiseAdditionArguments { left, right })
+}
+
+/// Element-wise multiplication (intersection of the stored elements) of two matrices or two
+/// vectors. Requires a semiring, monoid or binary operator.
+/// Either argument may also be a SparseScalar, which requires a binary operator, and then the
+/// operator is applied to every stored element of the other argument. The argument and product
+/// types may differ, as for BinaryOperatorApplier.
+pub fn ewise_mult<'a, Left: ?Sized, Right: ?Sized, Product: ValueType>(
+    left: &'a Left,
+    right: &'a Right,
+) -> Operation<'a, ElementWiseMultiplicationArguments<'a, Left, Right>, NoOperator, Product, NoMask>
+{
+    Operation::new(ElementWiseMultiplicationArguments { left, right })
+}
+
+/// Kronecker product of two matrices.
+/// Requires a semiring, monoid or binary operator.
+pub fn kronecker<'a, Left: ValueType, Right: ValueType, Product: ValueType>(
+    left: &'a SparseMatrix<Left>,
+    right: &'a SparseMatrix<Right>,
+) -> Operation<
+    'a,
+    KroneckerProductArguments<'a, SparseMatrix<Left>, SparseMatrix<Right>>,
+    NoOperator,
+    Product,
+    NoMask,
+> {
+    Operation::new(KroneckerProductArguments { left, right })
+}
+
+macro_rules! implement_element_wise_operation {
+    (
+        $arguments:ident,
+        $container:ident,
+        $mask:ident,
+        $semiring_operator:ident,
+        $monoid_operator:ident,
+        $binary_operator_operator:ident
+    ) => {
+        impl<'a, Left: ValueType, Right: ValueType, Product: ValueType, Mask, Accumulate>
+            Operation<
+                'a,
+                $arguments<'a, $container<Left>, $container<Right>>,
+                NoOperator,
+                Product,
+                Mask,
+                Accumulate,
+            >
+        {
+            pub fn semiring<Operator: Semiring<Left, Right, Product> + ?Sized>(
+                self,
+                semiring: &'a Operator,
+            ) -> Operation<
+                'a,
+                $arguments<'a, $container<Left>, $container<Right>>,
+                UsingSemiring<'a, Operator>,
+                Product,
+                Mask,
+                Accumulate,
+            > {
+                self.with_operator(UsingSemiring(semiring))
+            }
+
+            pub fn binary_operator<Operator: BinaryOperator<Left, Right, Product> + ?Sized>(
+                self,
+                binary_operator: &'a Operator,
+            ) -> Operation<
+                'a,
+                $arguments<'a, $container<Left>, $container<Right>>,
+                UsingBinaryOperator<'a, Operator>,
+                Product,
+                Mask,
+                Accumulate,
+            > {
+                self.with_operator(UsingBinaryOperator(binary_operator))
+            }
+        }
+
+        impl<'a, T: ValueType, Mask, Accumulate>
+            Operation<
+                'a,
+                $arguments<'a, $container<T>, $container<T>>,
+                NoOperator,
+                T,
+                Mask,
+                Accumulate,
+            >
+        {
+            pub fn monoid<Operator: Monoid<T> + ?Sized>(
+                self,
+                monoid: &'a Operator,
+            ) -> Operation<
+                'a,
+                $arguments<'a, $container<T>, $container<T>>,
+                UsingMonoid<'a, Operator>,
+                T,
+                Mask,
+                Accumulate,
+            > {
+                self.with_operator(UsingMonoid(monoid))
+            }
+        }
+
+        impl<
+                'a,
+                Left: ValueType,
+                Right: ValueType,
+                Product: ValueType,
+                Operator: Semiring<Left, Right, Product> + ?Sized,
+                Accumulate: Accumulator<Product, Product, Product>,
+            >
+            Operation<
+                'a,
+                $arguments<'a, $container<Left>, $container<Right>>,
+                UsingSemiring<'a, Operator>,
+                Product,
+                NoMask,
+                Accumulate,
+            >
+        {
+            pub fn into(
+                self,
+                product: &mut $container<Product>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                $semiring_operator::new(self.operator.0, &self.options(), &self.accumulator).apply(
+                    self.arguments.left,
+                    self.arguments.right,
+                    product,
+                )
+            }
+        }
+
+        impl<
+                'a,
+                Left: ValueType,
+                Right: ValueType,
+                Product: ValueType,
+                MaskValueType: ValueType,
+                AsBool: AsBoolean<MaskValueType>,
+                Operator: Semiring<Left, Right, Product> + ?Sized,
+                Accumulate: Accumulator<Product, Product, Product>,
+            >
+            Operation<
+                'a,
+                $arguments<'a, $container<Left>, $container<Right>>,
+                UsingSemiring<'a, Operator>,
+                Product,
+                $mask<'a, MaskValueType, AsBool>,
+                Accumulate,
+            >
+        {
+            pub fn into(
+                self,
+                product: &mut $container<Product>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                $semiring_operator::new(self.operator.0, &self.options(), &self.accumulator)
+                    .apply_with_mask(
+                        &self.mask,
+                        self.arguments.left,
+                        self.arguments.right,
+                        product,
+                    )
+            }
+        }
+
+        impl<
+                'a,
+                Left: ValueType,
+                Right: ValueType,
+                Product: ValueType,
+                Operator: BinaryOperator<Left, Right, Product> + ?Sized,
+                Accumulate: Accumulator<Product, Product, Product>,
+            >
+            Operation<
+                'a,
+                $arguments<'a, $container<Left>, $container<Right>>,
+                UsingBinaryOperator<'a, Operator>,
+                Product,
+                NoMask,
+                Accumulate,
+            >
+        {
+            pub fn into(
+                self,
+                product: &mut $container<Product>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                $binary_operator_operator::new(self.operator.0, &self.options(), &self.accumulator)
+                    .apply(self.arguments.left, self.arguments.right, product)
+            }
+        }
+
+        impl<
+                'a,
+                Left: ValueType,
+                Right: ValueType,
+                Product: ValueType,
+                MaskValueType: ValueType,
+                AsBool: AsBoolean<MaskValueType>,
+                Operator: BinaryOperator<Left, Right, Product> + ?Sized,
+                Accumulate: Accumulator<Product, Product, Product>,
+            >
+            Operation<
+                'a,
+                $arguments<'a, $container<Left>, $container<Right>>,
+                UsingBinaryOperator<'a, Operator>,
+                Product,
+                $mask<'a, MaskValueType, AsBool>,
+                Accumulate,
+            >
+        {
+            pub fn into(
+                self,
+                product: &mut $container<Product>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                $binary_operator_operator::new(self.operator.0, &self.options(), &self.accumulator)
+                    .apply_with_mask(
+                        &self.mask,
+                        self.arguments.left,
+                        self.arguments.right,
+                        product,
+                    )
+            }
+        }
+
+        impl<'a, T: ValueType, Operator: Monoid<T> + ?Sized, Accumulate: Accumulator<T, T, T>>
+            Operation<
+                'a,
+                $arguments<'a, $container<T>, $container<T>>,
+                UsingMonoid<'a, Operator>,
+                T,
+                NoMask,
+                Accumulate,
+            >
+        {
+            pub fn into(self, product: &mut $container<T>) -> Result<(), SparseLinearAlgebraError> {
+                $monoid_operator::new(self.operator.0, &self.options(), &self.accumulator).apply(
+                    self.arguments.left,
+                    self.arguments.right,
+                    product,
+                )
+            }
+        }
+
+        impl<
+                'a,
+                T: ValueType,
+                MaskValueType: ValueType,
+                AsBool: AsBoolean<MaskValueType>,
+                Operator: Monoid<T> + ?Sized,
+                Accumulate: Accumulator<T, T, T>,
+            >
+            Operation<
+                'a,
+                $arguments<'a, $container<T>, $container<T>>,
+                UsingMonoid<'a, Operator>,
+                T,
+                $mask<'a, MaskValueType, AsBool>,
+                Accumulate,
+            >
+        {
+            pub fn into(self, product: &mut $container<T>) -> Result<(), SparseLinearAlgebraError> {
+                $monoid_operator::new(self.operator.0, &self.options(), &self.accumulator)
+                    .apply_with_mask(
+                        &self.mask,
+                        self.arguments.left,
+                        self.arguments.right,
+                        product,
+                    )
+            }
+        }
+    };
+}
+
+implement_element_wise_operation!(
+    ElementWiseAdditionArguments,
+    SparseMatrix,
+    MatrixMask,
+    ElementWiseMatrixAdditionSemiring,
+    ElementWiseMatrixAdditionMonoidOperator,
+    ElementWiseMatrixAdditionBinaryOperator
+);
+implement_element_wise_operation!(
+    ElementWiseAdditionArguments,
+    SparseVector,
+    VectorMask,
+    ElementWiseVectorAdditionSemiring,
+    ElementWiseVectorAdditionMonoidOperator,
+    ElementWiseVectorAdditionBinaryOperator
+);
+implement_element_wise_operation!(
+    ElementWiseMultiplicationArguments,
+    SparseMatrix,
+    MatrixMask,
+    ElementWiseMatrixMultiplicationSemiring,
+    ElementWiseMatrixMultiplicationMonoidOperator,
+    ElementWiseMatrixMultiplicationBinaryOperator
+);
+implement_element_wise_operation!(
+    ElementWiseMultiplicationArguments,
+    SparseVector,
+    VectorMask,
+    ElementWiseVectorMultiplicationSemiring,
+    ElementWiseVectorMultiplicationMonoidOperator,
+    ElementWiseVectorMultiplicationBinaryOperator
+);
+implement_element_wise_operation!(
+    KroneckerProductArguments,
+    SparseMatrix,
+    MatrixMask,
+    SemiringKroneckerProduct,
+    MonoidKroneckerProduct,
+    BinaryOperatorKroneckerProductOperator
+);
+
+// Binding a scalar in an element-wise multiplication applies the binary operator
+// to every stored element of the matrix or vector. An empty scalar computes no elements.
+macro_rules! implement_element_wise_multiplication_with_sparse_scalar {
+    (
+        $left:ident,
+        $right:ident,
+        $container:ident,
+        $mask:ident,
+        $apply:ident,
+        $apply_with_mask:ident
+    ) => {
+        impl<'a, Left: ValueType, Right: ValueType, Product: ValueType, Mask, Accumulate>
+            Operation<
+                'a,
+                ElementWiseMultiplicationArguments<'a, $left<Left>, $right<Right>>,
+                NoOperator,
+                Product,
+                Mask,
+                Accumulate,
+            >
+        {
+            pub fn binary_operator<Operator: BinaryOperator<Left, Right, Product> + ?Sized>(
+                self,
+                binary_operator: &'a Operator,
+            ) -> Operation<
+                'a,
+                ElementWiseMultiplicationArguments<'a, $left<Left>, $right<Right>>,
+                UsingBinaryOperator<'a, Operator>,
+                Product,
+                Mask,
+                Accumulate,
+            > {
+                self.with_operator(UsingBinaryOperator(binary_operator))
+            }
+        }
+
+        impl<
+                'a,
+                Left: ValueType,
+                Right: ValueType,
+                Product: ValueType + BuiltInValueType<Product>,
+                Operator: BinaryOperator<Left, Right, Product> + ?Sized,
+                Accumulate: Accumulator<Product, Product, Product>,
+            >
+            Operation<
+                'a,
+                ElementWiseMultiplicationArguments<'a, $left<Left>, $right<Right>>,
+                UsingBinaryOperator<'a, Operator>,
+                Product,
+                NoMask,
+                Accumulate,
+            >
+        {
+            pub fn into(
+                self,
+                product: &mut $container<Product>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                BinaryOperatorApplier::new(self.operator.0, &self.options(), &self.accumulator)
+                    .$apply(self.arguments.left, self.arguments.right, product)
+            }
+        }
+
+        impl<
+                'a,
+                Left: ValueType,
+                Right: ValueType,
+                Product: ValueType + BuiltInValueType<Product>,
+                MaskValueType: ValueType,
+                AsBool: AsBoolean<MaskValueType>,
+                Operator: BinaryOperator<Left, Right, Product> + ?Sized,
+                Accumulate: Accumulator<Product, Product, Product>,
+            >
+            Operation<
+                'a,
+                ElementWiseMultiplicationArguments<'a, $left<Left>, $right<Right>>,
+                UsingBinaryOperator<'a, Operator>,
+                Product,
+                $mask<'a, MaskValueType, AsBool>,
+                Accumulate,
+            >
+        {
+            pub fn into(
+                self,
+                product: &mut $container<Product>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                BinaryOperatorApplier::new(self.operator.0, &self.options(), &self.accumulator)
+                    .$apply_with_mask(
+                        self.arguments.left,
+                        self.arguments.right,
+                        product,
+                        &self.mask,
+                    )
+            }
+        }
+    };
+}
+
+implement_element_wise_multiplication_with_sparse_scalar!(
+    SparseMatrix,
+    SparseScalar,
+    SparseMatrix,
+    MatrixMask,
+    apply_sparse_scalar_with_matrix_as_first_argument,
+    apply_sparse_scalar_with_matrix_as_first_argument_and_mask
+);
+implement_element_wise_multiplication_with_sparse_scalar!(
+    SparseScalar,
+    SparseMatrix,
+    SparseMatrix,
+    MatrixMask,
+    apply_sparse_scalar_with_matrix_as_second_argument,
+    apply_sparse_scalar_with_matrix_as_second_argument_and_mask
+);
+implement_element_wise_multiplication_with_sparse_scalar!(
+    SparseVector,
+    SparseScalar,
+    SparseVector,
+    VectorMask,
+    apply_sparse_scalar_with_vector_as_first_argument,
+    apply_sparse_scalar_with_vector_as_first_argument_and_mask
+);
+implement_element_wise_multiplication_with_sparse_scalar!(
+    SparseScalar,
+    SparseVector,
+    SparseVector,
+    VectorMask,
+    apply_sparse_scalar_with_vector_as_second_argument,
+    apply_sparse_scalar_with_vector_as_second_argument_and_mask
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::context::{Context, Mode};
+    use crate::operators::binary_operator::{Divide, First, LessThan, Minus, Plus, Times};
+    use crate::operators::ops;
+    use crate::value_types::sparse_scalar::SetScalarValue;
+    use crate::value_types::sparse_vector::{
+        FromVectorElementList, GetVectorElementValue, VectorElementList,
+    };
+
+    #[test]
+    fn test_element_wise_vector_operations() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let left = SparseVector::<i32>::from_element_list(
+            &context,
+            &4,
+            &VectorElementList::<i32>::from_element_vector(vec![(0, 1).into(), (1, 2).into()]),
+            &First::<i32, i32, i32>::new(),
+        )
+        .unwrap();
+        let right = SparseVector::<i32>::from_element_list(
+            &context,
+            &4,
+            &VectorElementList::<i32>::from_element_vector(vec![(1, 3).into(), (2, 4).into()]),
+            &First::<i32, i32, i32>::new(),
+        )
+        .unwrap();
+
+        let mut sum = SparseVector::<i32>::new(&context, &4).unwrap();
+        ops::ewise_add(&left, &right)
+            .binary_operator(&Plus::<i32, i32, i32>::new())
+            .into(&mut sum)
+            .unwrap();
+
+        assert_eq!(sum.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(sum.get_element_value(&0).unwrap(), 1);
+        assert_eq!(sum.get_element_value(&1).unwrap(), 5);
+        assert_eq!(sum.get_element_value(&2).unwrap(), 4);
+
+        let mut product = SparseVector::<i32>::new(&context, &4).unwrap();
+        ops::ewise_mult(&left, &right)
+            .binary_operator(&Times::<i32, i32, i32>::new())
+            .into(&mut product)
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 1);
+        assert_eq!(product.get_element_value(&1).unwrap(), 6);
+    }
+
+    #[test]
+    fn test_element_wise_multiplication_with_sparse_scalar() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let vector = SparseVector::<f64>::from_element_list(
+            &context,
+            &4,
+            &VectorElementList::<f64>::from_element_vector(vec![(0, 1.).into(), (2, 5.).into()]),
+            &First::<f64, f64, f64>::new(),
+        )
+        .unwrap();
+
+        // Subtract the mean of the stored elements
+        let mut mean = SparseScalar::<f64>::new(&context).unwrap();
+        mean.set_value(&3.).unwrap();
+
+        let mut centered = SparseVector::<f64>::new(&context, &4).unwrap();
+        ops::ewise_mult(&vector, &mean)
+            .binary_operator(&Minus::<f64, f64, f64>::new())
+            .into(&mut centered)
+            .unwrap();
+
+        assert_eq!(centered.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(centered.get_element_value(&0).unwrap(), -2.);
+        assert_eq!(centered.get_element_value(&2).unwrap(), 2.);
+
+        let mut scaled = SparseVector::<f64>::new(&context, &4).unwrap();
+        ops::ewise_mult(&mean, &vector)
+            .binary_operator(&Divide::<f64, f64, f64>::new())
+            .into(&mut scaled)
+            .unwrap();
+
+        assert_eq!(scaled.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(scaled.get_element_value(&0).unwrap(), 3.);
+
+        // Compare the stored elements to a threshold
+        let mut is_below_mean = SparseVector::<bool>::new(&context, &4).unwrap();
+        ops::ewise_mult(&vector, &mean)
+            .binary_operator(&LessThan::<f64, f64, bool>::new())
+            .into(&mut is_below_mean)
+            .unwrap();
+
+        assert_eq!(is_below_mean.number_of_stored_elements().unwrap(), 2);
+        assert!(is_below_mean.get_element_value(&0).unwrap());
+        assert!(!is_below_mean.get_element_value(&2).unwrap());
+    }
+}
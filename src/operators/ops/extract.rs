@@ -0,0 +1,270 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::Accumulator;
+use crate::operators::extract::{
+    MatrixColumnExtractor, MatrixRowExtractor, SubMatrixExtractor, SubVectorExtractor,
+};
+use crate::operators::mask::{MatrixMask, VectorMask};
+use crate::util::{ElementIndex, ElementIndexSelector};
+use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::sparse_vector::SparseVector;
+use crate::value_types::value_type::{AsBoolean, ValueType};
+
+use super::operation::{NoMask, NoOperator, Operation};
+
+pub struct SubMatrixArguments<'a, Matrix: ValueType> {
+    matrix: &'a SparseMatrix<Matrix>,
+    rows: &'a ElementIndexSelector<'a>,
+    columns: &'a ElementIndexSelector<'a>,
+}
+
+pub struct SubVectorArguments<'a, Vector: ValueType> {
+    vector: &'a SparseVector<Vector>,
+    indices: &'a ElementIndexSelector<'a>,
+}
+
+pub struct MatrixColumnArguments<'a, Matrix: ValueType> {
+    matrix: &'a SparseMatrix<Matrix>,
+    column_index: ElementIndex,
+    indices: &'a ElementIndexSelector<'a>,
+}
+
+pub struct MatrixRowArguments<'a, Matrix: ValueType> {
+    matrix: &'a SparseMatrix<Matrix>,
+    row_index: ElementIndex,
+    indices: &'a ElementIndexSelector<'a>,
+}
+
+/// Extracts the sub-matrix at the selected rows and columns
+pub fn extract_sub_matrix<'a, Matrix: ValueType, SubMatrix: ValueType>(
+    matrix: &'a SparseMatrix<Matrix>,
+    rows: &'a ElementIndexSelector<'a>,
+    columns: &'a ElementIndexSelector<'a>,
+) -> Operation<'a, SubMatrixArguments<'a, Matrix>, NoOperator, SubMatrix, NoMask> {
+    Operation::new(SubMatrixArguments {
+        matrix,
+        rows,
+        columns,
+    })
+}
+
+/// Extracts the sub-vector at the selected indices
+pub fn extract_sub_vector<'a, Vector: ValueType, SubVector: ValueType>(
+    vector: &'a SparseVector<Vector>,
+    indices: &'a ElementIndexSelector<'a>,
+) -> Operation<'a, SubVectorArguments<'a, Vector>, NoOperator, SubVector, NoMask> {
+    Operation::new(SubVectorArguments { vector, indices })
+}
+
+/// Extracts the selected row indices of a matrix column
+pub fn extract_column<'a, Matrix: ValueType, Column: ValueType>(
+    matrix: &'a SparseMatrix<Matrix>,
+    column_index: ElementIndex,
+    indices: &'a ElementIndexSelector<'a>,
+) -> Operation<'a, MatrixColumnArguments<'a, Matrix>, NoOperator, Column, NoMask> {
+    Operation::new(MatrixColumnArguments {
+        matrix,
+        column_index,
+        indices,
+    })
+}
+
+/// Extracts the selected column indices of a matrix row
+pub fn extract_row<'a, Matrix: ValueType, Row: ValueType>(
+    matrix: &'a SparseMatrix<Matrix>,
+    row_index: ElementIndex,
+    indices: &'a ElementIndexSelector<'a>,
+) -> Operation<'a, MatrixRowArguments<'a, Matrix>, NoOperator, Row, NoMask> {
+    Operation::new(MatrixRowArguments {
+        matrix,
+        row_index,
+        indices,
+    })
+}
+
+impl<
+        'a,
+        Matrix: ValueType,
+        SubMatrix: ValueType,
+        Accumulate: Accumulator<SubMatrix, SubMatrix, SubMatrix>,
+    > Operation<'a, SubMatrixArguments<'a, Matrix>, NoOperator, SubMatrix, NoMask, Accumulate>
+{
+    pub fn into(
+        self,
+        sub_matrix: &mut SparseMatrix<SubMatrix>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        SubMatrixExtractor::new(&self.options(), &self.accumulator).apply(
+            self.arguments.matrix,
+            self.arguments.rows,
+            self.arguments.columns,
+            sub_matrix,
+        )
+    }
+}
+
+impl<
+        'a,
+        Matrix: ValueType,
+        SubMatrix: ValueType,
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+        Accumulate: Accumulator<SubMatrix, SubMatrix, SubMatrix>,
+    >
+    Operation<
+        'a,
+        SubMatrixArguments<'a, Matrix>,
+        NoOperator,
+        SubMatrix,
+        MatrixMask<'a, MaskValueType, AsBool>,
+        Accumulate,
+    >
+{
+    pub fn into(
+        self,
+        sub_matrix: &mut SparseMatrix<SubMatrix>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        SubMatrixExtractor::new(&self.options(), &self.accumulator).apply_with_mask(
+            self.arguments.matrix,
+            self.arguments.rows,
+            self.arguments.columns,
+            sub_matrix,
+            &self.mask,
+        )
+    }
+}
+
+impl<
+        'a,
+        Vector: ValueType,
+        SubVector: ValueType,
+        Accumulate: Accumulator<SubVector, SubVector, SubVector>,
+    > Operation<'a, SubVectorArguments<'a, Vector>, NoOperator, SubVector, NoMask, Accumulate>
+{
+    pub fn into(
+        self,
+        sub_vector: &mut SparseVector<SubVector>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        SubVectorExtractor::new(&self.options(), &self.accumulator).apply(
+            self.arguments.vector,
+            self.arguments.indices,
+            sub_vector,
+        )
+    }
+}
+
+impl<
+        'a,
+        Vector: ValueType,
+        SubVector: ValueType,
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+        Accumulate: Accumulator<SubVector, SubVector, SubVector>,
+    >
+    Operation<
+        'a,
+        SubVectorArguments<'a, Vector>,
+        NoOperator,
+        SubVector,
+        VectorMask<'a, MaskValueType, AsBool>,
+        Accumulate,
+    >
+{
+    pub fn into(
+        self,
+        sub_vector: &mut SparseVector<SubVector>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        SubVectorExtractor::new(&self.options(), &self.accumulator).apply_with_mask(
+            self.arguments.vector,
+            self.arguments.indices,
+            sub_vector,
+            &self.mask,
+        )
+    }
+}
+
+impl<'a, Matrix: ValueType, Column: ValueType, Accumulate: Accumulator<Column, Column, Column>>
+    Operation<'a, MatrixColumnArguments<'a, Matrix>, NoOperator, Column, NoMask, Accumulate>
+{
+    pub fn into(
+        self,
+        column_vector: &mut SparseVector<Column>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MatrixColumnExtractor::new(&self.options(), &self.accumulator).apply(
+            self.arguments.matrix,
+            &self.arguments.column_index,
+            self.arguments.indices,
+            column_vector,
+        )
+    }
+}
+
+impl<
+        'a,
+        Matrix: ValueType,
+        Column: ValueType,
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+        Accumulate: Accumulator<Column, Column, Column>,
+    >
+    Operation<
+        'a,
+        MatrixColumnArguments<'a, Matrix>,
+        NoOperator,
+        Column,
+        VectorMask<'a, MaskValueType, AsBool>,
+        Accumulate,
+    >
+{
+    pub fn into(
+        self,
+        column_vector: &mut SparseVector<Column>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MatrixColumnExtractor::new(&self.options(), &self.accumulator).apply_with_mask(
+            self.arguments.matrix,
+            &self.arguments.column_index,
+            self.arguments.indices,
+            column_vector,
+            &self.mask,
+        )
+    }
+}
+
+impl<'a, Matrix: ValueType, Row: ValueType, Accumulate: Accumulator<Row, Row, Row>>
+    Operation<'a, MatrixRowArguments<'a, Matrix>, NoOperator, Row, NoMask, Accumulate>
+{
+    pub fn into(self, row_vector: &mut SparseVector<Row>) -> Result<(), SparseLinearAlgebraError> {
+        MatrixRowExtractor::new(&self.options(), &self.accumulator).apply(
+            self.arguments.matrix,
+            &self.arguments.row_index,
+            self.arguments.indices,
+            row_vector,
+        )
+    }
+}
+
+impl<
+        'a,
+        Matrix: ValueType,
+        Row: ValueType,
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+        Accumulate: Accumulator<Row, Row, Row>,
+    >
+    Operation<
+        'a,
+        MatrixRowArguments<'a, Matrix>,
+        NoOperator,
+        Row,
+        VectorMask<'a, MaskValueType, AsBool>,
+        Accumulate,
+    >
+{
+    pub fn into(self, row_vector: &mut SparseVector<Row>) -> Result<(), SparseLinearAlgebraError> {
+        MatrixRowExtractor::new(&self.options(), &self.accumulator).apply_with_mask(
+            self.arguments.matrix,
+            &self.arguments.row_index,
+            self.arguments.indices,
+            row_vector,
+            &self.mask,
+        )
+    }
+}
@@ -0,0 +1,34 @@
+mod apply;
+mod assign;
+mod element_wise;
+mod extract;
+mod multiplication;
+mod operation;
+mod reduce;
+mod select;
+
+pub use apply::{apply, transpose, ApplyArguments, TransposeArguments};
+pub use assign::{
+    assign_column, assign_matrix, assign_row, assign_scalar_to_matrix, assign_scalar_to_vector,
+    assign_vector, subassign_column, subassign_matrix, subassign_row, subassign_scalar_to_matrix,
+    subassign_scalar_to_vector, subassign_vector, AssignToColumnArguments, AssignToMatrixArguments,
+    AssignToRowArguments, AssignToVectorArguments, SubAssignArguments,
+};
+pub use element_wise::{
+    ewise_add, ewise_mult, kronecker, ElementWiseAdditionArguments,
+    ElementWiseMultiplicationArguments, KroneckerProductArguments,
+};
+pub use extract::{
+    extract_column, extract_row, extract_sub_matrix, extract_sub_vector, MatrixColumnArguments,
+    MatrixRowArguments, SubMatrixArguments, SubVectorArguments,
+};
+pub use multiplication::{
+    mxm, mxv, vxm, MatrixMultiplicationArguments, MatrixVectorMultiplicationArguments,
+    VectorMatrixMultiplicationArguments,
+};
+pub use operation::{
+    NoMask, NoOperator, Operation, UsingBinaryOperator, UsingMonoid, UsingSemiring,
+    UsingUnaryOperator,
+};
+pub use reduce::{reduce, ReduceArguments};
+pub use select::{select, MatrixSelection, SelectArguments, VectorSelection};
@@ -0,0 +1,361 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::Accumulator;
+use crate::operators::mask::{MatrixMask, VectorMask};
+use crate::operators::multiplication::{
+    MatrixMultiplicationOperator, MatrixVectorMultiplicationOperator,
+    VectorMatrixMultiplicationOperator,
+};
+use crate::operators::semiring::Semiring;
+use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::sparse_vector::SparseVector;
+use crate::value_types::value_type::{AsBoolean, ValueType};
+
+use super::operation::{NoMask, NoOperator, Operation, UsingSemiring};
+
+pub struct MatrixMultiplicationArguments<'a, Multiplier: ValueType, Multiplicant: ValueType> {
+    multiplier: &'a SparseMatrix<Multiplier>,
+    multiplicant: &'a SparseMatrix<Multiplicant>,
+}
+
+pub struct MatrixVectorMultiplicationArguments<'a, Multiplier: ValueType, Multiplicant: ValueType> {
+    multiplier: &'a SparseMatrix<Multiplier>,
+    multiplicant: &'a SparseVector<Multiplicant>,
+}
+
+pub struct VectorMatrixMultiplicationArguments<'a, Multiplier: ValueType, Multiplicant: ValueType> {
+    multiplier: &'a SparseVector<Multiplier>,
+    multiplicant: &'a SparseMatrix<Multiplicant>,
+}
+
+/// Matrix-matrix multiplication, requires a semiring
+pub fn mxm<'a, Multiplier: ValueType, Multiplicant: ValueType, Product: ValueType>(
+    multiplier: &'a SparseMatrix<Multiplier>,
+    multiplicant: &'a SparseMatrix<Multiplicant>,
+) -> Operation<
+    'a,
+    MatrixMultiplicationArguments<'a, Multiplier, Multiplicant>,
+    NoOperator,
+    Product,
+    NoMask,
+> {
+    Operation::new(MatrixMultiplicationArguments {
+        multiplier,
+        multiplicant,
+    })
+}
+
+/// Matrix-vector multiplication, requires a semiring
+pub fn mxv<'a, Multiplier: ValueType, Multiplicant: ValueType, Product: ValueType>(
+    multiplier: &'a SparseMatrix<Multiplier>,
+    multiplicant: &'a SparseVector<Multiplicant>,
+) -> Operation<
+    'a,
+    MatrixVectorMultiplicationArguments<'a, Multiplier, Multiplicant>,
+    NoOperator,
+    Product,
+    NoMask,
+> {
+    Operation::new(MatrixVectorMultiplicationArguments {
+        multiplier,
+        multiplicant,
+    })
+}
+
+/// Vector-matrix multiplication, requires a semiring
+pub fn vxm<'a, Multiplier: ValueType, Multiplicant: ValueType, Product: ValueType>(
+    multiplier: &'a SparseVector<Multiplier>,
+    multiplicant: &'a SparseMatrix<Multiplicant>,
+) -> Operation<
+    'a,
+    VectorMatrixMultiplicationArguments<'a, Multiplier, Multiplicant>,
+    NoOperator,
+    Product,
+    NoMask,
+> {
+    Operation::new(VectorMatrixMultiplicationArguments {
+        multiplier,
+        multiplicant,
+    })
+}
+
+macro_rules! implement_multiplication_operation {
+    ($arguments:ident, $product_container:ident, $mask:ident, $operator:ident) => {
+        impl<
+                'a,
+                Multiplier: ValueType,
+                Multiplicant: ValueType,
+                Product: ValueType,
+                Mask,
+                Accumulate,
+            >
+            Operation<
+                'a,
+                $arguments<'a, Multiplier, Multiplicant>,
+                NoOperator,
+                Product,
+                Mask,
+                Accumulate,
+            >
+        {
+            pub fn semiring<Operator: Semiring<Multiplier, Multiplicant, Product> + ?Sized>(
+                self,
+                semiring: &'a Operator,
+            ) -> Operation<
+                'a,
+                $arguments<'a, Multiplier, Multiplicant>,
+                UsingSemiring<'a, Operator>,
+                Product,
+                Mask,
+                Accumulate,
+            > {
+                self.with_operator(UsingSemiring(semiring))
+            }
+        }
+
+        impl<
+                'a,
+                Multiplier: ValueType,
+                Multiplicant: ValueType,
+                Product: ValueType,
+                Operator: Semiring<Multiplier, Multiplicant, Product> + ?Sized,
+                Accumulate: Accumulator<Product, Product, Product>,
+            >
+            Operation<
+                'a,
+                $arguments<'a, Multiplier, Multiplicant>,
+                UsingSemiring<'a, Operator>,
+                Product,
+                NoMask,
+                Accumulate,
+            >
+        {
+            pub fn into(
+                self,
+                product: &mut $product_container<Product>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                $operator::new(self.operator.0, &self.options(), &self.accumulator).apply(
+                    self.arguments.multiplier,
+                    self.arguments.multiplicant,
+                    product,
+                )
+            }
+        }
+
+        impl<
+                'a,
+                Multiplier: ValueType,
+                Multiplicant: ValueType,
+                Product: ValueType,
+                MaskValueType: ValueType,
+                AsBool: AsBoolean<MaskValueType>,
+                Operator: Semiring<Multiplier, Multiplicant, Product> + ?Sized,
+                Accumulate: Accumulator<Product, Product, Product>,
+            >
+            Operation<
+                'a,
+                $arguments<'a, Multiplier, Multiplicant>,
+                UsingSemiring<'a, Operator>,
+                Product,
+                $mask<'a, MaskValueType, AsBool>,
+                Accumulate,
+            >
+        {
+            pub fn into(
+                self,
+                product: &mut $product_container<Product>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                $operator::new(self.operator.0, &self.options(), &self.accumulator).apply_with_mask(
+                    &self.mask,
+                    self.arguments.multiplier,
+                    self.arguments.multiplicant,
+                    product,
+                )
+            }
+        }
+    };
+}
+
+implement_multiplication_operation!(
+    MatrixMultiplicationArguments,
+    SparseMatrix,
+    MatrixMask,
+    MatrixMultiplicationOperator
+);
+implement_multiplication_operation!(
+    MatrixVectorMultiplicationArguments,
+    SparseVector,
+    VectorMask,
+    MatrixVectorMultiplicationOperator
+);
+implement_multiplication_operation!(
+    VectorMatrixMultiplicationArguments,
+    SparseVector,
+    VectorMask,
+    VectorMatrixMultiplicationOperator
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::context::{Context, Mode};
+    use crate::operators::binary_operator::{First, Plus};
+    use crate::operators::ops;
+    use crate::operators::semiring::PlusTimes;
+    use crate::value_types::sparse_matrix::{
+        FromMatrixElementList, GetMatrixElementValue, MatrixElementList, Size,
+    };
+    use crate::value_types::sparse_vector::{
+        FromVectorElementList, GetVectorElementValue, VectorElementList,
+    };
+
+    #[test]
+    fn test_matrix_multiplication() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let semiring = PlusTimes::<f32, f32, f32>::new();
+        let size: Size = (2, 2).into();
+
+        let multiplier = SparseMatrix::<f32>::from_element_list(
+            &context,
+            &size,
+            &MatrixElementList::<f32>::from_element_vector(vec![
+                (0, 0, 1.0).into(),
+                (1, 0, 2.0).into(),
+                (0, 1, 3.0).into(),
+                (1, 1, 4.0).into(),
+            ]),
+            &First::<f32, f32, f32>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseMatrix::<f32>::from_element_list(
+            &context,
+            &size,
+            &MatrixElementList::<f32>::from_element_vector(vec![
+                (0, 0, 5.0).into(),
+                (1, 0, 6.0).into(),
+                (0, 1, 7.0).into(),
+                (1, 1, 8.0).into(),
+            ]),
+            &First::<f32, f32, f32>::new(),
+        )
+        .unwrap();
+
+        let mut product = SparseMatrix::<f32>::new(&context, &size).unwrap();
+        ops::mxm(&multiplier, &multiplicant)
+            .semiring(&semiring)
+            .into(&mut product)
+            .unwrap();
+
+        assert_eq!(product.get_element_value(&(0, 0).into()).unwrap(), 23.);
+        assert_eq!(product.get_element_value(&(1, 0).into()).unwrap(), 34.);
+        assert_eq!(product.get_element_value(&(0, 1).into()).unwrap(), 31.);
+        assert_eq!(product.get_element_value(&(1, 1).into()).unwrap(), 46.);
+
+        let accumulator = Plus::<f32, f32, f32>::new();
+        ops::mxm(&multiplier, &multiplicant)
+            .semiring(&semiring)
+            .accumulate(&accumulator)
+            .into(&mut product)
+            .unwrap();
+
+        assert_eq!(product.get_element_value(&(0, 0).into()).unwrap(), 23. * 2.);
+        assert_eq!(product.get_element_value(&(1, 1).into()).unwrap(), 46. * 2.);
+
+        let mask_matrix = SparseMatrix::<u8>::from_element_list(
+            &context,
+            &size,
+            &MatrixElementList::<u8>::from_element_vector(vec![
+                (0, 0, 3).into(),
+                (1, 0, 0).into(),
+                (1, 1, 1).into(),
+            ]),
+            &First::<u8, u8, u8>::new(),
+        )
+        .unwrap();
+        let mask: MatrixMask<u8, u8> = MatrixMask::new(&mask_matrix);
+
+        let mut product = SparseMatrix::<f32>::new(&context, &size).unwrap();
+        ops::mxm(&multiplier, &multiplicant)
+            .semiring(&semiring)
+            .mask(&mask)
+            .into(&mut product)
+            .unwrap();
+
+        assert_eq!(product.get_element_value(&(0, 0).into()).unwrap(), 23.);
+        assert_eq!(product.get_element_value(&(1, 0).into()).unwrap(), 0.);
+        assert_eq!(product.get_element_value(&(0, 1).into()).unwrap(), 0.);
+        assert_eq!(product.get_element_value(&(1, 1).into()).unwrap(), 46.);
+
+        let mut product = SparseMatrix::<f32>::new(&context, &size).unwrap();
+        ops::mxm(&multiplier, &multiplicant)
+            .semiring(&semiring)
+            .mask(&mask)
+            .complement()
+            .structural()
+            .into(&mut product)
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 1);
+        assert_eq!(product.get_element_value(&(0, 1).into()).unwrap(), 31.);
+
+        let mut product = SparseMatrix::<f32>::new(&context, &size).unwrap();
+        ops::mxm(&multiplier, &multiplicant)
+            .semiring(&semiring)
+            .mask(&mask.structure().complement())
+            .into(&mut product)
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 1);
+        assert_eq!(product.get_element_value(&(0, 1).into()).unwrap(), 31.);
+
+        // The complement of the builder inverts the complement of the mask
+        let mut product = SparseMatrix::<f32>::new(&context, &size).unwrap();
+        ops::mxm(&multiplier, &multiplicant)
+            .semiring(&semiring)
+            .mask(&mask.complement())
+            .complement()
+            .into(&mut product)
+            .unwrap();
+
+        assert_eq!(product.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(product.get_element_value(&(0, 0).into()).unwrap(), 23.);
+        assert_eq!(product.get_element_value(&(1, 1).into()).unwrap(), 46.);
+    }
+
+    #[test]
+    fn test_matrix_vector_multiplication_with_transpose() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let semiring = PlusTimes::<i32, i32, i32>::new();
+
+        let multiplier = SparseMatrix::<i32>::from_element_list(
+            &context,
+            &(2, 2).into(),
+            &MatrixElementList::<i32>::from_element_vector(vec![
+                (0, 0, 1).into(),
+                (0, 1, 2).into(),
+                (1, 1, 3).into(),
+            ]),
+            &First::<i32, i32, i32>::new(),
+        )
+        .unwrap();
+        let multiplicant = SparseVector::<i32>::from_element_list(
+            &context,
+            &2,
+            &VectorElementList::<i32>::from_element_vector(vec![(0, 1).into(), (1, 1).into()]),
+            &First::<i32, i32, i32>::new(),
+        )
+        .unwrap();
+
+        let mut product = SparseVector::<i32>::new(&context, &2).unwrap();
+        ops::mxv(&multiplier, &multiplicant)
+            .semiring(&semiring)
+            .transpose_first()
+            .into(&mut product)
+            .unwrap();
+
+        assert_eq!(product.get_element_value(&0).unwrap(), 1);
+        assert_eq!(product.get_element_value(&1).unwrap(), 5);
+    }
+}
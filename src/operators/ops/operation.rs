@@ -0,0 +1,180 @@
+use std::marker::PhantomData;
+
+use crate::operators::binary_operator::{Accumulator, NoAccumulator};
+use crate::operators::mask::{MatrixMask, VectorMask};
+use crate::operators::options::OperatorOptions;
+use crate::value_types::value_type::{AsBoolean, ValueType};
+
+/// Marks an Operation without a mask
+#[derive(Debug, Clone, Copy)]
+pub struct NoMask;
+
+/// Marks an Operation for which the operator has not been set yet
+#[derive(Debug, Clone, Copy)]
+pub struct NoOperator;
+
+/// Marks an Operation computed by a semiring
+#[derive(Debug, Clone, Copy)]
+pub struct UsingSemiring<'a, Semiring: ?Sized>(pub(super) &'a Semiring);
+
+/// Marks an Operation computed by a monoid
+#[derive(Debug, Clone, Copy)]
+pub struct UsingMonoid<'a, Monoid: ?Sized>(pub(super) &'a Monoid);
+
+/// Marks an Operation computed by a binary operator
+#[derive(Debug, Clone, Copy)]
+pub struct UsingBinaryOperator<'a, BinaryOperator: ?Sized>(pub(super) &'a BinaryOperator);
+
+/// Marks an Operation computed by a unary operator
+#[derive(Debug, Clone, Copy)]
+pub struct UsingUnaryOperator<'a, UnaryOperator: ?Sized>(pub(super) &'a UnaryOperator);
+
+/// Collects the arguments, operator, mask, accumulator and options of a GraphBLAS operation.
+/// The operation is executed by into(), which is available once all required parts are set.
+pub struct Operation<'a, Arguments, Operator, Product: ValueType, Mask, Accumulate = NoAccumulator>
+{
+    pub(super) arguments: Arguments,
+    pub(super) operator: Operator,
+    pub(super) mask: Mask,
+    pub(super) accumulator: Accumulate,
+
+    settings: Settings,
+    _product: PhantomData<&'a Product>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Settings {
+    clear_output_before_use: bool,
+    transpose_input0: bool,
+    transpose_input1: bool,
+}
+
+impl<'a, Arguments, Product: ValueType> Operation<'a, Arguments, NoOperator, Product, NoMask> {
+    pub(super) fn new(arguments: Arguments) -> Self {
+        Self {
+            arguments,
+            operator: NoOperator,
+            mask: NoMask,
+            accumulator: NoAccumulator,
+
+            settings: Settings::default(),
+            _product: PhantomData,
+        }
+    }
+}
+
+impl<'a, Arguments, Operator, Product: ValueType, Mask, Accumulate>
+    Operation<'a, Arguments, Operator, Product, Mask, Accumulate>
+{
+    /// Accumulates the result into the existing values of the output: Z = accumulator(C, T)
+    pub fn accumulate<NewAccumulate: Accumulator<Product, Product, Product> + ?Sized>(
+        self,
+        accumulator: &'a NewAccumulate,
+    ) -> Operation<'a, Arguments, Operator, Product, Mask, &'a NewAccumulate> {
+        Operation {
+            arguments: self.arguments,
+            operator: self.operator,
+            mask: self.mask,
+            accumulator,
+
+            settings: self.settings,
+            _product: PhantomData,
+        }
+    }
+
+    /// Clears all output values not selected by the mask
+    pub fn replace(mut self) -> Self {
+        self.settings.clear_output_before_use = true;
+        self
+    }
+
+    /// Transposes the first matrix argument
+    pub fn transpose_first(mut self) -> Self {
+        self.settings.transpose_input0 = true;
+        self
+    }
+
+    /// Transposes the second matrix argument
+    pub fn transpose_second(mut self) -> Self {
+        self.settings.transpose_input1 = true;
+        self
+    }
+
+    pub(super) fn options(&self) -> OperatorOptions {
+        OperatorOptions::new(
+            self.settings.clear_output_before_use,
+            self.settings.transpose_input0,
+            self.settings.transpose_input1,
+        )
+    }
+
+    pub(super) fn with_operator<NewOperator>(
+        self,
+        operator: NewOperator,
+    ) -> Operation<'a, Arguments, NewOperator, Product, Mask, Accumulate> {
+        Operation {
+            arguments: self.arguments,
+            operator,
+            mask: self.mask,
+            accumulator: self.accumulator,
+
+            settings: self.settings,
+            _product: PhantomData,
+        }
+    }
+}
+
+impl<'a, Arguments, Operator, Product: ValueType, Accumulate>
+    Operation<'a, Arguments, Operator, Product, NoMask, Accumulate>
+{
+    /// Only writes output values selected by the mask.
+    /// A MatrixMask is expected for matrix outputs, a VectorMask for vector outputs.
+    pub fn mask<Mask: Copy>(
+        self,
+        mask: &Mask,
+    ) -> Operation<'a, Arguments, Operator, Product, Mask, Accumulate> {
+        Operation {
+            arguments: self.arguments,
+            operator: self.operator,
+            mask: *mask,
+            accumulator: self.accumulator,
+
+            settings: self.settings,
+            _product: PhantomData,
+        }
+    }
+}
+
+macro_rules! implement_mask_combinators {
+    ($mask:ident) => {
+        impl<'a, Arguments, Operator, Product, MaskValueType, AsBool, Accumulate>
+            Operation<
+                'a,
+                Arguments,
+                Operator,
+                Product,
+                $mask<'a, MaskValueType, AsBool>,
+                Accumulate,
+            >
+        where
+            Product: ValueType,
+            MaskValueType: ValueType,
+            AsBool: AsBoolean<MaskValueType>,
+        {
+            /// Inverts the selection of the mask, see complement() of the mask
+            pub fn complement(mut self) -> Self {
+                self.mask = self.mask.complement();
+                self
+            }
+
+            /// Selects the output by the stored elements of the mask, see structure() of the mask
+            pub fn structural(mut self) -> Self {
+                self.mask = self.mask.structure();
+                self
+            }
+        }
+    };
+}
+
+implement_mask_combinators!(MatrixMask);
+implement_mask_combinators!(VectorMask);
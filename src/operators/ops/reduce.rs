@@ -0,0 +1,283 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::{Accumulator, BinaryOperator};
+use crate::operators::mask::{MatrixMask, VectorMask};
+use crate::operators::monoid::Monoid;
+use crate::operators::reduce::{
+    BinaryOperatorReducer, MonoidReducer, MonoidScalarReducer, MonoidSparseScalarReducer,
+};
+use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::sparse_scalar::SparseScalar;
+use crate::value_types::sparse_vector::SparseVector;
+use crate::value_types::value_type::{AsBoolean, ValueType};
+
+use super::operation::{NoMask, NoOperator, Operation, UsingBinaryOperator, UsingMonoid};
+
+pub struct ReduceArguments<'a, Argument: ?Sized> {
+    argument: &'a Argument,
+}
+
+/// Reduces a matrix to a vector by combining the stored elements of each row,
+/// or a matrix or vector to a scalar. Requires a monoid or binary operator.
+pub fn reduce<'a, Argument: ?Sized, T: ValueType>(
+    argument: &'a Argument,
+) -> Operation<'a, ReduceArguments<'a, Argument>, NoOperator, T, NoMask> {
+    Operation::new(ReduceArguments { argument })
+}
+
+impl<'a, Argument: ?Sized, T: ValueType, Mask, Accumulate>
+    Operation<'a, ReduceArguments<'a, Argument>, NoOperator, T, Mask, Accumulate>
+{
+    pub fn monoid<Operator: Monoid<T> + ?Sized>(
+        self,
+        monoid: &'a Operator,
+    ) -> Operation<
+        'a,
+        ReduceArguments<'a, Argument>,
+        UsingMonoid<'a, Operator>,
+        T,
+        Mask,
+        Accumulate,
+    > {
+        self.with_operator(UsingMonoid(monoid))
+    }
+
+    pub fn binary_operator<Operator: BinaryOperator<T, T, T> + ?Sized>(
+        self,
+        binary_operator: &'a Operator,
+    ) -> Operation<
+        'a,
+        ReduceArguments<'a, Argument>,
+        UsingBinaryOperator<'a, Operator>,
+        T,
+        Mask,
+        Accumulate,
+    > {
+        self.with_operator(UsingBinaryOperator(binary_operator))
+    }
+}
+
+impl<'a, T: ValueType, Operator: Monoid<T> + ?Sized, Accumulate: Accumulator<T, T, T>>
+    Operation<
+        'a,
+        ReduceArguments<'a, SparseMatrix<T>>,
+        UsingMonoid<'a, Operator>,
+        T,
+        NoMask,
+        Accumulate,
+    >
+{
+    pub fn into(self, product: &mut SparseVector<T>) -> Result<(), SparseLinearAlgebraError> {
+        MonoidReducer::new(self.operator.0, &self.options(), &self.accumulator)
+            .to_vector(self.arguments.argument, product)
+    }
+}
+
+impl<
+        'a,
+        T: ValueType,
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+        Operator: Monoid<T> + ?Sized,
+        Accumulate: Accumulator<T, T, T>,
+    >
+    Operation<
+        'a,
+        ReduceArguments<'a, SparseMatrix<T>>,
+        UsingMonoid<'a, Operator>,
+        T,
+        VectorMask<'a, MaskValueType, AsBool>,
+        Accumulate,
+    >
+{
+    pub fn into(self, product: &mut SparseVector<T>) -> Result<(), SparseLinearAlgebraError> {
+        MonoidReducer::new(self.operator.0, &self.options(), &self.accumulator).to_vector_with_mask(
+            self.arguments.argument,
+            product,
+            &self.mask,
+        )
+    }
+}
+
+impl<
+        'a,
+        T: ValueType,
+        Operator: BinaryOperator<T, T, T> + ?Sized,
+        Accumulate: Accumulator<T, T, T>,
+    >
+    Operation<
+        'a,
+        ReduceArguments<'a, SparseMatrix<T>>,
+        UsingBinaryOperator<'a, Operator>,
+        T,
+        NoMask,
+        Accumulate,
+    >
+{
+    pub fn into(self, product: &mut SparseVector<T>) -> Result<(), SparseLinearAlgebraError> {
+        BinaryOperatorReducer::new(self.operator.0, &self.options(), &self.accumulator)
+            .to_vector(self.arguments.argument, product)
+    }
+}
+
+impl<
+        'a,
+        T: ValueType,
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+        Operator: BinaryOperator<T, T, T> + ?Sized,
+        Accumulate: Accumulator<T, T, T>,
+    >
+    Operation<
+        'a,
+        ReduceArguments<'a, SparseMatrix<T>>,
+        UsingBinaryOperator<'a, Operator>,
+        T,
+        VectorMask<'a, MaskValueType, AsBool>,
+        Accumulate,
+    >
+{
+    pub fn into(self, product: &mut SparseVector<T>) -> Result<(), SparseLinearAlgebraError> {
+        BinaryOperatorReducer::new(self.operator.0, &self.options(), &self.accumulator)
+            .to_vector_with_mask(self.arguments.argument, product, &self.mask)
+    }
+}
+
+impl<'a, T: ValueType, Operator: Monoid<T> + ?Sized, Accumulate: Accumulator<T, T, T>>
+    Operation<
+        'a,
+        ReduceArguments<'a, SparseMatrix<T>>,
+        UsingMonoid<'a, Operator>,
+        T,
+        NoMask,
+        Accumulate,
+    >
+where
+    MonoidReducer<T>: MonoidScalarReducer<T>,
+{
+    pub fn into_scalar(self, product: &mut T) -> Result<(), SparseLinearAlgebraError> {
+        MonoidReducer::new(self.operator.0, &self.options(), &self.accumulator)
+            .matrix_to_scalar(self.arguments.argument, product)
+    }
+}
+
+impl<'a, T: ValueType, Operator: Monoid<T> + ?Sized, Accumulate: Accumulator<T, T, T>>
+    Operation<
+        'a,
+        ReduceArguments<'a, SparseVector<T>>,
+        UsingMonoid<'a, Operator>,
+        T,
+        NoMask,
+        Accumulate,
+    >
+where
+    MonoidReducer<T>: MonoidScalarReducer<T>,
+{
+    pub fn into_scalar(self, product: &mut T) -> Result<(), SparseLinearAlgebraError> {
+        MonoidReducer::new(self.operator.0, &self.options(), &self.accumulator)
+            .vector_to_scalar(self.arguments.argument, product)
+    }
+}
+
+impl<'a, T: ValueType, Operator: Monoid<T> + ?Sized, Accumulate: Accumulator<T, T, T>>
+    Operation<
+        'a,
+        ReduceArguments<'a, SparseMatrix<T>>,
+        UsingMonoid<'a, Operator>,
+        T,
+        NoMask,
+        Accumulate,
+    >
+where
+    MonoidReducer<T>: MonoidSparseScalarReducer<T>,
+{
+    /// Leaves the product empty if the matrix has no stored elements to reduce
+    pub fn into_sparse_scalar(
+        self,
+        product: &mut SparseScalar<T>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MonoidReducer::new(self.operator.0, &self.options(), &self.accumulator)
+            .matrix_to_sparse_scalar(self.arguments.argument, product)
+    }
+}
+
+impl<
+        'a,
+        T: ValueType,
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+        Operator: Monoid<T> + ?Sized,
+        Accumulate: Accumulator<T, T, T>,
+    >
+    Operation<
+        'a,
+        ReduceArguments<'a, SparseMatrix<T>>,
+        UsingMonoid<'a, Operator>,
+        T,
+        MatrixMask<'a, MaskValueType, AsBool>,
+        Accumulate,
+    >
+where
+    MonoidReducer<T>: MonoidSparseScalarReducer<T>,
+{
+    /// Reduces the elements selected by the mask,
+    /// leaves the product empty if no stored element is selected
+    pub fn into_sparse_scalar(
+        self,
+        product: &mut SparseScalar<T>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MonoidReducer::new(self.operator.0, &self.options(), &self.accumulator)
+            .matrix_to_sparse_scalar_with_mask(self.arguments.argument, product, &self.mask)
+    }
+}
+
+impl<'a, T: ValueType, Operator: Monoid<T> + ?Sized, Accumulate: Accumulator<T, T, T>>
+    Operation<
+        'a,
+        ReduceArguments<'a, SparseVector<T>>,
+        UsingMonoid<'a, Operator>,
+        T,
+        NoMask,
+        Accumulate,
+    >
+where
+    MonoidReducer<T>: MonoidSparseScalarReducer<T>,
+{
+    /// Leaves the product empty if the vector has no stored elements to reduce
+    pub fn into_sparse_scalar(
+        self,
+        product: &mut SparseScalar<T>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MonoidReducer::new(self.operator.0, &self.options(), &self.accumulator)
+            .vector_to_sparse_scalar(self.arguments.argument, product)
+    }
+}
+
+impl<
+        'a,
+        T: ValueType,
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+        Operator: Monoid<T> + ?Sized,
+        Accumulate: Accumulator<T, T, T>,
+    >
+    Operation<
+        'a,
+        ReduceArguments<'a, SparseVector<T>>,
+        UsingMonoid<'a, Operator>,
+        T,
+        VectorMask<'a, MaskValueType, AsBool>,
+        Accumulate,
+    >
+where
+    MonoidReducer<T>: MonoidSparseScalarReducer<T>,
+{
+    /// Reduces the elements selected by the mask,
+    /// leaves the product empty if no stored element is selected
+    pub fn into_sparse_scalar(
+        self,
+        product: &mut SparseScalar<T>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        MonoidReducer::new(self.operator.0, &self.options(), &self.accumulator)
+            .vector_to_sparse_scalar_with_mask(self.arguments.argument, product, &self.mask)
+    }
+}
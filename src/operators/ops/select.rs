@@ -0,0 +1,199 @@
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::binary_operator::Accumulator;
+use crate::operators::mask::{MatrixMask, VectorMask};
+use crate::operators::select::{DiagonalIndex, MatrixSelector, VectorSelector};
+use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::sparse_vector::SparseVector;
+use crate::value_types::value_type::{AsBoolean, ValueType};
+
+use super::operation::{NoMask, NoOperator, Operation};
+
+/// Selects the stored elements of a matrix to keep
+pub enum MatrixSelection {
+    LowerTriangle(DiagonalIndex),
+    UpperTriangle(DiagonalIndex),
+    Diagonal(DiagonalIndex),
+    ClearDiagonal(DiagonalIndex),
+    NonZero,
+    Zero,
+    Positive,
+    ZeroOrPositive,
+    Negative,
+    ZeroOrNegative,
+}
+
+/// Selects the stored elements of a vector to keep
+#[derive(Debug, Clone, Copy)]
+pub enum VectorSelection {
+    NonZero,
+    Zero,
+    Positive,
+    ZeroOrPositive,
+    Negative,
+    ZeroOrNegative,
+}
+
+pub struct SelectArguments<'a, Argument: ?Sized> {
+    argument: &'a Argument,
+}
+
+/// Keeps the stored elements of a matrix or vector chosen by a MatrixSelection or VectorSelection
+pub fn select<'a, Argument: ?Sized, T: ValueType>(
+    argument: &'a Argument,
+) -> Operation<'a, SelectArguments<'a, Argument>, NoOperator, T, NoMask> {
+    Operation::new(SelectArguments { argument })
+}
+
+impl<'a, T: ValueType, Mask, Accumulate>
+    Operation<'a, SelectArguments<'a, SparseMatrix<T>>, NoOperator, T, Mask, Accumulate>
+{
+    pub fn selection(
+        self,
+        selection: MatrixSelection,
+    ) -> Operation<'a, SelectArguments<'a, SparseMatrix<T>>, MatrixSelection, T, Mask, Accumulate>
+    {
+        self.with_operator(selection)
+    }
+}
+
+impl<'a, T: ValueType, Mask, Accumulate>
+    Operation<'a, SelectArguments<'a, SparseVector<T>>, NoOperator, T, Mask, Accumulate>
+{
+    pub fn selection(
+        self,
+        selection: VectorSelection,
+    ) -> Operation<'a, SelectArguments<'a, SparseVector<T>>, VectorSelection, T, Mask, Accumulate>
+    {
+        self.with_operator(selection)
+    }
+}
+
+impl<'a, T: ValueType, Accumulate: Accumulator<T, T, T>>
+    Operation<'a, SelectArguments<'a, SparseMatrix<T>>, MatrixSelection, T, NoMask, Accumulate>
+{
+    pub fn into(self, product: &mut SparseMatrix<T>) -> Result<(), SparseLinearAlgebraError> {
+        let selector = MatrixSelector::new(&self.options(), &self.accumulator);
+        let argument = self.arguments.argument;
+
+        match &self.operator {
+            MatrixSelection::LowerTriangle(diagonal) => {
+                selector.lower_triangle(argument, product, diagonal)
+            }
+            MatrixSelection::UpperTriangle(diagonal) => {
+                selector.upper_triangle(argument, product, diagonal)
+            }
+            MatrixSelection::Diagonal(diagonal) => selector.diagonal(argument, product, diagonal),
+            MatrixSelection::ClearDiagonal(diagonal) => {
+                selector.clear_diagonal(argument, product, diagonal)
+            }
+            MatrixSelection::NonZero => selector.non_zero(argument, product),
+            MatrixSelection::Zero => selector.zero(argument, product),
+            MatrixSelection::Positive => selector.positive(argument, product),
+            MatrixSelection::ZeroOrPositive => selector.zero_or_positive(argument, product),
+            MatrixSelection::Negative => selector.negative(argument, product),
+            MatrixSelection::ZeroOrNegative => selector.zero_or_negative(argument, product),
+        }
+    }
+}
+
+impl<
+        'a,
+        T: ValueType,
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+        Accumulate: Accumulator<T, T, T>,
+    >
+    Operation<
+        'a,
+        SelectArguments<'a, SparseMatrix<T>>,
+        MatrixSelection,
+        T,
+        MatrixMask<'a, MaskValueType, AsBool>,
+        Accumulate,
+    >
+{
+    pub fn into(self, product: &mut SparseMatrix<T>) -> Result<(), SparseLinearAlgebraError> {
+        let selector = MatrixSelector::new(&self.options(), &self.accumulator);
+        let argument = self.arguments.argument;
+        let mask = &self.mask;
+
+        match &self.operator {
+            MatrixSelection::LowerTriangle(diagonal) => {
+                selector.lower_triangle_with_mask(argument, product, diagonal, mask)
+            }
+            MatrixSelection::UpperTriangle(diagonal) => {
+                selector.upper_triangle_with_mask(argument, product, diagonal, mask)
+            }
+            MatrixSelection::Diagonal(diagonal) => {
+                selector.diagonal_with_mask(argument, product, diagonal, mask)
+            }
+            MatrixSelection::ClearDiagonal(diagonal) => {
+                selector.clear_diagonal_with_mask(argument, product, diagonal, mask)
+            }
+            MatrixSelection::NonZero => selector.non_zero_with_mask(argument, product, mask),
+            MatrixSelection::Zero => selector.zero_with_mask(argument, product, mask),
+            MatrixSelection::Positive => selector.positive_with_mask(argument, product, mask),
+            MatrixSelection::ZeroOrPositive => {
+                selector.zero_or_positive_with_mask(argument, product, mask)
+            }
+            MatrixSelection::Negative => selector.negative_with_mask(argument, product, mask),
+            MatrixSelection::ZeroOrNegative => {
+                selector.zero_or_negative_with_mask(argument, product, mask)
+            }
+        }
+    }
+}
+
+impl<'a, T: ValueType, Accumulate: Accumulator<T, T, T>>
+    Operation<'a, SelectArguments<'a, SparseVector<T>>, VectorSelection, T, NoMask, Accumulate>
+{
+    pub fn into(self, product: &mut SparseVector<T>) -> Result<(), SparseLinearAlgebraError> {
+        let selector = VectorSelector::new(&self.options(), &self.accumulator);
+        let argument = self.arguments.argument;
+
+        match self.operator {
+            VectorSelection::NonZero => selector.non_zero(argument, product),
+            VectorSelection::Zero => selector.zero(argument, product),
+            VectorSelection::Positive => selector.positive(argument, product),
+            VectorSelection::ZeroOrPositive => selector.zero_or_positive(argument, product),
+            VectorSelection::Negative => selector.negative(argument, product),
+            VectorSelection::ZeroOrNegative => selector.zero_or_negative(argument, product),
+        }
+    }
+}
+
+impl<
+        'a,
+        T: ValueType,
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+        Accumulate: Accumulator<T, T, T>,
+    >
+    Operation<
+        'a,
+        SelectArguments<'a, SparseVector<T>>,
+        VectorSelection,
+        T,
+        VectorMask<'a, MaskValueType, AsBool>,
+        Accumulate,
+    >
+{
+    pub fn into(self, product: &mut SparseVector<T>) -> Result<(), SparseLinearAlgebraError> {
+        let selector = VectorSelector::new(&self.options(), &self.accumulator);
+        let argument = self.arguments.argument;
+        let mask = &self.mask;
+
+        match self.operator {
+            VectorSelection::NonZero => selector.non_zero_with_mask(argument, product, mask),
+            VectorSelection::Zero => selector.zero_with_mask(argument, product, mask),
+            VectorSelection::Positive => selector.positive_with_mask(argument, product, mask),
+            VectorSelection::ZeroOrPositive => {
+                selector.zero_or_positive_with_mask(argument, product, mask)
+            }
+            VectorSelection::Negative => selector.negative_with_mask(argument, product, mask),
+            VectorSelection::ZeroOrNegative => {
+                selector.zero_or_negative_with_mask(argument, product, mask)
+            }
+        }
+    }
+}
@@ -1,4 +1,12 @@
+use std::mem::MaybeUninit;
+use std::os::raw::{c_double, c_int};
 use std::ptr;
+use std::sync::Arc;
+
+use crate::context::Context;
+use crate::error::{LogicError, LogicErrorType, SparseLinearAlgebraError};
+use crate::value_types::sparse_matrix::{Size, SparseMatrix};
+use crate::value_types::value_type::ValueType;
 
 use crate::bindings_to_graphblas_implementation::{
     GrB_DESC_C, GrB_DESC_CT0, GrB_DESC_CT0T1, GrB_DESC_CT1, GrB_DESC_R, GrB_DESC_RC, GrB_DESC_RCT0,
@@ -6,38 +14,129 @@ use crate::bindings_to_graphblas_implementation::{
     GrB_DESC_RSCT1, GrB_DESC_RST0, GrB_DESC_RST0T1, GrB_DESC_RST1, GrB_DESC_RT0, GrB_DESC_RT0T1,
     GrB_DESC_RT1, GrB_DESC_S, GrB_DESC_SC, GrB_DESC_SCT0, GrB_DESC_SCT0T1, GrB_DESC_SCT1,
     GrB_DESC_ST0, GrB_DESC_ST0T1, GrB_DESC_ST1, GrB_DESC_T0, GrB_DESC_T0T1, GrB_DESC_T1,
-    GrB_Descriptor,
+    GrB_Desc_Field_GrB_INP0, GrB_Desc_Field_GrB_INP1, GrB_Desc_Field_GrB_MASK,
+    GrB_Desc_Field_GrB_OUTP, GrB_Desc_Field_GxB_AxB_METHOD, GrB_Desc_Field_GxB_DESCRIPTOR_CHUNK,
+    GrB_Desc_Field_GxB_DESCRIPTOR_NTHREADS, GrB_Desc_Field_GxB_SORT, GrB_Desc_Value,
+    GrB_Desc_Value_GrB_COMP, GrB_Desc_Value_GrB_REPLACE, GrB_Desc_Value_GrB_STRUCTURE,
+    GrB_Desc_Value_GrB_TRAN, GrB_Desc_Value_GxB_AxB_DOT, GrB_Desc_Value_GxB_AxB_GUSTAVSON,
+    GrB_Desc_Value_GxB_AxB_HASH, GrB_Desc_Value_GxB_AxB_SAXPY, GrB_Descriptor, GrB_Descriptor_free,
+    GrB_Descriptor_new, GrB_Descriptor_set, GxB_Desc_set,
 };
 
-// pub enum GraphblasDescriptor {
-//     // Default(*const GrB_Descriptor),
-//     Default(GrB_Descriptor),
-//     Value(GrB_Descriptor),
-// }
-
 #[derive(Debug, Clone)]
 pub struct OperatorOptions {
     clear_output_before_use: bool,
-    use_mask_structure_of_stored_values_as_mask: bool,
-    use_mask_complement: bool,
     transpose_input0: bool,
     transpose_input1: bool,
+
+    validate_arguments: bool, // check argument sizes and indices before calling GraphBLAS
+    extended_options: Option<ExtendedOperatorOptions>,
+    owned_descriptors: Option<Arc<Vec<OwnedDescriptor>>>, // only set when extended options are used, the predefined descriptors are used otherwise
+}
+
+/// The algorithm SuiteSparse:GraphBLAS uses to multiply matrices (GxB_AxB_METHOD)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatrixMultiplicationMethod {
+    Automatic,
+    Gustavson,
+    DotProduct,
+    Hash,
+    Saxpy,
+}
+
+/// Settings beyond the predefined GrB_DESC_* descriptors.
+/// Settings left at None use the global GraphBLAS setting.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtendedOperatorOptions {
+    matrix_multiplication_method: MatrixMultiplicationMethod,
+    number_of_threads: Option<usize>,
+    chunk_size: Option<f64>,
+    sort_output: bool,
+}
+
+impl ExtendedOperatorOptions {
+    pub fn new_default() -> Self {
+        Self {
+            matrix_multiplication_method: MatrixMultiplicationMethod::Automatic,
+            number_of_threads: None,
+            chunk_size: None,
+            sort_output: false,
+        }
+    }
+
+    pub fn with_matrix_multiplication_method(mut self, method: MatrixMultiplicationMethod) -> Self {
+        self.matrix_multiplication_method = method;
+        self
+    }
+
+    /// Maximum number of threads a single operation may use
+    pub fn with_number_of_threads(mut self, number_of_threads: usize) -> Self {
+        self.number_of_threads = Some(number_of_threads);
+        self
+    }
+
+    /// Amount of work per thread, used to decide how many threads an operation uses
+    pub fn with_chunk_size(mut self, chunk_size: f64) -> Self {
+        self.chunk_size = Some(chunk_size);
+        self
+    }
+
+    /// Forces the product to be sorted when the operation returns, instead of lazily
+    pub fn with_sorted_output(mut self, sort_output: bool) -> Self {
+        self.sort_output = sort_output;
+        self
+    }
+
+    pub fn matrix_multiplication_method(&self) -> MatrixMultiplicationMethod {
+        self.matrix_multiplication_method
+    }
+
+    pub fn number_of_threads(&self) -> Option<usize> {
+        self.number_of_threads
+    }
+
+    pub fn chunk_size(&self) -> Option<f64> {
+        self.chunk_size
+    }
+
+    pub fn sort_output(&self) -> bool {
+        self.sort_output
+    }
+}
+
+#[derive(Debug)]
+struct OwnedDescriptor {
+    context: Arc<Context>,
+    descriptor: GrB_Descriptor,
+}
+
+// The descriptor is never modified after creation.
+// https://doc.rust-lang.org/nomicon/send-and-sync.html
+unsafe impl Send for OwnedDescriptor {}
+unsafe impl Sync for OwnedDescriptor {}
+
+impl Drop for OwnedDescriptor {
+    fn drop(&mut self) -> () {
+        let context = self.context.clone();
+        let _ = context.call(|| unsafe { GrB_Descriptor_free(&mut self.descriptor) });
+    }
 }
 
 impl OperatorOptions {
+    /// Whether the mask is structural or complemented is set on the MatrixMask or VectorMask
     pub fn new(
         clear_output_before_use: bool,
-        use_mask_structure_of_stored_values_as_mask: bool,
-        use_mask_complement: bool,
         transpose_input0: bool,
         transpose_input1: bool,
     ) -> Self {
         Self {
             clear_output_before_use,
-            use_mask_structure_of_stored_values_as_mask,
-            use_mask_complement,
             transpose_input0,
             transpose_input1,
+
+            validate_arguments: true,
+            extended_options: None,
+            owned_descriptors: None,
         }
     }
 
@@ -45,52 +144,115 @@ impl OperatorOptions {
         Self {
             transpose_input0: false,
             transpose_input1: false,
-            use_mask_complement: false,
-            use_mask_structure_of_stored_values_as_mask: false,
             clear_output_before_use: false,
+
+            validate_arguments: true,
+            extended_options: None,
+            owned_descriptors: None,
+        }
+    }
+
+    /// Creates descriptors owned by the returned options, carrying both these options and the extended options
+    pub fn with_extended_options(
+        &self,
+        context: &Arc<Context>,
+        extended_options: &ExtendedOperatorOptions,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        // One descriptor for each combination of output, mask and transpose settings, see
+        // descriptor_index(), such that options with other settings can be derived without creating descriptors
+        let mut owned_descriptors = Vec::with_capacity(32);
+        for index in 0..32 {
+            owned_descriptors.push(new_owned_descriptor(
+                context,
+                extended_options,
+                index & 16 != 0,
+                index & 8 != 0,
+                index & 4 != 0,
+                index & 2 != 0,
+                index & 1 != 0,
+            )?);
         }
+
+        let mut options = self.clone();
+        options.extended_options = Some(extended_options.clone());
+        options.owned_descriptors = Some(Arc::new(owned_descriptors));
+        Ok(options)
+    }
+
+    pub fn extended_options(&self) -> Option<&ExtendedOperatorOptions> {
+        self.extended_options.as_ref()
+    }
+
+    pub fn transpose_input0(&self) -> bool {
+        self.transpose_input0
+    }
+
+    pub fn transpose_input1(&self) -> bool {
+        self.transpose_input1
+    }
+
+    /// The same options, transposing the first input if these options do not, and vice versa
+    pub(crate) fn with_inverted_transpose_input0(&self) -> Self {
+        self.with_transposed_inputs(!self.transpose_input0, self.transpose_input1)
+    }
+
+    /// The same options without transposed inputs, for operators that orient their arguments themselves
+    pub(crate) fn without_transposed_inputs(&self) -> Self {
+        self.with_transposed_inputs(false, false)
+    }
+
+    /// The same options without clearing the output, for intermediate results without a mask
+    pub(crate) fn without_clearing_output(&self) -> Self {
+        let mut options = self.clone();
+        options.clear_output_before_use = false;
+        options
+    }
+
+    // Shares the owned descriptors, which cover every combination of transposed inputs
+    fn with_transposed_inputs(&self, transpose_input0: bool, transpose_input1: bool) -> Self {
+        let mut options = self.clone();
+        options.transpose_input0 = transpose_input0;
+        options.transpose_input1 = transpose_input1;
+        options
+    }
+
+    /// Enables or disables the checks of argument sizes, mask sizes and indices that operators
+    /// run before calling GraphBLAS. Validation is enabled by default.
+    pub fn with_argument_validation(&self, validate_arguments: bool) -> Self {
+        let mut options = self.clone();
+        options.validate_arguments = validate_arguments;
+        options
     }
 
-    // pub fn to_graphblas_descriptor(&self) -> GrB_Descriptor {
-    //     match (self.clearOutputBeforeUse, self.useMaskStructureOfStoredValuesAsMask, self.useMaskComplement, self.transposeInput0, self.transposeInput1) {
-    //         (false,false,false,false,false) => unsafe {GraphblasDescriptor::Default(ptr::null())},
-    //         (false,false,false,false,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_T1)},
-    //         (false,false,false,true,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_T0)},
-    //         (false,false,false,true,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_T0T1)},
-    //         (false,false,true,false,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_C)},
-    //         (false,false,true,false,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_CT1)},
-    //         (false,false,true,true,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_CT0)},
-    //         (false,false,true,true,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_CT0T1)},
-    //         (false,true,false,false,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_S)},
-    //         (false,true,false,false,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_ST1)},
-    //         (false,true,false,true,false) => unsnewnsafe {GraphblasDescriptor::Value(GrB_DESC_SC)},
-    //         (false,true,true,true,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_SCT0)},
-    //         (false,true,true,false,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_SCT1)},
-    //         (false,true,true,true,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_SCT0T1)},
-    //         (true,false,false,false,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_R)},
-    //         (true,false,false,false,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RT1)},
-    //         (true,false,false,true,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RT0)},
-    //         (true,false,false,true,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RT0T1)},
-    //         (true,false,true,false,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RC)},
-    //         (true,false,true,false,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RCT1)},
-    //         (true,false,true,true,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RCT0)},
-    //         (true,false,true,true,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RCT0T1)},
-    //         (true,true,false,false,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RS)},
-    //         (true,true,false,false,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RST1)},
-    //         (true,true,false,true,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RST0)},
-    //         (true,true,false,true,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RST0T1)},
-    //         (true,true,true,false,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RSC)},
-    //         (true,true,true,true,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RSCT0)},
-    //         (true,true,true,false,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RSCT1)},
-    //         (true,true,true,true,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RSCT0T1)},
-    //     }
-    // }
+    pub fn validate_arguments(&self) -> bool {
+        self.validate_arguments
+    }
 
     pub fn to_graphblas_descriptor(&self) -> GrB_Descriptor {
+        self.to_graphblas_descriptor_with_mask(false, false)
+    }
+
+    /// The descriptor for an operation with a mask, taking the mask settings from the mask only
+    pub(crate) fn to_graphblas_descriptor_with_mask(
+        &self,
+        use_mask_structure: bool,
+        use_mask_complement: bool,
+    ) -> GrB_Descriptor {
+        if let Some(owned_descriptors) = &self.owned_descriptors {
+            return owned_descriptors[descriptor_index(
+                self.clear_output_before_use,
+                use_mask_structure,
+                use_mask_complement,
+                self.transpose_input0,
+                self.transpose_input1,
+            )]
+            .descriptor;
+        }
+
         match (
             self.clear_output_before_use,
-            self.use_mask_structure_of_stored_values_as_mask,
-            self.use_mask_complement,
+            use_mask_structure,
+            use_mask_complement,
             self.transpose_input0,
             self.transpose_input1,
         ) {
@@ -126,142 +288,148 @@ impl OperatorOptions {
             (true, true, true, true, false) => unsafe { GrB_DESC_RSCT0 },
             (true, true, true, false, true) => unsafe { GrB_DESC_RSCT1 },
             (true, true, true, true, true) => unsafe { GrB_DESC_RSCT0T1 },
-            // (false, false, false, false, false) => unsafe {
-            //     GraphblasDescriptor::Default(ptr::null_mut())
-            // },
-            // (false, false, false, false, true) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_T1)
-            // },
-            // (false, false, false, true, false) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_T0)
-            // },
-            // (false, false, false, true, true) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_T0T1)
-            // },
-            // (false, false, true, false, false) => unsafe { GraphblasDescriptor::Value(GrB_DESC_C) },
-            // (false, false, true, false, true) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_CT1)
-            // },
-            // (false, false, true, true, false) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_CT0)
-            // },
-            // (false, false, true, true, true) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_CT0T1)
-            // },
-            // (false, true, false, false, false) => unsafe { GraphblasDescriptor::Value(GrB_DESC_S) },
-            // (false, true, false, false, true) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_ST1)
-            // },
-            // (false, true, false, true, false) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_ST0)
-            // },
-            // (false, true, false, true, true) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_ST0T1)
-            // },
-            // (false, true, true, false, false) => unsafe { GraphblasDescriptor::Value(GrB_DESC_SC) },
-            // (false, true, true, true, false) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_SCT0)
-            // },
-            // (false, true, true, false, true) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_SCT1)
-            // },
-            // (false, true, true, true, true) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_SCT0T1)
-            // },
-            // (true, false, false, false, false) => unsafe { GraphblasDescriptor::Value(GrB_DESC_R) },
-            // (true, false, false, false, true) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_RT1)
-            // },
-            // (true, false, false, true, false) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_RT0)
-            // },
-            // (true, false, false, true, true) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_RT0T1)
-            // },
-            // (true, false, true, false, false) => unsafe { GraphblasDescriptor::Value(GrB_DESC_RC) },
-            // (true, false, true, false, true) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_RCT1)
-            // },
-            // (true, false, true, true, false) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_RCT0)
-            // },
-            // (true, false, true, true, true) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_RCT0T1)
-            // },
-            // (true, true, false, false, false) => unsafe { GraphblasDescriptor::Value(GrB_DESC_RS) },
-            // (true, true, false, false, true) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_RST1)
-            // },
-            // (true, true, false, true, false) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_RST0)
-            // },
-            // (true, true, false, true, true) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_RST0T1)
-            // },
-            // (true, true, true, false, false) => unsafe { GraphblasDescriptor::Value(GrB_DESC_RSC) },
-            // (true, true, true, true, false) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_RSCT0)
-            // },
-            // (true, true, true, false, true) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_RSCT1)
-            // },newe, true) => unsafe {
-            //     GraphblasDescriptor::Value(GrB_DESC_RSCT0T1)
-            // },
         }
     }
+}
 
-    // pub fn to_graphblas_descriptor(&self) -> GraphblasDescriptor {
-    //     match (self.clearOutputBeforeUse, self.useMaskStructureOfStoredValuesAsMask, self.useMaskComplement, self.transposeInput0, self.transposeInput1) {
-    //         (false,false,false,false,false) => unsafe {GraphblasDescriptor::Default(ptr::null())},
-    //         (false,false,false,false,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_T1)},
-    //         (false,false,false,true,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_T0)},
-    //         (false,false,false,true,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_T0T1)},
-    //         (false,false,true,false,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_C)},
-    //         (false,false,true,false,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_CT1)},
-    //         (false,false,true,true,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_CT0)},
-    //         (false,false,true,true,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_CT0T1)},
-    //         (false,true,false,false,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_S)},
-    //         (false,true,false,false,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_ST1)},
-    //         (false,true,false,true,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_ST0)},
-    //         (false,true,false,true,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_ST0T1)},
-    //         (false,true,true,false,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_SC)},
-    //         (false,true,true,true,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_SCT0)},
-    //         (false,true,true,false,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_SCT1)},
-    //         (false,true,true,true,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_SCT0T1)},
-    //         (true,false,false,false,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_R)},
-    //         (true,false,false,false,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RT1)},
-    //         (true,false,false,true,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RT0)},
-    //         (true,false,false,true,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RT0T1)},
-    //         (true,false,true,false,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RC)},
-    //         (true,false,true,false,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RCT1)},
-    //         (true,false,true,true,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RCT0)},
-    //         (true,false,true,true,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RCT0T1)},
-    //         (true,true,false,false,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RS)},
-    //         (true,true,false,false,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RST1)},
-    //         (true,true,false,true,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RST0)},
-    //         (true,true,false,true,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RST0T1)},
-    //         (true,true,true,false,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RSC)},
-    //         (true,true,true,true,false) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RSCT0)},
-    //         (true,true,true,false,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RSCT1)},
-    //         (true,true,true,true,true) => unsafe {GraphblasDescriptor::Value(GrB_DESC_RSCT0T1)},
-    //     }
-    // }
+fn new_owned_descriptor(
+    context: &Arc<Context>,
+    extended_options: &ExtendedOperatorOptions,
+    clear_output_before_use: bool,
+    use_mask_structure: bool,
+    use_mask_complement: bool,
+    transpose_input0: bool,
+    transpose_input1: bool,
+) -> Result<OwnedDescriptor, SparseLinearAlgebraError> {
+    let mut descriptor: MaybeUninit<GrB_Descriptor> = MaybeUninit::uninit();
+    context.call(|| unsafe { GrB_Descriptor_new(descriptor.as_mut_ptr()) })?;
+    // Owned before any setting is applied, such that the descriptor is freed if a setting fails
+    let owned_descriptor = OwnedDescriptor {
+        context: context.clone(),
+        descriptor: unsafe { descriptor.assume_init() },
+    };
+    let descriptor = owned_descriptor.descriptor;
+
+    let set = |field, value: GrB_Desc_Value| {
+        context.call(|| unsafe { GrB_Descriptor_set(descriptor, field, value) })
+    };
+    if clear_output_before_use {
+        set(GrB_Desc_Field_GrB_OUTP, GrB_Desc_Value_GrB_REPLACE)?;
+    }
+    if use_mask_structure {
+        set(GrB_Desc_Field_GrB_MASK, GrB_Desc_Value_GrB_STRUCTURE)?;
+    }
+    if use_mask_complement {
+        set(GrB_Desc_Field_GrB_MASK, GrB_Desc_Value_GrB_COMP)?;
+    }
+    if transpose_input0 {
+        set(GrB_Desc_Field_GrB_INP0, GrB_Desc_Value_GrB_TRAN)?;
+    }
+    if transpose_input1 {
+        set(GrB_Desc_Field_GrB_INP1, GrB_Desc_Value_GrB_TRAN)?;
+    }
+
+    let method = match extended_options.matrix_multiplication_method {
+        MatrixMultiplicationMethod::Automatic => None,
+        MatrixMultiplicationMethod::Gustavson => Some(GrB_Desc_Value_GxB_AxB_GUSTAVSON),
+        MatrixMultiplicationMethod::DotProduct => Some(GrB_Desc_Value_GxB_AxB_DOT),
+        MatrixMultiplicationMethod::Hash => Some(GrB_Desc_Value_GxB_AxB_HASH),
+        MatrixMultiplicationMethod::Saxpy => Some(GrB_Desc_Value_GxB_AxB_SAXPY),
+    };
+    if let Some(method) = method {
+        set(GrB_Desc_Field_GxB_AxB_METHOD, method)?;
+    }
+    if let Some(number_of_threads) = extended_options.number_of_threads {
+        let number_of_threads = to_c_int(number_of_threads, "number_of_threads")?;
+        context.call(|| unsafe {
+            GxB_Desc_set(
+                descriptor,
+                GrB_Desc_Field_GxB_DESCRIPTOR_NTHREADS,
+                number_of_threads,
+            )
+        })?;
+    }
+    if let Some(chunk_size) = extended_options.chunk_size {
+        context.call(|| unsafe {
+            GxB_Desc_set(
+                descriptor,
+                GrB_Desc_Field_GxB_DESCRIPTOR_CHUNK,
+                chunk_size as c_double,
+            )
+        })?;
+    }
+    if extended_options.sort_output {
+        context
+            .call(|| unsafe { GxB_Desc_set(descriptor, GrB_Desc_Field_GxB_SORT, 1 as c_int) })?;
+    }
+
+    Ok(owned_descriptor)
 }
 
-// pub enum ArithmeticOperatorOptionsList {
-//     Default, // GrB_NULL
-//     TransposeInput1, // T1
-//     TransposeInput0, // T0
-//     TransposeInput0And1, // T0T1,
-//     UseMaskComplement, // C
-//     UseMaskStructure, // use the masks's stored values as the mask
-//     UseMaskStructureAndTransposeInput
+fn descriptor_index(
+    clear_output_before_use: bool,
+    use_mask_structure: bool,
+    use_mask_complement: bool,
+    transpose_input0: bool,
+    transpose_input1: bool,
+) -> usize {
+    (clear_output_before_use as usize) * 16
+        + (use_mask_structure as usize) * 8
+        + (use_mask_complement as usize) * 4
+        + (transpose_input0 as usize) * 2
+        + (transpose_input1 as usize)
+}
+
+fn to_c_int(value: usize, name: &str) -> Result<c_int, SparseLinearAlgebraError> {
+    if value <= c_int::MAX as usize {
+        Ok(value as c_int)
+    } else {
+        Err(LogicError::new(
+            LogicErrorType::Other,
+            format!(
+                "{} must not be larger than {}, found {}",
+                name,
+                c_int::MAX,
+                value
+            ),
+            None,
+        )
+        .into())
+    }
+}
+
+/// The size of the first matrix argument, as seen by an operation using the options
+pub(crate) fn size_of_first_argument<T: ValueType>(
+    argument: &SparseMatrix<T>,
+    options: &OperatorOptions,
+) -> Result<Size, SparseLinearAlgebraError> {
+    size_of_argument(argument, options.transpose_input0)
+}
 
-// }
+/// The size of the second matrix argument, as seen by an operation using the options
+pub(crate) fn size_of_second_argument<T: ValueType>(
+    argument: &SparseMatrix<T>,
+    options: &OperatorOptions,
+) -> Result<Size, SparseLinearAlgebraError> {
+    size_of_argument(argument, options.transpose_input1)
+}
+
+fn size_of_argument<T: ValueType>(
+    argument: &SparseMatrix<T>,
+    is_transposed: bool,
+) -> Result<Size, SparseLinearAlgebraError> {
+    let size = argument.size()?;
+    if is_transposed {
+        Ok(size.transpose())
+    } else {
+        Ok(size)
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::context::Mode;
 
     #[test]
     fn test_options() {
@@ -269,4 +437,75 @@ mod tests {
         let expected_value: GrB_Descriptor = ptr::null_mut();
         assert_eq!(default_options.to_graphblas_descriptor(), expected_value)
     }
+
+    #[test]
+    fn test_extended_options() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let extended_options = ExtendedOperatorOptions::new_default()
+            .with_matrix_multiplication_method(MatrixMultiplicationMethod::DotProduct)
+            .with_number_of_threads(2)
+            .with_chunk_size(4096.0)
+            .with_sorted_output(true);
+        let options = OperatorOptions::new(false, true, false)
+            .with_extended_options(&context, &extended_options)
+            .unwrap();
+
+        let descriptor = options.to_graphblas_descriptor();
+        assert!(!descriptor.is_null());
+        assert_ne!(descriptor, unsafe { GrB_DESC_T0 });
+        assert!(options.transpose_input0());
+        assert_eq!(options.extended_options(), Some(&extended_options));
+
+        // Clones share the descriptor, which is freed when the last clone is dropped
+        let cloned_options = options.clone();
+        drop(options);
+        assert_eq!(cloned_options.to_graphblas_descriptor(), descriptor);
+
+        let inverted_options = cloned_options.with_inverted_transpose_input0();
+        assert!(!inverted_options.transpose_input0());
+        assert_ne!(inverted_options.to_graphblas_descriptor(), descriptor);
+        assert_eq!(inverted_options.extended_options(), Some(&extended_options));
+
+        // Derived options share the descriptors of the options they are derived from
+        assert_eq!(
+            inverted_options
+                .with_inverted_transpose_input0()
+                .to_graphblas_descriptor(),
+            descriptor
+        );
+    }
+
+    #[test]
+    fn test_inverted_transpose_input0() {
+        let options = OperatorOptions::new_default().with_inverted_transpose_input0();
+        assert!(options.transpose_input0());
+        assert_eq!(options.to_graphblas_descriptor(), unsafe { GrB_DESC_T0 });
+        assert!(!options.with_inverted_transpose_input0().transpose_input0());
+    }
+
+    #[test]
+    fn test_without_clearing_output() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let options = OperatorOptions::new(true, false, false);
+        assert_eq!(options.to_graphblas_descriptor(), unsafe { GrB_DESC_R });
+        assert!(options
+            .without_clearing_output()
+            .to_graphblas_descriptor()
+            .is_null());
+
+        let extended_options = options
+            .with_extended_options(&context, &ExtendedOperatorOptions::new_default())
+            .unwrap();
+        let without_clearing_output = extended_options.without_clearing_output();
+        assert_ne!(
+            without_clearing_output.to_graphblas_descriptor(),
+            extended_options.to_graphblas_descriptor()
+        );
+        assert_eq!(
+            without_clearing_output.extended_options(),
+            extended_options.extended_options()
+        );
+    }
 }
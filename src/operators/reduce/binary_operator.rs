@@ -3,18 +3,20 @@ use std::ptr;
 use std::marker::PhantomData;
 
 use crate::error::SparseLinearAlgebraError;
+use crate::operators::options::size_of_first_argument;
+use crate::operators::validation::check_length;
 use crate::operators::{
-    binary_operator::BinaryOperator, mask::VectorMask, options::OperatorOptions,
+    binary_operator::{Accumulator, BinaryOperator},
+    mask::VectorMask,
+    options::OperatorOptions,
 };
 
 use crate::value_types::sparse_matrix::SparseMatrix;
 use crate::value_types::sparse_vector::SparseVector;
 
-use crate::value_types::value_type::{AsBoolean, ValueType};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
 
-use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GrB_Matrix_reduce_BinaryOp,
-};
+use crate::bindings_to_graphblas_implementation::{GrB_BinaryOp, GrB_Matrix_reduce_BinaryOp};
 
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
 // Code review must consider that no mtable access is provided.
@@ -49,25 +51,21 @@ pub struct BinaryOperatorReducer<T: ValueType> {
 
     binary_operator: GrB_BinaryOp,
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<T: ValueType> BinaryOperatorReducer<T> {
     pub fn new(
-        binary_operator: &dyn BinaryOperator<T, T, T>,
+        binary_operator: &(impl BinaryOperator<T, T, T> + ?Sized),
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<T, T, T>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<T, T, T> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             binary_operator: binary_operator.graphblas_type(),
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _value: PhantomData,
         }
@@ -78,6 +76,10 @@ impl<T: ValueType> BinaryOperatorReducer<T> {
         argument: &SparseMatrix<T>,
         product: &mut SparseVector<T>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            self.check_arguments(argument, product)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -87,7 +89,7 @@ impl<T: ValueType> BinaryOperatorReducer<T> {
                 self.accumulator,
                 self.binary_operator,
                 argument.graphblas_matrix(),
-                self.options,
+                self.options.to_graphblas_descriptor(),
             )
         })?;
 
@@ -100,6 +102,11 @@ impl<T: ValueType> BinaryOperatorReducer<T> {
         product: &mut SparseVector<T>,
         mask: &VectorMask<MaskValueType, AsBool>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            self.check_arguments(argument, product)?;
+            check_length("mask", product.length()?, mask.length()?)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -109,12 +116,57 @@ impl<T: ValueType> BinaryOperatorReducer<T> {
                 self.accumulator,
                 self.binary_operator,
                 argument.graphblas_matrix(),
-                self.options,
+                mask.graphblas_descriptor(&self.options),
             )
         })?;
 
         Ok(())
     }
+
+    fn check_arguments(
+        &self,
+        argument: &SparseMatrix<T>,
+        product: &SparseVector<T>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        check_length(
+            "product",
+            size_of_first_argument(argument, &self.options)?.row_height(),
+            product.length()?,
+        )
+    }
+}
+
+impl<T: ValueType + BuiltInValueType<T>> BinaryOperatorReducer<T> {
+    /// Like to_vector(), but allocates the product, sized by the argument and options
+    pub fn compute_vector(
+        &self,
+        argument: &SparseMatrix<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut product = self.allocate_product(argument)?;
+        self.to_vector(argument, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like to_vector_with_mask(), but allocates the product, sized by the argument and options
+    pub fn compute_vector_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        argument: &SparseMatrix<T>,
+        mask: &VectorMask<MaskValueType, AsBool>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut product = self.allocate_product(argument)?;
+        self.to_vector_with_mask(argument, &mut product, mask)?;
+        Ok(product)
+    }
+
+    fn allocate_product(
+        &self,
+        argument: &SparseMatrix<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        SparseVector::new(
+            argument.context_ref(),
+            &size_of_first_argument(argument, &self.options)?.row_height(),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -122,7 +174,7 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::{First, Plus};
+    use crate::operators::binary_operator::{First, NoAccumulator, Plus};
 
     use crate::value_types::sparse_matrix::{FromMatrixElementList, MatrixElementList, Size};
     use crate::value_types::sparse_vector::{
@@ -156,7 +208,7 @@ mod tests {
         let reducer = BinaryOperatorReducer::new(
             &Plus::<u8, u8, u8>::new(),
             &OperatorOptions::new_default(),
-            None,
+            &NoAccumulator,
         );
 
         reducer.to_vector(&matrix, &mut product_vector).unwrap();
@@ -187,7 +239,7 @@ mod tests {
             SparseVector::<u8>::new(&context, &matrix_size.row_height()).unwrap();
 
         reducer
-            .to_vector_with_mask(&matrix, &mut product_vector, &mask.into())
+            .to_vector_with_mask(&matrix, &mut product_vector, &(&mask).into())
             .unwrap();
 
         println!("{}", matrix);
@@ -2,4 +2,4 @@ mod binary_operator;
 mod monoid;
 
 pub use binary_operator::BinaryOperatorReducer;
-pub use monoid::{MonoidReducer, MonoidScalarReducer};
+pub use monoid::{MonoidReducer, MonoidScalarReducer, MonoidSparseScalarReducer};
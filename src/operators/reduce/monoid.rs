@@ -3,24 +3,32 @@ use std::ptr;
 use std::marker::PhantomData;
 
 use crate::error::SparseLinearAlgebraError;
+use crate::operators::extract::{SubMatrixExtractor, SubVectorExtractor};
+use crate::operators::options::size_of_first_argument;
+use crate::operators::validation::check_length;
 use crate::operators::{
-    binary_operator::BinaryOperator, mask::VectorMask, monoid::Monoid, options::OperatorOptions,
+    binary_operator::{Accumulator, NoAccumulator},
+    mask::{MatrixMask, VectorMask},
+    monoid::Monoid,
+    options::OperatorOptions,
 };
+use crate::util::ElementIndexSelector;
 
 use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::value_types::sparse_scalar::{GetScalarValue, SetScalarValue, SparseScalar};
 use crate::value_types::sparse_vector::SparseVector;
 
-use crate::value_types::value_type::{AsBoolean, ValueType};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
 
 use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GrB_Matrix_reduce_BOOL, GrB_Matrix_reduce_FP32,
-    GrB_Matrix_reduce_FP64, GrB_Matrix_reduce_INT16, GrB_Matrix_reduce_INT32,
-    GrB_Matrix_reduce_INT64, GrB_Matrix_reduce_INT8, GrB_Matrix_reduce_Monoid,
-    GrB_Matrix_reduce_UINT16, GrB_Matrix_reduce_UINT32, GrB_Matrix_reduce_UINT64,
-    GrB_Matrix_reduce_UINT8, GrB_Monoid, GrB_Vector_reduce_BOOL, GrB_Vector_reduce_FP32,
-    GrB_Vector_reduce_FP64, GrB_Vector_reduce_INT16, GrB_Vector_reduce_INT32,
-    GrB_Vector_reduce_INT64, GrB_Vector_reduce_INT8, GrB_Vector_reduce_UINT16,
-    GrB_Vector_reduce_UINT32, GrB_Vector_reduce_UINT64, GrB_Vector_reduce_UINT8,
+    GrB_BinaryOp, GrB_Info, GrB_Matrix_reduce_BOOL, GrB_Matrix_reduce_FP32, GrB_Matrix_reduce_FP64,
+    GrB_Matrix_reduce_INT16, GrB_Matrix_reduce_INT32, GrB_Matrix_reduce_INT64,
+    GrB_Matrix_reduce_INT8, GrB_Matrix_reduce_Monoid, GrB_Matrix_reduce_UINT16,
+    GrB_Matrix_reduce_UINT32, GrB_Matrix_reduce_UINT64, GrB_Matrix_reduce_UINT8, GrB_Monoid,
+    GrB_Vector_reduce_BOOL, GrB_Vector_reduce_FP32, GrB_Vector_reduce_FP64,
+    GrB_Vector_reduce_INT16, GrB_Vector_reduce_INT32, GrB_Vector_reduce_INT64,
+    GrB_Vector_reduce_INT8, GrB_Vector_reduce_UINT16, GrB_Vector_reduce_UINT32,
+    GrB_Vector_reduce_UINT64, GrB_Vector_reduce_UINT8,
 };
 
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
@@ -56,7 +64,7 @@ pub struct MonoidReducer<T: ValueType> {
 
     monoid: GrB_Monoid,
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 pub trait MonoidScalarReducer<T>
@@ -76,22 +84,60 @@ where
     ) -> Result<(), SparseLinearAlgebraError>;
 }
 
+/// Reductions into a SparseScalar, which distinguish a reduction of no elements from one that
+/// results in the identity of the monoid. GraphBLAS only reduces to scalars with monoids.
+///
+/// Reducing no elements gives an empty result: the product is kept when accumulating,
+/// and cleared otherwise. The mask selects the elements of the argument to reduce.
+pub trait MonoidSparseScalarReducer<T>
+where
+    T: ValueType,
+{
+    fn matrix_to_sparse_scalar(
+        &self,
+        argument: &SparseMatrix<T>,
+        product: &mut SparseScalar<T>,
+    ) -> Result<(), SparseLinearAlgebraError>;
+
+    fn matrix_to_sparse_scalar_with_mask<
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+    >(
+        &self,
+        argument: &SparseMatrix<T>,
+        product: &mut SparseScalar<T>,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+    ) -> Result<(), SparseLinearAlgebraError>;
+
+    fn vector_to_sparse_scalar(
+        &self,
+        argument: &SparseVector<T>,
+        product: &mut SparseScalar<T>,
+    ) -> Result<(), SparseLinearAlgebraError>;
+
+    fn vector_to_sparse_scalar_with_mask<
+        MaskValueType: ValueType,
+        AsBool: AsBoolean<MaskValueType>,
+    >(
+        &self,
+        argument: &SparseVector<T>,
+        product: &mut SparseScalar<T>,
+        mask: &VectorMask<MaskValueType, AsBool>,
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
 impl<T: ValueType> MonoidReducer<T> {
     pub fn new(
-        monoid: &dyn Monoid<T>,
+        monoid: &(impl Monoid<T> + ?Sized),
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<T, T, T>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<T, T, T> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             monoid: monoid.graphblas_type(),
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _value: PhantomData,
         }
@@ -102,6 +148,10 @@ impl<T: ValueType> MonoidReducer<T> {
         argument: &SparseMatrix<T>,
         product: &mut SparseVector<T>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            self.check_arguments(argument, product)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -111,7 +161,7 @@ impl<T: ValueType> MonoidReducer<T> {
                 self.accumulator,
                 self.monoid,
                 argument.graphblas_matrix(),
-                self.options,
+                self.options.to_graphblas_descriptor(),
             )
         })?;
 
@@ -124,6 +174,11 @@ impl<T: ValueType> MonoidReducer<T> {
         product: &mut SparseVector<T>,
         mask: &VectorMask<MaskValueType, AsBool>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            self.check_arguments(argument, product)?;
+            check_length("mask", product.length()?, mask.length()?)?;
+        }
+
         let context = product.context();
 
         context.call(|| unsafe {
@@ -133,12 +188,130 @@ impl<T: ValueType> MonoidReducer<T> {
                 self.accumulator,
                 self.monoid,
                 argument.graphblas_matrix(),
-                self.options,
+                mask.graphblas_descriptor(&self.options),
             )
         })?;
 
         Ok(())
     }
+
+    fn check_arguments(
+        &self,
+        argument: &SparseMatrix<T>,
+        product: &SparseVector<T>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        check_length(
+            "product",
+            size_of_first_argument(argument, &self.options)?.row_height(),
+            product.length()?,
+        )
+    }
+}
+
+impl<T: ValueType + BuiltInValueType<T>> MonoidReducer<T> {
+    /// Like to_vector(), but allocates the product, sized by the argument and options
+    pub fn compute_vector(
+        &self,
+        argument: &SparseMatrix<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut product = self.allocate_product(argument)?;
+        self.to_vector(argument, &mut product)?;
+        Ok(product)
+    }
+
+    /// Like to_vector_with_mask(), but allocates the product, sized by the argument and options
+    pub fn compute_vector_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        argument: &SparseMatrix<T>,
+        mask: &VectorMask<MaskValueType, AsBool>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut product = self.allocate_product(argument)?;
+        self.to_vector_with_mask(argument, &mut product, mask)?;
+        Ok(product)
+    }
+
+    fn allocate_product(
+        &self,
+        argument: &SparseMatrix<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        SparseVector::new(
+            argument.context_ref(),
+            &size_of_first_argument(argument, &self.options)?.row_height(),
+        )
+    }
+}
+
+impl<T> MonoidReducer<T>
+where
+    T: ValueType + BuiltInValueType<T> + Default,
+    SparseScalar<T>: GetScalarValue<T> + SetScalarValue<T>,
+{
+    // Selects the elements of the argument to reduce, the options may transpose the argument
+    fn masked_matrix<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        argument: &SparseMatrix<T>,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        let mut masked_argument = SparseMatrix::new(
+            argument.context_ref(),
+            &size_of_first_argument(argument, &self.options)?,
+        )?;
+        SubMatrixExtractor::new(&self.options, &NoAccumulator).apply_with_mask(
+            argument,
+            &ElementIndexSelector::All,
+            &ElementIndexSelector::All,
+            &mut masked_argument,
+            mask,
+        )?;
+        Ok(masked_argument)
+    }
+
+    fn masked_vector<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        argument: &SparseVector<T>,
+        mask: &VectorMask<MaskValueType, AsBool>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let mut masked_argument = SparseVector::new(argument.context_ref(), &argument.length()?)?;
+        SubVectorExtractor::new(&self.options, &NoAccumulator).apply_with_mask(
+            argument,
+            &ElementIndexSelector::All,
+            &mut masked_argument,
+            mask,
+        )?;
+        Ok(masked_argument)
+    }
+
+    // Z = accumulator(C, T), where T is empty if no element is reduced
+    fn reduce_into_sparse_scalar(
+        &self,
+        product: &mut SparseScalar<T>,
+        number_of_elements_to_reduce: usize,
+        mut reduce: impl FnMut(&mut T, GrB_BinaryOp) -> GrB_Info,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let accumulate = !self.accumulator.is_null();
+
+        if number_of_elements_to_reduce == 0 {
+            if !accumulate {
+                product.clear()?;
+            }
+            return Ok(());
+        }
+
+        let mut value;
+        let accumulator;
+        if accumulate && product.number_of_stored_elements()? > 0 {
+            value = product.get_value()?;
+            accumulator = self.accumulator;
+        } else {
+            value = T::default();
+            accumulator = ptr::null_mut();
+        }
+
+        product
+            .context_ref()
+            .call(|| reduce(&mut value, accumulator))?;
+        product.set_value(&value)
+    }
 }
 
 macro_rules! implement_monoid_reducer {
@@ -157,7 +330,7 @@ macro_rules! implement_monoid_reducer {
                         self.accumulator,
                         self.monoid,
                         argument.graphblas_matrix(),
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
 
@@ -177,13 +350,81 @@ macro_rules! implement_monoid_reducer {
                         self.accumulator,
                         self.monoid,
                         argument.graphblas_vector(),
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
 
                 Ok(())
             }
         }
+
+        impl MonoidSparseScalarReducer<$value_type> for MonoidReducer<$value_type> {
+            fn matrix_to_sparse_scalar(
+                &self,
+                argument: &SparseMatrix<$value_type>,
+                product: &mut SparseScalar<$value_type>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                self.reduce_into_sparse_scalar(
+                    product,
+                    argument.number_of_stored_elements()?,
+                    |value, accumulator| unsafe {
+                        $matrix_reducer_operator(
+                            value,
+                            accumulator,
+                            self.monoid,
+                            argument.graphblas_matrix(),
+                            self.options.to_graphblas_descriptor(),
+                        )
+                    },
+                )
+            }
+
+            fn matrix_to_sparse_scalar_with_mask<
+                MaskValueType: ValueType,
+                AsBool: AsBoolean<MaskValueType>,
+            >(
+                &self,
+                argument: &SparseMatrix<$value_type>,
+                product: &mut SparseScalar<$value_type>,
+                mask: &MatrixMask<MaskValueType, AsBool>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                let masked_argument = self.masked_matrix(argument, mask)?;
+                self.matrix_to_sparse_scalar(&masked_argument, product)
+            }
+
+            fn vector_to_sparse_scalar(
+                &self,
+                argument: &SparseVector<$value_type>,
+                product: &mut SparseScalar<$value_type>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                self.reduce_into_sparse_scalar(
+                    product,
+                    argument.number_of_stored_elements()?,
+                    |value, accumulator| unsafe {
+                        $vector_reducer_operator(
+                            value,
+                            accumulator,
+                            self.monoid,
+                            argument.graphblas_vector(),
+                            self.options.to_graphblas_descriptor(),
+                        )
+                    },
+                )
+            }
+
+            fn vector_to_sparse_scalar_with_mask<
+                MaskValueType: ValueType,
+                AsBool: AsBoolean<MaskValueType>,
+            >(
+                &self,
+                argument: &SparseVector<$value_type>,
+                product: &mut SparseScalar<$value_type>,
+                mask: &VectorMask<MaskValueType, AsBool>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                let masked_argument = self.masked_vector(argument, mask)?;
+                self.vector_to_sparse_scalar(&masked_argument, product)
+            }
+        }
     };
 }
 
@@ -204,7 +445,7 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator, Plus};
     use crate::operators::monoid::Plus as MonoidPlus;
 
     use crate::value_types::sparse_matrix::{FromMatrixElementList, MatrixElementList, Size};
@@ -239,7 +480,7 @@ mod tests {
         let reducer = MonoidReducer::new(
             &MonoidPlus::<u8>::new(),
             &OperatorOptions::new_default(),
-            None,
+            &NoAccumulator,
         );
 
         reducer.to_vector(&matrix, &mut product_vector).unwrap();
@@ -270,7 +511,7 @@ mod tests {
             SparseVector::<u8>::new(&context, &matrix_size.row_height()).unwrap();
 
         reducer
-            .to_vector_with_mask(&matrix, &mut product_vector, &mask.into())
+            .to_vector_with_mask(&matrix, &mut product_vector, &(&mask).into())
             .unwrap();
 
         println!("{}", matrix);
@@ -309,7 +550,7 @@ mod tests {
         let reducer = MonoidReducer::new(
             &MonoidPlus::<u8>::new(),
             &OperatorOptions::new_default(),
-            None,
+            &NoAccumulator,
         );
 
         reducer.matrix_to_scalar(&matrix, &mut product).unwrap();
@@ -344,7 +585,7 @@ mod tests {
         let reducer = MonoidReducer::new(
             &MonoidPlus::<u8>::new(),
             &OperatorOptions::new_default(),
-            None,
+            &NoAccumulator,
         );
 
         reducer.vector_to_scalar(&vector, &mut product).unwrap();
@@ -353,4 +594,72 @@ mod tests {
 
         assert_eq!(product, 12);
     }
+
+    #[test]
+    fn test_monoid_to_sparse_scalar_reducer() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let vector = SparseVector::<u8>::from_element_list(
+            &context.clone(),
+            &10,
+            &VectorElementList::<u8>::from_element_vector(vec![
+                (1, 1).into(),
+                (2, 2).into(),
+                (4, 4).into(),
+            ]),
+            &First::<u8, u8, u8>::new(),
+        )
+        .unwrap();
+        let empty_vector = SparseVector::<u8>::new(&context, &10).unwrap();
+
+        let reducer = MonoidReducer::new(
+            &MonoidPlus::<u8>::new(),
+            &OperatorOptions::new_default(),
+            &NoAccumulator,
+        );
+        let mut product = SparseScalar::<u8>::new(&context).unwrap();
+
+        reducer
+            .vector_to_sparse_scalar(&vector, &mut product)
+            .unwrap();
+        assert_eq!(product.number_of_stored_elements().unwrap(), 1);
+        assert_eq!(product.get_value().unwrap(), 7);
+
+        // A reduction of no elements is empty, rather than the identity
+        reducer
+            .vector_to_sparse_scalar(&empty_vector, &mut product)
+            .unwrap();
+        assert_eq!(product.number_of_stored_elements().unwrap(), 0);
+
+        let mask = SparseVector::<bool>::from_element_list(
+            &context.clone(),
+            &10,
+            &VectorElementList::<bool>::from_element_vector(vec![
+                (2, true).into(),
+                (4, true).into(),
+            ]),
+            &First::<bool, bool, bool>::new(),
+        )
+        .unwrap();
+        reducer
+            .vector_to_sparse_scalar_with_mask(&vector, &mut product, &(&mask).into())
+            .unwrap();
+        assert_eq!(product.get_value().unwrap(), 6);
+
+        // Accumulating an empty reduction keeps the product
+        let accumulating_reducer = MonoidReducer::new(
+            &MonoidPlus::<u8>::new(),
+            &OperatorOptions::new_default(),
+            &Plus::<u8, u8, u8>::new(),
+        );
+        accumulating_reducer
+            .vector_to_sparse_scalar(&empty_vector, &mut product)
+            .unwrap();
+        assert_eq!(product.get_value().unwrap(), 6);
+
+        accumulating_reducer
+            .vector_to_sparse_scalar(&vector, &mut product)
+            .unwrap();
+        assert_eq!(product.get_value().unwrap(), 13);
+    }
 }
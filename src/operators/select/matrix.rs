@@ -3,19 +3,20 @@ use std::ptr;
 use std::marker::PhantomData;
 
 use crate::error::SparseLinearAlgebraError;
-use crate::operators::{
-    binary_operator::BinaryOperator, mask::MatrixMask, options::OperatorOptions,
-};
+use crate::operators::options::size_of_first_argument;
+use crate::operators::sparse_scalar_operand::{is_empty, write_empty_matrix_result};
+use crate::operators::validation::check_size;
+use crate::operators::{binary_operator::Accumulator, mask::MatrixMask, options::OperatorOptions};
 
 use crate::value_types::sparse_matrix::SparseMatrix;
 use crate::value_types::sparse_scalar::{SetScalarValue, SparseScalar};
 
-use crate::value_types::value_type::{AsBoolean, ValueType};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
 
 use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GxB_DIAG, GxB_EQ_THUNK, GxB_EQ_ZERO, GxB_GE_THUNK, GxB_GE_ZERO,
-    GxB_GT_THUNK, GxB_GT_ZERO, GxB_LE_THUNK, GxB_LE_ZERO, GxB_LT_THUNK, GxB_LT_ZERO,
-    GxB_Matrix_select, GxB_NE_THUNK, GxB_NONZERO, GxB_OFFDIAG, GxB_TRIL, GxB_TRIU,
+    GrB_BinaryOp, GxB_DIAG, GxB_EQ_THUNK, GxB_EQ_ZERO, GxB_GE_THUNK, GxB_GE_ZERO, GxB_GT_THUNK,
+    GxB_GT_ZERO, GxB_LE_THUNK, GxB_LE_ZERO, GxB_LT_THUNK, GxB_LT_ZERO, GxB_Matrix_select,
+    GxB_NE_THUNK, GxB_NONZERO, GxB_OFFDIAG, GxB_TRIL, GxB_TRIU,
 };
 
 use super::diagonal_index::{DiagonalIndex, DiagonalIndexGraphblasType};
@@ -52,31 +53,51 @@ pub struct MatrixSelector<T: ValueType> {
     _value: PhantomData<T>,
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<T: ValueType> MatrixSelector<T> {
     pub fn new(
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<T, T, T>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<T, T, T> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _value: PhantomData,
         }
     }
+
+    fn check_arguments(
+        &self,
+        argument: &SparseMatrix<T>,
+        product: &SparseMatrix<T>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        check_size(
+            "product",
+            &size_of_first_argument(argument, &self.options)?,
+            &product.size()?,
+        )
+    }
+}
+
+impl<T: ValueType + BuiltInValueType<T>> MatrixSelector<T> {
+    fn allocate_product(
+        &self,
+        argument: &SparseMatrix<T>,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        SparseMatrix::new(
+            argument.context_ref(),
+            &size_of_first_argument(argument, &self.options)?,
+        )
+    }
 }
 
 macro_rules! implement_selector_with_diagonal {
-    ($method_name:ident, $method_name_with_mask:ident, $graphblas_operator:ident) => {
+    ($method_name:ident, $method_name_with_mask:ident, $compute_name:ident, $compute_name_with_mask:ident, $graphblas_operator:ident) => {
         impl<T: ValueType> MatrixSelector<T> {
             /// k = 0 selects the main diagonal, positive for avove, negative for below
             pub fn $method_name(
@@ -85,6 +106,10 @@ macro_rules! implement_selector_with_diagonal {
                 product: &mut SparseMatrix<T>,
                 diagional: &DiagonalIndex,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(argument, product)?;
+                }
+
                 let context = product.context();
                 let diagonal_index = diagional.to_graphblas_type(&context)?;
 
@@ -98,7 +123,7 @@ macro_rules! implement_selector_with_diagonal {
                                 $graphblas_operator,
                                 argument.graphblas_matrix(),
                                 index.graphblas_scalar(),
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -111,7 +136,7 @@ macro_rules! implement_selector_with_diagonal {
                                 $graphblas_operator,
                                 argument.graphblas_matrix(),
                                 ptr::null_mut(),
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -131,6 +156,11 @@ macro_rules! implement_selector_with_diagonal {
                 diagional: &DiagonalIndex,
                 mask: &MatrixMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(argument, product)?;
+                    check_size("mask", &product.size()?, &mask.size()?)?;
+                }
+
                 let context = product.context();
                 let diagonal_index = diagional.to_graphblas_type(&context)?;
 
@@ -144,7 +174,7 @@ macro_rules! implement_selector_with_diagonal {
                                 $graphblas_operator,
                                 argument.graphblas_matrix(),
                                 index.graphblas_scalar(),
-                                self.options,
+                                mask.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -157,7 +187,7 @@ macro_rules! implement_selector_with_diagonal {
                                 $graphblas_operator,
                                 argument.graphblas_matrix(),
                                 ptr::null_mut(),
-                                self.options,
+                                mask.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -166,13 +196,64 @@ macro_rules! implement_selector_with_diagonal {
                 Ok(())
             }
         }
+
+        impl<T: ValueType + BuiltInValueType<T>> MatrixSelector<T> {
+            /// Allocates the product; k = 0 selects the main diagonal, positive for above, negative for below
+            pub fn $compute_name(
+                &self,
+                argument: &SparseMatrix<T>,
+                diagional: &DiagonalIndex,
+            ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+                let mut product = self.allocate_product(argument)?;
+                self.$method_name(argument, &mut product, diagional)?;
+                Ok(product)
+            }
+
+            pub fn $compute_name_with_mask<
+                MaskValueType: ValueType,
+                AsBool: AsBoolean<MaskValueType>,
+            >(
+                &self,
+                argument: &SparseMatrix<T>,
+                diagional: &DiagonalIndex,
+                mask: &MatrixMask<MaskValueType, AsBool>,
+            ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+                let mut product = self.allocate_product(argument)?;
+                self.$method_name_with_mask(argument, &mut product, diagional, mask)?;
+                Ok(product)
+            }
+        }
     };
 }
 
-implement_selector_with_diagonal!(lower_triangle, lower_triangle_with_mask, GxB_TRIL);
-implement_selector_with_diagonal!(upper_triangle, upper_triangle_with_mask, GxB_TRIU);
-implement_selector_with_diagonal!(diagonal, diagonal_with_mask, GxB_DIAG);
-implement_selector_with_diagonal!(clear_diagonal, clear_diagonal_with_mask, GxB_OFFDIAG);
+implement_selector_with_diagonal!(
+    lower_triangle,
+    lower_triangle_with_mask,
+    compute_lower_triangle,
+    compute_lower_triangle_with_mask,
+    GxB_TRIL
+);
+implement_selector_with_diagonal!(
+    upper_triangle,
+    upper_triangle_with_mask,
+    compute_upper_triangle,
+    compute_upper_triangle_with_mask,
+    GxB_TRIU
+);
+implement_selector_with_diagonal!(
+    diagonal,
+    diagonal_with_mask,
+    compute_diagonal,
+    compute_diagonal_with_mask,
+    GxB_DIAG
+);
+implement_selector_with_diagonal!(
+    clear_diagonal,
+    clear_diagonal_with_mask,
+    compute_clear_diagonal,
+    compute_clear_diagonal_with_mask,
+    GxB_OFFDIAG
+);
 
 macro_rules! implement_scalar_selector {
     ($value_type:ty, $selector_trait:ident, $method_name:ident, $method_name_with_mask:ident, $graphblas_operator:ident) => {
@@ -183,6 +264,10 @@ macro_rules! implement_scalar_selector {
                 product: &mut SparseMatrix<$value_type>,
                 scalar: &$value_type,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(argument, product)?;
+                }
+
                 let context = product.context();
                 let mut sparse_scalar = SparseScalar::<$value_type>::new(&context)?;
                 sparse_scalar.set_value(scalar)?;
@@ -195,7 +280,7 @@ macro_rules! implement_scalar_selector {
                         $graphblas_operator,
                         argument.graphblas_matrix(),
                         sparse_scalar.graphblas_scalar(),
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
 
@@ -212,6 +297,10 @@ macro_rules! implement_scalar_selector {
                 scalar: &$value_type,
                 _mask: &MatrixMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(argument, product)?;
+                }
+
                 let context = product.context();
                 let mut sparse_scalar = SparseScalar::<$value_type>::new(&context)?;
                 sparse_scalar.set_value(scalar)?;
@@ -224,7 +313,7 @@ macro_rules! implement_scalar_selector {
                         $graphblas_operator,
                         argument.graphblas_matrix(),
                         sparse_scalar.graphblas_scalar(),
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
 
@@ -810,14 +899,136 @@ implement_scalar_selector!(
     GxB_LE_THUNK
 );
 
-macro_rules! implement_selector_with_zero {
+// The thunk is a SparseScalar, such that it may be the output of a preceding operation.
+// An empty thunk has no value to compare with, such that no element is selected.
+macro_rules! implement_sparse_scalar_selector {
     ($method_name:ident, $method_name_with_mask:ident, $graphblas_operator:ident) => {
+        impl<T: ValueType + BuiltInValueType<T>> MatrixSelector<T> {
+            pub fn $method_name(
+                &self,
+                argument: &SparseMatrix<T>,
+                product: &mut SparseMatrix<T>,
+                scalar: &SparseScalar<T>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(argument, product)?;
+                }
+
+                if is_empty(scalar)? {
+                    return write_empty_matrix_result(
+                        product,
+                        ptr::null_mut(),
+                        self.accumulator,
+                        &self.options,
+                        self.options.to_graphblas_descriptor(),
+                    );
+                }
+
+                let context = product.context();
+
+                context.call(|| unsafe {
+                    GxB_Matrix_select(
+                        product.graphblas_matrix(),
+                        ptr::null_mut(),
+                        self.accumulator,
+                        $graphblas_operator,
+                        argument.graphblas_matrix(),
+                        scalar.graphblas_scalar(),
+                        self.options.to_graphblas_descriptor(),
+                    )
+                })?;
+
+                Ok(())
+            }
+
+            pub fn $method_name_with_mask<
+                MaskValueType: ValueType,
+                AsBool: AsBoolean<MaskValueType>,
+            >(
+                &self,
+                argument: &SparseMatrix<T>,
+                product: &mut SparseMatrix<T>,
+                scalar: &SparseScalar<T>,
+                mask: &MatrixMask<MaskValueType, AsBool>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(argument, product)?;
+                    check_size("mask", &product.size()?, &mask.size()?)?;
+                }
+
+                if is_empty(scalar)? {
+                    return write_empty_matrix_result(
+                        product,
+                        mask.graphblas_matrix(),
+                        self.accumulator,
+                        &self.options,
+                        mask.graphblas_descriptor(&self.options),
+                    );
+                }
+
+                let context = product.context();
+
+                context.call(|| unsafe {
+                    GxB_Matrix_select(
+                        product.graphblas_matrix(),
+                        mask.graphblas_matrix(),
+                        self.accumulator,
+                        $graphblas_operator,
+                        argument.graphblas_matrix(),
+                        scalar.graphblas_scalar(),
+                        mask.graphblas_descriptor(&self.options),
+                    )
+                })?;
+
+                Ok(())
+            }
+        }
+    };
+}
+
+implement_sparse_scalar_selector!(
+    not_equal_to_sparse_scalar,
+    not_equal_to_sparse_scalar_with_mask,
+    GxB_NE_THUNK
+);
+implement_sparse_scalar_selector!(
+    equal_to_sparse_scalar,
+    equal_to_sparse_scalar_with_mask,
+    GxB_EQ_THUNK
+);
+implement_sparse_scalar_selector!(
+    greater_than_sparse_scalar,
+    greater_than_sparse_scalar_with_mask,
+    GxB_GT_THUNK
+);
+implement_sparse_scalar_selector!(
+    greater_than_or_equal_to_sparse_scalar,
+    greater_than_or_equal_to_sparse_scalar_with_mask,
+    GxB_GE_THUNK
+);
+implement_sparse_scalar_selector!(
+    less_than_sparse_scalar,
+    less_than_sparse_scalar_with_mask,
+    GxB_LT_THUNK
+);
+implement_sparse_scalar_selector!(
+    less_than_or_equal_to_sparse_scalar,
+    less_than_or_equal_to_sparse_scalar_with_mask,
+    GxB_LE_THUNK
+);
+
+macro_rules! implement_selector_with_zero {
+    ($method_name:ident, $method_name_with_mask:ident, $compute_name:ident, $compute_name_with_mask:ident, $graphblas_operator:ident) => {
         impl<T: ValueType> MatrixSelector<T> {
             pub fn $method_name(
                 &self,
                 argument: &SparseMatrix<T>,
                 product: &mut SparseMatrix<T>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(argument, product)?;
+                }
+
                 let context = product.context();
 
                 context.call(|| unsafe {
@@ -828,7 +1039,7 @@ macro_rules! implement_selector_with_zero {
                         $graphblas_operator,
                         argument.graphblas_matrix(),
                         ptr::null_mut(),
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
 
@@ -844,6 +1055,11 @@ macro_rules! implement_selector_with_zero {
                 product: &mut SparseMatrix<T>,
                 mask: &MatrixMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(argument, product)?;
+                    check_size("mask", &product.size()?, &mask.size()?)?;
+                }
+
                 let context = product.context();
 
                 context.call(|| unsafe {
@@ -854,29 +1070,89 @@ macro_rules! implement_selector_with_zero {
                         $graphblas_operator,
                         argument.graphblas_matrix(),
                         ptr::null_mut(),
-                        self.options,
+                        mask.graphblas_descriptor(&self.options),
                     )
                 })?;
 
                 Ok(())
             }
         }
+
+        impl<T: ValueType + BuiltInValueType<T>> MatrixSelector<T> {
+            pub fn $compute_name(
+                &self,
+                argument: &SparseMatrix<T>,
+            ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+                let mut product = self.allocate_product(argument)?;
+                self.$method_name(argument, &mut product)?;
+                Ok(product)
+            }
+
+            pub fn $compute_name_with_mask<
+                MaskValueType: ValueType,
+                AsBool: AsBoolean<MaskValueType>,
+            >(
+                &self,
+                argument: &SparseMatrix<T>,
+                mask: &MatrixMask<MaskValueType, AsBool>,
+            ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+                let mut product = self.allocate_product(argument)?;
+                self.$method_name_with_mask(argument, &mut product, mask)?;
+                Ok(product)
+            }
+        }
     };
 }
 
-implement_selector_with_zero!(non_zero, non_zero_with_mask, GxB_NONZERO);
-implement_selector_with_zero!(zero, zero_with_mask, GxB_EQ_ZERO);
-implement_selector_with_zero!(positive, positive_with_mask, GxB_GT_ZERO);
-implement_selector_with_zero!(zero_or_positive, zero_or_positive_with_mask, GxB_GE_ZERO);
-implement_selector_with_zero!(negative, negative_with_mask, GxB_LT_ZERO);
-implement_selector_with_zero!(zero_or_negative, zero_or_negative_with_mask, GxB_LE_ZERO);
+implement_selector_with_zero!(
+    non_zero,
+    non_zero_with_mask,
+    compute_non_zero,
+    compute_non_zero_with_mask,
+    GxB_NONZERO
+);
+implement_selector_with_zero!(
+    zero,
+    zero_with_mask,
+    compute_zero,
+    compute_zero_with_mask,
+    GxB_EQ_ZERO
+);
+implement_selector_with_zero!(
+    positive,
+    positive_with_mask,
+    compute_positive,
+    compute_positive_with_mask,
+    GxB_GT_ZERO
+);
+implement_selector_with_zero!(
+    zero_or_positive,
+    zero_or_positive_with_mask,
+    compute_zero_or_positive,
+    compute_zero_or_positive_with_mask,
+    GxB_GE_ZERO
+);
+implement_selector_with_zero!(
+    negative,
+    negative_with_mask,
+    compute_negative,
+    compute_negative_with_mask,
+    GxB_LT_ZERO
+);
+implement_selector_with_zero!(
+    zero_or_negative,
+    zero_or_negative_with_mask,
+    compute_zero_or_negative,
+    compute_zero_or_negative_with_mask,
+    GxB_LE_ZERO
+);
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator};
 
     use crate::value_types::sparse_matrix::{
         FromMatrixElementList, GetMatrixElementValue, MatrixElementList, Size,
@@ -904,7 +1180,7 @@ mod tests {
 
         let mut product_matrix = SparseMatrix::<u8>::new(&context, &matrix_size).unwrap();
 
-        let selector = MatrixSelector::new(&OperatorOptions::new_default(), None);
+        let selector = MatrixSelector::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         let diagonal_index = DiagonalIndex::Default();
 
@@ -958,7 +1234,7 @@ mod tests {
 
         let mut product_matrix = SparseMatrix::<u8>::new(&context, &matrix_size).unwrap();
 
-        let selector = MatrixSelector::new(&OperatorOptions::new_default(), None);
+        let selector = MatrixSelector::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         let diagonal_index = DiagonalIndex::Default();
 
@@ -1012,7 +1288,7 @@ mod tests {
 
         let mut product_matrix = SparseMatrix::<u8>::new(&context, &matrix_size).unwrap();
 
-        let selector = MatrixSelector::new(&OperatorOptions::new_default(), None);
+        let selector = MatrixSelector::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         let diagonal_index = DiagonalIndex::Default();
 
@@ -1066,7 +1342,7 @@ mod tests {
 
         let mut product_matrix = SparseMatrix::<u8>::new(&context, &matrix_size).unwrap();
 
-        let selector = MatrixSelector::new(&OperatorOptions::new_default(), None);
+        let selector = MatrixSelector::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         let diagonal_index = DiagonalIndex::Default();
 
@@ -1120,7 +1396,7 @@ mod tests {
 
         let mut product_matrix = SparseMatrix::<u8>::new(&context, &matrix_size).unwrap();
 
-        let selector = MatrixSelector::new(&OperatorOptions::new_default(), None);
+        let selector = MatrixSelector::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         selector.positive(&matrix, &mut product_matrix).unwrap();
 
@@ -1165,7 +1441,7 @@ mod tests {
 
         let mut product_matrix = SparseMatrix::<u8>::new(&context, &matrix_size).unwrap();
 
-        let selector = MatrixSelector::new(&OperatorOptions::new_default(), None);
+        let selector = MatrixSelector::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         selector
             .greater_than_scalar(&matrix, &mut product_matrix, &1)
@@ -1191,4 +1467,47 @@ mod tests {
         assert_eq!(product_matrix.get_element_value(&(0, 1).into()).unwrap(), 0);
         assert_eq!(product_matrix.get_element_value(&(1, 1).into()).unwrap(), 0);
     }
+
+    #[test]
+    fn test_sparse_scalar_selector() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let element_list = MatrixElementList::<u8>::from_element_vector(vec![
+            (0, 0, 1).into(),
+            (1, 0, 2).into(),
+            (0, 1, 3).into(),
+            (1, 1, 4).into(),
+        ]);
+
+        let matrix_size: Size = (2, 2).into();
+        let matrix = SparseMatrix::<u8>::from_element_list(
+            &context.clone(),
+            &matrix_size,
+            &element_list,
+            &First::<u8, u8, u8>::new(),
+        )
+        .unwrap();
+
+        let mut product_matrix = SparseMatrix::<u8>::new(&context, &matrix_size).unwrap();
+        let mut thunk = SparseScalar::<u8>::new(&context).unwrap();
+        thunk.set_value(&2).unwrap();
+
+        let selector = MatrixSelector::new(&OperatorOptions::new_default(), &NoAccumulator);
+
+        selector
+            .greater_than_or_equal_to_sparse_scalar(&matrix, &mut product_matrix, &thunk)
+            .unwrap();
+
+        assert_eq!(product_matrix.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(product_matrix.get_element_value(&(0, 0).into()).unwrap(), 0);
+        assert_eq!(product_matrix.get_element_value(&(1, 0).into()).unwrap(), 2);
+
+        // An empty thunk selects no elements
+        thunk.clear().unwrap();
+        selector
+            .greater_than_or_equal_to_sparse_scalar(&matrix, &mut product_matrix, &thunk)
+            .unwrap();
+
+        assert_eq!(product_matrix.number_of_stored_elements().unwrap(), 0);
+    }
 }
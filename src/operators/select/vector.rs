@@ -3,19 +3,19 @@ use std::ptr;
 use std::marker::PhantomData;
 
 use crate::error::SparseLinearAlgebraError;
-use crate::operators::{
-    binary_operator::BinaryOperator, mask::VectorMask, options::OperatorOptions,
-};
+use crate::operators::sparse_scalar_operand::{is_empty, write_empty_vector_result};
+use crate::operators::validation::check_length;
+use crate::operators::{binary_operator::Accumulator, mask::VectorMask, options::OperatorOptions};
 
 use crate::value_types::sparse_scalar::{SetScalarValue, SparseScalar};
 use crate::value_types::sparse_vector::SparseVector;
 
-use crate::value_types::value_type::{AsBoolean, ValueType};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
 
 use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GxB_EQ_THUNK, GxB_EQ_ZERO, GxB_GE_THUNK, GxB_GE_ZERO,
-    GxB_GT_THUNK, GxB_GT_ZERO, GxB_LE_THUNK, GxB_LE_ZERO, GxB_LT_THUNK, GxB_LT_ZERO, GxB_NE_THUNK,
-    GxB_NONZERO, GxB_Vector_select,
+    GrB_BinaryOp, GxB_EQ_THUNK, GxB_EQ_ZERO, GxB_GE_THUNK, GxB_GE_ZERO, GxB_GT_THUNK, GxB_GT_ZERO,
+    GxB_LE_THUNK, GxB_LE_ZERO, GxB_LT_THUNK, GxB_LT_ZERO, GxB_NE_THUNK, GxB_NONZERO,
+    GxB_Vector_select,
 };
 
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
@@ -50,27 +50,40 @@ pub struct VectorSelector<T: ValueType> {
     _value: PhantomData<T>,
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<T: ValueType> VectorSelector<T> {
     pub fn new(
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<T, T, T>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<T, T, T> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _value: PhantomData,
         }
     }
+
+    fn check_arguments(
+        &self,
+        argument: &SparseVector<T>,
+        product: &SparseVector<T>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        check_length("product", argument.length()?, product.length()?)
+    }
+}
+
+impl<T: ValueType + BuiltInValueType<T>> VectorSelector<T> {
+    fn allocate_product(
+        &self,
+        argument: &SparseVector<T>,
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        SparseVector::new(argument.context_ref(), &argument.length()?)
+    }
 }
 
 macro_rules! implement_scalar_selector {
@@ -82,6 +95,10 @@ macro_rules! implement_scalar_selector {
                 product: &mut SparseVector<$value_type>,
                 scalar: &$value_type,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(argument, product)?;
+                }
+
                 let context = product.context();
                 let mut sparse_scalar = SparseScalar::<$value_type>::new(&context)?;
                 sparse_scalar.set_value(scalar)?;
@@ -94,7 +111,7 @@ macro_rules! implement_scalar_selector {
                         $graphblas_operator,
                         argument.graphblas_vector(),
                         sparse_scalar.graphblas_scalar(),
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
 
@@ -111,6 +128,10 @@ macro_rules! implement_scalar_selector {
                 scalar: &$value_type,
                 _mask: &VectorMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(argument, product)?;
+                }
+
                 let context = product.context();
                 let mut sparse_scalar = SparseScalar::<$value_type>::new(&context)?;
                 sparse_scalar.set_value(scalar)?;
@@ -123,7 +144,7 @@ macro_rules! implement_scalar_selector {
                         $graphblas_operator,
                         argument.graphblas_vector(),
                         sparse_scalar.graphblas_scalar(),
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
 
@@ -709,14 +730,134 @@ implement_scalar_selector!(
     GxB_LE_THUNK
 );
 
-macro_rules! implement_selector_with_zero {
+// The thunk is a SparseScalar, such that it may be the output of a preceding operation.
+// An empty thunk has no value to compare with, such that no element is selected.
+macro_rules! implement_sparse_scalar_selector {
     ($method_name:ident, $method_name_with_mask:ident, $graphblas_operator:ident) => {
+        impl<T: ValueType + BuiltInValueType<T>> VectorSelector<T> {
+            pub fn $method_name(
+                &self,
+                argument: &SparseVector<T>,
+                product: &mut SparseVector<T>,
+                scalar: &SparseScalar<T>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(argument, product)?;
+                }
+
+                if is_empty(scalar)? {
+                    return write_empty_vector_result(
+                        product,
+                        ptr::null_mut(),
+                        self.accumulator,
+                        self.options.to_graphblas_descriptor(),
+                    );
+                }
+
+                let context = product.context();
+
+                context.call(|| unsafe {
+                    GxB_Vector_select(
+                        product.graphblas_vector(),
+                        ptr::null_mut(),
+                        self.accumulator,
+                        $graphblas_operator,
+                        argument.graphblas_vector(),
+                        scalar.graphblas_scalar(),
+                        self.options.to_graphblas_descriptor(),
+                    )
+                })?;
+
+                Ok(())
+            }
+
+            pub fn $method_name_with_mask<
+                MaskValueType: ValueType,
+                AsBool: AsBoolean<MaskValueType>,
+            >(
+                &self,
+                argument: &SparseVector<T>,
+                product: &mut SparseVector<T>,
+                scalar: &SparseScalar<T>,
+                mask: &VectorMask<MaskValueType, AsBool>,
+            ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(argument, product)?;
+                    check_length("mask", product.length()?, mask.length()?)?;
+                }
+
+                if is_empty(scalar)? {
+                    return write_empty_vector_result(
+                        product,
+                        mask.graphblas_vector(),
+                        self.accumulator,
+                        mask.graphblas_descriptor(&self.options),
+                    );
+                }
+
+                let context = product.context();
+
+                context.call(|| unsafe {
+                    GxB_Vector_select(
+                        product.graphblas_vector(),
+                        mask.graphblas_vector(),
+                        self.accumulator,
+                        $graphblas_operator,
+                        argument.graphblas_vector(),
+                        scalar.graphblas_scalar(),
+                        mask.graphblas_descriptor(&self.options),
+                    )
+                })?;
+
+                Ok(())
+            }
+        }
+    };
+}
+
+implement_sparse_scalar_selector!(
+    not_equal_to_sparse_scalar,
+    not_equal_to_sparse_scalar_with_mask,
+    GxB_NE_THUNK
+);
+implement_sparse_scalar_selector!(
+    equal_to_sparse_scalar,
+    equal_to_sparse_scalar_with_mask,
+    GxB_EQ_THUNK
+);
+implement_sparse_scalar_selector!(
+    greater_than_sparse_scalar,
+    greater_than_sparse_scalar_with_mask,
+    GxB_GT_THUNK
+);
+implement_sparse_scalar_selector!(
+    greater_than_or_equal_to_sparse_scalar,
+    greater_than_or_equal_to_sparse_scalar_with_mask,
+    GxB_GE_THUNK
+);
+implement_sparse_scalar_selector!(
+    less_than_sparse_scalar,
+    less_than_sparse_scalar_with_mask,
+    GxB_LT_THUNK
+);
+implement_sparse_scalar_selector!(
+    less_than_or_equal_to_sparse_scalar,
+    less_than_or_equal_to_sparse_scalar_with_mask,
+    GxB_LE_THUNK
+);
+
+macro_rules! implement_selector_with_zero {
+    ($method_name:ident, $method_name_with_mask:ident, $compute_name:ident, $compute_name_with_mask:ident, $graphblas_operator:ident) => {
         impl<T: ValueType> VectorSelector<T> {
             pub fn $method_name(
                 &self,
                 argument: &SparseVector<T>,
                 product: &mut SparseVector<T>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(argument, product)?;
+                }
+
                 let context = product.context();
 
                 context.call(|| unsafe {
@@ -727,7 +868,7 @@ macro_rules! implement_selector_with_zero {
                         $graphblas_operator,
                         argument.graphblas_vector(),
                         ptr::null_mut(),
-                        self.options,
+                        self.options.to_graphblas_descriptor(),
                     )
                 })?;
 
@@ -743,6 +884,11 @@ macro_rules! implement_selector_with_zero {
                 product: &mut SparseVector<T>,
                 mask: &VectorMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(argument, product)?;
+                    check_length("mask", product.length()?, mask.length()?)?;
+                }
+
                 let context = product.context();
 
                 context.call(|| unsafe {
@@ -753,29 +899,89 @@ macro_rules! implement_selector_with_zero {
                         $graphblas_operator,
                         argument.graphblas_vector(),
                         ptr::null_mut(),
-                        self.options,
+                        mask.graphblas_descriptor(&self.options),
                     )
                 })?;
 
                 Ok(())
             }
         }
+
+        impl<T: ValueType + BuiltInValueType<T>> VectorSelector<T> {
+            pub fn $compute_name(
+                &self,
+                argument: &SparseVector<T>,
+            ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+                let mut product = self.allocate_product(argument)?;
+                self.$method_name(argument, &mut product)?;
+                Ok(product)
+            }
+
+            pub fn $compute_name_with_mask<
+                MaskValueType: ValueType,
+                AsBool: AsBoolean<MaskValueType>,
+            >(
+                &self,
+                argument: &SparseVector<T>,
+                mask: &VectorMask<MaskValueType, AsBool>,
+            ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+                let mut product = self.allocate_product(argument)?;
+                self.$method_name_with_mask(argument, &mut product, mask)?;
+                Ok(product)
+            }
+        }
     };
 }
 
-implement_selector_with_zero!(non_zero, non_zero_with_mask, GxB_NONZERO);
-implement_selector_with_zero!(zero, zero_with_mask, GxB_EQ_ZERO);
-implement_selector_with_zero!(positive, positive_with_mask, GxB_GT_ZERO);
-implement_selector_with_zero!(zero_or_positive, zero_or_positive_with_mask, GxB_GE_ZERO);
-implement_selector_with_zero!(negative, negative_with_mask, GxB_LT_ZERO);
-implement_selector_with_zero!(zero_or_negative, zero_or_negative_with_mask, GxB_LE_ZERO);
+implement_selector_with_zero!(
+    non_zero,
+    non_zero_with_mask,
+    compute_non_zero,
+    compute_non_zero_with_mask,
+    GxB_NONZERO
+);
+implement_selector_with_zero!(
+    zero,
+    zero_with_mask,
+    compute_zero,
+    compute_zero_with_mask,
+    GxB_EQ_ZERO
+);
+implement_selector_with_zero!(
+    positive,
+    positive_with_mask,
+    compute_positive,
+    compute_positive_with_mask,
+    GxB_GT_ZERO
+);
+implement_selector_with_zero!(
+    zero_or_positive,
+    zero_or_positive_with_mask,
+    compute_zero_or_positive,
+    compute_zero_or_positive_with_mask,
+    GxB_GE_ZERO
+);
+implement_selector_with_zero!(
+    negative,
+    negative_with_mask,
+    compute_negative,
+    compute_negative_with_mask,
+    GxB_LT_ZERO
+);
+implement_selector_with_zero!(
+    zero_or_negative,
+    zero_or_negative_with_mask,
+    compute_zero_or_negative,
+    compute_zero_or_negative_with_mask,
+    GxB_LE_ZERO
+);
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator};
 
     use crate::value_types::sparse_vector::{
         FromVectorElementList, GetVectorElementValue, VectorElementList,
@@ -803,7 +1009,7 @@ mod tests {
 
         let mut product_vector = SparseVector::<u8>::new(&context, &vector_length).unwrap();
 
-        let selector = VectorSelector::new(&OperatorOptions::new_default(), None);
+        let selector = VectorSelector::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         selector.positive(&vector, &mut product_vector).unwrap();
 
@@ -848,7 +1054,7 @@ mod tests {
 
         let mut product_vector = SparseVector::<u8>::new(&context, &vector_length).unwrap();
 
-        let selector = VectorSelector::new(&OperatorOptions::new_default(), None);
+        let selector = VectorSelector::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         selector
             .greater_than_scalar(&vector, &mut product_vector, &1)
@@ -1,6 +1,8 @@
-use crate::operators::binary_operator::{Plus, Times};
+use std::marker::PhantomData;
+
+use crate::operators::binary_operator::{Min, Minus, Plus, Times};
 use crate::operators::monoid::{LogicalAnd, LogicalOr};
-use crate::value_types::value_type::ValueType;
+use crate::value_types::value_type::{BuiltInValueType, ValueType};
 
 use crate::bindings_to_graphblas_implementation::*;
 
@@ -25,7 +27,7 @@ macro_rules! implement_semiring_operator {
         }
 
         impl $semiring<$multiplier, $multiplicant, $product> {
-            pub fn new() -> Self {
+            pub const fn new() -> Self {
                 Self {
                     addition_operator: $addition_operator::<
                         $product,
@@ -44,7 +46,7 @@ macro_rules! implement_semiring_operator {
     };
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct PlusTimes<Multiplier, Multiplicant, Product>
 where
     Multiplier: ValueType,
@@ -147,7 +149,213 @@ implement_semiring_operator!(
     GrB_PLUS_TIMES_SEMIRING_FP64
 );
 
-#[derive(Debug, Clone)]
+/// The tropical semiring, the product of a path is the sum of its edges, the shortest path is the minimum
+#[derive(Debug, Clone, Copy)]
+pub struct MinPlus<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType,
+{
+    addition_operator: Min<Product, Product, Product>,
+    multiplication_operator: Plus<Multiplier, Multiplicant, Product>,
+}
+
+implement_semiring_operator!(
+    MinPlus,
+    Min,
+    Plus,
+    u8,
+    u8,
+    u8,
+    GrB_MIN_PLUS_SEMIRING_UINT8
+);
+implement_semiring_operator!(
+    MinPlus,
+    Min,
+    Plus,
+    u16,
+    u16,
+    u16,
+    GrB_MIN_PLUS_SEMIRING_UINT16
+);
+implement_semiring_operator!(
+    MinPlus,
+    Min,
+    Plus,
+    u32,
+    u32,
+    u32,
+    GrB_MIN_PLUS_SEMIRING_UINT32
+);
+implement_semiring_operator!(
+    MinPlus,
+    Min,
+    Plus,
+    u64,
+    u64,
+    u64,
+    GrB_MIN_PLUS_SEMIRING_UINT64
+);
+implement_semiring_operator!(
+    MinPlus,
+    Min,
+    Plus,
+    i8,
+    i8,
+    i8,
+    GrB_MIN_PLUS_SEMIRING_INT8
+);
+implement_semiring_operator!(
+    MinPlus,
+    Min,
+    Plus,
+    i16,
+    i16,
+    i16,
+    GrB_MIN_PLUS_SEMIRING_INT16
+);
+implement_semiring_operator!(
+    MinPlus,
+    Min,
+    Plus,
+    i32,
+    i32,
+    i32,
+    GrB_MIN_PLUS_SEMIRING_INT32
+);
+implement_semiring_operator!(
+    MinPlus,
+    Min,
+    Plus,
+    i64,
+    i64,
+    i64,
+    GrB_MIN_PLUS_SEMIRING_INT64
+);
+implement_semiring_operator!(
+    MinPlus,
+    Min,
+    Plus,
+    f32,
+    f32,
+    f32,
+    GrB_MIN_PLUS_SEMIRING_FP32
+);
+implement_semiring_operator!(
+    MinPlus,
+    Min,
+    Plus,
+    f64,
+    f64,
+    f64,
+    GrB_MIN_PLUS_SEMIRING_FP64
+);
+
+/// Minimum of the differences, min(A(i,k) - B(k,j)) over k
+#[derive(Debug, Clone, Copy)]
+pub struct MinMinus<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType,
+{
+    addition_operator: Min<Product, Product, Product>,
+    multiplication_operator: Minus<Multiplier, Multiplicant, Product>,
+}
+
+implement_semiring_operator!(
+    MinMinus,
+    Min,
+    Minus,
+    u8,
+    u8,
+    u8,
+    GxB_MIN_MINUS_UINT8
+);
+implement_semiring_operator!(
+    MinMinus,
+    Min,
+    Minus,
+    u16,
+    u16,
+    u16,
+    GxB_MIN_MINUS_UINT16
+);
+implement_semiring_operator!(
+    MinMinus,
+    Min,
+    Minus,
+    u32,
+    u32,
+    u32,
+    GxB_MIN_MINUS_UINT32
+);
+implement_semiring_operator!(
+    MinMinus,
+    Min,
+    Minus,
+    u64,
+    u64,
+    u64,
+    GxB_MIN_MINUS_UINT64
+);
+implement_semiring_operator!(
+    MinMinus,
+    Min,
+    Minus,
+    i8,
+    i8,
+    i8,
+    GxB_MIN_MINUS_INT8
+);
+implement_semiring_operator!(
+    MinMinus,
+    Min,
+    Minus,
+    i16,
+    i16,
+    i16,
+    GxB_MIN_MINUS_INT16
+);
+implement_semiring_operator!(
+    MinMinus,
+    Min,
+    Minus,
+    i32,
+    i32,
+    i32,
+    GxB_MIN_MINUS_INT32
+);
+implement_semiring_operator!(
+    MinMinus,
+    Min,
+    Minus,
+    i64,
+    i64,
+    i64,
+    GxB_MIN_MINUS_INT64
+);
+implement_semiring_operator!(
+    MinMinus,
+    Min,
+    Minus,
+    f32,
+    f32,
+    f32,
+    GxB_MIN_MINUS_FP32
+);
+implement_semiring_operator!(
+    MinMinus,
+    Min,
+    Minus,
+    f64,
+    f64,
+    f64,
+    GxB_MIN_MINUS_FP64
+);
+
+#[derive(Debug, Clone, Copy)]
 pub struct LAndLOr {
     and_op: LogicalAnd<bool>,
     or_op: LogicalOr<bool>,
@@ -160,7 +368,7 @@ impl Semiring<bool, bool, bool> for LAndLOr {
 }
 
 impl LAndLOr {
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self {
             and_op: LogicalAnd::new(),
             or_op: LogicalOr::new(),
@@ -168,6 +376,262 @@ impl LAndLOr {
     }
 }
 
+// The multiplication operators of these semirings ignore the values of their arguments,
+// such that the semirings apply to arguments of any built-in type.
+macro_rules! implement_structural_semiring {
+    ($semiring:ident, $product:ty, $graphblas_operator:ident) => {
+        impl<Multiplier, Multiplicant> Semiring<Multiplier, Multiplicant, $product>
+            for $semiring<Multiplier, Multiplicant, $product>
+        where
+            Multiplier: ValueType + BuiltInValueType<Multiplier>,
+            Multiplicant: ValueType + BuiltInValueType<Multiplicant>,
+        {
+            fn graphblas_type(&self) -> GrB_Semiring {
+                unsafe { $graphblas_operator }
+            }
+        }
+
+        impl<Multiplier, Multiplicant> $semiring<Multiplier, Multiplicant, $product>
+        where
+            Multiplier: ValueType + BuiltInValueType<Multiplier>,
+            Multiplicant: ValueType + BuiltInValueType<Multiplicant>,
+        {
+            pub const fn new() -> Self {
+                Self {
+                    _multiplier: PhantomData,
+                    _multiplicant: PhantomData,
+                    _product: PhantomData,
+                }
+            }
+        }
+    };
+}
+
+/// Any of the products, which are 1 for every pair of stored elements
+#[derive(Debug, Clone, Copy)]
+pub struct AnyPair<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType,
+{
+    _multiplier: PhantomData<Multiplier>,
+    _multiplicant: PhantomData<Multiplicant>,
+    _product: PhantomData<Product>,
+}
+
+implement_structural_semiring!(AnyPair, bool, GxB_ANY_PAIR_BOOL);
+
+/// Any of the products, which are the row index of the multiplicant, the k in A(i,k)*B(k,j)
+#[derive(Debug, Clone, Copy)]
+pub struct AnySecondIndex<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType,
+{
+    _multiplier: PhantomData<Multiplier>,
+    _multiplicant: PhantomData<Multiplicant>,
+    _product: PhantomData<Product>,
+}
+
+implement_structural_semiring!(AnySecondIndex, i64, GxB_ANY_SECONDI_INT64);
+
+/// Minimum of the products, which are the row index of the multiplicant, the k in A(i,k)*B(k,j)
+#[derive(Debug, Clone, Copy)]
+pub struct MinSecondIndex<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType,
+{
+    _multiplier: PhantomData<Multiplier>,
+    _multiplicant: PhantomData<Multiplicant>,
+    _product: PhantomData<Product>,
+}
+
+implement_structural_semiring!(MinSecondIndex, i64, GxB_MIN_SECONDI_INT64);
+
+/// Sum of the products, which are 1 for every pair of stored elements, counts the pairs
+#[derive(Debug, Clone, Copy)]
+pub struct PlusPair<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType,
+{
+    _multiplier: PhantomData<Multiplier>,
+    _multiplicant: PhantomData<Multiplicant>,
+    _product: PhantomData<Product>,
+}
+
+implement_structural_semiring!(PlusPair, u8, GxB_PLUS_PAIR_UINT8);
+implement_structural_semiring!(PlusPair, u16, GxB_PLUS_PAIR_UINT16);
+implement_structural_semiring!(PlusPair, u32, GxB_PLUS_PAIR_UINT32);
+implement_structural_semiring!(PlusPair, u64, GxB_PLUS_PAIR_UINT64);
+implement_structural_semiring!(PlusPair, i8, GxB_PLUS_PAIR_INT8);
+implement_structural_semiring!(PlusPair, i16, GxB_PLUS_PAIR_INT16);
+implement_structural_semiring!(PlusPair, i32, GxB_PLUS_PAIR_INT32);
+implement_structural_semiring!(PlusPair, i64, GxB_PLUS_PAIR_INT64);
+implement_structural_semiring!(PlusPair, f32, GxB_PLUS_PAIR_FP32);
+implement_structural_semiring!(PlusPair, f64, GxB_PLUS_PAIR_FP64);
+
+// The multiplication operators of these semirings ignore the value of the multiplier,
+// such that the multiplier may be of any built-in type.
+macro_rules! implement_semiring_ignoring_multiplier {
+    ($semiring:ident, $value_type:ty, $graphblas_operator:ident) => {
+        impl<Multiplier> Semiring<Multiplier, $value_type, $value_type>
+            for $semiring<Multiplier, $value_type, $value_type>
+        where
+            Multiplier: ValueType + BuiltInValueType<Multiplier>,
+        {
+            fn graphblas_type(&self) -> GrB_Semiring {
+                unsafe { $graphblas_operator }
+            }
+        }
+
+        impl<Multiplier> $semiring<Multiplier, $value_type, $value_type>
+        where
+            Multiplier: ValueType + BuiltInValueType<Multiplier>,
+        {
+            pub const fn new() -> Self {
+                Self {
+                    _multiplier: PhantomData,
+                    _multiplicant: PhantomData,
+                    _product: PhantomData,
+                }
+            }
+        }
+    };
+}
+
+/// Sum of the multiplicants, the values of A(i,k) are ignored, such that A'*x sums x over the edges
+#[derive(Debug, Clone, Copy)]
+pub struct PlusSecond<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType,
+{
+    _multiplier: PhantomData<Multiplier>,
+    _multiplicant: PhantomData<Multiplicant>,
+    _product: PhantomData<Product>,
+}
+
+implement_semiring_ignoring_multiplier!(PlusSecond, u8, GxB_PLUS_SECOND_UINT8);
+implement_semiring_ignoring_multiplier!(PlusSecond, u16, GxB_PLUS_SECOND_UINT16);
+implement_semiring_ignoring_multiplier!(PlusSecond, u32, GxB_PLUS_SECOND_UINT32);
+implement_semiring_ignoring_multiplier!(PlusSecond, u64, GxB_PLUS_SECOND_UINT64);
+implement_semiring_ignoring_multiplier!(PlusSecond, i8, GxB_PLUS_SECOND_INT8);
+implement_semiring_ignoring_multiplier!(PlusSecond, i16, GxB_PLUS_SECOND_INT16);
+implement_semiring_ignoring_multiplier!(PlusSecond, i32, GxB_PLUS_SECOND_INT32);
+implement_semiring_ignoring_multiplier!(PlusSecond, i64, GxB_PLUS_SECOND_INT64);
+implement_semiring_ignoring_multiplier!(PlusSecond, f32, GxB_PLUS_SECOND_FP32);
+implement_semiring_ignoring_multiplier!(PlusSecond, f64, GxB_PLUS_SECOND_FP64);
+
+/// Minimum of the multiplicants, the values of A(i,k) are ignored,
+/// such that A*x is the smallest x over the edges
+#[derive(Debug, Clone, Copy)]
+pub struct MinSecond<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType,
+{
+    _multiplier: PhantomData<Multiplier>,
+    _multiplicant: PhantomData<Multiplicant>,
+    _product: PhantomData<Product>,
+}
+
+implement_semiring_ignoring_multiplier!(MinSecond, u8, GxB_MIN_SECOND_UINT8);
+implement_semiring_ignoring_multiplier!(MinSecond, u16, GxB_MIN_SECOND_UINT16);
+implement_semiring_ignoring_multiplier!(MinSecond, u32, GxB_MIN_SECOND_UINT32);
+implement_semiring_ignoring_multiplier!(MinSecond, u64, GxB_MIN_SECOND_UINT64);
+implement_semiring_ignoring_multiplier!(MinSecond, i8, GxB_MIN_SECOND_INT8);
+implement_semiring_ignoring_multiplier!(MinSecond, i16, GxB_MIN_SECOND_INT16);
+implement_semiring_ignoring_multiplier!(MinSecond, i32, GxB_MIN_SECOND_INT32);
+implement_semiring_ignoring_multiplier!(MinSecond, i64, GxB_MIN_SECOND_INT64);
+implement_semiring_ignoring_multiplier!(MinSecond, f32, GxB_MIN_SECOND_FP32);
+implement_semiring_ignoring_multiplier!(MinSecond, f64, GxB_MIN_SECOND_FP64);
+
+/// Maximum of the multiplicants, the values of A(i,k) are ignored,
+/// such that A*x is the largest x over the edges
+#[derive(Debug, Clone, Copy)]
+pub struct MaxSecond<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType,
+{
+    _multiplier: PhantomData<Multiplier>,
+    _multiplicant: PhantomData<Multiplicant>,
+    _product: PhantomData<Product>,
+}
+
+implement_semiring_ignoring_multiplier!(MaxSecond, u8, GxB_MAX_SECOND_UINT8);
+implement_semiring_ignoring_multiplier!(MaxSecond, u16, GxB_MAX_SECOND_UINT16);
+implement_semiring_ignoring_multiplier!(MaxSecond, u32, GxB_MAX_SECOND_UINT32);
+implement_semiring_ignoring_multiplier!(MaxSecond, u64, GxB_MAX_SECOND_UINT64);
+implement_semiring_ignoring_multiplier!(MaxSecond, i8, GxB_MAX_SECOND_INT8);
+implement_semiring_ignoring_multiplier!(MaxSecond, i16, GxB_MAX_SECOND_INT16);
+implement_semiring_ignoring_multiplier!(MaxSecond, i32, GxB_MAX_SECOND_INT32);
+implement_semiring_ignoring_multiplier!(MaxSecond, i64, GxB_MAX_SECOND_INT64);
+implement_semiring_ignoring_multiplier!(MaxSecond, f32, GxB_MAX_SECOND_FP32);
+implement_semiring_ignoring_multiplier!(MaxSecond, f64, GxB_MAX_SECOND_FP64);
+
+// The multiplication operators of these semirings ignore the value of the multiplicant,
+// such that the multiplicant may be of any built-in type.
+macro_rules! implement_semiring_ignoring_multiplicant {
+    ($semiring:ident, $value_type:ty, $graphblas_operator:ident) => {
+        impl<Multiplicant> Semiring<$value_type, Multiplicant, $value_type>
+            for $semiring<$value_type, Multiplicant, $value_type>
+        where
+            Multiplicant: ValueType + BuiltInValueType<Multiplicant>,
+        {
+            fn graphblas_type(&self) -> GrB_Semiring {
+                unsafe { $graphblas_operator }
+            }
+        }
+
+        impl<Multiplicant> $semiring<$value_type, Multiplicant, $value_type>
+        where
+            Multiplicant: ValueType + BuiltInValueType<Multiplicant>,
+        {
+            pub const fn new() -> Self {
+                Self {
+                    _multiplier: PhantomData,
+                    _multiplicant: PhantomData,
+                    _product: PhantomData,
+                }
+            }
+        }
+    };
+}
+
+/// Sum of the multipliers, the values of B(k,j) are ignored, such that X*A sums X over the edges
+#[derive(Debug, Clone, Copy)]
+pub struct PlusFirst<Multiplier, Multiplicant, Product>
+where
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType,
+{
+    _multiplier: PhantomData<Multiplier>,
+    _multiplicant: PhantomData<Multiplicant>,
+    _product: PhantomData<Product>,
+}
+
+implement_semiring_ignoring_multiplicant!(PlusFirst, u8, GxB_PLUS_FIRST_UINT8);
+implement_semiring_ignoring_multiplicant!(PlusFirst, u16, GxB_PLUS_FIRST_UINT16);
+implement_semiring_ignoring_multiplicant!(PlusFirst, u32, GxB_PLUS_FIRST_UINT32);
+implement_semiring_ignoring_multiplicant!(PlusFirst, u64, GxB_PLUS_FIRST_UINT64);
+implement_semiring_ignoring_multiplicant!(PlusFirst, i8, GxB_PLUS_FIRST_INT8);
+implement_semiring_ignoring_multiplicant!(PlusFirst, i16, GxB_PLUS_FIRST_INT16);
+implement_semiring_ignoring_multiplicant!(PlusFirst, i32, GxB_PLUS_FIRST_INT32);
+implement_semiring_ignoring_multiplicant!(PlusFirst, i64, GxB_PLUS_FIRST_INT64);
+implement_semiring_ignoring_multiplicant!(PlusFirst, f32, GxB_PLUS_FIRST_FP32);
+implement_semiring_ignoring_multiplicant!(PlusFirst, f64, GxB_PLUS_FIRST_FP64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,8 +649,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_structural_semiring_applies_to_any_argument_types() {
+        let semiring = AnySecondIndex::<bool, f64, i64>::new();
+        unsafe {
+            assert_eq!(semiring.graphblas_type(), GxB_ANY_SECONDI_INT64);
+        }
+        let semiring = AnySecondIndex::<i64, u8, i64>::new();
+        unsafe {
+            assert_eq!(semiring.graphblas_type(), GxB_ANY_SECONDI_INT64);
+        }
+        let semiring = MinSecondIndex::<f32, bool, i64>::new();
+        unsafe {
+            assert_eq!(semiring.graphblas_type(), GxB_MIN_SECONDI_INT64);
+        }
+    }
+
+    #[test]
+    fn test_semiring_ignoring_multiplier_applies_to_any_multiplier_type() {
+        let semiring = PlusSecond::<bool, f64, f64>::new();
+        unsafe {
+            assert_eq!(semiring.graphblas_type(), GxB_PLUS_SECOND_FP64);
+        }
+        let semiring = PlusSecond::<u8, f64, f64>::new();
+        unsafe {
+            assert_eq!(semiring.graphblas_type(), GxB_PLUS_SECOND_FP64);
+        }
+        let semiring = MinSecond::<f32, u64, u64>::new();
+        unsafe {
+            assert_eq!(semiring.graphblas_type(), GxB_MIN_SECOND_UINT64);
+        }
+        let semiring = MaxSecond::<bool, f64, f64>::new();
+        unsafe {
+            assert_eq!(semiring.graphblas_type(), GxB_MAX_SECOND_FP64);
+        }
+    }
+
+    #[test]
+    fn test_semiring_ignoring_multiplicant_applies_to_any_multiplicant_type() {
+        let semiring = PlusFirst::<f64, bool, f64>::new();
+        unsafe {
+            assert_eq!(semiring.graphblas_type(), GxB_PLUS_FIRST_FP64);
+        }
+        let semiring = PlusFirst::<f64, i32, f64>::new();
+        unsafe {
+            assert_eq!(semiring.graphblas_type(), GxB_PLUS_FIRST_FP64);
+        }
+    }
+
     #[test]
     fn test_new_binary_operator() {
         let _plus = Plus::<i8, i8, i8>::new();
     }
+
+    #[test]
+    fn test_semiring_is_zero_sized_and_const() {
+        const SEMIRING: PlusTimes<f64, f64, f64> =
+            PlusTimes::<f64, f64, f64>::new();
+
+        let copy = SEMIRING;
+        assert_eq!(std::mem::size_of_val(&copy), 0);
+        assert_eq!(std::mem::size_of::<LAndLOr>(), 0);
+        assert_eq!(std::mem::size_of::<MinPlus<u32, u32, u32>>(), 0);
+        assert_eq!(std::mem::size_of::<AnyPair<f32, u8, bool>>(), 0);
+        assert_eq!(copy.graphblas_type(), SEMIRING.graphblas_type());
+    }
 }
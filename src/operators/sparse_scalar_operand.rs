@@ -0,0 +1,76 @@
+use std::ptr;
+
+use crate::bindings_to_graphblas_implementation::{
+    GrB_BinaryOp, GrB_Descriptor, GrB_Matrix, GrB_Vector, GxB_Matrix_select, GxB_NONZERO,
+    GxB_Vector_select,
+};
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::options::OperatorOptions;
+use crate::value_types::sparse_matrix::{Size, SparseMatrix};
+use crate::value_types::sparse_scalar::SparseScalar;
+use crate::value_types::sparse_vector::SparseVector;
+use crate::value_types::value_type::{BuiltInValueType, ValueType};
+
+// An empty SparseScalar has no value to bind to an operator, such that no element is computed.
+// The product is then written as if the computed result was empty: without accumulator,
+// the product elements selected by the mask are cleared, with accumulator the product is kept.
+// GraphBLAS itself rejects empty bound scalars, the empty result is written by selecting
+// from an empty argument instead.
+
+pub(crate) fn is_empty<T: ValueType>(
+    scalar: &SparseScalar<T>,
+) -> Result<bool, SparseLinearAlgebraError> {
+    Ok(scalar.number_of_stored_elements()? == 0)
+}
+
+pub(crate) fn write_empty_matrix_result<Product: ValueType + BuiltInValueType<Product>>(
+    product: &mut SparseMatrix<Product>,
+    mask: GrB_Matrix,
+    accumulator: GrB_BinaryOp,
+    options: &OperatorOptions,
+    descriptor: GrB_Descriptor,
+) -> Result<(), SparseLinearAlgebraError> {
+    let product_size = product.size()?;
+    // The descriptor may transpose the first input of GxB_Matrix_select
+    let empty_argument_size = if options.transpose_input0() {
+        Size::new(product_size.column_width(), product_size.row_height())
+    } else {
+        product_size
+    };
+    let empty_argument = SparseMatrix::<Product>::new(product.context_ref(), &empty_argument_size)?;
+
+    product.context_ref().call(|| unsafe {
+        GxB_Matrix_select(
+            product.graphblas_matrix(),
+            mask,
+            accumulator,
+            GxB_NONZERO,
+            empty_argument.graphblas_matrix(),
+            ptr::null_mut(),
+            descriptor,
+        )
+    })?;
+    Ok(())
+}
+
+pub(crate) fn write_empty_vector_result<Product: ValueType + BuiltInValueType<Product>>(
+    product: &mut SparseVector<Product>,
+    mask: GrB_Vector,
+    accumulator: GrB_BinaryOp,
+    descriptor: GrB_Descriptor,
+) -> Result<(), SparseLinearAlgebraError> {
+    let empty_argument = SparseVector::<Product>::new(product.context_ref(), &product.length()?)?;
+
+    product.context_ref().call(|| unsafe {
+        GxB_Vector_select(
+            product.graphblas_vector(),
+            mask,
+            accumulator,
+            GxB_NONZERO,
+            empty_argument.graphblas_vector(),
+            ptr::null_mut(),
+            descriptor,
+        )
+    })?;
+    Ok(())
+}
@@ -3,17 +3,15 @@ use std::ptr;
 use std::marker::PhantomData;
 
 use crate::error::SparseLinearAlgebraError;
-use crate::operators::{
-    binary_operator::BinaryOperator, mask::MatrixMask, options::OperatorOptions,
-};
-use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::operators::options::size_of_first_argument;
+use crate::operators::validation::{check_indices, check_size};
+use crate::operators::{binary_operator::Accumulator, mask::MatrixMask, options::OperatorOptions};
+use crate::value_types::sparse_matrix::{Size, SparseMatrix};
 
-use crate::util::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
+use crate::util::{ElementIndexSelector, ElementIndexSelectorGraphblasType};
 use crate::value_types::value_type::{AsBoolean, ValueType};
 
-use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GxB_Matrix_subassign,
-};
+use crate::bindings_to_graphblas_implementation::{GrB_BinaryOp, GxB_Matrix_subassign};
 
 // TODO: explicitly define how dupicates are handled
 
@@ -50,7 +48,7 @@ pub struct InsertMatrixIntoSubMatrix<MatrixToInsertInto: ValueType, MatrixToInse
     _matrix_to_insert: PhantomData<MatrixToInsert>,
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<MatrixToInsertInto, MatrixToInsert>
@@ -61,24 +59,47 @@ where
 {
     pub fn new(
         options: &OperatorOptions,
-        accumulator: Option<
-            &dyn BinaryOperator<MatrixToInsert, MatrixToInsertInto, MatrixToInsertInto>,
-        >, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<MatrixToInsert, MatrixToInsertInto, MatrixToInsertInto>
+              + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _matrix_to_insert_into: PhantomData,
             _matrix_to_insert: PhantomData,
         }
     }
+
+    fn check_arguments(
+        &self,
+        matrix_to_insert_into: &SparseMatrix<MatrixToInsertInto>,
+        rows_to_insert_into: &ElementIndexSelector,
+        columns_to_insert_into: &ElementIndexSelector,
+        matrix_to_insert: &SparseMatrix<MatrixToInsert>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let size = matrix_to_insert_into.size()?;
+        check_indices(
+            "rows_to_insert_into",
+            rows_to_insert_into,
+            size.row_height(),
+        )?;
+        check_indices(
+            "columns_to_insert_into",
+            columns_to_insert_into,
+            size.column_width(),
+        )?;
+        check_size(
+            "matrix_to_insert",
+            &Size::new(
+                rows_to_insert_into.number_of_selected_elements(size.row_height())?,
+                columns_to_insert_into.number_of_selected_elements(size.column_width())?,
+            ),
+            &size_of_first_argument(matrix_to_insert, &self.options)?,
+        )
+    }
 }
 
 pub trait InsertMatrixIntoSubMatrixTrait<MatrixToInsertInto, MatrixToInsert>
@@ -128,15 +149,22 @@ macro_rules! implement_insert_matrix_into_sub_matrix_trait {
                 columns_to_insert_into: &ElementIndexSelector, // length must equal column_width of matrix_to_insert
                 matrix_to_insert: &SparseMatrix<$value_type_matrix_to_insert>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(
+                        matrix_to_insert_into,
+                        rows_to_insert_into,
+                        columns_to_insert_into,
+                        matrix_to_insert,
+                    )?;
+                }
+
                 let context = matrix_to_insert_into.context();
 
                 let number_of_rows_to_insert_into = rows_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.row_height()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.row_height()?)?;
 
                 let number_of_columns_to_insert_into = columns_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.column_width()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.column_width()?)?;
 
                 let rows_to_insert_into = rows_to_insert_into.to_graphblas_type()?;
                 let columns_to_insert_into = columns_to_insert_into.to_graphblas_type()?;
@@ -156,7 +184,7 @@ macro_rules! implement_insert_matrix_into_sub_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column.as_ptr(),
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -174,7 +202,7 @@ macro_rules! implement_insert_matrix_into_sub_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column.as_ptr(),
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -192,7 +220,7 @@ macro_rules! implement_insert_matrix_into_sub_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column,
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -210,7 +238,7 @@ macro_rules! implement_insert_matrix_into_sub_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column,
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -228,15 +256,27 @@ macro_rules! implement_insert_matrix_into_sub_matrix_trait {
                 matrix_to_insert: &SparseMatrix<$value_type_matrix_to_insert>,
                 mask_for_matrix_to_insert_into: &MatrixMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(
+                        matrix_to_insert_into,
+                        rows_to_insert_into,
+                        columns_to_insert_into,
+                        matrix_to_insert,
+                    )?;
+                    check_size(
+                        "mask_for_matrix_to_insert_into",
+                        &size_of_first_argument(matrix_to_insert, &self.options)?,
+                        &mask_for_matrix_to_insert_into.size()?,
+                    )?;
+                }
+
                 let context = matrix_to_insert_into.context();
 
                 let number_of_rows_to_insert_into = rows_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.row_height()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.row_height()?)?;
 
                 let number_of_columns_to_insert_into = columns_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.column_width()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.column_width()?)?;
 
                 let rows_to_insert_into = rows_to_insert_into.to_graphblas_type()?;
                 let columns_to_insert_into = columns_to_insert_into.to_graphblas_type()?;
@@ -256,7 +296,7 @@ macro_rules! implement_insert_matrix_into_sub_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column.as_ptr(),
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                mask_for_matrix_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -274,7 +314,7 @@ macro_rules! implement_insert_matrix_into_sub_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column.as_ptr(),
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                mask_for_matrix_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -292,7 +332,7 @@ macro_rules! implement_insert_matrix_into_sub_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column,
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                mask_for_matrix_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -310,7 +350,7 @@ macro_rules! implement_insert_matrix_into_sub_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column,
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                mask_for_matrix_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -339,7 +379,7 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator};
 
     use crate::util::ElementIndex;
     use crate::value_types::sparse_matrix::{
@@ -401,7 +441,8 @@ mod tests {
         let columns_to_insert: Vec<ElementIndex> = (0..2).collect();
         let columns_to_insert = ElementIndexSelector::Index(&columns_to_insert);
 
-        let insert_operator = InsertMatrixIntoSubMatrix::new(&OperatorOptions::new_default(), None);
+        let insert_operator =
+            InsertMatrixIntoSubMatrix::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         insert_operator
             .apply(
@@ -435,7 +476,7 @@ mod tests {
                 &rows_to_insert,
                 &columns_to_insert,
                 &matrix_to_insert,
-                &mask.into(),
+                &(&mask).into(),
             )
             .unwrap();
 
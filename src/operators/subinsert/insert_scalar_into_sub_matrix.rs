@@ -3,19 +3,18 @@ use std::ptr;
 use std::marker::PhantomData;
 
 use crate::error::SparseLinearAlgebraError;
-use crate::operators::{
-    binary_operator::BinaryOperator, mask::MatrixMask, options::OperatorOptions,
-};
-use crate::value_types::sparse_matrix::SparseMatrix;
+use crate::operators::validation::{check_indices, check_size};
+use crate::operators::{binary_operator::Accumulator, mask::MatrixMask, options::OperatorOptions};
+use crate::value_types::sparse_matrix::{Size, SparseMatrix};
 
-use crate::util::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
+use crate::util::{ElementIndexSelector, ElementIndexSelectorGraphblasType};
 use crate::value_types::value_type::{AsBoolean, ValueType};
 
 use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GxB_Matrix_subassign_BOOL, GxB_Matrix_subassign_FP32,
-    GxB_Matrix_subassign_FP64, GxB_Matrix_subassign_INT16, GxB_Matrix_subassign_INT32,
-    GxB_Matrix_subassign_INT64, GxB_Matrix_subassign_INT8, GxB_Matrix_subassign_UINT16,
-    GxB_Matrix_subassign_UINT32, GxB_Matrix_subassign_UINT64, GxB_Matrix_subassign_UINT8,
+    GrB_BinaryOp, GxB_Matrix_subassign_BOOL, GxB_Matrix_subassign_FP32, GxB_Matrix_subassign_FP64,
+    GxB_Matrix_subassign_INT16, GxB_Matrix_subassign_INT32, GxB_Matrix_subassign_INT64,
+    GxB_Matrix_subassign_INT8, GxB_Matrix_subassign_UINT16, GxB_Matrix_subassign_UINT32,
+    GxB_Matrix_subassign_UINT64, GxB_Matrix_subassign_UINT8,
 };
 
 // TODO: explicitly define how dupicates are handled
@@ -53,7 +52,7 @@ pub struct InsertScalarIntoSubMatrix<MatrixToInsertInto: ValueType, ScalarToInse
     _scalar_to_insert: PhantomData<ScalarToInsert>,
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<MatrixToInsertInto, ScalarToInsert>
@@ -64,24 +63,51 @@ where
 {
     pub fn new(
         options: &OperatorOptions,
-        accumulator: Option<
-            &dyn BinaryOperator<ScalarToInsert, MatrixToInsertInto, MatrixToInsertInto>,
-        >, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<ScalarToInsert, MatrixToInsertInto, MatrixToInsertInto>
+              + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _matrix_to_insert_into: PhantomData,
             _scalar_to_insert: PhantomData,
         }
     }
+
+    fn check_arguments(
+        &self,
+        matrix_to_insert_into: &SparseMatrix<MatrixToInsertInto>,
+        rows_to_insert_into: &ElementIndexSelector,
+        columns_to_insert_into: &ElementIndexSelector,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let size = matrix_to_insert_into.size()?;
+        check_indices(
+            "rows_to_insert_into",
+            rows_to_insert_into,
+            size.row_height(),
+        )?;
+        check_indices(
+            "columns_to_insert_into",
+            columns_to_insert_into,
+            size.column_width(),
+        )
+    }
+
+    fn sub_matrix_size(
+        &self,
+        matrix_to_insert_into: &SparseMatrix<MatrixToInsertInto>,
+        rows_to_insert_into: &ElementIndexSelector,
+        columns_to_insert_into: &ElementIndexSelector,
+    ) -> Result<Size, SparseLinearAlgebraError> {
+        let size = matrix_to_insert_into.size()?;
+        Ok(Size::new(
+            rows_to_insert_into.number_of_selected_elements(size.row_height())?,
+            columns_to_insert_into.number_of_selected_elements(size.column_width())?,
+        ))
+    }
 }
 
 pub trait InsertScalarIntoSubMatrixTrait<MatrixToInsertInto, ScalarToInsert>
@@ -131,15 +157,21 @@ macro_rules! implement_insert_scalar_into_sub_matrix_trait {
                 columns_to_insert_into: &ElementIndexSelector, // length must equal column_width of matrix_to_insert
                 scalar_to_insert: &$value_type_scalar_to_insert,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(
+                        matrix_to_insert_into,
+                        rows_to_insert_into,
+                        columns_to_insert_into,
+                    )?;
+                }
+
                 let context = matrix_to_insert_into.context();
 
                 let number_of_rows_to_insert_into = rows_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.row_height()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.row_height()?)?;
 
                 let number_of_columns_to_insert_into = columns_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.column_width()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.column_width()?)?;
 
                 let rows_to_insert_into = rows_to_insert_into.to_graphblas_type()?;
                 let columns_to_insert_into = columns_to_insert_into.to_graphblas_type()?;
@@ -159,7 +191,7 @@ macro_rules! implement_insert_scalar_into_sub_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column.as_ptr(),
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -177,7 +209,7 @@ macro_rules! implement_insert_scalar_into_sub_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column.as_ptr(),
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -195,7 +227,7 @@ macro_rules! implement_insert_scalar_into_sub_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column,
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -213,7 +245,7 @@ macro_rules! implement_insert_scalar_into_sub_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column,
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -231,15 +263,30 @@ macro_rules! implement_insert_scalar_into_sub_matrix_trait {
                 scalar_to_insert: &$value_type_scalar_to_insert,
                 mask_for_matrix_to_insert_into: &MatrixMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(
+                        matrix_to_insert_into,
+                        rows_to_insert_into,
+                        columns_to_insert_into,
+                    )?;
+                    check_size(
+                        "mask_for_matrix_to_insert_into",
+                        &self.sub_matrix_size(
+                            matrix_to_insert_into,
+                            rows_to_insert_into,
+                            columns_to_insert_into,
+                        )?,
+                        &mask_for_matrix_to_insert_into.size()?,
+                    )?;
+                }
+
                 let context = matrix_to_insert_into.context();
 
                 let number_of_rows_to_insert_into = rows_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.row_height()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.row_height()?)?;
 
                 let number_of_columns_to_insert_into = columns_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.column_width()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.column_width()?)?;
 
                 let rows_to_insert_into = rows_to_insert_into.to_graphblas_type()?;
                 let columns_to_insert_into = columns_to_insert_into.to_graphblas_type()?;
@@ -259,7 +306,7 @@ macro_rules! implement_insert_scalar_into_sub_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column.as_ptr(),
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                mask_for_matrix_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -277,7 +324,7 @@ macro_rules! implement_insert_scalar_into_sub_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column.as_ptr(),
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                mask_for_matrix_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -295,7 +342,7 @@ macro_rules! implement_insert_scalar_into_sub_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column,
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                mask_for_matrix_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -313,7 +360,7 @@ macro_rules! implement_insert_scalar_into_sub_matrix_trait {
                                 number_of_rows_to_insert_into,
                                 column,
                                 number_of_columns_to_insert_into,
-                                self.options,
+                                mask_for_matrix_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -342,7 +389,7 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator};
 
     use crate::util::ElementIndex;
     use crate::value_types::sparse_matrix::{
@@ -388,7 +435,8 @@ mod tests {
         let columns_to_insert: Vec<ElementIndex> = (0..6).collect();
         let columns_to_insert = ElementIndexSelector::Index(&columns_to_insert);
 
-        let insert_operator = InsertScalarIntoSubMatrix::new(&OperatorOptions::new_default(), None);
+        let insert_operator =
+            InsertScalarIntoSubMatrix::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         let scalar_to_insert: u8 = 8;
 
@@ -421,7 +469,7 @@ mod tests {
                 &rows_to_insert,
                 &columns_to_insert,
                 &scalar_to_insert,
-                &mask.into(),
+                &(&mask).into(),
             )
             .unwrap();
 
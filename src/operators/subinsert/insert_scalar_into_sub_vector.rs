@@ -3,19 +3,18 @@ use std::ptr;
 use std::marker::PhantomData;
 
 use crate::error::SparseLinearAlgebraError;
-use crate::operators::{
-    binary_operator::BinaryOperator, mask::VectorMask, options::OperatorOptions,
-};
+use crate::operators::validation::{check_indices, check_length};
+use crate::operators::{binary_operator::Accumulator, mask::VectorMask, options::OperatorOptions};
 
-use crate::util::{ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion};
+use crate::util::{ElementIndexSelector, ElementIndexSelectorGraphblasType};
 use crate::value_types::sparse_vector::SparseVector;
 use crate::value_types::value_type::{AsBoolean, ValueType};
 
 use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GxB_Vector_subassign_BOOL, GxB_Vector_subassign_FP32,
-    GxB_Vector_subassign_FP64, GxB_Vector_subassign_INT16, GxB_Vector_subassign_INT32,
-    GxB_Vector_subassign_INT64, GxB_Vector_subassign_INT8, GxB_Vector_subassign_UINT16,
-    GxB_Vector_subassign_UINT32, GxB_Vector_subassign_UINT64, GxB_Vector_subassign_UINT8,
+    GrB_BinaryOp, GxB_Vector_subassign_BOOL, GxB_Vector_subassign_FP32, GxB_Vector_subassign_FP64,
+    GxB_Vector_subassign_INT16, GxB_Vector_subassign_INT32, GxB_Vector_subassign_INT64,
+    GxB_Vector_subassign_INT8, GxB_Vector_subassign_UINT16, GxB_Vector_subassign_UINT32,
+    GxB_Vector_subassign_UINT64, GxB_Vector_subassign_UINT8,
 };
 
 // TODO: explicitly define how dupicates are handled
@@ -53,7 +52,7 @@ pub struct InsertScalarIntoSubVector<VectorToInsertInto: ValueType, ScalarToInse
     _scalar_to_insert: PhantomData<ScalarToInsert>,
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<VectorToInsertInto, ScalarToInsert>
@@ -64,24 +63,31 @@ where
 {
     pub fn new(
         options: &OperatorOptions,
-        accumulator: Option<
-            &dyn BinaryOperator<ScalarToInsert, VectorToInsertInto, VectorToInsertInto>,
-        >, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<ScalarToInsert, VectorToInsertInto, VectorToInsertInto>
+              + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _vector_to_insert_into: PhantomData,
             _scalar_to_insert: PhantomData,
         }
     }
+
+    fn check_arguments(
+        &self,
+        vector_to_insert_into: &SparseVector<VectorToInsertInto>,
+        indices_to_insert_into: &ElementIndexSelector,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        check_indices(
+            "indices_to_insert_into",
+            indices_to_insert_into,
+            vector_to_insert_into.length()?,
+        )
+    }
 }
 
 pub trait InsertScalarIntoSubVectorTrait<VectorToInsertInto, ScalarToInsert>
@@ -128,11 +134,14 @@ macro_rules! implement_insert_scalar_into_sub_vector_trait {
                 indices_to_insert_into: &ElementIndexSelector,
                 scalar_to_insert: &$value_type_scalar_to_insert,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(vector_to_insert_into, indices_to_insert_into)?;
+                }
+
                 let context = vector_to_insert_into.context();
 
                 let number_of_indices_to_insert_into = indices_to_insert_into
-                    .number_of_selected_elements(vector_to_insert_into.length()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(vector_to_insert_into.length()?)?;
 
                 let indices_to_insert_into = indices_to_insert_into.to_graphblas_type()?;
 
@@ -146,7 +155,7 @@ macro_rules! implement_insert_scalar_into_sub_vector_trait {
                                 *scalar_to_insert,
                                 index.as_ptr(),
                                 number_of_indices_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -160,7 +169,7 @@ macro_rules! implement_insert_scalar_into_sub_vector_trait {
                                 *scalar_to_insert,
                                 index,
                                 number_of_indices_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -177,11 +186,20 @@ macro_rules! implement_insert_scalar_into_sub_vector_trait {
                 scalar_to_insert: &$value_type_scalar_to_insert,
                 mask_for_vector_to_insert_into: &VectorMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(vector_to_insert_into, indices_to_insert_into)?;
+                    check_length(
+                        "mask_for_vector_to_insert_into",
+                        indices_to_insert_into
+                            .number_of_selected_elements(vector_to_insert_into.length()?)?,
+                        mask_for_vector_to_insert_into.length()?,
+                    )?;
+                }
+
                 let context = vector_to_insert_into.context();
 
                 let number_of_indices_to_insert_into = indices_to_insert_into
-                    .number_of_selected_elements(vector_to_insert_into.length()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(vector_to_insert_into.length()?)?;
 
                 let indices_to_insert_into = indices_to_insert_into.to_graphblas_type()?;
 
@@ -195,7 +213,7 @@ macro_rules! implement_insert_scalar_into_sub_vector_trait {
                                 *scalar_to_insert,
                                 index.as_ptr(),
                                 number_of_indices_to_insert_into,
-                                self.options,
+                                mask_for_vector_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -209,7 +227,7 @@ macro_rules! implement_insert_scalar_into_sub_vector_trait {
                                 *scalar_to_insert,
                                 index,
                                 number_of_indices_to_insert_into,
-                                self.options,
+                                mask_for_vector_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -238,7 +256,7 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator};
 
     use crate::util::ElementIndex;
     use crate::value_types::sparse_vector::{
@@ -282,7 +300,8 @@ mod tests {
         let indices_to_insert: Vec<ElementIndex> = (0..3).collect();
         let indices_to_insert = ElementIndexSelector::Index(&indices_to_insert);
 
-        let insert_operator = InsertScalarIntoSubVector::new(&OperatorOptions::new_default(), None);
+        let insert_operator =
+            InsertScalarIntoSubVector::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         let scalar_to_insert: u8 = 8;
 
@@ -309,7 +328,7 @@ mod tests {
                 &mut vector,
                 &indices_to_insert,
                 &scalar_to_insert,
-                &mask.into(),
+                &(&mask).into(),
             )
             .unwrap();
 
@@ -3,9 +3,8 @@ use std::ptr;
 use std::marker::PhantomData;
 
 use crate::error::SparseLinearAlgebraError;
-use crate::operators::{
-    binary_operator::BinaryOperator, mask::VectorMask, options::OperatorOptions,
-};
+use crate::operators::validation::{check_index, check_indices, check_length};
+use crate::operators::{binary_operator::Accumulator, mask::VectorMask, options::OperatorOptions};
 use crate::util::{
     ElementIndex, ElementIndexSelector, ElementIndexSelectorGraphblasType, IndexConversion,
 };
@@ -13,9 +12,7 @@ use crate::value_types::sparse_matrix::SparseMatrix;
 use crate::value_types::sparse_vector::SparseVector;
 use crate::value_types::value_type::{AsBoolean, ValueType};
 
-use crate::bindings_to_graphblas_implementation::{
-    GrB_BinaryOp, GrB_Descriptor, GxB_Row_subassign,
-};
+use crate::bindings_to_graphblas_implementation::{GrB_BinaryOp, GxB_Row_subassign};
 
 // TODO: explicitly define how dupicates are handled
 
@@ -52,7 +49,7 @@ pub struct InsertVectorIntoSubRow<MatrixToInsertInto: ValueType, VectorToInsert:
     _vector_to_insert: PhantomData<VectorToInsert>,
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 impl<MatrixToInsertInto, VectorToInsert> InsertVectorIntoSubRow<MatrixToInsertInto, VectorToInsert>
@@ -62,24 +59,40 @@ where
 {
     pub fn new(
         options: &OperatorOptions,
-        accumulator: Option<
-            &dyn BinaryOperator<VectorToInsert, MatrixToInsertInto, MatrixToInsertInto>,
-        >, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<VectorToInsert, MatrixToInsertInto, MatrixToInsertInto>
+              + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _matrix_to_insert_into: PhantomData,
             _vector_to_insert: PhantomData,
         }
     }
+
+    fn check_arguments(
+        &self,
+        matrix_to_insert_into: &SparseMatrix<MatrixToInsertInto>,
+        row_indices_to_insert_into: &ElementIndexSelector,
+        row_to_insert_into: &ElementIndex,
+        vector_to_insert: &SparseVector<VectorToInsert>,
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let size = matrix_to_insert_into.size()?;
+        check_index("row_to_insert_into", *row_to_insert_into, size.row_height())?;
+        check_indices(
+            "row_indices_to_insert_into",
+            row_indices_to_insert_into,
+            size.column_width(),
+        )?;
+        check_length(
+            "vector_to_insert",
+            row_indices_to_insert_into.number_of_selected_elements(size.column_width())?,
+            vector_to_insert.length()?,
+        )
+    }
 }
 
 pub trait InsertVectorIntoSubRowTrait<MatrixToInsertInto, VectorToInsert>
@@ -129,11 +142,19 @@ macro_rules! implement_insert_vector_into_sub_row_trait {
                 row_to_insert_into: &ElementIndex,
                 vector_to_insert: &SparseVector<$value_type_vector_to_insert>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(
+                        matrix_to_insert_into,
+                        row_indices_to_insert_into,
+                        row_to_insert_into,
+                        vector_to_insert,
+                    )?;
+                }
+
                 let context = matrix_to_insert_into.context();
 
                 let number_of_indices_to_insert_into = row_indices_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.row_height()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.column_width()?)?;
 
                 let indices_to_insert_into = row_indices_to_insert_into.to_graphblas_type()?;
                 let row_to_insert_into = row_to_insert_into.to_graphblas_index()?;
@@ -149,7 +170,7 @@ macro_rules! implement_insert_vector_into_sub_row_trait {
                                 row_to_insert_into,
                                 index.as_ptr(),
                                 number_of_indices_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -164,7 +185,7 @@ macro_rules! implement_insert_vector_into_sub_row_trait {
                                 row_to_insert_into,
                                 index,
                                 number_of_indices_to_insert_into,
-                                self.options,
+                                self.options.to_graphblas_descriptor(),
                             )
                         })?;
                     }
@@ -182,11 +203,24 @@ macro_rules! implement_insert_vector_into_sub_row_trait {
                 vector_to_insert: &SparseVector<$value_type_vector_to_insert>,
                 mask_for_row_to_insert_into: &VectorMask<MaskValueType, AsBool>,
             ) -> Result<(), SparseLinearAlgebraError> {
+                if self.options.validate_arguments() {
+                    self.check_arguments(
+                        matrix_to_insert_into,
+                        row_indices_to_insert_into,
+                        row_to_insert_into,
+                        vector_to_insert,
+                    )?;
+                    check_length(
+                        "mask_for_row_to_insert_into",
+                        vector_to_insert.length()?,
+                        mask_for_row_to_insert_into.length()?,
+                    )?;
+                }
+
                 let context = matrix_to_insert_into.context();
 
                 let number_of_indices_to_insert_into = row_indices_to_insert_into
-                    .number_of_selected_elements(matrix_to_insert_into.row_height()?)?
-                    .to_graphblas_index()?;
+                    .graphblas_number_of_indices(matrix_to_insert_into.column_width()?)?;
 
                 let indices_to_insert_into = row_indices_to_insert_into.to_graphblas_type()?;
                 let row_to_insert_into = row_to_insert_into.to_graphblas_index()?;
@@ -202,7 +236,7 @@ macro_rules! implement_insert_vector_into_sub_row_trait {
                                 row_to_insert_into,
                                 index.as_ptr(),
                                 number_of_indices_to_insert_into,
-                                self.options,
+                                mask_for_row_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -217,7 +251,7 @@ macro_rules! implement_insert_vector_into_sub_row_trait {
                                 row_to_insert_into,
                                 index,
                                 number_of_indices_to_insert_into,
-                                self.options,
+                                mask_for_row_to_insert_into.graphblas_descriptor(&self.options),
                             )
                         })?;
                     }
@@ -246,7 +280,7 @@ mod tests {
     use super::*;
 
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator};
 
     use crate::util::ElementIndex;
     use crate::value_types::sparse_matrix::{
@@ -307,7 +341,8 @@ mod tests {
         let indices_to_insert: Vec<ElementIndex> = (0..vector_to_insert_length).collect();
         let indices_to_insert = ElementIndexSelector::Index(&indices_to_insert);
 
-        let insert_operator = InsertVectorIntoSubRow::new(&OperatorOptions::new_default(), None);
+        let insert_operator =
+            InsertVectorIntoSubRow::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         let row_to_insert_into: ElementIndex = 2;
 
@@ -343,7 +378,7 @@ mod tests {
                 &indices_to_insert,
                 &row_to_insert_into,
                 &vector_to_insert,
-                &mask.into(),
+                &(&mask).into(),
             )
             .unwrap();
 
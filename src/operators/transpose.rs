@@ -1,13 +1,13 @@
 use std::marker::PhantomData;
 use std::ptr;
 
-use crate::bindings_to_graphblas_implementation::{GrB_BinaryOp, GrB_Descriptor, GrB_transpose};
+use crate::bindings_to_graphblas_implementation::{GrB_BinaryOp, GrB_transpose};
 use crate::error::SparseLinearAlgebraError;
-use crate::operators::{
-    binary_operator::BinaryOperator, mask::MatrixMask, options::OperatorOptions,
-};
+use crate::operators::options::size_of_first_argument;
+use crate::operators::validation::check_size;
+use crate::operators::{binary_operator::Accumulator, mask::MatrixMask, options::OperatorOptions};
 use crate::value_types::sparse_matrix::SparseMatrix;
-use crate::value_types::value_type::{AsBoolean, ValueType};
+use crate::value_types::value_type::{AsBoolean, BuiltInValueType, ValueType};
 
 #[derive(Debug, Clone)]
 pub struct MatrixTranspose<Applicant, Product>
@@ -19,7 +19,7 @@ where
     _product: PhantomData<Product>,
 
     accumulator: GrB_BinaryOp, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
-    options: GrB_Descriptor,
+    options: OperatorOptions,
 }
 
 // Implemented methods do not provide mutable access to GraphBLAS operators or options.
@@ -56,17 +56,13 @@ where
 {
     pub fn new(
         options: &OperatorOptions,
-        accumulator: Option<&dyn BinaryOperator<Product, Product, Product>>, // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
+        accumulator: &(impl Accumulator<Product, Product, Product> + ?Sized), // optional accum for Z=accum(C,T), determines how results are written into the result matrix C
     ) -> Self {
-        let accumulator_to_use;
-        match accumulator {
-            Some(accumulator) => accumulator_to_use = accumulator.graphblas_type(),
-            None => accumulator_to_use = ptr::null_mut(),
-        }
+        let accumulator_to_use = accumulator.accumulator_graphblas_type();
 
         Self {
             accumulator: accumulator_to_use,
-            options: options.to_graphblas_descriptor(),
+            options: options.clone(),
 
             _applicant: PhantomData,
             _product: PhantomData,
@@ -78,6 +74,14 @@ where
         matrix: &SparseMatrix<Applicant>,
         transpose: &mut SparseMatrix<Product>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_size(
+                "transpose",
+                &size_of_first_argument(matrix, &self.options)?.transpose(),
+                &transpose.size()?,
+            )?;
+        }
+
         let context = transpose.context();
 
         context.call(|| unsafe {
@@ -86,7 +90,7 @@ where
                 ptr::null_mut(),
                 self.accumulator,
                 matrix.graphblas_matrix(),
-                self.options,
+                self.options.to_graphblas_descriptor(),
             )
         })?;
 
@@ -99,6 +103,15 @@ where
         transpose: &mut SparseMatrix<Product>,
         mask: &MatrixMask<MaskValueType, AsBool>,
     ) -> Result<(), SparseLinearAlgebraError> {
+        if self.options.validate_arguments() {
+            check_size(
+                "transpose",
+                &size_of_first_argument(matrix, &self.options)?.transpose(),
+                &transpose.size()?,
+            )?;
+            check_size("mask", &transpose.size()?, &mask.size()?)?;
+        }
+
         let context = transpose.context();
 
         context.call(|| unsafe {
@@ -107,7 +120,7 @@ where
                 mask.graphblas_matrix(),
                 self.accumulator,
                 matrix.graphblas_matrix(),
-                self.options,
+                mask.graphblas_descriptor(&self.options),
             )
         })?;
 
@@ -115,11 +128,44 @@ where
     }
 }
 
+impl<Applicant, Product> MatrixTranspose<Applicant, Product>
+where
+    Applicant: ValueType,
+    Product: ValueType + BuiltInValueType<Product>,
+{
+    /// Like apply(), but allocates the transpose, sized by the argument and options
+    pub fn compute(
+        &self,
+        matrix: &SparseMatrix<Applicant>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut transpose = SparseMatrix::<Product>::new(
+            matrix.context_ref(),
+            &size_of_first_argument(matrix, &self.options)?.transpose(),
+        )?;
+        self.apply(matrix, &mut transpose)?;
+        Ok(transpose)
+    }
+
+    /// Like apply_with_mask(), but allocates the transpose, sized by the argument and options
+    pub fn compute_with_mask<MaskValueType: ValueType, AsBool: AsBoolean<MaskValueType>>(
+        &self,
+        matrix: &SparseMatrix<Applicant>,
+        mask: &MatrixMask<MaskValueType, AsBool>,
+    ) -> Result<SparseMatrix<Product>, SparseLinearAlgebraError> {
+        let mut transpose = SparseMatrix::<Product>::new(
+            matrix.context_ref(),
+            &size_of_first_argument(matrix, &self.options)?.transpose(),
+        )?;
+        self.apply_with_mask(matrix, &mut transpose, mask)?;
+        Ok(transpose)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::context::{Context, Mode};
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, NoAccumulator};
     use crate::value_types::sparse_matrix::{
         FromMatrixElementList, GetMatrixElementValue, MatrixElementList,
     };
@@ -145,7 +191,8 @@ mod tests {
 
         let mut matrix_transpose = SparseMatrix::<u8>::new(&context, &(2, 2).into()).unwrap();
 
-        let transpose_operator = MatrixTranspose::new(&OperatorOptions::new_default(), None);
+        let transpose_operator =
+            MatrixTranspose::new(&OperatorOptions::new_default(), &NoAccumulator);
 
         transpose_operator
             .apply(&matrix, &mut matrix_transpose)
@@ -168,4 +215,42 @@ mod tests {
             4
         );
     }
+
+    #[test]
+    fn test_compute_transpose() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let element_list =
+            MatrixElementList::<u8>::from_element_vector(vec![(0, 0, 1).into(), (0, 2, 2).into()]);
+
+        let matrix = SparseMatrix::<u8>::from_element_list(
+            &context,
+            &(1, 3).into(),
+            &element_list,
+            &First::<u8, u8, u8>::new(),
+        )
+        .unwrap();
+
+        let transpose_operator =
+            MatrixTranspose::<u8, u8>::new(&OperatorOptions::new_default(), &NoAccumulator);
+        let matrix_transpose = transpose_operator.compute(&matrix).unwrap();
+
+        assert_eq!(matrix_transpose.row_height().unwrap(), 3);
+        assert_eq!(matrix_transpose.column_width().unwrap(), 1);
+        assert_eq!(
+            matrix_transpose.get_element_value(&(2, 0).into()).unwrap(),
+            2
+        );
+
+        // Transposing the input cancels out the transpose
+        let transpose_operator = MatrixTranspose::<u8, u8>::new(
+            &OperatorOptions::new(false, true, false),
+            &NoAccumulator,
+        );
+        let matrix_copy = transpose_operator.compute(&matrix).unwrap();
+
+        assert_eq!(matrix_copy.row_height().unwrap(), 1);
+        assert_eq!(matrix_copy.column_width().unwrap(), 3);
+        assert_eq!(matrix_copy.get_element_value(&(0, 2).into()).unwrap(), 2);
+    }
 }
@@ -23,7 +23,7 @@ macro_rules! implement_unary_operator {
         }
 
         impl $operator_name<$value_type> {
-            pub fn new() -> Self {
+            pub const fn new() -> Self {
                 Self {
                     _value_type: PhantomData,
                 }
@@ -33,7 +33,7 @@ macro_rules! implement_unary_operator {
 }
 
 ///z = x
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct Identity<T: ValueType> {
     _value_type: PhantomData<T>,
 }
@@ -51,7 +51,7 @@ implement_unary_operator!(Identity, GrB_IDENTITY_FP32, f32);
 implement_unary_operator!(Identity, GrB_IDENTITY_FP64, f64);
 
 ///z = -x
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct AdditiveInverse<T: ValueType> {
     _value_type: PhantomData<T>,
 }
@@ -69,7 +69,7 @@ implement_unary_operator!(AdditiveInverse, GrB_AINV_FP32, f32);
 implement_unary_operator!(AdditiveInverse, GrB_AINV_FP64, f64);
 
 ///z = 1/x
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct MultiplicativeInverse<T: ValueType> {
     _value_type: PhantomData<T>,
 }
@@ -87,7 +87,7 @@ implement_unary_operator!(MultiplicativeInverse, GrB_MINV_FP32, f32);
 implement_unary_operator!(MultiplicativeInverse, GrB_MINV_FP64, f64);
 
 /// z = ! (x != 0)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct LogicalNegation<T: ValueType> {
     _value_type: PhantomData<T>,
 }
@@ -106,7 +106,7 @@ implement_unary_operator!(LogicalNegation, GxB_LNOT_FP64, f64);
 
 /// z = 1
 /// Only operators in non-zero elements
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct One<T: ValueType> {
     _value_type: PhantomData<T>,
 }
@@ -124,7 +124,7 @@ implement_unary_operator!(One, GxB_ONE_FP32, f32);
 implement_unary_operator!(One, GxB_ONE_FP64, f64);
 
 ///z = abs(x)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub struct AbsoluteValue<T: ValueType> {
     _value_type: PhantomData<T>,
 }
@@ -0,0 +1,177 @@
+use crate::error::{GraphBlasError, GraphBlasErrorType, SparseLinearAlgebraError};
+use crate::operators::options::{size_of_first_argument, size_of_second_argument, OperatorOptions};
+use crate::util::{ElementIndex, ElementIndexSelector};
+use crate::value_types::sparse_matrix::{Size, SparseMatrix};
+use crate::value_types::sparse_vector::SparseVector;
+use crate::value_types::value_type::ValueType;
+
+// Operators check their arguments with these functions before calling GraphBLAS,
+// unless validation is disabled in the OperatorOptions.
+// GraphBLAS only reports that some dimension does not match, the errors returned here name the argument.
+
+pub(crate) fn check_size(
+    argument_name: &str,
+    expected_size: &Size,
+    actual_size: &Size,
+) -> Result<(), SparseLinearAlgebraError> {
+    if expected_size == actual_size {
+        Ok(())
+    } else {
+        Err(GraphBlasError::new(
+            GraphBlasErrorType::DimensionMismatch,
+            format!(
+                "Size of {} does not match, expected: {:?}, found: {:?}",
+                argument_name, expected_size, actual_size
+            ),
+        )
+        .into())
+    }
+}
+
+pub(crate) fn check_length(
+    argument_name: &str,
+    expected_length: ElementIndex,
+    actual_length: ElementIndex,
+) -> Result<(), SparseLinearAlgebraError> {
+    if expected_length == actual_length {
+        Ok(())
+    } else {
+        Err(GraphBlasError::new(
+            GraphBlasErrorType::DimensionMismatch,
+            format!(
+                "Length of {} does not match, expected: {}, found: {}",
+                argument_name, expected_length, actual_length
+            ),
+        )
+        .into())
+    }
+}
+
+pub(crate) fn check_index(
+    argument_name: &str,
+    index: ElementIndex,
+    number_of_elements: ElementIndex,
+) -> Result<(), SparseLinearAlgebraError> {
+    if index < number_of_elements {
+        Ok(())
+    } else {
+        Err(GraphBlasError::new(
+            GraphBlasErrorType::IndexOutOfBounds,
+            format!(
+                "{} {} is out of bounds, the dimension has {} elements",
+                argument_name, index, number_of_elements
+            ),
+        )
+        .into())
+    }
+}
+
+pub(crate) fn check_indices(
+    argument_name: &str,
+    indices: &ElementIndexSelector,
+    number_of_elements: ElementIndex,
+) -> Result<(), SparseLinearAlgebraError> {
+    match indices {
+        ElementIndexSelector::Index(indices) => {
+            for index in indices.iter() {
+                check_index(argument_name, *index, number_of_elements)?;
+            }
+            Ok(())
+        }
+        ElementIndexSelector::All => Ok(()),
+        _ => match indices.largest_selected_index() {
+            Some(index) => check_index(argument_name, index, number_of_elements),
+            None => Ok(()),
+        },
+    }
+}
+
+/// The arguments of an element-wise operation must have the size of the product
+pub(crate) fn check_element_wise_matrix_arguments<
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType,
+>(
+    multiplier: &SparseMatrix<Multiplier>,
+    multiplicant: &SparseMatrix<Multiplicant>,
+    product: &SparseMatrix<Product>,
+    options: &OperatorOptions,
+) -> Result<(), SparseLinearAlgebraError> {
+    let product_size = product.size()?;
+    check_size(
+        "multiplier",
+        &product_size,
+        &size_of_first_argument(multiplier, options)?,
+    )?;
+    check_size(
+        "multiplicant",
+        &product_size,
+        &size_of_second_argument(multiplicant, options)?,
+    )
+}
+
+/// The arguments of an element-wise operation must have the length of the product
+pub(crate) fn check_element_wise_vector_arguments<
+    Multiplier: ValueType,
+    Multiplicant: ValueType,
+    Product: ValueType,
+>(
+    multiplier: &SparseVector<Multiplier>,
+    multiplicant: &SparseVector<Multiplicant>,
+    product: &SparseVector<Product>,
+) -> Result<(), SparseLinearAlgebraError> {
+    let product_length = product.length()?;
+    check_length("multiplier", product_length, multiplier.length()?)?;
+    check_length("multiplicant", product_length, multiplicant.length()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::LogicErrorType;
+
+    #[test]
+    fn test_check_size() {
+        assert!(check_size("product", &Size::new(2, 3), &Size::new(2, 3)).is_ok());
+
+        match check_size("product", &Size::new(2, 3), &Size::new(3, 2)) {
+            Err(SparseLinearAlgebraError::LogicError(error)) => {
+                assert_eq!(
+                    error.error_type(),
+                    LogicErrorType::GraphBlas(GraphBlasErrorType::DimensionMismatch)
+                );
+                assert!(error.explanation().contains("product"));
+                assert!(error
+                    .explanation()
+                    .contains(&format!("{:?}", Size::new(2, 3))));
+                assert!(error
+                    .explanation()
+                    .contains(&format!("{:?}", Size::new(3, 2))));
+            }
+            _ => panic!("Expected a dimension mismatch"),
+        }
+    }
+
+    #[test]
+    fn test_check_indices() {
+        let indices: Vec<ElementIndex> = vec![0, 4, 2];
+        assert!(check_indices("rows", &ElementIndexSelector::Index(&indices), 5).is_ok());
+        assert!(check_indices("rows", &ElementIndexSelector::All, 0).is_ok());
+
+        match check_indices("rows", &ElementIndexSelector::Index(&indices), 4) {
+            Err(SparseLinearAlgebraError::LogicError(error)) => {
+                assert_eq!(
+                    error.error_type(),
+                    LogicErrorType::GraphBlas(GraphBlasErrorType::IndexOutOfBounds)
+                );
+                assert!(error.explanation().contains("rows 4"));
+            }
+            _ => panic!("Expected an index out of bounds"),
+        }
+
+        assert!(check_indices("rows", &ElementIndexSelector::Range(2..5), 5).is_ok());
+        assert!(check_indices("rows", &ElementIndexSelector::Range(2..6), 5).is_err());
+        assert!(check_indices("rows", &ElementIndexSelector::Stride(0..6, 2), 5).is_ok());
+        assert!(check_indices("rows", &ElementIndexSelector::Backwards(6..6, 2), 5).is_ok());
+    }
+}
@@ -1,15 +1,34 @@
+use std::ops::Range;
+
 use crate::bindings_to_graphblas_implementation::{GrB_ALL, GrB_Index};
 use crate::error::{SparseLinearAlgebraError, SystemError};
 use crate::util::{ElementIndex, IndexConversion};
 
-#[derive(Debug)]
+// GxB_RANGE, GxB_STRIDE and GxB_BACKWARDS are C macros, missing from the generated bindings.
+// Passed as number of indices, they make GraphBLAS read the index array as [begin, end, inc],
+// with an inclusive end.
+const GXB_RANGE: GrB_Index = i64::MAX as GrB_Index;
+const GXB_STRIDE: GrB_Index = (i64::MAX - 1) as GrB_Index;
+const GXB_BACKWARDS: GrB_Index = (i64::MAX - 2) as GrB_Index;
+
+/// Selects the indices an operator reads or writes.
+///
+/// Range, Stride and Backwards select indices without allocating them.
+/// A step of zero selects nothing.
+#[derive(Debug, Clone)]
 pub enum ElementIndexSelector<'a> {
-    Index(&'a Vec<ElementIndex>),
+    Index(&'a [ElementIndex]),
     All,
+    /// The indices in the range, ascending
+    Range(Range<ElementIndex>),
+    /// Every step-th index in the range, ascending from the start of the range
+    Stride(Range<ElementIndex>, ElementIndex),
+    /// Every step-th index in the range, descending from the last index in the range
+    Backwards(Range<ElementIndex>, ElementIndex),
 }
 
 pub(crate) enum ElementIndexSelectorGraphblasType {
-    Index(Vec<GrB_Index>),
+    Index(Vec<GrB_Index>), // the indices, or [begin, end, inc] for ranges
     All(*const GrB_Index),
 }
 
@@ -17,21 +36,45 @@ impl<'a> ElementIndexSelector<'a> {
     pub(crate) fn to_graphblas_type(
         &self,
     ) -> Result<ElementIndexSelectorGraphblasType, SparseLinearAlgebraError> {
-        match self {
-            ElementIndexSelector::Index(columns) => {
-                let indices: Result<Vec<GrB_Index>, SystemError> = columns
-                    .into_iter()
-                    .map(|index| index.to_graphblas_index())
-                    .collect();
-
-                match indices {
-                    Ok(indices) => Ok(ElementIndexSelectorGraphblasType::Index(indices)),
-                    Err(error) => Err(error.into()),
-                }
-            }
+        let indices: Result<Vec<GrB_Index>, SystemError> = match self {
+            ElementIndexSelector::Index(indices) => indices
+                .iter()
+                .map(|index| index.to_graphblas_index())
+                .collect(),
             ElementIndexSelector::All => {
-                Ok(unsafe { ElementIndexSelectorGraphblasType::All(GrB_ALL) })
+                return Ok(unsafe { ElementIndexSelectorGraphblasType::All(GrB_ALL) })
             }
+            _ if self.selects_nothing() => Ok(Vec::new()),
+            ElementIndexSelector::Range(range) => {
+                to_graphblas_indices(&[range.start, range.end - 1])
+            }
+            ElementIndexSelector::Stride(range, step) => {
+                to_graphblas_indices(&[range.start, range.end - 1, *step])
+            }
+            ElementIndexSelector::Backwards(range, step) => {
+                to_graphblas_indices(&[range.end - 1, range.start, *step])
+            }
+        };
+
+        match indices {
+            Ok(indices) => Ok(ElementIndexSelectorGraphblasType::Index(indices)),
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// The number of indices to pass to GraphBLAS along with to_graphblas_type()
+    pub(crate) fn graphblas_number_of_indices(
+        &self,
+        number_elements_for_all: ElementIndex,
+    ) -> Result<GrB_Index, SparseLinearAlgebraError> {
+        match self {
+            ElementIndexSelector::Index(_) | ElementIndexSelector::All => Ok(self
+                .number_of_selected_elements(number_elements_for_all)?
+                .to_graphblas_index()?),
+            _ if self.selects_nothing() => Ok(0),
+            ElementIndexSelector::Range(_) => Ok(GXB_RANGE),
+            ElementIndexSelector::Stride(_, _) => Ok(GXB_STRIDE),
+            ElementIndexSelector::Backwards(_, _) => Ok(GXB_BACKWARDS),
         }
     }
 
@@ -42,6 +85,116 @@ impl<'a> ElementIndexSelector<'a> {
         match self {
             ElementIndexSelector::Index(indices) => Ok(indices.len()),
             ElementIndexSelector::All => Ok(number_elements_for_all),
+            ElementIndexSelector::Range(range) => Ok(range.len()),
+            ElementIndexSelector::Stride(range, step)
+            | ElementIndexSelector::Backwards(range, step) => {
+                if range.is_empty() || *step == 0 {
+                    Ok(0)
+                } else {
+                    Ok((range.end - 1 - range.start) / step + 1)
+                }
+            }
+        }
+    }
+
+    /// None if nothing is selected, or if all indices are selected
+    pub(crate) fn largest_selected_index(&self) -> Option<ElementIndex> {
+        match self {
+            ElementIndexSelector::Index(indices) => indices.iter().max().copied(),
+            ElementIndexSelector::All => None,
+            _ if self.selects_nothing() => None,
+            ElementIndexSelector::Range(range) | ElementIndexSelector::Backwards(range, _) => {
+                Some(range.end - 1)
+            }
+            ElementIndexSelector::Stride(range, step) => {
+                Some(range.start + (range.end - 1 - range.start) / step * step)
+            }
+        }
+    }
+
+    fn selects_nothing(&self) -> bool {
+        match self {
+            ElementIndexSelector::Index(indices) => indices.is_empty(),
+            ElementIndexSelector::All => false,
+            ElementIndexSelector::Range(range) => range.is_empty(),
+            ElementIndexSelector::Stride(range, step)
+            | ElementIndexSelector::Backwards(range, step) => range.is_empty() || *step == 0,
+        }
+    }
+}
+
+fn to_graphblas_indices(indices: &[ElementIndex]) -> Result<Vec<GrB_Index>, SystemError> {
+    indices
+        .iter()
+        .map(|index| index.to_graphblas_index())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_of_selected_elements() {
+        let indices: Vec<ElementIndex> = vec![3, 1];
+        assert_eq!(
+            ElementIndexSelector::Index(&indices)
+                .number_of_selected_elements(10)
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            ElementIndexSelector::All
+                .number_of_selected_elements(10)
+                .unwrap(),
+            10
+        );
+        assert_eq!(
+            ElementIndexSelector::Range(2..5)
+                .number_of_selected_elements(10)
+                .unwrap(),
+            3
+        );
+        // 0, 3, 6, 9
+        let stride = ElementIndexSelector::Stride(0..10, 3);
+        assert_eq!(stride.number_of_selected_elements(10).unwrap(), 4);
+        assert_eq!(stride.largest_selected_index(), Some(9));
+        // 9, 6, 3
+        let backwards = ElementIndexSelector::Backwards(1..10, 3);
+        assert_eq!(backwards.number_of_selected_elements(10).unwrap(), 3);
+        assert_eq!(backwards.largest_selected_index(), Some(9));
+
+        assert_eq!(
+            ElementIndexSelector::Stride(0..10, 0)
+                .number_of_selected_elements(10)
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            ElementIndexSelector::Range(4..4).largest_selected_index(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_range_to_graphblas_type() {
+        let backwards = ElementIndexSelector::Backwards(1..10, 3);
+        match backwards.to_graphblas_type().unwrap() {
+            ElementIndexSelectorGraphblasType::Index(indices) => {
+                assert_eq!(indices, vec![9, 1, 3])
+            }
+            _ => panic!("Expected a range descriptor"),
+        }
+        assert_eq!(
+            backwards.graphblas_number_of_indices(10).unwrap(),
+            GXB_BACKWARDS
+        );
+
+        let empty_range = ElementIndexSelector::Range(0..0);
+        match empty_range.to_graphblas_type().unwrap() {
+            ElementIndexSelectorGraphblasType::Index(indices) => assert!(indices.is_empty()),
+            _ => panic!("Expected no indices"),
         }
+        assert_eq!(empty_range.graphblas_number_of_indices(10).unwrap(), 0);
     }
 }
@@ -0,0 +1,71 @@
+use std::ffi::c_void;
+use std::mem::size_of;
+
+use crate::bindings_to_graphblas_implementation::{free, malloc, GrB_Index};
+use crate::error::{
+    GraphBlasError, GraphBlasErrorType, SparseLinearAlgebraError, SystemError, SystemErrorType,
+};
+use crate::util::ElementIndex;
+
+/// Copies values into memory allocated by the C allocator,
+/// such that ownership can be handed over to GraphBLAS, for example by GxB_*_import_*.
+/// Returns the pointer to the array, and the size of the array in bytes.
+pub(crate) fn copy_to_graphblas_owned_array<T: Copy>(
+    values: &[T],
+) -> Result<(*mut T, u64), SparseLinearAlgebraError> {
+    let size_in_bytes = values.len() * size_of::<T>();
+    // malloc(0) may return a null pointer, which GraphBLAS would reject
+    let array = unsafe { malloc(size_in_bytes.max(1) as u64) } as *mut T;
+    if array.is_null() {
+        return Err(GraphBlasError::new(
+            GraphBlasErrorType::OutOfMemory,
+            format!("Unable to allocate {} bytes", size_in_bytes),
+        )
+        .into());
+    }
+    unsafe {
+        std::ptr::copy_nonoverlapping(values.as_ptr(), array, values.len());
+    }
+    Ok((array, size_in_bytes as u64))
+}
+
+/// Copies an array owned by GraphBLAS, for example returned by GxB_*_export_*, into a Vec.
+pub(crate) unsafe fn copy_from_graphblas_owned_array<T: Copy>(
+    array: *const T,
+    length: usize,
+) -> Vec<T> {
+    if length == 0 || array.is_null() {
+        return Vec::new();
+    }
+    std::slice::from_raw_parts(array, length).to_vec()
+}
+
+/// Frees an array whose ownership was handed over by GraphBLAS.
+/// Null pointers are ignored.
+pub(crate) unsafe fn free_graphblas_owned_array<T>(array: *mut T) {
+    if !array.is_null() {
+        free(array as *mut c_void);
+    }
+}
+
+/// Exported GrB_Index arrays can only be borrowed as ElementIndex slices if both types have the same width.
+pub(crate) fn check_element_index_width() -> Result<(), SparseLinearAlgebraError> {
+    if size_of::<ElementIndex>() != size_of::<GrB_Index>() {
+        return Err(SystemError::new(
+            SystemErrorType::Other,
+            String::from("Exported indices can only be borrowed when ElementIndex and GrB_Index have equal width"),
+            None,
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Borrows an array owned by GraphBLAS as a slice.
+pub(crate) unsafe fn graphblas_owned_slice<'b, U>(array: *const U, length: usize) -> &'b [U] {
+    if length == 0 || array.is_null() {
+        &[]
+    } else {
+        std::slice::from_raw_parts(array, length)
+    }
+}
@@ -1,6 +1,11 @@
 mod element_index;
 mod element_index_selector;
+mod graphblas_owned_array;
 
 pub use element_index::{ElementIndex, IndexConversion};
 pub use element_index_selector::ElementIndexSelector;
 pub(crate) use element_index_selector::ElementIndexSelectorGraphblasType;
+pub(crate) use graphblas_owned_array::{
+    check_element_index_width, copy_from_graphblas_owned_array, copy_to_graphblas_owned_array,
+    free_graphblas_owned_array, graphblas_owned_slice,
+};
@@ -0,0 +1,329 @@
+//! Operator overloading for common arithmetic on matrices and vectors of built-in numeric types.
+//!
+//! | expression | operation                                     |
+//! |------------|-----------------------------------------------|
+//! | `&a + &b`  | element-wise addition with the Plus monoid    |
+//! | `&a & &b`  | element-wise multiplication with the Times monoid |
+//! | `&a * &b`  | multiplication with the PlusTimes semiring    |
+//! | `-&a`      | additive inverse of every stored element      |
+//! | `&a * x`   | multiplication of every stored element by x   |
+//!
+//! Every operator returns a newly allocated container of the right size, wrapped in a Result.
+//! Arguments with incompatible dimensions result in a DimensionMismatch error.
+
+use std::ops::{Add, BitAnd, Mul, Neg};
+
+use crate::error::SparseLinearAlgebraError;
+use crate::operators::apply::{
+    BinaryOperatorApplier, BinaryOperatorApplierTrait, UnaryOperatorApplier,
+    UnaryOperatorApplierTrait,
+};
+use crate::operators::binary_operator::{NoAccumulator, Times as TimesBinaryOperator};
+use crate::operators::element_wise_addition::{
+    ElementWiseMatrixAdditionMonoidOperator, ElementWiseVectorAdditionMonoidOperator,
+};
+use crate::operators::element_wise_multiplication::{
+    ElementWiseMatrixMultiplicationMonoidOperator, ElementWiseVectorMultiplicationMonoidOperator,
+};
+use crate::operators::monoid::{Plus, Times};
+use crate::operators::multiplication::{
+    MatrixMultiplicationOperator, MatrixVectorMultiplicationOperator,
+    VectorMatrixMultiplicationOperator,
+};
+use crate::operators::options::OperatorOptions;
+use crate::operators::semiring::PlusTimes;
+use crate::operators::unary_operator::AdditiveInverse;
+
+use super::sparse_matrix::{Size, SparseMatrix};
+use super::sparse_vector::SparseVector;
+
+macro_rules! implement_arithmetic {
+    ($value_type:ty) => {
+        impl Add for &SparseMatrix<$value_type> {
+            type Output = Result<SparseMatrix<$value_type>, SparseLinearAlgebraError>;
+
+            fn add(self, other: Self) -> Self::Output {
+                let mut product =
+                    SparseMatrix::<$value_type>::new(self.context_ref(), &self.size()?)?;
+                ElementWiseMatrixAdditionMonoidOperator::<$value_type>::new(
+                    &Plus::<$value_type>::new(),
+                    &OperatorOptions::new_default(),
+                    &NoAccumulator,
+                )
+                .apply(self, other, &mut product)?;
+                Ok(product)
+            }
+        }
+
+        impl Add for &SparseVector<$value_type> {
+            type Output = Result<SparseVector<$value_type>, SparseLinearAlgebraError>;
+
+            fn add(self, other: Self) -> Self::Output {
+                let mut product =
+                    SparseVector::<$value_type>::new(self.context_ref(), &self.length()?)?;
+                ElementWiseVectorAdditionMonoidOperator::<$value_type>::new(
+                    &Plus::<$value_type>::new(),
+                    &OperatorOptions::new_default(),
+                    &NoAccumulator,
+                )
+                .apply(self, other, &mut product)?;
+                Ok(product)
+            }
+        }
+
+        impl BitAnd for &SparseMatrix<$value_type> {
+            type Output = Result<SparseMatrix<$value_type>, SparseLinearAlgebraError>;
+
+            fn bitand(self, other: Self) -> Self::Output {
+                let mut product =
+                    SparseMatrix::<$value_type>::new(self.context_ref(), &self.size()?)?;
+                ElementWiseMatrixMultiplicationMonoidOperator::<$value_type>::new(
+                    &Times::<$value_type>::new(),
+                    &OperatorOptions::new_default(),
+                    &NoAccumulator,
+                )
+                .apply(self, other, &mut product)?;
+                Ok(product)
+            }
+        }
+
+        impl BitAnd for &SparseVector<$value_type> {
+            type Output = Result<SparseVector<$value_type>, SparseLinearAlgebraError>;
+
+            fn bitand(self, other: Self) -> Self::Output {
+                let mut product =
+                    SparseVector::<$value_type>::new(self.context_ref(), &self.length()?)?;
+                ElementWiseVectorMultiplicationMonoidOperator::<$value_type>::new(
+                    &Times::<$value_type>::new(),
+                    &OperatorOptions::new_default(),
+                    &NoAccumulator,
+                )
+                .apply(self, other, &mut product)?;
+                Ok(product)
+            }
+        }
+
+        impl Mul for &SparseMatrix<$value_type> {
+            type Output = Result<SparseMatrix<$value_type>, SparseLinearAlgebraError>;
+
+            fn mul(self, other: Self) -> Self::Output {
+                let size: Size = (self.row_height()?, other.column_width()?).into();
+                let mut product = SparseMatrix::<$value_type>::new(self.context_ref(), &size)?;
+                MatrixMultiplicationOperator::<$value_type, $value_type, $value_type>::new(
+                    &PlusTimes::<$value_type, $value_type, $value_type>::new(),
+                    &OperatorOptions::new_default(),
+                    &NoAccumulator,
+                )
+                .apply(self, other, &mut product)?;
+                Ok(product)
+            }
+        }
+
+        impl Mul<&SparseVector<$value_type>> for &SparseMatrix<$value_type> {
+            type Output = Result<SparseVector<$value_type>, SparseLinearAlgebraError>;
+
+            fn mul(self, other: &SparseVector<$value_type>) -> Self::Output {
+                let mut product =
+                    SparseVector::<$value_type>::new(self.context_ref(), &self.row_height()?)?;
+                MatrixVectorMultiplicationOperator::<$value_type, $value_type, $value_type>::new(
+                    &PlusTimes::<$value_type, $value_type, $value_type>::new(),
+                    &OperatorOptions::new_default(),
+                    &NoAccumulator,
+                )
+                .apply(self, other, &mut product)?;
+                Ok(product)
+            }
+        }
+
+        impl Mul<&SparseMatrix<$value_type>> for &SparseVector<$value_type> {
+            type Output = Result<SparseVector<$value_type>, SparseLinearAlgebraError>;
+
+            fn mul(self, other: &SparseMatrix<$value_type>) -> Self::Output {
+                let mut product =
+                    SparseVector::<$value_type>::new(self.context_ref(), &other.column_width()?)?;
+                VectorMatrixMultiplicationOperator::<$value_type, $value_type, $value_type>::new(
+                    &PlusTimes::<$value_type, $value_type, $value_type>::new(),
+                    &OperatorOptions::new_default(),
+                    &NoAccumulator,
+                )
+                .apply(self, other, &mut product)?;
+                Ok(product)
+            }
+        }
+
+        impl Mul<$value_type> for &SparseMatrix<$value_type> {
+            type Output = Result<SparseMatrix<$value_type>, SparseLinearAlgebraError>;
+
+            fn mul(self, scalar: $value_type) -> Self::Output {
+                let mut product =
+                    SparseMatrix::<$value_type>::new(self.context_ref(), &self.size()?)?;
+                BinaryOperatorApplier::<$value_type, $value_type, $value_type>::new(
+                    &TimesBinaryOperator::<$value_type, $value_type, $value_type>::new(),
+                    &OperatorOptions::new_default(),
+                    &NoAccumulator,
+                )
+                .apply_with_matrix_as_first_argument(self, &scalar, &mut product)?;
+                Ok(product)
+            }
+        }
+
+        impl Mul<$value_type> for &SparseVector<$value_type> {
+            type Output = Result<SparseVector<$value_type>, SparseLinearAlgebraError>;
+
+            fn mul(self, scalar: $value_type) -> Self::Output {
+                let mut product =
+                    SparseVector::<$value_type>::new(self.context_ref(), &self.length()?)?;
+                BinaryOperatorApplier::<$value_type, $value_type, $value_type>::new(
+                    &TimesBinaryOperator::<$value_type, $value_type, $value_type>::new(),
+                    &OperatorOptions::new_default(),
+                    &NoAccumulator,
+                )
+                .apply_with_vector_as_first_argument(self, &scalar, &mut product)?;
+                Ok(product)
+            }
+        }
+
+        impl Neg for &SparseMatrix<$value_type> {
+            type Output = Result<SparseMatrix<$value_type>, SparseLinearAlgebraError>;
+
+            fn neg(self) -> Self::Output {
+                let mut product =
+                    SparseMatrix::<$value_type>::new(self.context_ref(), &self.size()?)?;
+                UnaryOperatorApplier::<$value_type>::new(
+                    &AdditiveInverse::<$value_type>::new(),
+                    &OperatorOptions::new_default(),
+                    &NoAccumulator,
+                )
+                .apply_to_matrix(self, &mut product)?;
+                Ok(product)
+            }
+        }
+
+        impl Neg for &SparseVector<$value_type> {
+            type Output = Result<SparseVector<$value_type>, SparseLinearAlgebraError>;
+
+            fn neg(self) -> Self::Output {
+                let mut product =
+                    SparseVector::<$value_type>::new(self.context_ref(), &self.length()?)?;
+                UnaryOperatorApplier::<$value_type>::new(
+                    &AdditiveInverse::<$value_type>::new(),
+                    &OperatorOptions::new_default(),
+                    &NoAccumulator,
+                )
+                .apply_to_vector(self, &mut product)?;
+                Ok(product)
+            }
+        }
+    };
+}
+
+implement_arithmetic!(i8);
+implement_arithmetic!(i16);
+implement_arithmetic!(i32);
+implement_arithmetic!(i64);
+implement_arithmetic!(u8);
+implement_arithmetic!(u16);
+implement_arithmetic!(u32);
+implement_arithmetic!(u64);
+implement_arithmetic!(f32);
+implement_arithmetic!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::context::{Context, Mode};
+    use crate::error::{GraphBlasErrorType, LogicErrorType, SparseLinearAlgebraErrorType};
+    use crate::operators::binary_operator::First;
+    use crate::value_types::sparse_matrix::{
+        FromMatrixElementList, GetMatrixElementValue, MatrixElementList,
+    };
+    use crate::value_types::sparse_vector::{
+        FromVectorElementList, GetVectorElementValue, VectorElementList,
+    };
+
+    #[test]
+    fn test_matrix_arithmetic() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let size: Size = (2, 2).into();
+        let a = SparseMatrix::<f32>::from_element_list(
+            &context,
+            &size,
+            &MatrixElementList::<f32>::from_element_vector(vec![
+                (0, 0, 1.0).into(),
+                (1, 0, 2.0).into(),
+                (0, 1, 3.0).into(),
+                (1, 1, 4.0).into(),
+            ]),
+            &First::<f32, f32, f32>::new(),
+        )
+        .unwrap();
+        let b = SparseMatrix::<f32>::from_element_list(
+            &context,
+            &size,
+            &MatrixElementList::<f32>::from_element_vector(vec![
+                (0, 0, 5.0).into(),
+                (1, 0, 6.0).into(),
+                (0, 1, 7.0).into(),
+            ]),
+            &First::<f32, f32, f32>::new(),
+        )
+        .unwrap();
+
+        let sum = (&a + &b).unwrap();
+        assert_eq!(sum.get_element_value(&(0, 0).into()).unwrap(), 6.);
+        assert_eq!(sum.get_element_value(&(1, 1).into()).unwrap(), 4.);
+
+        let element_wise_product = (&a & &b).unwrap();
+        assert_eq!(element_wise_product.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(
+            element_wise_product
+                .get_element_value(&(1, 0).into())
+                .unwrap(),
+            12.
+        );
+
+        let product = (&a * &b).unwrap();
+        assert_eq!(product.get_element_value(&(0, 0).into()).unwrap(), 23.);
+        assert_eq!(product.get_element_value(&(1, 0).into()).unwrap(), 34.);
+        assert_eq!(product.get_element_value(&(0, 1).into()).unwrap(), 7.);
+        assert_eq!(product.get_element_value(&(1, 1).into()).unwrap(), 14.);
+
+        let negation = (-&a).unwrap();
+        assert_eq!(negation.get_element_value(&(1, 1).into()).unwrap(), -4.);
+
+        let scaled = (&a * 2.0).unwrap();
+        assert_eq!(scaled.get_element_value(&(1, 0).into()).unwrap(), 4.);
+
+        let v = SparseVector::<f32>::from_element_list(
+            &context,
+            &2,
+            &VectorElementList::<f32>::from_element_vector(vec![(0, 1.0).into(), (1, 1.0).into()]),
+            &First::<f32, f32, f32>::new(),
+        )
+        .unwrap();
+        let matrix_vector_product = (&a * &v).unwrap();
+        assert_eq!(matrix_vector_product.get_element_value(&0).unwrap(), 4.);
+        assert_eq!(matrix_vector_product.get_element_value(&1).unwrap(), 6.);
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let a = SparseMatrix::<i32>::new(&context, &(2, 3).into()).unwrap();
+        let b = SparseMatrix::<i32>::new(&context, &(2, 3).into()).unwrap();
+
+        assert!((&a + &b).is_ok());
+        match &a * &b {
+            Err(error) => assert_eq!(
+                error.error_type(),
+                SparseLinearAlgebraErrorType::LogicErrorType(LogicErrorType::GraphBlas(
+                    GraphBlasErrorType::DimensionMismatch
+                ))
+            ),
+            Ok(_) => panic!("expected a dimension mismatch"),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+mod arithmetic;
 pub mod sparse_matrix;
 pub mod sparse_scalar;
 pub mod sparse_vector;
@@ -0,0 +1,379 @@
+use std::ptr;
+
+use crate::bindings_to_graphblas_implementation::{
+    GrB_Index, GrB_Type, GxB_Matrix_export_CSC, GxB_Matrix_export_CSR, GxB_Matrix_import_CSC,
+    GxB_Matrix_import_CSR,
+};
+use crate::error::SparseLinearAlgebraError;
+use crate::util::{
+    check_element_index_width, free_graphblas_owned_array, graphblas_owned_slice, ElementIndex,
+};
+use crate::value_types::value_type::{BuiltInValueType, ValueType};
+
+use super::sparse_matrix::SparseMatrix;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompressedOrientation {
+    /// Compressed sparse row (CSR)
+    RowMajor,
+    /// Compressed sparse column (CSC)
+    ColumnMajor,
+}
+
+/// Read-only view on the stored elements of a SparseMatrix, exported from GraphBLAS without copying.
+/// The matrix is imported back into GraphBLAS when the view is restored or dropped.
+pub struct CompressedSparseMatrixView<'a, T: ValueType> {
+    matrix: &'a mut SparseMatrix<T>,
+    orientation: CompressedOrientation,
+    is_restored: bool,
+
+    value_type: GrB_Type,
+    row_height: GrB_Index,
+    column_width: GrB_Index,
+    pointers: *mut GrB_Index,
+    indices: *mut GrB_Index,
+    values: *mut T,
+    pointers_size: GrB_Index,
+    indices_size: GrB_Index,
+    values_size: GrB_Index,
+}
+
+impl<T: ValueType + BuiltInValueType<T>> SparseMatrix<T> {
+    /// Exports the matrix in compressed sparse row format.
+    /// The matrix cannot be used until the view is restored or dropped.
+    pub fn row_major_view(
+        &mut self,
+    ) -> Result<CompressedSparseMatrixView<'_, T>, SparseLinearAlgebraError> {
+        CompressedSparseMatrixView::export(self, CompressedOrientation::RowMajor)
+    }
+
+    /// Exports the matrix in compressed sparse column format.
+    /// The matrix cannot be used until the view is restored or dropped.
+    pub fn column_major_view(
+        &mut self,
+    ) -> Result<CompressedSparseMatrixView<'_, T>, SparseLinearAlgebraError> {
+        CompressedSparseMatrixView::export(self, CompressedOrientation::ColumnMajor)
+    }
+}
+
+impl<'a, T: ValueType + BuiltInValueType<T>> CompressedSparseMatrixView<'a, T> {
+    fn export(
+        matrix: &'a mut SparseMatrix<T>,
+        orientation: CompressedOrientation,
+    ) -> Result<Self, SparseLinearAlgebraError> {
+        check_element_index_width()?;
+
+        let context = matrix.context();
+        let mut view = CompressedSparseMatrixView {
+            matrix,
+            orientation,
+            is_restored: true,
+            value_type: ptr::null_mut(),
+            row_height: 0,
+            column_width: 0,
+            pointers: ptr::null_mut(),
+            indices: ptr::null_mut(),
+            values: ptr::null_mut(),
+            pointers_size: 0,
+            indices_size: 0,
+            values_size: 0,
+        };
+
+        // Passing a null pointer for jumbled requires GraphBLAS to return sorted indices
+        context.call(|| unsafe {
+            let export_function = match orientation {
+                CompressedOrientation::RowMajor => GxB_Matrix_export_CSR,
+                CompressedOrientation::ColumnMajor => GxB_Matrix_export_CSC,
+            };
+            export_function(
+                view.matrix.graphblas_matrix_mut_ref(),
+                &mut view.value_type,
+                &mut view.row_height,
+                &mut view.column_width,
+                &mut view.pointers,
+                &mut view.indices,
+                &mut view.values as *mut *mut T as *mut *mut std::ffi::c_void,
+                &mut view.pointers_size,
+                &mut view.indices_size,
+                &mut view.values_size,
+                ptr::null_mut(),
+                ptr::null_mut(),
+            )
+        })?;
+        view.is_restored = false;
+        Ok(view)
+    }
+}
+
+impl<'a, T: ValueType> CompressedSparseMatrixView<'a, T> {
+    /// Imports the exported arrays back into the matrix.
+    pub fn restore(mut self) -> Result<(), SparseLinearAlgebraError> {
+        self.import()
+    }
+
+    fn import(&mut self) -> Result<(), SparseLinearAlgebraError> {
+        if self.is_restored {
+            return Ok(());
+        }
+        self.is_restored = true;
+
+        let context = self.matrix.context();
+        let orientation = self.orientation;
+        let value_type = self.value_type;
+        let row_height = self.row_height;
+        let column_width = self.column_width;
+        let pointers_size = self.pointers_size;
+        let indices_size = self.indices_size;
+        let values_size = self.values_size;
+        let mut pointers = self.pointers;
+        let mut indices = self.indices;
+        let mut values = self.values;
+
+        let result = context.call(|| unsafe {
+            let import_function = match orientation {
+                CompressedOrientation::RowMajor => GxB_Matrix_import_CSR,
+                CompressedOrientation::ColumnMajor => GxB_Matrix_import_CSC,
+            };
+            import_function(
+                self.matrix.graphblas_matrix_mut_ref(),
+                value_type,
+                row_height,
+                column_width,
+                &mut pointers,
+                &mut indices,
+                &mut values as *mut *mut T as *mut *mut std::ffi::c_void,
+                pointers_size,
+                indices_size,
+                values_size,
+                false,
+                ptr::null_mut(),
+            )
+        });
+
+        // GraphBLAS only takes ownership of the arrays on success
+        if result.is_err() {
+            unsafe {
+                free_graphblas_owned_array(pointers);
+                free_graphblas_owned_array(indices);
+                free_graphblas_owned_array(values);
+            }
+        }
+        self.pointers = ptr::null_mut();
+        self.indices = ptr::null_mut();
+        self.values = ptr::null_mut();
+        result?;
+        Ok(())
+    }
+
+    pub fn orientation(&self) -> CompressedOrientation {
+        self.orientation
+    }
+
+    pub fn row_height(&self) -> ElementIndex {
+        self.row_height as ElementIndex
+    }
+
+    pub fn column_width(&self) -> ElementIndex {
+        self.column_width as ElementIndex
+    }
+
+    fn number_of_compressed_vectors(&self) -> ElementIndex {
+        match self.orientation {
+            CompressedOrientation::RowMajor => self.row_height(),
+            CompressedOrientation::ColumnMajor => self.column_width(),
+        }
+    }
+
+    fn pointers_ref(&self) -> &[ElementIndex] {
+        unsafe {
+            graphblas_owned_slice(
+                self.pointers as *const ElementIndex,
+                self.number_of_compressed_vectors() + 1,
+            )
+        }
+    }
+
+    pub fn number_of_stored_elements(&self) -> ElementIndex {
+        match self.pointers_ref().last() {
+            Some(number_of_stored_elements) => *number_of_stored_elements,
+            None => 0,
+        }
+    }
+
+    /// Column indices for a row-major view, row indices for a column-major view.
+    pub fn indices_ref(&self) -> &[ElementIndex] {
+        unsafe {
+            graphblas_owned_slice(
+                self.indices as *const ElementIndex,
+                self.number_of_stored_elements(),
+            )
+        }
+    }
+
+    pub fn values_ref(&self) -> &[T] {
+        unsafe { graphblas_owned_slice(self.values, self.number_of_stored_elements()) }
+    }
+
+    /// Iterates over (row_index, column_index, &value) of all stored elements,
+    /// in the order of the view.
+    pub fn iter(&self) -> StoredMatrixElements<'_, T> {
+        StoredMatrixElements {
+            vectors: self.compressed_vectors(),
+            current_vector: None,
+            position_in_vector: 0,
+        }
+    }
+
+    /// For a row-major view, yields (row_index, column_indices, values) for each non-empty row.
+    /// For a column-major view, yields (column_index, row_indices, values) for each non-empty column.
+    pub fn compressed_vectors(&self) -> CompressedVectors<'_, T> {
+        CompressedVectors {
+            orientation: self.orientation,
+            pointers: self.pointers_ref(),
+            indices: self.indices_ref(),
+            values: self.values_ref(),
+            next_vector: 0,
+        }
+    }
+}
+
+impl<'a, T: ValueType> Drop for CompressedSparseMatrixView<'a, T> {
+    fn drop(&mut self) {
+        let _ = self.import();
+    }
+}
+
+pub struct CompressedVectors<'b, T> {
+    orientation: CompressedOrientation,
+    pointers: &'b [ElementIndex],
+    indices: &'b [ElementIndex],
+    values: &'b [T],
+    next_vector: ElementIndex,
+}
+
+impl<'b, T> CompressedVectors<'b, T> {
+    pub fn orientation(&self) -> CompressedOrientation {
+        self.orientation
+    }
+}
+
+impl<'b, T> Iterator for CompressedVectors<'b, T> {
+    type Item = (ElementIndex, &'b [ElementIndex], &'b [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next_vector + 1 < self.pointers.len() {
+            let vector_index = self.next_vector;
+            self.next_vector += 1;
+
+            let start = self.pointers[vector_index];
+            let end = self.pointers[vector_index + 1];
+            if start < end {
+                return Some((
+                    vector_index,
+                    &self.indices[start..end],
+                    &self.values[start..end],
+                ));
+            }
+        }
+        None
+    }
+}
+
+pub struct StoredMatrixElements<'b, T> {
+    vectors: CompressedVectors<'b, T>,
+    current_vector: Option<(ElementIndex, &'b [ElementIndex], &'b [T])>,
+    position_in_vector: usize,
+}
+
+impl<'b, T> Iterator for StoredMatrixElements<'b, T> {
+    /// (row_index, column_index, &value)
+    type Item = (ElementIndex, ElementIndex, &'b T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((vector_index, indices, values)) = self.current_vector {
+                if self.position_in_vector < indices.len() {
+                    let index = indices[self.position_in_vector];
+                    let value = &values[self.position_in_vector];
+                    self.position_in_vector += 1;
+                    return match self.vectors.orientation() {
+                        CompressedOrientation::RowMajor => Some((vector_index, index, value)),
+                        CompressedOrientation::ColumnMajor => Some((index, vector_index, value)),
+                    };
+                }
+            }
+            self.current_vector = Some(self.vectors.next()?);
+            self.position_in_vector = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::context::{Context, Mode};
+    use crate::operators::binary_operator::First;
+    use crate::value_types::sparse_matrix::Coordinate;
+    use crate::value_types::sparse_matrix::{
+        FromMatrixElementList, GetMatrixElementValue, MatrixElementList, Size,
+    };
+
+    fn test_matrix() -> SparseMatrix<i32> {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+        let element_list = MatrixElementList::<i32>::from_element_vector(vec![
+            (0, 1, 1).into(),
+            (2, 0, 2).into(),
+            (2, 3, 3).into(),
+            (0, 2, 4).into(),
+        ]);
+        SparseMatrix::<i32>::from_element_list(
+            &context,
+            &Size::new(3, 4),
+            &element_list,
+            &First::<i32, i32, i32>::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn iterate_row_major() {
+        let mut matrix = test_matrix();
+        {
+            let view = matrix.row_major_view().unwrap();
+            let elements: Vec<(usize, usize, i32)> = view
+                .iter()
+                .map(|(row, column, value)| (row, column, *value))
+                .collect();
+            assert_eq!(elements, vec![(0, 1, 1), (0, 2, 4), (2, 0, 2), (2, 3, 3)]);
+
+            let rows: Vec<(usize, Vec<usize>, Vec<i32>)> = view
+                .compressed_vectors()
+                .map(|(row, columns, values)| (row, columns.to_vec(), values.to_vec()))
+                .collect();
+            assert_eq!(
+                rows,
+                vec![(0, vec![1, 2], vec![1, 4]), (2, vec![0, 3], vec![2, 3])]
+            );
+        }
+
+        // the matrix is restored when the view is dropped
+        assert_eq!(matrix.number_of_stored_elements().unwrap(), 4);
+        assert_eq!(matrix.get_element_value(&Coordinate::new(2, 3)).unwrap(), 3);
+    }
+
+    #[test]
+    fn iterate_column_major() {
+        let mut matrix = test_matrix();
+        let view = matrix.column_major_view().unwrap();
+        let elements: Vec<(usize, usize, i32)> = view
+            .iter()
+            .map(|(row, column, value)| (row, column, *value))
+            .collect();
+        assert_eq!(elements, vec![(2, 0, 2), (0, 1, 1), (0, 2, 4), (2, 3, 3)]);
+        view.restore().unwrap();
+
+        assert_eq!(matrix.size().unwrap(), Size::new(3, 4));
+        assert_eq!(matrix.number_of_stored_elements().unwrap(), 4);
+    }
+}
@@ -195,7 +195,7 @@ pub trait FromElementList<
     fn from_element_list(
         context: Arc<Context>,
         elements: MatrixElementList<T>,
-        reduction_operator_for_duplicates: &dyn BinaryOperator<T, T, T>, // TODO: the operater could be included in the trait bound for better runtime performance
+        reduction_operator_for_duplicates: &(impl BinaryOperator<T, T, T> + ?Sized),
                                                                          // reduction_operator_for_duplicates: Box<dyn BinaryOperator<T, T, T>>,
     ) -> Result<SparseMatrix<T, RowHeight, ColumnWidth>, SparseLinearAlgebraError>;
 }
@@ -255,11 +255,11 @@ macro_rules! sparse_matrix_from_element_vector {
             fn from_element_list(
                 context: Arc<Context>,
                 elements: MatrixElementList<$value_type>,
-                reduction_operator_for_duplicates: &dyn BinaryOperator<
+                reduction_operator_for_duplicates: &(impl BinaryOperator<
                     $value_type,
                     $value_type,
                     $value_type,
-                >,
+                > + ?Sized),
             ) -> Result<Self, SparseLinearAlgebraError> {
                 let matrix = SparseMatrix::<$value_type, RowHeight, ColumnWidth>::new(context)?;
 
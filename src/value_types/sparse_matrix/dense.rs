@@ -0,0 +1,277 @@
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::sync::Arc;
+
+use crate::bindings_to_graphblas_implementation::{
+    GrB_Index, GrB_Matrix, GrB_Matrix_dup, GrB_Type, GxB_Matrix_export_BitmapR,
+    GxB_Matrix_import_BitmapR, GxB_Matrix_import_FullR,
+};
+use crate::context::Context;
+use crate::error::{GraphBlasError, GraphBlasErrorType, SparseLinearAlgebraError};
+use crate::util::{
+    copy_from_graphblas_owned_array, copy_to_graphblas_owned_array, free_graphblas_owned_array,
+    ElementIndex, IndexConversion,
+};
+use crate::value_types::value_type::{BuiltInValueType, ValueType};
+
+use super::size::Size;
+use super::sparse_matrix::SparseMatrix;
+
+pub trait FromDenseMatrix<T: ValueType> {
+    /// Stores every value. Values are in row-major order, values.len() must equal the number of elements in size.
+    fn from_dense(
+        context: &Arc<Context>,
+        values: &[T],
+        size: &Size,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+
+    /// Stores values[i] for every i for which is_implicit[i] is false.
+    /// Values and is_implicit are in row-major order.
+    fn from_dense_with_implicit(
+        context: &Arc<Context>,
+        values: &[T],
+        is_implicit: &[bool],
+        size: &Size,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>;
+}
+
+pub trait ToDenseMatrix<T: ValueType> {
+    /// Returns a value for every coordinate of the matrix in row-major order, implicit values are set to fill,
+    /// together with the size of the matrix.
+    fn to_dense(&self, fill: T) -> Result<(Vec<T>, Size), SparseLinearAlgebraError>;
+}
+
+fn check_number_of_dense_elements(
+    name: &str,
+    number_of_elements: usize,
+    size: &Size,
+) -> Result<(), SparseLinearAlgebraError> {
+    let number_of_elements_in_size = match size.row_height().checked_mul(size.column_width()) {
+        Some(number_of_elements_in_size) => number_of_elements_in_size,
+        None => {
+            return Err(GraphBlasError::new(
+                GraphBlasErrorType::DimensionMismatch,
+                format!(
+                    "A matrix of size {:?} has too many elements to be stored densely",
+                    size
+                ),
+            )
+            .into())
+        }
+    };
+    if number_of_elements != number_of_elements_in_size {
+        return Err(GraphBlasError::new(
+            GraphBlasErrorType::DimensionMismatch,
+            format!(
+                "{}.len() = {} must equal the number of elements in a matrix of size {:?}",
+                name, number_of_elements, size
+            ),
+        )
+        .into());
+    }
+    Ok(())
+}
+
+impl<T: ValueType + BuiltInValueType<T> + Copy> FromDenseMatrix<T> for SparseMatrix<T> {
+    fn from_dense(
+        context: &Arc<Context>,
+        values: &[T],
+        size: &Size,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        check_number_of_dense_elements("values", values.len(), size)?;
+
+        let row_height = size.row_height().to_graphblas_index()?;
+        let column_width = size.column_width().to_graphblas_index()?;
+        let (mut graphblas_values, values_size) = copy_to_graphblas_owned_array(values)?;
+        let mut matrix: MaybeUninit<GrB_Matrix> = MaybeUninit::uninit();
+
+        // On success, GraphBLAS takes ownership of graphblas_values and sets the pointer to null
+        let result = context.call(|| unsafe {
+            GxB_Matrix_import_FullR(
+                matrix.as_mut_ptr(),
+                <T>::to_graphblas_type(),
+                row_height,
+                column_width,
+                &mut graphblas_values as *mut *mut T as *mut *mut std::ffi::c_void,
+                values_size,
+                ptr::null_mut(),
+            )
+        });
+        if let Err(error) = result {
+            unsafe { free_graphblas_owned_array(graphblas_values) };
+            return Err(error);
+        }
+
+        Ok(SparseMatrix::from_graphblas_matrix(context, unsafe {
+            matrix.assume_init()
+        }))
+    }
+
+    fn from_dense_with_implicit(
+        context: &Arc<Context>,
+        values: &[T],
+        is_implicit: &[bool],
+        size: &Size,
+    ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError> {
+        check_number_of_dense_elements("values", values.len(), size)?;
+        check_number_of_dense_elements("is_implicit", is_implicit.len(), size)?;
+
+        let bitmap: Vec<i8> = is_implicit
+            .iter()
+            .map(|implicit| if *implicit { 0 } else { 1 })
+            .collect();
+        let number_of_stored_elements = is_implicit
+            .iter()
+            .filter(|implicit| !**implicit)
+            .count()
+            .to_graphblas_index()?;
+
+        let row_height = size.row_height().to_graphblas_index()?;
+        let column_width = size.column_width().to_graphblas_index()?;
+        let (mut graphblas_bitmap, bitmap_size) = copy_to_graphblas_owned_array(&bitmap)?;
+        let (mut graphblas_values, values_size) = match copy_to_graphblas_owned_array(values) {
+            Ok(array) => array,
+            Err(error) => {
+                unsafe { free_graphblas_owned_array(graphblas_bitmap) };
+                return Err(error);
+            }
+        };
+        let mut matrix: MaybeUninit<GrB_Matrix> = MaybeUninit::uninit();
+
+        let result = context.call(|| unsafe {
+            GxB_Matrix_import_BitmapR(
+                matrix.as_mut_ptr(),
+                <T>::to_graphblas_type(),
+                row_height,
+                column_width,
+                &mut graphblas_bitmap,
+                &mut graphblas_values as *mut *mut T as *mut *mut std::ffi::c_void,
+                bitmap_size,
+                values_size,
+                number_of_stored_elements,
+                ptr::null_mut(),
+            )
+        });
+        if let Err(error) = result {
+            unsafe {
+                free_graphblas_owned_array(graphblas_bitmap);
+                free_graphblas_owned_array(graphblas_values);
+            }
+            return Err(error);
+        }
+
+        Ok(SparseMatrix::from_graphblas_matrix(context, unsafe {
+            matrix.assume_init()
+        }))
+    }
+}
+
+impl<T: ValueType + BuiltInValueType<T> + Copy> ToDenseMatrix<T> for SparseMatrix<T> {
+    fn to_dense(&self, fill: T) -> Result<(Vec<T>, Size), SparseLinearAlgebraError> {
+        let context = self.context_ref();
+
+        // Exporting consumes the GraphBLAS matrix, so export a copy
+        let mut copy: MaybeUninit<GrB_Matrix> = MaybeUninit::uninit();
+        context.call(|| unsafe { GrB_Matrix_dup(copy.as_mut_ptr(), self.graphblas_matrix()) })?;
+        let mut copy = unsafe { copy.assume_init() };
+
+        let mut value_type: MaybeUninit<GrB_Type> = MaybeUninit::uninit();
+        let mut row_height: GrB_Index = 0;
+        let mut column_width: GrB_Index = 0;
+        let mut graphblas_bitmap: *mut i8 = ptr::null_mut();
+        let mut graphblas_values: *mut T = ptr::null_mut();
+        let mut bitmap_size: GrB_Index = 0;
+        let mut values_size: GrB_Index = 0;
+        let mut number_of_stored_elements: GrB_Index = 0;
+
+        let result = context.call(|| unsafe {
+            GxB_Matrix_export_BitmapR(
+                &mut copy,
+                value_type.as_mut_ptr(),
+                &mut row_height,
+                &mut column_width,
+                &mut graphblas_bitmap,
+                &mut graphblas_values as *mut *mut T as *mut *mut std::ffi::c_void,
+                &mut bitmap_size,
+                &mut values_size,
+                &mut number_of_stored_elements,
+                ptr::null_mut(),
+            )
+        });
+        if let Err(error) = result {
+            // The copy is only consumed on success
+            let _ = SparseMatrix::<T>::from_graphblas_matrix(context, copy);
+            return Err(error);
+        }
+
+        let size = Size::new(
+            ElementIndex::from_graphblas_index(row_height)?,
+            ElementIndex::from_graphblas_index(column_width)?,
+        );
+        // GraphBLAS allocated the bitmap, so the number of elements fits
+        let number_of_elements = size.row_height() * size.column_width();
+        let dense = unsafe {
+            let bitmap = copy_from_graphblas_owned_array(graphblas_bitmap, number_of_elements);
+            let values = copy_from_graphblas_owned_array(graphblas_values, number_of_elements);
+            free_graphblas_owned_array(graphblas_bitmap);
+            free_graphblas_owned_array(graphblas_values);
+
+            bitmap
+                .into_iter()
+                .zip(values)
+                .map(|(is_stored, value)| if is_stored != 0 { value } else { fill })
+                .collect()
+        };
+        Ok((dense, size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::context::Mode;
+    use crate::value_types::sparse_matrix::{Coordinate, GetMatrixElementValue};
+
+    #[test]
+    fn dense_matrix_round_trip() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let values: Vec<u16> = vec![1, 2, 3, 4, 5, 6];
+        let matrix = SparseMatrix::<u16>::from_dense(&context, &values, &(2, 3).into()).unwrap();
+
+        assert_eq!(matrix.number_of_stored_elements().unwrap(), 6);
+        assert_eq!(matrix.get_element_value(&Coordinate::new(1, 0)).unwrap(), 4);
+        assert_eq!(matrix.to_dense(0).unwrap(), (values, Size::new(2, 3)));
+    }
+
+    #[test]
+    fn dense_matrix_with_implicit_values() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let matrix = SparseMatrix::<f32>::from_dense_with_implicit(
+            &context,
+            &[1.0, 2.0, 3.0, 4.0],
+            &[true, false, false, true],
+            &(2, 2).into(),
+        )
+        .unwrap();
+
+        assert_eq!(matrix.number_of_stored_elements().unwrap(), 2);
+        let (values, size) = matrix.to_dense(0.0).unwrap();
+        assert_eq!(values, vec![0.0, 2.0, 3.0, 0.0]);
+        assert_eq!(size, Size::new(2, 2));
+    }
+
+    #[test]
+    fn dense_matrix_with_wrong_number_of_values() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let result = SparseMatrix::<u8>::from_dense(&context, &[1, 2, 3], &(2, 2).into());
+        assert!(result.is_err());
+
+        let result =
+            SparseMatrix::<u8>::from_dense(&context, &[1, 2, 3], &(usize::MAX / 2, 3).into());
+        assert!(result.is_err());
+    }
+}
@@ -1,13 +1,19 @@
+mod compressed_view;
 mod coordinate;
+mod dense;
 mod element;
 mod size;
 mod sparse_matrix;
 // mod constant_sized_sparse_matrix;
 
+pub use compressed_view::{
+    CompressedOrientation, CompressedSparseMatrixView, CompressedVectors, StoredMatrixElements,
+};
 pub use coordinate::Coordinate;
+pub use dense::{FromDenseMatrix, ToDenseMatrix};
 pub use element::{MatrixElement, MatrixElementList};
 pub use size::Size;
 pub use sparse_matrix::{
     FromMatrixElementList, GetMatrixElement, GetMatrixElementList, GetMatrixElementValue,
-    SetMatrixElement, SparseMatrix,
+    SetMatrixElement, SetMatrixElementList, SparseMatrix,
 };
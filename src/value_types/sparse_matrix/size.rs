@@ -24,6 +24,11 @@ impl Size {
     pub fn column_width(&self) -> ElementIndex {
         self.column_width
     }
+
+    /// The size of the transposed matrix
+    pub fn transpose(&self) -> Self {
+        Self::new(self.column_width, self.row_height)
+    }
 }
 
 impl From<(ElementIndex, ElementIndex)> for Size {
@@ -4,6 +4,7 @@ use crate::error::{
 };
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
+use std::ptr;
 use std::sync::Arc;
 
 use super::coordinate::Coordinate;
@@ -14,7 +15,8 @@ use crate::bindings_to_graphblas_implementation::{
     GrB_Matrix_build_FP64, GrB_Matrix_build_INT16, GrB_Matrix_build_INT32,
     GrB_Matrix_build_INT64, GrB_Matrix_build_INT8, GrB_Matrix_build_UINT16,
     GrB_Matrix_build_UINT32, GrB_Matrix_build_UINT64, GrB_Matrix_build_UINT8,
-    GrB_Matrix_clear, GrB_Matrix_dup, GrB_Matrix_extractElement_BOOL,
+    GrB_Matrix_clear, GrB_Matrix_dup, GrB_Matrix_eWiseAdd_BinaryOp,
+    GrB_Matrix_extractElement_BOOL,
     GrB_Matrix_extractElement_FP32, GrB_Matrix_extractElement_FP64,
     GrB_Matrix_extractElement_INT16, GrB_Matrix_extractElement_INT32,
     GrB_Matrix_extractElement_INT64, GrB_Matrix_extractElement_INT8,
@@ -126,6 +128,23 @@ impl<T: ValueType> SparseMatrix<T> {
         self.matrix
     }
 
+    /// Mutable access to the GrB_Matrix handle, for example to export and re-import it.
+    pub(crate) fn graphblas_matrix_mut_ref(&mut self) -> &mut GrB_Matrix {
+        &mut self.matrix
+    }
+
+    /// Takes ownership of the GrB_Matrix, which is freed when the SparseMatrix is dropped.
+    pub(crate) fn from_graphblas_matrix(
+        context: &Arc<Context>,
+        matrix: GrB_Matrix,
+    ) -> Self {
+        SparseMatrix {
+            context: context.clone(),
+            matrix,
+            value_type: PhantomData,
+        }
+    }
+
     /// All elements of self with an index coordinate outside of the new size are dropped.
     pub fn resize(
         &mut self,
@@ -282,7 +301,7 @@ pub trait FromMatrixElementList<T: ValueType> {
         context: &Arc<Context>,
         size: &Size,
         elements: &MatrixElementList<T>,
-        reduction_operator_for_duplicates: &dyn BinaryOperator<T, T, T>,
+        reduction_operator_for_duplicates: &(impl BinaryOperator<T, T, T> + ?Sized),
         // reduction_operator_for_duplicates: Box<dyn BinaryOperator<T, T, T>>,
     ) -> Result<SparseMatrix<T>, SparseLinearAlgebraError>;
 }
@@ -340,11 +359,11 @@ macro_rules! sparse_matrix_from_element_vector {
                 context: &Arc<Context>,
                 size: &Size,
                 elements: &MatrixElementList<$value_type>,
-                reduction_operator_for_duplicates: &dyn BinaryOperator<
+                reduction_operator_for_duplicates: &(impl BinaryOperator<
                     $value_type,
                     $value_type,
                     $value_type,
-                >,
+                > + ?Sized),
             ) -> Result<Self, SparseLinearAlgebraError> {
                 // TODO: check for duplicates
                 // TODO: check size constraints
@@ -453,6 +472,56 @@ implement_set_element!(u64, GrB_Matrix_setElement_UINT64);
 implement_set_element!(f32, GrB_Matrix_setElement_FP32);
 implement_set_element!(f64, GrB_Matrix_setElement_FP64);
 
+/// Inserts or updates many elements at once, instead of calling GraphBLAS for each element.
+///
+/// The reduction operator combines duplicate elements in the list, as in from_element_list.
+/// SuiteSparse:GraphBLAS combines the duplicates in the order of the list, such that First keeps
+/// the earliest and Second the latest of them.
+/// The operator for stored elements then combines the value already stored at a coordinate with
+/// the value from the list, as in Z = operator(existing, new), and may be any binary operator.
+/// Second replaces stored values, Plus adds to them, Minus subtracts from them.
+pub trait SetMatrixElementList<T: ValueType> {
+    fn set_element_list(
+        &mut self,
+        elements: &MatrixElementList<T>,
+        reduction_operator_for_duplicates: &(impl BinaryOperator<T, T, T> + ?Sized),
+        operator_for_stored_elements: &(impl BinaryOperator<T, T, T> + ?Sized),
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType + BuiltInValueType<T>> SetMatrixElementList<T> for SparseMatrix<T>
+where
+    SparseMatrix<T>: FromMatrixElementList<T>,
+{
+    fn set_element_list(
+        &mut self,
+        elements: &MatrixElementList<T>,
+        reduction_operator_for_duplicates: &(impl BinaryOperator<T, T, T> + ?Sized),
+        operator_for_stored_elements: &(impl BinaryOperator<T, T, T> + ?Sized),
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let elements_to_set = SparseMatrix::<T>::from_element_list(
+            &self.context,
+            &self.size()?,
+            elements,
+            reduction_operator_for_duplicates,
+        )?;
+
+        let context = self.context.clone();
+        context.call(|| unsafe {
+            GrB_Matrix_eWiseAdd_BinaryOp(
+                self.matrix,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                operator_for_stored_elements.graphblas_type(),
+                self.matrix,
+                elements_to_set.matrix,
+                ptr::null_mut(),
+            )
+        })?;
+        Ok(())
+    }
+}
+
 pub trait GetMatrixElementValue<T: ValueType + Default> {
     fn get_element_value(
         &self,
@@ -678,7 +747,7 @@ mod tests {
     use super::*;
     use crate::context::Mode;
     use crate::error::LogicErrorType;
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, Minus, Plus, Second};
 
     #[test]
     fn new_matrix() {
@@ -822,6 +891,74 @@ mod tests {
         }
     }
 
+    #[test]
+    fn set_element_list_in_matrix() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let mut sparse_matrix = SparseMatrix::<i32>::from_element_list(
+            &context,
+            &(3, 5).into(),
+            &MatrixElementList::<i32>::from_element_vector(vec![
+                (1, 1, 1).into(),
+                (2, 4, 10).into(),
+            ]),
+            &First::<i32, i32, i32>::new(),
+        )
+        .unwrap();
+
+        let elements = MatrixElementList::<i32>::from_element_vector(vec![
+            (2, 4, 2).into(),
+            (0, 0, 3).into(),
+            (0, 0, 4).into(), // duplicate, of which Second keeps the later value
+        ]);
+
+        let mut updated_matrix = sparse_matrix.clone();
+        updated_matrix
+            .set_element_list(
+                &elements,
+                &Second::<i32, i32, i32>::new(),
+                &Second::<i32, i32, i32>::new(),
+            )
+            .unwrap();
+        assert_eq!(3, updated_matrix.number_of_stored_elements().unwrap());
+        assert_eq!(1, updated_matrix.get_element_value(&(1, 1).into()).unwrap());
+        assert_eq!(2, updated_matrix.get_element_value(&(2, 4).into()).unwrap());
+        assert_eq!(4, updated_matrix.get_element_value(&(0, 0).into()).unwrap());
+
+        sparse_matrix
+            .set_element_list(
+                &elements,
+                &Plus::<i32, i32, i32>::new(),
+                &Plus::<i32, i32, i32>::new(),
+            )
+            .unwrap();
+        assert_eq!(3, sparse_matrix.number_of_stored_elements().unwrap());
+        assert_eq!(12, sparse_matrix.get_element_value(&(2, 4).into()).unwrap());
+        assert_eq!(7, sparse_matrix.get_element_value(&(0, 0).into()).unwrap());
+
+        sparse_matrix
+            .set_element_list(
+                &elements,
+                &Second::<i32, i32, i32>::new(),
+                &Minus::<i32, i32, i32>::new(),
+            )
+            .unwrap();
+        assert_eq!(3, sparse_matrix.number_of_stored_elements().unwrap());
+        assert_eq!(10, sparse_matrix.get_element_value(&(2, 4).into()).unwrap());
+        assert_eq!(3, sparse_matrix.get_element_value(&(0, 0).into()).unwrap());
+
+        let out_of_bounds = MatrixElementList::<i32>::from_element_vector(vec![
+            (3, 0, 1).into(),
+        ]);
+        assert!(sparse_matrix
+            .set_element_list(
+                &out_of_bounds,
+                &Second::<i32, i32, i32>::new(),
+                &Second::<i32, i32, i32>::new(),
+            )
+            .is_err());
+    }
+
     #[test]
     fn remove_element_from_matrix() {
         let context = Context::init_ready(Mode::NonBlocking).unwrap();
@@ -0,0 +1,224 @@
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::sync::Arc;
+
+use crate::bindings_to_graphblas_implementation::{
+    GrB_Index, GrB_Type, GrB_Vector, GrB_Vector_dup, GxB_Vector_export_Bitmap,
+    GxB_Vector_import_Bitmap, GxB_Vector_import_Full,
+};
+use crate::context::Context;
+use crate::error::{GraphBlasError, GraphBlasErrorType, SparseLinearAlgebraError};
+use crate::util::{
+    copy_from_graphblas_owned_array, copy_to_graphblas_owned_array, free_graphblas_owned_array,
+    ElementIndex, IndexConversion,
+};
+use crate::value_types::value_type::{BuiltInValueType, ValueType};
+
+use super::sparse_vector::SparseVector;
+
+pub trait FromDenseVector<T: ValueType> {
+    /// Stores every value, the length of the vector equals values.len().
+    fn from_dense(
+        context: &Arc<Context>,
+        values: &[T],
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>;
+
+    /// Stores values[i] for every i for which is_implicit[i] is false.
+    fn from_dense_with_implicit(
+        context: &Arc<Context>,
+        values: &[T],
+        is_implicit: &[bool],
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError>;
+}
+
+pub trait ToDenseVector<T: ValueType> {
+    /// Returns a value for every index of the vector, implicit values are set to fill.
+    fn to_dense(&self, fill: T) -> Result<Vec<T>, SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType + BuiltInValueType<T> + Copy> FromDenseVector<T> for SparseVector<T> {
+    fn from_dense(
+        context: &Arc<Context>,
+        values: &[T],
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        let length = values.len().to_graphblas_index()?;
+        let (mut graphblas_values, values_size) = copy_to_graphblas_owned_array(values)?;
+        let mut vector: MaybeUninit<GrB_Vector> = MaybeUninit::uninit();
+
+        // On success, GraphBLAS takes ownership of graphblas_values and sets the pointer to null
+        let result = context.call(|| unsafe {
+            GxB_Vector_import_Full(
+                vector.as_mut_ptr(),
+                <T>::to_graphblas_type(),
+                length,
+                &mut graphblas_values as *mut *mut T as *mut *mut std::ffi::c_void,
+                values_size,
+                ptr::null_mut(),
+            )
+        });
+        if let Err(error) = result {
+            unsafe { free_graphblas_owned_array(graphblas_values) };
+            return Err(error);
+        }
+
+        Ok(SparseVector::from_graphblas_vector(context, unsafe {
+            vector.assume_init()
+        }))
+    }
+
+    fn from_dense_with_implicit(
+        context: &Arc<Context>,
+        values: &[T],
+        is_implicit: &[bool],
+    ) -> Result<SparseVector<T>, SparseLinearAlgebraError> {
+        if values.len() != is_implicit.len() {
+            return Err(GraphBlasError::new(
+                GraphBlasErrorType::DimensionMismatch,
+                format!(
+                    "Length of values and is_implicit must be equal: values.len() = {}, is_implicit.len() = {}",
+                    values.len(),
+                    is_implicit.len()
+                ),
+            )
+            .into());
+        }
+
+        let bitmap: Vec<i8> = is_implicit
+            .iter()
+            .map(|implicit| if *implicit { 0 } else { 1 })
+            .collect();
+        let number_of_stored_elements = is_implicit
+            .iter()
+            .filter(|implicit| !**implicit)
+            .count()
+            .to_graphblas_index()?;
+
+        let length = values.len().to_graphblas_index()?;
+        let (mut graphblas_bitmap, bitmap_size) = copy_to_graphblas_owned_array(&bitmap)?;
+        let (mut graphblas_values, values_size) = match copy_to_graphblas_owned_array(values) {
+            Ok(array) => array,
+            Err(error) => {
+                unsafe { free_graphblas_owned_array(graphblas_bitmap) };
+                return Err(error);
+            }
+        };
+        let mut vector: MaybeUninit<GrB_Vector> = MaybeUninit::uninit();
+
+        let result = context.call(|| unsafe {
+            GxB_Vector_import_Bitmap(
+                vector.as_mut_ptr(),
+                <T>::to_graphblas_type(),
+                length,
+                &mut graphblas_bitmap,
+                &mut graphblas_values as *mut *mut T as *mut *mut std::ffi::c_void,
+                bitmap_size,
+                values_size,
+                number_of_stored_elements,
+                ptr::null_mut(),
+            )
+        });
+        if let Err(error) = result {
+            unsafe {
+                free_graphblas_owned_array(graphblas_bitmap);
+                free_graphblas_owned_array(graphblas_values);
+            }
+            return Err(error);
+        }
+
+        Ok(SparseVector::from_graphblas_vector(context, unsafe {
+            vector.assume_init()
+        }))
+    }
+}
+
+impl<T: ValueType + BuiltInValueType<T> + Copy> ToDenseVector<T> for SparseVector<T> {
+    fn to_dense(&self, fill: T) -> Result<Vec<T>, SparseLinearAlgebraError> {
+        let context = self.context_ref();
+
+        // Exporting consumes the GraphBLAS vector, so export a copy
+        let mut copy: MaybeUninit<GrB_Vector> = MaybeUninit::uninit();
+        context.call(|| unsafe { GrB_Vector_dup(copy.as_mut_ptr(), self.graphblas_vector()) })?;
+        let mut copy = unsafe { copy.assume_init() };
+
+        let mut value_type: MaybeUninit<GrB_Type> = MaybeUninit::uninit();
+        let mut length: GrB_Index = 0;
+        let mut graphblas_bitmap: *mut i8 = ptr::null_mut();
+        let mut graphblas_values: *mut T = ptr::null_mut();
+        let mut bitmap_size: GrB_Index = 0;
+        let mut values_size: GrB_Index = 0;
+        let mut number_of_stored_elements: GrB_Index = 0;
+
+        let result = context.call(|| unsafe {
+            GxB_Vector_export_Bitmap(
+                &mut copy,
+                value_type.as_mut_ptr(),
+                &mut length,
+                &mut graphblas_bitmap,
+                &mut graphblas_values as *mut *mut T as *mut *mut std::ffi::c_void,
+                &mut bitmap_size,
+                &mut values_size,
+                &mut number_of_stored_elements,
+                ptr::null_mut(),
+            )
+        });
+        if let Err(error) = result {
+            // The copy is only consumed on success
+            let _ = SparseVector::<T>::from_graphblas_vector(context, copy);
+            return Err(error);
+        }
+
+        let length = ElementIndex::from_graphblas_index(length)?;
+        let dense = unsafe {
+            let bitmap = copy_from_graphblas_owned_array(graphblas_bitmap, length);
+            let values = copy_from_graphblas_owned_array(graphblas_values, length);
+            free_graphblas_owned_array(graphblas_bitmap);
+            free_graphblas_owned_array(graphblas_values);
+
+            bitmap
+                .into_iter()
+                .zip(values)
+                .map(|(is_stored, value)| if is_stored != 0 { value } else { fill })
+                .collect()
+        };
+        Ok(dense)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::context::Mode;
+    use crate::value_types::sparse_vector::GetVectorElementValue;
+
+    #[test]
+    fn dense_vector_round_trip() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let values: Vec<f64> = vec![0.5, 1.0, 2.0, 4.0];
+        let vector = SparseVector::<f64>::from_dense(&context, &values).unwrap();
+
+        assert_eq!(vector.length().unwrap(), 4);
+        assert_eq!(vector.number_of_stored_elements().unwrap(), 4);
+        assert_eq!(vector.get_element_value(&2).unwrap(), 2.0);
+        assert_eq!(vector.to_dense(-1.0).unwrap(), values);
+    }
+
+    #[test]
+    fn dense_vector_with_implicit_values() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let vector = SparseVector::<i32>::from_dense_with_implicit(
+            &context,
+            &[1, 2, 3, 4],
+            &[false, true, true, false],
+        )
+        .unwrap();
+
+        assert_eq!(vector.number_of_stored_elements().unwrap(), 2);
+        assert_eq!(vector.to_dense(-1).unwrap(), vec![1, -1, -1, 4]);
+
+        let empty_vector = SparseVector::<i32>::new(&context, &3).unwrap();
+        assert_eq!(empty_vector.to_dense(7).unwrap(), vec![7, 7, 7]);
+    }
+}
@@ -1,8 +1,12 @@
+mod dense;
 mod element;
 mod sparse_vector;
+mod sparse_view;
 
+pub use dense::{FromDenseVector, ToDenseVector};
 pub use element::{VectorElement, VectorElementList};
 pub use sparse_vector::{
     FromVectorElementList, GetVectorElement, GetVectorElementList, GetVectorElementValue,
-    SetVectorElement, SparseVector,
+    SetVectorElement, SetVectorElementList, SparseVector,
 };
+pub use sparse_view::{SparseVectorView, StoredVectorElements};
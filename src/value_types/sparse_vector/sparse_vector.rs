@@ -1,5 +1,6 @@
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
+use std::ptr;
 use std::sync::Arc;
 
 use crate::error::{
@@ -13,7 +14,8 @@ use crate::bindings_to_graphblas_implementation::{
     GrB_Vector_build_FP64, GrB_Vector_build_INT16, GrB_Vector_build_INT32,
     GrB_Vector_build_INT64, GrB_Vector_build_INT8, GrB_Vector_build_UINT16,
     GrB_Vector_build_UINT32, GrB_Vector_build_UINT64, GrB_Vector_build_UINT8,
-    GrB_Vector_clear, GrB_Vector_dup, GrB_Vector_extractElement_BOOL,
+    GrB_Vector_clear, GrB_Vector_dup, GrB_Vector_eWiseAdd_BinaryOp,
+    GrB_Vector_extractElement_BOOL,
     GrB_Vector_extractElement_FP32, GrB_Vector_extractElement_FP64,
     GrB_Vector_extractElement_INT16, GrB_Vector_extractElement_INT32,
     GrB_Vector_extractElement_INT64, GrB_Vector_extractElement_INT8,
@@ -188,6 +190,23 @@ impl<T: ValueType> SparseVector<T> {
     pub fn graphblas_vector(&self) -> GrB_Vector {
         self.vector.clone()
     }
+
+    /// Mutable access to the GrB_Vector handle, for example to export and re-import it.
+    pub(crate) fn graphblas_vector_mut_ref(&mut self) -> &mut GrB_Vector {
+        &mut self.vector
+    }
+
+    /// Takes ownership of the GrB_Vector, which is freed when the SparseVector is dropped.
+    pub(crate) fn from_graphblas_vector(
+        context: &Arc<Context>,
+        vector: GrB_Vector,
+    ) -> Self {
+        SparseVector {
+            context: context.clone(),
+            vector,
+            value_type: PhantomData,
+        }
+    }
 }
 
 impl<T: ValueType> Drop for SparseVector<T> {
@@ -265,7 +284,7 @@ pub trait FromVectorElementList<T: ValueType> {
         context: &Arc<Context>,
         lenth: &ElementIndex,
         elements: &VectorElementList<T>,
-        reduction_operator_for_duplicates: &dyn BinaryOperator<T, T, T>,
+        reduction_operator_for_duplicates: &(impl BinaryOperator<T, T, T> + ?Sized),
         // reduction_operator_for_duplicates: Box<dyn BinaryOperator<T, T, T>>,
     ) -> Result<SparseVector<T>, SparseLinearAlgebraError>;
 }
@@ -277,11 +296,11 @@ macro_rules! sparse_matrix_from_element_vector {
                 context: &Arc<Context>,
                 length: &ElementIndex,
                 elements: &VectorElementList<$value_type>,
-                reduction_operator_for_duplicates: &dyn BinaryOperator<
+                reduction_operator_for_duplicates: &(impl BinaryOperator<
                     $value_type,
                     $value_type,
                     $value_type,
-                >,
+                > + ?Sized),
             ) -> Result<Self, SparseLinearAlgebraError> {
                 // TODO: check for duplicates
                 // TODO: check size constraints
@@ -403,6 +422,50 @@ macro_rules! implement_set_element_for_custom_type {
 // implement_set_element_for_custom_type!(i128);
 // implement_set_element_for_custom_type!(u128);
 
+/// Inserts or updates many elements at once, see SetMatrixElementList.
+/// Duplicates in the list are combined in the order of the list.
+pub trait SetVectorElementList<T: ValueType> {
+    fn set_element_list(
+        &mut self,
+        elements: &VectorElementList<T>,
+        reduction_operator_for_duplicates: &(impl BinaryOperator<T, T, T> + ?Sized),
+        operator_for_stored_elements: &(impl BinaryOperator<T, T, T> + ?Sized),
+    ) -> Result<(), SparseLinearAlgebraError>;
+}
+
+impl<T: ValueType + BuiltInValueType<T>> SetVectorElementList<T> for SparseVector<T>
+where
+    SparseVector<T>: FromVectorElementList<T>,
+{
+    fn set_element_list(
+        &mut self,
+        elements: &VectorElementList<T>,
+        reduction_operator_for_duplicates: &(impl BinaryOperator<T, T, T> + ?Sized),
+        operator_for_stored_elements: &(impl BinaryOperator<T, T, T> + ?Sized),
+    ) -> Result<(), SparseLinearAlgebraError> {
+        let elements_to_set = SparseVector::<T>::from_element_list(
+            &self.context,
+            &self.length()?,
+            elements,
+            reduction_operator_for_duplicates,
+        )?;
+
+        let context = self.context.clone();
+        context.call(|| unsafe {
+            GrB_Vector_eWiseAdd_BinaryOp(
+                self.vector,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                operator_for_stored_elements.graphblas_type(),
+                self.vector,
+                elements_to_set.vector,
+                ptr::null_mut(),
+            )
+        })?;
+        Ok(())
+    }
+}
+
 pub trait GetVectorElementValue<T: ValueType + Default> {
     fn get_element_value(
         &self,
@@ -637,7 +700,7 @@ mod tests {
     use super::*;
     use crate::context::Mode;
     use crate::error::LogicErrorType;
-    use crate::operators::binary_operator::First;
+    use crate::operators::binary_operator::{First, Plus, Second};
 
     #[test]
     fn new_vector() {
@@ -764,6 +827,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn set_element_list_in_vector() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+
+        let mut sparse_vector = SparseVector::<i32>::from_element_list(
+            &context,
+            &10,
+            &VectorElementList::<i32>::from_element_vector(vec![
+                (1, 1).into(),
+                (4, 10).into(),
+            ]),
+            &First::<i32, i32, i32>::new(),
+        )
+        .unwrap();
+
+        let elements = VectorElementList::<i32>::from_element_vector(vec![
+            (4, 2).into(),
+            (0, 3).into(),
+            (0, 4).into(), // duplicate, of which Second keeps the later value
+        ]);
+
+        let mut updated_vector = sparse_vector.clone();
+        updated_vector
+            .set_element_list(
+                &elements,
+                &Second::<i32, i32, i32>::new(),
+                &Second::<i32, i32, i32>::new(),
+            )
+            .unwrap();
+        assert_eq!(3, updated_vector.number_of_stored_elements().unwrap());
+        assert_eq!(1, updated_vector.get_element_value(&1).unwrap());
+        assert_eq!(2, updated_vector.get_element_value(&4).unwrap());
+        assert_eq!(4, updated_vector.get_element_value(&0).unwrap());
+
+        sparse_vector
+            .set_element_list(
+                &elements,
+                &Plus::<i32, i32, i32>::new(),
+                &Plus::<i32, i32, i32>::new(),
+            )
+            .unwrap();
+        assert_eq!(3, sparse_vector.number_of_stored_elements().unwrap());
+        assert_eq!(12, sparse_vector.get_element_value(&4).unwrap());
+        assert_eq!(7, sparse_vector.get_element_value(&0).unwrap());
+    }
+
     // #[test]
     // fn set_element_in_vector_custom_type() {
     //     let context = Context::init_ready(Mode::NonBlocking).unwrap();
@@ -0,0 +1,205 @@
+use std::ptr;
+
+use crate::bindings_to_graphblas_implementation::{
+    GrB_Index, GrB_Type, GxB_Vector_export_CSC, GxB_Vector_import_CSC,
+};
+use crate::error::SparseLinearAlgebraError;
+use crate::util::{
+    check_element_index_width, free_graphblas_owned_array, graphblas_owned_slice, ElementIndex,
+};
+use crate::value_types::value_type::{BuiltInValueType, ValueType};
+
+use super::sparse_vector::SparseVector;
+
+/// Read-only view on the stored elements of a SparseVector, exported from GraphBLAS without copying.
+/// The vector is imported back into GraphBLAS when the view is restored or dropped.
+pub struct SparseVectorView<'a, T: ValueType> {
+    vector: &'a mut SparseVector<T>,
+    is_restored: bool,
+
+    value_type: GrB_Type,
+    length: GrB_Index,
+    indices: *mut GrB_Index,
+    values: *mut T,
+    indices_size: GrB_Index,
+    values_size: GrB_Index,
+    number_of_stored_elements: GrB_Index,
+}
+
+impl<T: ValueType + BuiltInValueType<T>> SparseVector<T> {
+    /// Exports the stored elements of the vector, sorted by index.
+    /// The vector cannot be used until the view is restored or dropped.
+    pub fn sparse_view(&mut self) -> Result<SparseVectorView<'_, T>, SparseLinearAlgebraError> {
+        check_element_index_width()?;
+
+        let context = self.context();
+        let mut view = SparseVectorView {
+            vector: self,
+            is_restored: true,
+            value_type: ptr::null_mut(),
+            length: 0,
+            indices: ptr::null_mut(),
+            values: ptr::null_mut(),
+            indices_size: 0,
+            values_size: 0,
+            number_of_stored_elements: 0,
+        };
+
+        // Passing a null pointer for jumbled requires GraphBLAS to return sorted indices
+        context.call(|| unsafe {
+            GxB_Vector_export_CSC(
+                view.vector.graphblas_vector_mut_ref(),
+                &mut view.value_type,
+                &mut view.length,
+                &mut view.indices,
+                &mut view.values as *mut *mut T as *mut *mut std::ffi::c_void,
+                &mut view.indices_size,
+                &mut view.values_size,
+                &mut view.number_of_stored_elements,
+                ptr::null_mut(),
+                ptr::null_mut(),
+            )
+        })?;
+        view.is_restored = false;
+        Ok(view)
+    }
+}
+
+impl<'a, T: ValueType> SparseVectorView<'a, T> {
+    /// Imports the exported arrays back into the vector.
+    pub fn restore(mut self) -> Result<(), SparseLinearAlgebraError> {
+        self.import()
+    }
+
+    fn import(&mut self) -> Result<(), SparseLinearAlgebraError> {
+        if self.is_restored {
+            return Ok(());
+        }
+        self.is_restored = true;
+
+        let context = self.vector.context();
+        let value_type = self.value_type;
+        let length = self.length;
+        let indices_size = self.indices_size;
+        let values_size = self.values_size;
+        let number_of_stored_elements = self.number_of_stored_elements;
+        let mut indices = self.indices;
+        let mut values = self.values;
+
+        let result = context.call(|| unsafe {
+            GxB_Vector_import_CSC(
+                self.vector.graphblas_vector_mut_ref(),
+                value_type,
+                length,
+                &mut indices,
+                &mut values as *mut *mut T as *mut *mut std::ffi::c_void,
+                indices_size,
+                values_size,
+                number_of_stored_elements,
+                false,
+                ptr::null_mut(),
+            )
+        });
+
+        // GraphBLAS only takes ownership of the arrays on success
+        if result.is_err() {
+            unsafe {
+                free_graphblas_owned_array(indices);
+                free_graphblas_owned_array(values);
+            }
+        }
+        self.indices = ptr::null_mut();
+        self.values = ptr::null_mut();
+        result?;
+        Ok(())
+    }
+
+    pub fn length(&self) -> ElementIndex {
+        self.length as ElementIndex
+    }
+
+    pub fn number_of_stored_elements(&self) -> ElementIndex {
+        self.number_of_stored_elements as ElementIndex
+    }
+
+    pub fn indices_ref(&self) -> &[ElementIndex] {
+        unsafe {
+            graphblas_owned_slice(
+                self.indices as *const ElementIndex,
+                self.number_of_stored_elements(),
+            )
+        }
+    }
+
+    pub fn values_ref(&self) -> &[T] {
+        unsafe { graphblas_owned_slice(self.values, self.number_of_stored_elements()) }
+    }
+
+    /// Iterates over (index, &value) of all stored elements, in order of increasing index.
+    pub fn iter(&self) -> StoredVectorElements<'_, T> {
+        StoredVectorElements {
+            indices: self.indices_ref().iter(),
+            values: self.values_ref().iter(),
+        }
+    }
+}
+
+impl<'a, T: ValueType> Drop for SparseVectorView<'a, T> {
+    fn drop(&mut self) {
+        let _ = self.import();
+    }
+}
+
+pub struct StoredVectorElements<'b, T> {
+    indices: std::slice::Iter<'b, ElementIndex>,
+    values: std::slice::Iter<'b, T>,
+}
+
+impl<'b, T> Iterator for StoredVectorElements<'b, T> {
+    /// (index, &value)
+    type Item = (ElementIndex, &'b T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((*self.indices.next()?, self.values.next()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::context::{Context, Mode};
+    use crate::operators::binary_operator::First;
+    use crate::value_types::sparse_vector::{
+        FromVectorElementList, GetVectorElementValue, VectorElementList,
+    };
+
+    #[test]
+    fn iterate_stored_vector_elements() {
+        let context = Context::init_ready(Mode::NonBlocking).unwrap();
+        let element_list = VectorElementList::<u8>::from_element_vector(vec![
+            (4, 2).into(),
+            (1, 1).into(),
+            (7, 3).into(),
+        ]);
+        let mut vector = SparseVector::<u8>::from_element_list(
+            &context,
+            &10,
+            &element_list,
+            &First::<u8, u8, u8>::new(),
+        )
+        .unwrap();
+
+        {
+            let view = vector.sparse_view().unwrap();
+            let elements: Vec<(usize, u8)> =
+                view.iter().map(|(index, value)| (index, *value)).collect();
+            assert_eq!(elements, vec![(1, 1), (4, 2), (7, 3)]);
+            assert_eq!(view.indices_ref(), &[1, 4, 7]);
+        }
+
+        assert_eq!(vector.length().unwrap(), 10);
+        assert_eq!(vector.number_of_stored_elements().unwrap(), 3);
+        assert_eq!(vector.get_element_value(&7).unwrap(), 3);
+    }
+}